@@ -4,28 +4,54 @@ pub mod check;
 pub mod generate;
 pub mod import;
 pub mod solve;
+pub mod validate;
 
+#[cfg(test)]
+#[path = "../../tests/unit/commands/commands_test.rs"]
+mod commands_test;
+
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
 use std::fs::File;
-use std::io::{stdout, BufWriter, Write};
+use std::io::{stdout, BufWriter, Read, Write};
 use std::process;
 
-fn open_file(path: &str, description: &str) -> File {
-    File::open(path).unwrap_or_else(|err| {
+/// Opens file for reading. A file with `.gz` extension is decompressed transparently.
+fn open_file(path: &str, description: &str) -> Box<dyn Read> {
+    let file = File::open(path).unwrap_or_else(|err| {
         eprintln!("Cannot open {} file '{}': '{}'", description, path, err.to_string());
         process::exit(1);
-    })
+    });
+
+    if is_gzip(path) {
+        Box::new(GzDecoder::new(file))
+    } else {
+        Box::new(file)
+    }
 }
 
-fn create_file(path: &str, description: &str) -> File {
-    File::create(path).unwrap_or_else(|err| {
+/// Creates file for writing. A file with `.gz` extension is compressed transparently.
+fn create_file(path: &str, description: &str) -> Box<dyn Write> {
+    let file = File::create(path).unwrap_or_else(|err| {
         eprintln!("Cannot create {} file '{}': '{}'", description, path, err.to_string());
         process::exit(1);
-    })
+    });
+
+    if is_gzip(path) {
+        Box::new(GzEncoder::new(file, Compression::default()))
+    } else {
+        Box::new(file)
+    }
+}
+
+fn is_gzip(path: &str) -> bool {
+    path.ends_with(".gz")
 }
 
-fn create_write_buffer(out_file: Option<File>) -> BufWriter<Box<dyn Write>> {
+fn create_write_buffer(out_file: Option<Box<dyn Write>>) -> BufWriter<Box<dyn Write>> {
     if let Some(out_file) = out_file {
-        BufWriter::new(Box::new(out_file))
+        BufWriter::new(out_file)
     } else {
         BufWriter::new(Box::new(stdout()))
     }
@@ -1,15 +1,20 @@
 use super::*;
 
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufReader, BufWriter, Write};
+use std::io::{BufReader, BufWriter, Read, Write};
 use std::process;
 use std::sync::Arc;
-use vrp_cli::{get_errors_serialized, get_locations_serialized};
+use vrp_cli::extensions::import::read_csv_matrix;
+use vrp_cli::{
+    get_errors_serialized, get_locations_serialized, get_population_fitness_serialized, get_solver_config_serialized,
+};
 use vrp_core::models::{Problem, Solution};
+use vrp_core::solver::mutation::RuinLimits;
 use vrp_core::solver::Builder;
-use vrp_pragmatic::format::problem::{deserialize_problem, PragmaticProblem};
+use vrp_pragmatic::format::problem::Problem as ApiProblem;
+use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_problem, Config, PragmaticProblem};
 use vrp_pragmatic::format::solution::PragmaticSolution;
+use vrp_pragmatic::get_unique_locations;
 use vrp_scientific::common::read_init_solution;
 use vrp_scientific::lilim::{LilimProblem, LilimSolution};
 use vrp_scientific::solomon::{SolomonProblem, SolomonSolution};
@@ -17,71 +22,144 @@ use vrp_scientific::solomon::{SolomonProblem, SolomonSolution};
 const FORMAT_ARG_NAME: &str = "FORMAT";
 const PROBLEM_ARG_NAME: &str = "PROBLEM";
 const MATRIX_ARG_NAME: &str = "matrix";
+const CSV_MATRIX_ARG_NAME: &str = "csv-matrix";
 const GENERATIONS_ARG_NAME: &str = "max-generations";
 const TIME_ARG_NAME: &str = "max-time";
 const COST_VARIATION_ARG_NAME: &str = "cost-variation";
+const STOP_WHEN_ASSIGNED_ARG_NAME: &str = "stop-when-assigned";
+const TARGET_COST_ARG_NAME: &str = "target-cost";
 const GEO_JSON_ARG_NAME: &str = "geo-json";
+const SUGGESTIONS_ARG_NAME: &str = "with-suggestions";
+const SEED_ARG_NAME: &str = "seed";
+const RUIN_LIMITS_ARG_NAME: &str = "ruin-limits";
+const TRAFFIC_FACTOR_ARG_NAME: &str = "traffic-factor";
 
 const INIT_SOLUTION_ARG_NAME: &str = "init-solution";
 const OUT_RESULT_ARG_NAME: &str = "out-result";
+const OUT_CONFIG_ARG_NAME: &str = "out-config";
+const OUT_POPULATION_ARG_NAME: &str = "out-population";
 const GET_LOCATIONS_ARG_NAME: &str = "get-locations";
 
-struct ProblemReader(pub Box<dyn Fn(File, Option<Vec<File>>) -> Result<Problem, String>>);
+type InputFile = Box<dyn Read>;
 
-struct InitSolutionReader(pub Box<dyn Fn(File, Arc<Problem>) -> Option<Solution>>);
+/// Specifies profile name, durations and distances csv files of routing matrix.
+type CsvMatrixFiles = (String, InputFile, InputFile);
+
+struct ProblemReader(
+    pub  Box<
+        dyn Fn(InputFile, Option<Vec<InputFile>>, Option<Vec<CsvMatrixFiles>>, Option<f64>) -> Result<Problem, String>,
+    >,
+);
+
+struct InitSolutionReader(pub Box<dyn Fn(InputFile, Arc<Problem>) -> Option<Solution>>);
 
 struct SolutionWriter(
     pub  Box<
-        dyn Fn(&Problem, Solution, BufWriter<Box<dyn Write>>, Option<BufWriter<Box<dyn Write>>>) -> Result<(), String>,
+        dyn Fn(
+            &Problem,
+            Solution,
+            BufWriter<Box<dyn Write>>,
+            Option<BufWriter<Box<dyn Write>>>,
+            bool,
+        ) -> Result<(), String>,
     >,
 );
 
-struct LocationWriter(pub Box<dyn Fn(File, BufWriter<Box<dyn Write>>) -> Result<(), String>>);
+struct LocationWriter(pub Box<dyn Fn(InputFile, BufWriter<Box<dyn Write>>) -> Result<(), String>>);
 
 fn get_formats<'a>() -> HashMap<&'a str, (ProblemReader, InitSolutionReader, SolutionWriter, LocationWriter)> {
     vec![
         (
             "solomon",
             (
-                ProblemReader(Box::new(|problem: File, matrices: Option<Vec<File>>| {
-                    assert!(matrices.is_none());
-                    BufReader::new(problem).read_solomon()
-                })),
+                ProblemReader(Box::new(
+                    |problem: InputFile, matrices: Option<Vec<InputFile>>, csv_matrices, traffic_factor| {
+                        assert!(matrices.is_none());
+                        if csv_matrices.is_some() {
+                            return Err("csv matrix is not supported by solomon format".to_string());
+                        }
+                        if traffic_factor.is_some() {
+                            return Err("traffic factor is not supported by solomon format".to_string());
+                        }
+                        BufReader::new(problem).read_solomon()
+                    },
+                )),
                 InitSolutionReader(Box::new(|file, problem| read_init_solution(BufReader::new(file), problem).ok())),
-                SolutionWriter(Box::new(|_, solution, writer, _| solution.write_solomon(writer))),
+                SolutionWriter(Box::new(|problem, solution, writer, _, _| {
+                    solution.write_solomon_with_stats(writer, problem.transport.as_ref())
+                })),
                 LocationWriter(Box::new(|_, _| unimplemented!())),
             ),
         ),
         (
             "lilim",
             (
-                ProblemReader(Box::new(|problem: File, matrices: Option<Vec<File>>| {
-                    assert!(matrices.is_none());
-                    BufReader::new(problem).read_lilim()
-                })),
+                ProblemReader(Box::new(
+                    |problem: InputFile, matrices: Option<Vec<InputFile>>, csv_matrices, traffic_factor| {
+                        assert!(matrices.is_none());
+                        if csv_matrices.is_some() {
+                            return Err("csv matrix is not supported by lilim format".to_string());
+                        }
+                        if traffic_factor.is_some() {
+                            return Err("traffic factor is not supported by lilim format".to_string());
+                        }
+                        BufReader::new(problem).read_lilim()
+                    },
+                )),
                 InitSolutionReader(Box::new(|_file, _problem| None)),
-                SolutionWriter(Box::new(|_, solution, writer, _| solution.write_lilim(writer))),
+                SolutionWriter(Box::new(|problem, solution, writer, _, _| {
+                    solution.write_lilim_with_stats(writer, problem.transport.as_ref())
+                })),
                 LocationWriter(Box::new(|_, _| unimplemented!())),
             ),
         ),
         (
             "pragmatic",
             (
-                ProblemReader(Box::new(|problem: File, matrices: Option<Vec<File>>| {
-                    if let Some(matrices) = matrices {
-                        let matrices = matrices.into_iter().map(|m| BufReader::new(m)).collect();
-                        (BufReader::new(problem), matrices).read_pragmatic()
-                    } else {
-                        println!("configured to use single approximated routing matrix");
-                        BufReader::new(problem).read_pragmatic()
-                    }
-                    .map_err(|errors| errors.iter().map(|err| err.to_string()).collect::<Vec<_>>().join("\t\n"))
-                })),
+                ProblemReader(Box::new(
+                    |problem: InputFile, matrices: Option<Vec<InputFile>>, csv_matrices, traffic_factor| {
+                        deserialize_problem(BufReader::new(problem))
+                            .map(|problem| with_traffic_factor(problem, traffic_factor))
+                            .and_then(|problem| {
+                                if matrices.is_none() && csv_matrices.is_none() {
+                                    println!("configured to use single approximated routing matrix");
+                                    return problem.read_pragmatic();
+                                }
+
+                                let size = get_unique_locations(&problem).len();
+                                let csv_matrices =
+                                    csv_matrices.into_iter().flatten().map(|(profile, durations, distances)| {
+                                        read_csv_matrix(
+                                            profile.as_str(),
+                                            BufReader::new(durations),
+                                            BufReader::new(distances),
+                                            size,
+                                        )
+                                        .map_err(|err| vec![err])
+                                    });
+
+                                matrices
+                                    .into_iter()
+                                    .flatten()
+                                    .map(|m| deserialize_matrix(BufReader::new(m)))
+                                    .chain(csv_matrices)
+                                    .collect::<Result<Vec<_>, _>>()
+                                    .and_then(|matrices| (problem, matrices).read_pragmatic())
+                            })
+                            .map_err(|errors| errors.iter().map(|err| err.to_string()).collect::<Vec<_>>().join("\t\n"))
+                    },
+                )),
                 InitSolutionReader(Box::new(|_file, _problem| None)),
-                SolutionWriter(Box::new(|problem, solution, default_writer, geojson_writer| {
+                SolutionWriter(Box::new(|problem, solution, default_writer, geojson_writer, with_suggestions| {
                     geojson_writer
                         .map_or(Ok(()), |geojson_writer| solution.write_geo_json(problem, geojson_writer))
-                        .and_then(|_| solution.write_pragmatic_json(problem, default_writer))
+                        .and_then(|_| {
+                            if with_suggestions {
+                                solution.write_pragmatic_json_with_suggestions(problem, default_writer)
+                            } else {
+                                solution.write_pragmatic_json(problem, default_writer)
+                            }
+                        })
                 })),
                 LocationWriter(Box::new(|problem, writer| {
                     let mut writer = writer;
@@ -97,6 +175,21 @@ fn get_formats<'a>() -> HashMap<&'a str, (ProblemReader, InitSolutionReader, Sol
     .collect()
 }
 
+fn with_traffic_factor(problem: ApiProblem, traffic_factor: Option<f64>) -> ApiProblem {
+    match traffic_factor {
+        Some(traffic_factor) => {
+            let config = problem.config.unwrap_or(Config {
+                location_precision: None,
+                time_window_grace: None,
+                traffic_factor: None,
+            });
+
+            ApiProblem { config: Some(Config { traffic_factor: Some(traffic_factor), ..config }), ..problem }
+        }
+        None => problem,
+    }
+}
+
 pub fn get_solve_app<'a, 'b>() -> App<'a, 'b> {
     App::new("solve")
         .about("Solves variations of Vehicle Routing Problem")
@@ -132,6 +225,19 @@ pub fn get_solve_app<'a, 'b>() -> App<'a, 'b> {
                 .required(false)
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name(STOP_WHEN_ASSIGNED_ARG_NAME)
+                .help("Stops as soon as solution with all jobs assigned is found")
+                .long(STOP_WHEN_ASSIGNED_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::with_name(TARGET_COST_ARG_NAME)
+                .help("Stops as soon as solution with cost less or equal to specified one is found")
+                .long(TARGET_COST_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
         .arg(
             Arg::with_name(INIT_SOLUTION_ARG_NAME)
                 .help("Specifies path to file with initial solution")
@@ -149,6 +255,14 @@ pub fn get_solve_app<'a, 'b>() -> App<'a, 'b> {
                 .required(false)
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name(CSV_MATRIX_ARG_NAME)
+                .help("Specifies routing matrix in csv format as \"profile,durations.csv,distances.csv\"")
+                .long(CSV_MATRIX_ARG_NAME)
+                .multiple(true)
+                .required(false)
+                .takes_value(true),
+        )
         .arg(
             Arg::with_name(OUT_RESULT_ARG_NAME)
                 .help("Specifies path to file for result output")
@@ -157,6 +271,20 @@ pub fn get_solve_app<'a, 'b>() -> App<'a, 'b> {
                 .required(false)
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name(OUT_CONFIG_ARG_NAME)
+                .help("Specifies path to file for solver configuration output")
+                .long(OUT_CONFIG_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(OUT_POPULATION_ARG_NAME)
+                .help("Specifies path to file for per-objective fitness of final population output in csv format")
+                .long(OUT_POPULATION_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
         .arg(
             Arg::with_name(GET_LOCATIONS_ARG_NAME)
                 .help("Returns list of unique locations")
@@ -172,6 +300,34 @@ pub fn get_solve_app<'a, 'b>() -> App<'a, 'b> {
                 .required(false)
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name(SUGGESTIONS_ARG_NAME)
+                .help("Adds closest feasible assignment of each unassigned job to solution (pragmatic format only)")
+                .long(SUGGESTIONS_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::with_name(SEED_ARG_NAME)
+                .help("Specifies seed for random generator to reproduce previous run")
+                .short("s")
+                .long(SEED_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(RUIN_LIMITS_ARG_NAME)
+                .help("Specifies amount of removed jobs as a ratio of assigned ones in form \"min,max,ratio\"")
+                .long(RUIN_LIMITS_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(TRAFFIC_FACTOR_ARG_NAME)
+                .help("Specifies a positive factor applied to all routing durations, e.g. to model traffic conditions")
+                .long(TRAFFIC_FACTOR_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
 }
 
 /// Runs solver commands.
@@ -206,13 +362,66 @@ pub fn run_solve(matches: &ArgMatches) {
             process::exit(1);
         }
     });
+    let is_stop_when_assigned_set = matches.is_present(STOP_WHEN_ASSIGNED_ARG_NAME);
+    let target_cost = matches.value_of(TARGET_COST_ARG_NAME).map(|arg| {
+        arg.parse::<f64>().unwrap_or_else(|err| {
+            eprintln!("cannot get target cost: '{}'", err.to_string());
+            process::exit(1);
+        })
+    });
     let init_solution = matches.value_of(INIT_SOLUTION_ARG_NAME).map(|path| open_file(path, "init solution"));
     let matrix_files = matches
         .values_of(MATRIX_ARG_NAME)
         .map(|paths: Values| paths.map(|path| open_file(path, "routing matrix")).collect());
+    let csv_matrix_files = matches.values_of(CSV_MATRIX_ARG_NAME).map(|args: Values| {
+        args.map(|arg| {
+            if let [profile, durations, distances] = arg.split(',').collect::<Vec<_>>().as_slice() {
+                (
+                    profile.to_string(),
+                    open_file(durations, "routing matrix durations"),
+                    open_file(distances, "routing matrix distances"),
+                )
+            } else {
+                eprintln!("cannot parse csv matrix: '{}'", arg);
+                process::exit(1);
+            }
+        })
+        .collect()
+    });
     let out_result = matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out solution"));
+    let out_config = matches.value_of(OUT_CONFIG_ARG_NAME).map(|path| create_file(path, "out config"));
+    let out_population = matches.value_of(OUT_POPULATION_ARG_NAME).map(|path| create_file(path, "out population"));
     let out_geojson = matches.value_of(GEO_JSON_ARG_NAME).map(|path| create_file(path, "out geojson"));
     let is_get_locations_set = matches.is_present(GET_LOCATIONS_ARG_NAME);
+    let is_with_suggestions_set = matches.is_present(SUGGESTIONS_ARG_NAME);
+    let seed = matches.value_of(SEED_ARG_NAME).map(|arg| {
+        arg.parse::<u64>().unwrap_or_else(|err| {
+            eprintln!("cannot get seed: '{}'", err.to_string());
+            process::exit(1);
+        })
+    });
+    let ruin_limits = matches.value_of(RUIN_LIMITS_ARG_NAME).map(|arg| {
+        if let [min, max, ratio] =
+            arg.split(',').filter_map(|line| line.parse::<f64>().ok()).collect::<Vec<_>>().as_slice()
+        {
+            if *min < 0. || min > max || *ratio <= 0. || *ratio > 1. {
+                eprintln!("invalid ruin limits: '{}'", arg);
+                process::exit(1);
+            }
+            RuinLimits::new(*min as usize, *max as usize, *ratio)
+        } else {
+            eprintln!("cannot parse ruin limits");
+            process::exit(1);
+        }
+    });
+
+    let traffic_factor = matches.value_of(TRAFFIC_FACTOR_ARG_NAME).map(|arg| match arg.parse::<f64>() {
+        Ok(factor) if factor > 0. => factor,
+        _ => {
+            eprintln!("invalid traffic factor: '{}'", arg);
+            process::exit(1);
+        }
+    });
 
     match formats.get(problem_format) {
         Some((problem_reader, init_reader, solution_writer, locations_writer)) => {
@@ -225,24 +434,54 @@ pub fn run_solve(matches: &ArgMatches) {
                     process::exit(1);
                 });
             } else {
-                match problem_reader.0(problem_file, matrix_files) {
+                match problem_reader.0(problem_file, matrix_files, csv_matrix_files, traffic_factor) {
                     Ok(problem) => {
                         let problem = Arc::new(problem);
                         let solution = init_solution.and_then(|file| init_reader.0(file, problem.clone()));
-                        let (solution, _) = Builder::default()
+                        let (solution, _, statistics) = Builder::default()
                             .with_problem(problem.clone())
                             .with_solutions(solution.map_or_else(|| vec![], |s| vec![Arc::new(s)]))
                             .with_max_generations(max_generations)
                             .with_max_time(max_time)
                             .with_cost_variation(cost_variation)
+                            .with_stop_when_all_assigned(is_stop_when_assigned_set)
+                            .with_target_cost(target_cost)
+                            .with_seed(seed)
+                            .with_ruin_limits(ruin_limits)
+                            .with_population_fitness(out_population.is_some())
                             .build()
-                            .and_then(|solver| solver.solve())
+                            .and_then(|solver| {
+                                if let Some(out_config) = out_config {
+                                    let mut writer = create_write_buffer(Some(out_config));
+                                    get_solver_config_serialized(&solver.settings)
+                                        .and_then(|config| {
+                                            writer.write_all(config.as_bytes()).map_err(|err| err.to_string())
+                                        })
+                                        .unwrap_or_else(|err| {
+                                            eprintln!("cannot write solver config: '{}'", err);
+                                            process::exit(1);
+                                        });
+                                }
+                                solver.solve_with_statistics()
+                            })
                             .unwrap_or_else(|err| {
                                 eprintln!("cannot find any solution: '{}'", err);
                                 process::exit(1);
                             });
 
-                        solution_writer.0(&problem, solution, out_buffer, geo_buffer).unwrap()
+                        if let (Some(out_population), Some(population_fitness)) =
+                            (out_population, statistics.population_fitness)
+                        {
+                            let mut writer = create_write_buffer(Some(out_population));
+                            writer
+                                .write_all(get_population_fitness_serialized(&population_fitness).as_bytes())
+                                .unwrap_or_else(|err| {
+                                    eprintln!("cannot write population fitness: '{}'", err);
+                                    process::exit(1);
+                                });
+                        }
+
+                        solution_writer.0(&problem, solution, out_buffer, geo_buffer, is_with_suggestions_set).unwrap()
                     }
                     Err(error) => {
                         eprintln!("cannot read {} problem from '{}': '{}'", problem_format, problem_path, error);
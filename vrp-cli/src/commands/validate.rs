@@ -0,0 +1,67 @@
+use super::*;
+use std::io::BufReader;
+use std::process;
+use vrp_cli::validate_pragmatic_solution;
+
+pub const FORMAT_ARG_NAME: &str = "FORMAT";
+pub const PROBLEM_ARG_NAME: &str = "problem-file";
+pub const MATRIX_ARG_NAME: &str = "matrix";
+pub const SOLUTION_ARG_NAME: &str = "solution-file";
+
+pub fn get_validate_solution_app<'a, 'b>() -> App<'a, 'b> {
+    App::new("validate-solution")
+        .about("Provides the way to validate solution against hard constraints of its problem")
+        .arg(
+            Arg::with_name(FORMAT_ARG_NAME)
+                .help("Specifies input type")
+                .required(true)
+                .possible_values(&["pragmatic"])
+                .index(1),
+        )
+        .arg(
+            Arg::with_name(PROBLEM_ARG_NAME)
+                .help("Sets input file which contains a VRP definition")
+                .short("p")
+                .long(PROBLEM_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(MATRIX_ARG_NAME)
+                .help("Specifies path to file with routing matrix")
+                .short("m")
+                .long(MATRIX_ARG_NAME)
+                .multiple(true)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(SOLUTION_ARG_NAME)
+                .help("Sets solution file")
+                .short("s")
+                .long(SOLUTION_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+}
+
+pub fn run_validate_solution(matches: &ArgMatches) {
+    let input_format = matches.value_of(FORMAT_ARG_NAME).unwrap();
+    let problem_file = matches.value_of(PROBLEM_ARG_NAME).map(|path| BufReader::new(open_file(path, "problem")));
+    let matrix_files = matches
+        .values_of(MATRIX_ARG_NAME)
+        .map(|paths: Values| paths.map(|path| BufReader::new(open_file(path, "routing matrix"))).collect::<Vec<_>>());
+    let solution_file = matches.value_of(SOLUTION_ARG_NAME).map(|path| BufReader::new(open_file(path, "solution")));
+
+    let result = match (input_format, problem_file, solution_file) {
+        ("pragmatic", Some(problem_file), Some(solution_file)) => {
+            validate_pragmatic_solution(problem_file, matrix_files, solution_file)
+        }
+        _ => Err(vec![format!("unknown format: '{}'", input_format)]),
+    };
+
+    if let Err(errors) = result {
+        errors.iter().for_each(|err| eprintln!("{}", err));
+        process::exit(1);
+    }
+}
@@ -27,6 +27,7 @@ pub fn generate_plan(problem_proto: &Problem, job_size: usize) -> Plan {
                             location: get_random_location(&bounding_box, &rnd),
                             duration: get_random_item(durations.as_slice(), &rnd).cloned().unwrap(),
                             times: get_random_item(time_windows.as_slice(), &rnd).cloned(),
+                            durations: None,
                         })
                         .collect(),
                     demand: if keep_original_demand {
@@ -57,11 +58,16 @@ pub fn generate_plan(problem_proto: &Problem, job_size: usize) -> Plan {
                 services: generate_tasks(&job_proto.services, true),
                 priority: job_proto.priority,
                 skills: job_proto.skills.clone(),
+                required: job_proto.required,
+                probability: job_proto.probability,
+                unassigned_penalty: job_proto.unassigned_penalty,
+                customer: job_proto.customer.clone(),
+                metadata: job_proto.metadata.clone(),
             }
         })
         .collect();
 
-    Plan { jobs, relations: None }
+    Plan { jobs, relations: None, hints: None }
 }
 
 fn get_plan_bounding_box(plan: &Plan) -> (Location, Location) {
@@ -70,7 +76,7 @@ fn get_plan_bounding_box(plan: &Plan) -> (Location, Location) {
     let mut lng_min = std::f64::MAX;
     let mut lng_max = std::f64::MIN;
 
-    get_plan_places(&plan).map(|job_place| &job_place.location).cloned().for_each(|Location { lat, lng }| {
+    get_plan_places(&plan).filter_map(|job_place| job_place.location.to_lat_lng()).for_each(|(lat, lng)| {
         lat_min = lat_min.min(lat);
         lat_max = lat_max.max(lat);
 
@@ -78,7 +84,7 @@ fn get_plan_bounding_box(plan: &Plan) -> (Location, Location) {
         lng_max = lng_max.max(lng);
     });
 
-    (Location { lat: lat_min, lng: lng_min }, Location { lat: lat_max, lng: lng_max })
+    (Location::new(lat_min, lng_min), Location::new(lat_max, lng_max))
 }
 
 fn get_plan_time_windows(plan: &Plan) -> Vec<Vec<Vec<String>>> {
@@ -121,8 +127,8 @@ fn get_random_item<'a, T>(items: &'a [T], rnd: &DefaultRandom) -> Option<&'a T>
 }
 
 fn get_random_location(bounding_box: &(Location, Location), rnd: &DefaultRandom) -> Location {
-    let lat = rnd.uniform_real(bounding_box.0.lat, bounding_box.1.lat);
-    let lng = rnd.uniform_real(bounding_box.0.lng, bounding_box.1.lng);
+    let ((lat_min, lng_min), (lat_max, lng_max)) =
+        (bounding_box.0.to_lat_lng().unwrap(), bounding_box.1.to_lat_lng().unwrap());
 
-    Location { lat, lng }
+    Location::new(rnd.uniform_real(lat_min, lat_max), rnd.uniform_real(lng_min, lng_max))
 }
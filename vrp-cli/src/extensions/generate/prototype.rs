@@ -1,5 +1,6 @@
 use super::*;
 use vrp_pragmatic::format::problem::Problem;
+use vrp_pragmatic::get_unique_locations;
 
 /// Generates meaningful problem from the prototype.
 /// There is another problem generation implementation in `vrp-pragmatic` crate, used by tests.
@@ -11,10 +12,15 @@ pub fn generate_from_prototype(problem: &Problem, job_size: usize) -> Result<Pro
         return Err("at least three jobs should be defined".to_string());
     }
 
+    if get_unique_locations(problem).iter().any(|location| location.to_lat_lng().is_none()) {
+        return Err("prototype with location references is not supported, use geo coordinates".to_string());
+    }
+
     Ok(Problem {
         plan: generate_plan(&problem, job_size),
         fleet: problem.fleet.clone(),
         objectives: problem.objectives.clone(),
+        horizon: problem.horizon.clone(),
         config: problem.config.clone(),
     })
 }
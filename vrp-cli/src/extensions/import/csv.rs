@@ -65,9 +65,10 @@ fn parse_tw(start: Option<String>, end: Option<String>) -> Option<Vec<String>> {
 fn read_jobs<R: Read>(reader: BufReader<R>) -> Result<Vec<Job>, Box<dyn Error>> {
     let get_task = |job: &CsvJob| JobTask {
         places: vec![JobPlace {
-            location: Location { lat: job.lat, lng: job.lng },
+            location: Location::new(job.lat, job.lng),
             duration: job.duration as f64 * 60.,
             times: parse_tw(job.tw_start.clone(), job.tw_end.clone()).map(|tw| vec![tw]),
+            durations: None,
         }],
         demand: if job.demand != 0 { Some(vec![job.demand.abs()]) } else { None },
         tag: None,
@@ -97,6 +98,11 @@ fn read_jobs<R: Read>(reader: BufReader<R>) -> Result<Vec<Job>, Box<dyn Error>>
             services: get_tasks(&tasks, Box::new(|j| j.demand == 0)),
             priority: None,
             skills: None,
+            required: None,
+            probability: None,
+            unassigned_penalty: None,
+            customer: None,
+            metadata: None,
         })
         .collect();
 
@@ -107,22 +113,35 @@ fn read_vehicles<R: Read>(reader: BufReader<R>) -> Result<Vec<VehicleType>, Box<
     let vehicles = read_csv_entries::<CsvVehicle, _>(reader)?
         .into_iter()
         .map(|vehicle| {
-            let depot_location = Location { lat: vehicle.lat, lng: vehicle.lng };
+            let depot_location = Location::new(vehicle.lat, vehicle.lng);
 
             VehicleType {
                 type_id: vehicle.id.clone(),
                 vehicle_ids: (1..vehicle.amount).map(|seq| format!("{}_{}", vehicle.profile, seq)).collect(),
                 profile: vehicle.profile,
-                costs: VehicleCosts { fixed: Some(25.), distance: 0.0002, time: 0.005 },
+                costs: VehicleCosts {
+                    fixed: Some(25.),
+                    distance: 0.0002,
+                    time: 0.005,
+                    waiting: None,
+                    overtime: None,
+                    min_duration: None,
+                    per_stop: None,
+                },
                 shifts: vec![VehicleShift {
                     start: VehiclePlace { time: vehicle.tw_start, location: depot_location.clone() },
                     end: Some(VehiclePlace { time: vehicle.tw_end, location: depot_location }),
+                    max_overtime: None,
+                    floating_start: None,
                     breaks: None,
                     reloads: None,
                 }],
                 capacity: vec![vehicle.capacity],
                 skills: None,
                 limits: None,
+                loaded_profile: None,
+                u_turn: None,
+                must_use: None,
             }
         })
         .collect();
@@ -139,6 +158,68 @@ fn create_format_error(entity: &str, error: Box<dyn Error>) -> FormatError {
     )
 }
 
+fn read_csv_matrix_values<R: Read>(reader: BufReader<R>, size: usize) -> Result<Vec<i64>, Box<dyn Error>> {
+    let mut reader = csv::ReaderBuilder::new().has_headers(false).trim(csv::Trim::All).from_reader(reader);
+    let mut values = vec![];
+    let mut rows = 0;
+
+    for (row_idx, record) in reader.records().enumerate() {
+        let record = record?;
+        if record.len() != size {
+            return Err(format!("row {} has {} values, expected {}", row_idx + 1, record.len(), size).into());
+        }
+
+        for (column_idx, value) in record.iter().enumerate() {
+            values.push(value.parse::<i64>().map_err(|err| {
+                format!("cannot parse value '{}' at row {}, column {}: {}", value, row_idx + 1, column_idx + 1, err)
+            })?);
+        }
+
+        rows += 1;
+    }
+
+    if rows != size {
+        return Err(format!("matrix has {} rows, expected {}", rows, size).into());
+    }
+
+    Ok(values)
+}
+
+/// Reads routing matrix of given profile from two csv files with durations and distances.
+/// Each file has no header and contains square matrix with `size` rows and columns: a value at
+/// row `i` and column `j` specifies routing info from location `i` to location `j`, where
+/// locations are ordered as returned by unique locations of the problem.
+pub fn read_csv_matrix<R1: Read, R2: Read>(
+    profile: &str,
+    durations_reader: BufReader<R1>,
+    distances_reader: BufReader<R2>,
+    size: usize,
+) -> Result<Matrix, FormatError> {
+    let create_matrix_error = |entity: &str, error: Box<dyn Error>| {
+        FormatError::new_with_details(
+            "E0000".to_string(),
+            format!("cannot read {}", entity),
+            format!("check that {} is a square matrix of integers with size {}: {}", entity, size, error),
+            format!("{}", error),
+        )
+    };
+
+    let travel_times =
+        read_csv_matrix_values(durations_reader, size).map_err(|err| create_matrix_error("durations matrix", err))?;
+    let distances =
+        read_csv_matrix_values(distances_reader, size).map_err(|err| create_matrix_error("distances matrix", err))?;
+
+    Ok(Matrix {
+        profile: profile.to_string(),
+        timestamp: None,
+        travel_times,
+        distances,
+        error_codes: None,
+        fallback: None,
+        avoidance: None,
+    })
+}
+
 /// Reads problem from csv format.
 pub fn read_csv_problem<R1: Read, R2: Read>(
     jobs_reader: BufReader<R1>,
@@ -149,12 +230,16 @@ pub fn read_csv_problem<R1: Read, R2: Read>(
     let profiles = vehicles.iter().map(|v| v.profile.clone()).collect::<HashSet<_>>();
 
     Ok(Problem {
-        plan: Plan { jobs, relations: None },
+        plan: Plan { jobs, relations: None, hints: None },
         fleet: Fleet {
             vehicles,
-            profiles: profiles.into_iter().map(|p| Profile { name: p.clone(), profile_type: p, speed: None }).collect(),
+            profiles: profiles
+                .into_iter()
+                .map(|p| Profile { name: p.clone(), profile_type: p, speed: None, road_classes: None })
+                .collect(),
         },
         objectives: None,
+        horizon: None,
         config: None,
     })
 }
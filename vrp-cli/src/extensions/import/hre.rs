@@ -304,7 +304,7 @@ mod hre {
 }
 
 fn to_loc(loc: &hre::Location) -> Location {
-    Location { lat: loc.lat, lng: loc.lng }
+    Location::new(loc.lat, loc.lng)
 }
 
 pub fn read_hre_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, FormatError> {
@@ -313,6 +313,7 @@ pub fn read_hre_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, Format
             location: to_loc(&place.location),
             duration: place.duration,
             times: place.times.clone(),
+            durations: None,
         }],
         demand: Some(job.demand.clone()),
         tag: place.tag.clone(),
@@ -330,6 +331,7 @@ pub fn read_hre_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, Format
                             location: to_loc(&place.location),
                             duration: place.duration,
                             times: place.times.clone(),
+                            durations: None,
                         }],
                         demand: Some(place.demand.clone()),
                         tag: place.tag.clone(),
@@ -357,6 +359,11 @@ pub fn read_hre_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, Format
                         services: None,
                         priority: job.priority.as_ref().map(|p| *p),
                         skills: job.skills.clone(),
+                        required: None,
+                        probability: None,
+                        unassigned_penalty: None,
+                        customer: None,
+                        metadata: None,
                     },
                     hre::JobVariant::Multi(job) => Job {
                         id: job.id.clone(),
@@ -366,6 +373,11 @@ pub fn read_hre_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, Format
                         services: None,
                         priority: job.priority.as_ref().map(|p| *p),
                         skills: job.skills.clone(),
+                        required: None,
+                        probability: None,
+                        unassigned_penalty: None,
+                        customer: None,
+                        metadata: None,
                     },
                 })
                 .collect(),
@@ -381,9 +393,11 @@ pub fn read_hre_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, Format
                         jobs: r.jobs.clone(),
                         vehicle_id: r.vehicle_id.clone(),
                         shift_index: r.shift_index.clone(),
+                        tags: None,
                     })
                     .collect()
             }),
+            hints: None,
         },
         fleet: Fleet {
             vehicles: hre_problem
@@ -398,6 +412,10 @@ pub fn read_hre_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, Format
                         fixed: v.costs.fixed.clone(),
                         distance: v.costs.distance,
                         time: v.costs.time,
+                        waiting: None,
+                        overtime: None,
+                        min_duration: None,
+                        per_stop: None,
                     },
                     shifts: v
                         .shifts
@@ -411,6 +429,8 @@ pub fn read_hre_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, Format
                                 .end
                                 .as_ref()
                                 .map(|end| VehiclePlace { time: end.time.clone(), location: to_loc(&end.location) }),
+                            max_overtime: None,
+                            floating_start: None,
                             breaks: shift.breaks.as_ref().map(|breaks| {
                                 breaks
                                     .iter()
@@ -418,6 +438,7 @@ pub fn read_hre_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, Format
                                         time: VehicleBreakTime::TimeWindow(b.times.first().unwrap().clone()),
                                         duration: b.duration,
                                         locations: b.location.as_ref().map(|l| vec![to_loc(l)]),
+                                        mandatory: None,
                                     })
                                     .collect()
                             }),
@@ -440,17 +461,32 @@ pub fn read_hre_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, Format
                         max_distance: l.max_distance.clone(),
                         shift_time: l.shift_time.clone(),
                         allowed_areas: None,
+                        max_pickups: None,
+                        min_work_before_break: None,
+                        max_breaks: None,
+                        min_break_spacing: None,
+                        min_jobs: None,
+                        max_jobs: None,
                     }),
+                    loaded_profile: None,
+                    u_turn: None,
+                    must_use: None,
                 })
                 .collect(),
             profiles: hre_problem
                 .fleet
                 .profiles
                 .iter()
-                .map(|p| Profile { name: p.name.clone(), profile_type: p.profile_type.clone(), speed: None })
+                .map(|p| Profile {
+                    name: p.name.clone(),
+                    profile_type: p.profile_type.clone(),
+                    speed: None,
+                    road_classes: None,
+                })
                 .collect(),
         },
         objectives: None,
+        horizon: None,
         config: None,
     })
 }
@@ -1,4 +1,5 @@
 mod csv;
+pub use self::csv::read_csv_matrix;
 use self::csv::read_csv_problem;
 mod hre;
 use self::hre::read_hre_problem;
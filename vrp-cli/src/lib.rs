@@ -8,12 +8,14 @@ mod features;
 pub mod extensions;
 
 use crate::extensions::import::import_problem;
-use std::io::{BufReader, BufWriter};
+use serde::Serialize;
+use std::io::{BufReader, BufWriter, Read};
 use std::sync::Arc;
 use vrp_core::models::Problem as CoreProblem;
-use vrp_core::solver::Builder;
+use vrp_core::solver::{Builder, SolverConfig};
+use vrp_pragmatic::checker::check_constraints;
 use vrp_pragmatic::format::problem::{serialize_problem, PragmaticProblem, Problem};
-use vrp_pragmatic::format::solution::PragmaticSolution;
+use vrp_pragmatic::format::solution::{deserialize_solution, PragmaticSolution};
 use vrp_pragmatic::format::FormatError;
 use vrp_pragmatic::get_unique_locations;
 
@@ -190,6 +192,22 @@ pub fn get_locations_serialized(problem: &Problem) -> Result<String, String> {
     Ok(buffer)
 }
 
+/// Validates solution in pragmatic format against hard constraints of its problem. Returns
+/// a list of problem reading errors or constraint violations.
+pub fn validate_pragmatic_solution<R: Read>(
+    problem: BufReader<R>,
+    matrices: Option<Vec<BufReader<R>>>,
+    solution: BufReader<R>,
+) -> Result<(), Vec<String>> {
+    let problem =
+        if let Some(matrices) = matrices { (problem, matrices).read_pragmatic() } else { problem.read_pragmatic() }
+            .map_err(|errors| vec![errors.iter().map(|err| err.to_string()).collect::<Vec<_>>().join("\t\n")])?;
+
+    deserialize_solution(solution)
+        .map_err(|err| vec![format!("cannot deserialize solution: '{}'", err)])
+        .and_then(|solution| check_constraints(Arc::new(problem), &solution))
+}
+
 pub fn get_solution_serialized(
     problem: &Arc<CoreProblem>,
     population_config: (usize, usize, usize, usize),
@@ -223,6 +241,130 @@ pub fn get_solution_serialized(
     Ok(buffer)
 }
 
+/// Returns solver configuration serialized into json.
+pub fn get_solver_config_serialized(config: &SolverConfig) -> Result<String, String> {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct CostVariation {
+        sample: usize,
+        threshold: f64,
+    }
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Termination {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        max_generations: Option<usize>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        max_time: Option<usize>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        cost_variation: Option<CostVariation>,
+        stop_when_all_assigned: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        target_cost: Option<f64>,
+    }
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Population {
+        population_size: usize,
+        offspring_size: usize,
+        elite_size: usize,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        tournament_size: Option<usize>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        diversity: Option<f64>,
+        initial_size: usize,
+        initial_weights: Vec<usize>,
+    }
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct RuinLimits {
+        min: usize,
+        max: usize,
+        ratio: f64,
+    }
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Mutation {
+        weights: Vec<usize>,
+        ruin_weights: Vec<usize>,
+        recreate_weights: Vec<usize>,
+    }
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Config {
+        objectives: Vec<String>,
+        termination: Termination,
+        population: Population,
+        mutation: Mutation,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ruin_limits: Option<RuinLimits>,
+        seed: u64,
+        thread_count: usize,
+    }
+
+    let config = Config {
+        objectives: config.objectives.clone(),
+        termination: Termination {
+            max_generations: config.max_generations,
+            max_time: config.max_time,
+            cost_variation: config.cost_variation.map(|(sample, threshold)| CostVariation { sample, threshold }),
+            stop_when_all_assigned: config.stop_when_all_assigned,
+            target_cost: config.target_cost,
+        },
+        population: Population {
+            population_size: config.population_size,
+            offspring_size: config.offspring_size,
+            elite_size: config.elite_size,
+            tournament_size: config.tournament_size,
+            diversity: config.diversity,
+            initial_size: config.initial_size,
+            initial_weights: config.initial_weights.clone(),
+        },
+        mutation: Mutation {
+            weights: config.mutation_weights.clone(),
+            ruin_weights: config.ruin_weights.clone(),
+            recreate_weights: config.recreate_weights.clone(),
+        },
+        ruin_limits: config.ruin_limits.as_ref().map(|limits| RuinLimits {
+            min: limits.min,
+            max: limits.max,
+            ratio: limits.ratio,
+        }),
+        seed: config.seed,
+        thread_count: config.thread_count,
+    };
+
+    let mut buffer = String::new();
+    let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+    serde_json::to_writer_pretty(writer, &config).map_err(|err| err.to_string())?;
+
+    Ok(buffer)
+}
+
+/// Returns per-objective fitness of population individuals serialized into csv: one row per individual.
+pub fn get_population_fitness_serialized(population_fitness: &[Vec<f64>]) -> String {
+    let objective_count = population_fitness.iter().map(|values| values.len()).max().unwrap_or(0);
+
+    let header = std::iter::once("individual".to_string())
+        .chain((1..=objective_count).map(|idx| format!("objective_{}", idx)))
+        .collect::<Vec<_>>()
+        .join(",");
+
+    let rows = population_fitness.iter().enumerate().map(|(idx, values)| {
+        std::iter::once(idx.to_string())
+            .chain(values.iter().map(|value| value.to_string()))
+            .collect::<Vec<_>>()
+            .join(",")
+    });
+
+    std::iter::once(header).chain(rows).collect::<Vec<_>>().join("\n")
+}
+
 pub fn get_errors_serialized(errors: &Vec<FormatError>) -> String {
     errors.iter().map(|err| format!("{}", err)).collect::<Vec<_>>().join("\n")
 }
@@ -15,6 +15,7 @@ mod cli {
     use super::commands::solve::{get_solve_app, run_solve};
     use crate::commands::check::{get_check_app, run_check};
     use crate::commands::generate::{get_generate_app, run_generate};
+    use crate::commands::validate::{get_validate_solution_app, run_validate_solution};
     use clap::App;
     use std::process;
 
@@ -27,6 +28,7 @@ mod cli {
             .subcommand(get_import_app())
             .subcommand(get_check_app())
             .subcommand(get_generate_app())
+            .subcommand(get_validate_solution_app())
             .get_matches();
 
         match matches.subcommand() {
@@ -34,6 +36,7 @@ mod cli {
             ("import", Some(import_matches)) => run_import(import_matches),
             ("check", Some(check_matches)) => run_check(check_matches),
             ("generate", Some(check_matches)) => run_generate(check_matches),
+            ("validate-solution", Some(validate_matches)) => run_validate_solution(validate_matches),
             ("", None) => {
                 eprintln!("No subcommand was used. Use -h to print help information.");
                 process::exit(1);
@@ -1 +1,4 @@
 mod generate;
+mod population_fitness;
+mod solver_config;
+mod validate_solution;
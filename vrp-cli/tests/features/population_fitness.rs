@@ -0,0 +1,35 @@
+use crate::get_population_fitness_serialized;
+use std::fs::File;
+use std::io::BufReader;
+use std::sync::Arc;
+use vrp_core::solver::Builder;
+use vrp_pragmatic::format::problem::PragmaticProblem;
+
+#[test]
+fn can_serialize_population_fitness() {
+    let reader = BufReader::new(File::open("../examples/data/pragmatic/simple.basic.problem.json").unwrap());
+    let problem = Arc::new(reader.read_pragmatic().unwrap());
+    let objective_count = problem.objective.objectives().count();
+
+    let (_, _, statistics) = Builder::default()
+        .with_problem(problem)
+        .with_max_generations(Some(10))
+        .with_population_fitness(true)
+        .build()
+        .unwrap()
+        .solve_with_statistics()
+        .unwrap();
+    let population_fitness = statistics.population_fitness.expect("no population fitness");
+
+    let csv = get_population_fitness_serialized(&population_fitness);
+    let lines = csv.lines().collect::<Vec<_>>();
+
+    assert_eq!(lines.len(), population_fitness.len() + 1);
+    assert_eq!(lines[0].split(',').count(), objective_count + 1);
+    lines.iter().skip(1).enumerate().for_each(|(idx, line)| {
+        let columns = line.split(',').collect::<Vec<_>>();
+        assert_eq!(columns.len(), objective_count + 1);
+        assert_eq!(columns[0], idx.to_string());
+        assert!(columns.iter().skip(1).all(|value| value.parse::<f64>().is_ok()));
+    });
+}
@@ -0,0 +1,70 @@
+use crate::get_solver_config_serialized;
+use std::fs::File;
+use std::io::BufReader;
+use std::sync::Arc;
+use vrp_core::solver::mutation::RuinLimits;
+use vrp_core::solver::Builder;
+use vrp_pragmatic::format::problem::PragmaticProblem;
+
+#[test]
+fn can_serialize_solver_config() {
+    let reader = BufReader::new(File::open("../examples/data/pragmatic/simple.basic.problem.json").unwrap());
+    let problem = Arc::new(reader.read_pragmatic().unwrap());
+
+    let solver = Builder::default()
+        .with_problem(problem)
+        .with_max_generations(Some(10))
+        .with_max_time(Some(20))
+        .with_cost_variation(Some((50, 0.1)))
+        .with_stop_when_all_assigned(true)
+        .with_target_cost(Some(100.))
+        .with_population_size(8)
+        .with_offspring_size(6)
+        .with_elite_size(3)
+        .with_tournament_size(Some(2))
+        .with_diversity(Some(0.05))
+        .with_initial_size(5)
+        .with_seed(Some(42))
+        .with_ruin_limits(Some(RuinLimits::new(2, 20, 0.1)))
+        .build()
+        .unwrap();
+
+    let config = get_solver_config_serialized(&solver.settings).unwrap();
+    let config: serde_json::Value = serde_json::from_str(config.as_str()).unwrap();
+
+    assert_eq!(config["objectives"], serde_json::json!(["TotalUnassignedJobs", "TotalRoutes", "TotalTransportCost"]));
+    assert_eq!(config["termination"]["maxGenerations"], 10);
+    assert_eq!(config["termination"]["maxTime"], 20);
+    assert_eq!(config["termination"]["costVariation"]["sample"], 50);
+    assert_eq!(config["termination"]["costVariation"]["threshold"], 0.1);
+    assert_eq!(config["termination"]["stopWhenAllAssigned"], true);
+    assert_eq!(config["termination"]["targetCost"], 100.);
+    assert_eq!(config["population"]["populationSize"], 8);
+    assert_eq!(config["population"]["offspringSize"], 6);
+    assert_eq!(config["population"]["eliteSize"], 3);
+    assert_eq!(config["population"]["tournamentSize"], 2);
+    assert_eq!(config["population"]["diversity"], 0.05);
+    assert_eq!(config["population"]["initialSize"], 5);
+    assert_eq!(config["population"]["initialWeights"], serde_json::json!([10, 10, 5]));
+    assert_eq!(config["mutation"]["weights"], serde_json::json!([100, 10]));
+    assert_eq!(config["mutation"]["ruinWeights"], serde_json::json!([100, 10, 50, 10, 10, 5, 10, 10, 10, 5]));
+    assert_eq!(config["mutation"]["recreateWeights"], serde_json::json!([100, 90, 30, 20, 15, 10, 10, 5]));
+    assert_eq!(config["ruinLimits"]["min"], 2);
+    assert_eq!(config["ruinLimits"]["max"], 20);
+    assert_eq!(config["ruinLimits"]["ratio"], 0.1);
+    assert_eq!(config["seed"], 42);
+    assert_eq!(config["threadCount"], solver.settings.thread_count);
+}
+
+#[test]
+fn can_report_default_termination_in_solver_config() {
+    let reader = BufReader::new(File::open("../examples/data/pragmatic/simple.basic.problem.json").unwrap());
+    let problem = Arc::new(reader.read_pragmatic().unwrap());
+
+    let solver = Builder::default().with_problem(problem).build().unwrap();
+
+    assert_eq!(solver.settings.max_generations, Some(2000));
+    assert_eq!(solver.settings.max_time, Some(300));
+    assert!(solver.settings.cost_variation.is_none());
+    assert!(solver.settings.ruin_limits.is_none());
+}
@@ -0,0 +1,40 @@
+use crate::validate_pragmatic_solution;
+use std::fs::File;
+use std::io::{BufReader, Read};
+
+fn get_reader(path: &str) -> BufReader<Box<dyn Read>> {
+    BufReader::new(Box::new(File::open(path).unwrap()))
+}
+
+fn get_problem_with_capacity(capacity: i32) -> BufReader<Box<dyn Read>> {
+    let mut problem: serde_json::Value =
+        serde_json::from_reader(get_reader("../examples/data/pragmatic/simple.basic.problem.json")).unwrap();
+    problem["fleet"]["vehicles"][0]["capacity"] = serde_json::json!([capacity]);
+
+    BufReader::new(Box::new(std::io::Cursor::new(problem.to_string().into_bytes())))
+}
+
+#[test]
+fn can_validate_feasible_solution() {
+    let result = validate_pragmatic_solution(
+        get_problem_with_capacity(10),
+        Some(vec![get_reader("../examples/data/pragmatic/simple.basic.matrix.json")]),
+        get_reader("../examples/data/pragmatic/simple.basic.solution.json"),
+    );
+
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn can_detect_capacity_violation() {
+    let result = validate_pragmatic_solution(
+        get_problem_with_capacity(1),
+        Some(vec![get_reader("../examples/data/pragmatic/simple.basic.matrix.json")]),
+        get_reader("../examples/data/pragmatic/simple.basic.solution.json"),
+    );
+
+    let errors = result.expect_err("capacity violation is not detected");
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("vehicle_1"), "unexpected error: {}", errors[0]);
+    assert!(errors[0].contains("capacity"), "unexpected error: {}", errors[0]);
+}
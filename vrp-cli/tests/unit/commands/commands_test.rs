@@ -0,0 +1,37 @@
+use super::*;
+use std::io::BufReader;
+use vrp_pragmatic::format::solution::*;
+
+fn create_test_solution() -> Solution {
+    Solution {
+        statistic: Statistic {
+            cost: 10.,
+            distance: 2,
+            duration: 3,
+            times: Timing { driving: 2, serving: 1, waiting: 0, break_time: 0 },
+        },
+        tours: vec![],
+        unassigned: vec![UnassignedJob {
+            job_id: "job1".to_string(),
+            reasons: vec![UnassignedJobReason { code: 3, description: "cannot be assigned".to_string() }],
+            suggestion: None,
+        }],
+        extras: None,
+    }
+}
+
+#[test]
+fn can_write_and_read_gzip_solution() {
+    let file = tempfile::Builder::new().prefix("vrp_cli_solution").suffix(".json.gz").tempfile().unwrap();
+    let path = file.path().to_str().unwrap();
+    let solution = create_test_solution();
+
+    serialize_solution(create_write_buffer(Some(create_file(path, "test solution"))), &solution).unwrap();
+
+    let mut header = [0_u8; 2];
+    File::open(path).unwrap().read_exact(&mut header).unwrap();
+    let result = deserialize_solution(BufReader::new(open_file(path, "test solution"))).unwrap();
+
+    assert_eq!(header, [0x1f, 0x8b]);
+    assert_eq!(result, solution);
+}
@@ -9,6 +9,11 @@ fn create_empty_job() -> Job {
         services: None,
         priority: None,
         skills: None,
+        required: None,
+        probability: None,
+        unassigned_penalty: None,
+        customer: None,
+        metadata: None,
     }
 }
 
@@ -17,14 +22,14 @@ fn create_empty_job_task() -> JobTask {
 }
 
 fn create_empty_job_place() -> JobPlace {
-    JobPlace { location: Location { lat: 0.0, lng: 0.0 }, duration: 0.0, times: None }
+    JobPlace { location: Location::Coordinate { lat: 0.0, lng: 0.0 }, duration: 0.0, times: None, durations: None }
 }
 
 #[test]
 fn can_generate_bounding_box() {
     let create_job_with_location = |lat: f64, lng: f64| Job {
         pickups: Some(vec![JobTask {
-            places: vec![JobPlace { location: Location { lat, lng }, ..create_empty_job_place() }],
+            places: vec![JobPlace { location: Location::Coordinate { lat, lng }, ..create_empty_job_place() }],
             ..create_empty_job_task()
         }]),
         ..create_empty_job()
@@ -37,10 +42,11 @@ fn can_generate_bounding_box() {
             create_job_with_location(1., 2.),
         ],
         relations: None,
+        hints: None,
     };
 
-    let (Location { lat: min_lat, lng: min_lng }, Location { lat: max_lat, lng: max_lng }) =
-        get_plan_bounding_box(&plan);
+    let (min, max) = get_plan_bounding_box(&plan);
+    let ((min_lat, min_lng), (max_lat, max_lng)) = (min.to_lat_lng().unwrap(), max.to_lat_lng().unwrap());
     assert_eq!(min_lat, -1.);
     assert_eq!(min_lng, 0.);
     assert_eq!(max_lat, 3.);
@@ -45,3 +45,42 @@ job2,52.5165,13.3808,3,,
     assert_eq!(result.action, "check jobs definition");
     assert!(result.details.is_some())
 }
+
+#[test]
+fn can_read_matrix() {
+    let durations_csv = "0,10,20\n10,0,15\n20,15,0\n";
+    let distances_csv = "0, 100, 200\n100, 0, 150\n200, 150, 0\n";
+
+    let matrix =
+        read_csv_matrix("car", BufReader::new(durations_csv.as_bytes()), BufReader::new(distances_csv.as_bytes()), 3)
+            .unwrap();
+
+    assert_eq!(matrix.profile, "car");
+    assert_eq!(matrix.travel_times, vec![0, 10, 20, 10, 0, 15, 20, 15, 0]);
+    assert_eq!(matrix.distances, vec![0, 100, 200, 100, 0, 150, 200, 150, 0]);
+}
+
+#[test]
+fn can_detect_invalid_matrix() {
+    let valid_csv = "0,10,20\n10,0,15\n20,15,0\n";
+    let cases = vec![
+        ("0,10\n10,0\n", valid_csv, "cannot read durations matrix"),
+        (valid_csv, "0,10\n10,0\n", "cannot read distances matrix"),
+        ("0,10,20\n10,0,15\n", valid_csv, "cannot read durations matrix"),
+        ("0,10,20\n10,0,x\n20,15,0\n", valid_csv, "cannot read durations matrix"),
+    ];
+
+    cases.into_iter().for_each(|(durations_csv, distances_csv, expected_cause)| {
+        let result = read_csv_matrix(
+            "car",
+            BufReader::new(durations_csv.as_bytes()),
+            BufReader::new(distances_csv.as_bytes()),
+            3,
+        )
+        .expect_err("Should return error!");
+
+        assert_eq!(result.code, "E0000");
+        assert_eq!(result.cause, expected_cause);
+        assert!(result.details.is_some())
+    });
+}
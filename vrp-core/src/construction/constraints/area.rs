@@ -124,8 +124,13 @@ fn can_serve_job_in_area(job: &Single, areas: &[Vec<(f64, f64)>], location_resol
 }
 
 /// Checks whether given location is inside area using ray casting algorithm.
-/// Location is interpreted as 2D point, area - as 2D polygon.
+/// Location is interpreted as 2D point, area - as 2D polygon. Location on area's edge
+/// is considered as inside.
 fn is_location_in_area(location: &(f64, f64), area: &Vec<(f64, f64)>) -> bool {
+    if is_location_on_edge(location, area) {
+        return true;
+    }
+
     let &(x, y) = location;
 
     let mut is_inside = false;
@@ -146,3 +151,28 @@ fn is_location_in_area(location: &(f64, f64), area: &Vec<(f64, f64)>) -> bool {
 
     is_inside
 }
+
+/// Checks whether given location lies on one of area's edges. Tolerance is relative to edge's
+/// length, so the check does not depend on coordinates scale (e.g. geo coordinates).
+fn is_location_on_edge(location: &(f64, f64), area: &[(f64, f64)]) -> bool {
+    const RELATIVE_TOLERANCE: f64 = 1E-9;
+
+    let &(x, y) = location;
+
+    area.iter().zip(area.iter().cycle().skip(1)).any(|(&(ix, iy), &(jx, jy))| {
+        let (dx, dy) = (jx - ix, jy - iy);
+        let length_sq = dx * dx + dy * dy;
+
+        if length_sq == 0. {
+            return x == ix && y == iy;
+        }
+
+        // NOTE cross product is a distance to edge's line multiplied by edge's length,
+        // dot product is a projection on edge's line multiplied by edge's length
+        let cross = dx * (y - iy) - dy * (x - ix);
+        let dot = dx * (x - ix) + dy * (y - iy);
+        let tolerance = RELATIVE_TOLERANCE * length_sq;
+
+        cross.abs() <= tolerance && dot >= -tolerance && dot <= length_sq + tolerance
+    })
+}
@@ -124,6 +124,46 @@ impl<Capacity: Add<Output = Capacity> + Sub<Output = Capacity> + Ord + Copy + De
         }
     }
 
+    /// Returns a function which determines whether vehicle is loaded when it leaves each tour
+    /// activity. It does not depend on the module state, so it can be used by other modules.
+    pub fn get_load_state(&self) -> LoadStateFunc {
+        let multi_trip = self.multi_trip.clone();
+
+        Arc::new(move |route| {
+            let intervals = route_intervals(route, {
+                let multi_trip = multi_trip.clone();
+                Box::new(move |a| multi_trip.get_reload(a).is_some())
+            });
+
+            let (_, states) = intervals.into_iter().fold(
+                (Capacity::default(), Vec::with_capacity(route.tour.total())),
+                |(acc, mut states), (start_idx, end_idx)| {
+                    let activities = route.tour.activities_slice(start_idx, end_idx);
+
+                    let (start_delivery, end_pickup) = activities
+                        .iter()
+                        .filter_map(|activity| Self::get_demand(activity))
+                        .fold((acc, Capacity::default()), |acc, demand| {
+                            (acc.0 + demand.delivery.0, acc.1 + demand.pickup.0)
+                        });
+
+                    let current = activities.iter().fold(start_delivery, |current, activity| {
+                        let change =
+                            Self::get_demand(activity).map(|demand| demand.change()).unwrap_or_else(Capacity::default);
+                        let current = current + change;
+                        states.push(current != Capacity::default());
+
+                        current
+                    });
+
+                    (current - end_pickup, states)
+                },
+            );
+
+            states
+        })
+    }
+
     fn recalculate_states(&self, ctx: &mut RouteContext) {
         self.actualize_intervals(ctx).into_iter().fold(Capacity::default(), |acc, (start_idx, end_idx)| {
             let (route, state) = ctx.as_mut();
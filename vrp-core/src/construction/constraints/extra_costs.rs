@@ -0,0 +1,20 @@
+use crate::construction::constraints::EXTRA_COST_KEY;
+use crate::construction::heuristics::RouteContext;
+use crate::models::common::Cost;
+use hashbrown::HashMap;
+
+/// Keeps extra route costs contributed by different sources, e.g. constraint modules.
+pub type ExtraCosts = HashMap<i32, Cost>;
+
+/// Sets extra route cost contributed by given source.
+pub fn set_extra_cost(route_ctx: &mut RouteContext, source: i32, cost: Cost) {
+    let mut costs = route_ctx.state.get_route_state::<ExtraCosts>(EXTRA_COST_KEY).cloned().unwrap_or_default();
+    costs.insert(source, cost);
+
+    route_ctx.state_mut().put_route_state(EXTRA_COST_KEY, costs);
+}
+
+/// Returns total extra route cost contributed by all sources.
+pub fn get_extra_cost(route_ctx: &RouteContext) -> Cost {
+    route_ctx.state.get_route_state::<ExtraCosts>(EXTRA_COST_KEY).map_or(0., |costs| costs.values().sum())
+}
@@ -1,11 +1,12 @@
 use crate::construction::constraints::{ConstraintModule, ConstraintVariant, SoftRouteConstraint};
 use crate::construction::heuristics::{RouteContext, SolutionContext};
-use crate::models::common::Cost;
+use crate::models::common::{Cost, ValueDimension};
 use crate::models::problem::Job;
 use std::slice::Iter;
 use std::sync::Arc;
 
-/// A module which controls fleet size usage.
+/// A module which controls fleet size usage. Opening a route for a vehicle with "must_use"
+/// dimension is always encouraged.
 pub struct FleetUsageConstraintModule {
     state_keys: Vec<i32>,
     constraints: Vec<ConstraintVariant>,
@@ -54,7 +55,12 @@ struct FleetCostSoftRouteConstraint {
 impl SoftRouteConstraint for FleetCostSoftRouteConstraint {
     fn estimate_job(&self, _: &SolutionContext, ctx: &RouteContext, _job: &Job) -> Cost {
         if ctx.route.tour.job_count() == 0 {
-            self.extra_cost
+            // NOTE vehicle marked with "must_use" dimension is always preferred to be used
+            if ctx.route.actor.vehicle.dimens.get_value::<bool>("must_use").cloned().unwrap_or(false) {
+                -self.extra_cost.abs()
+            } else {
+                self.extra_cost
+            }
         } else {
             0.
         }
@@ -0,0 +1,67 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/incompatibility_test.rs"]
+mod incompatibility_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{RouteContext, SolutionContext};
+use crate::models::common::ValueDimension;
+use crate::models::problem::Job;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// An incompatibility module provides way to forbid jobs of different groups (e.g. food and
+/// chemicals) to be served by the same route. The group is read from job's `incompatibility`
+/// dimension as an arbitrary string: jobs of the same group can share a route, jobs without
+/// the dimension are compatible with any other job.
+pub struct IncompatibilityConstraintModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl IncompatibilityConstraintModule {
+    /// Creates a new instance of [`IncompatibilityConstraintModule`].
+    pub fn new(code: i32) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::HardRoute(Arc::new(IncompatibilityHardRouteConstraint { code }))],
+            keys: vec![],
+        }
+    }
+}
+
+impl ConstraintModule for IncompatibilityConstraintModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_ctx: &mut RouteContext, _job: &Job) {}
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct IncompatibilityHardRouteConstraint {
+    code: i32,
+}
+
+impl HardRouteConstraint for IncompatibilityHardRouteConstraint {
+    fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
+        let group = get_group(job)?;
+
+        let has_conflict = ctx.route.tour.jobs().any(|other| get_group(&other).map_or(false, |other| other != group));
+
+        if has_conflict {
+            Some(RouteConstraintViolation { code: self.code })
+        } else {
+            None
+        }
+    }
+}
+
+fn get_group(job: &Job) -> Option<&String> {
+    job.dimens().get_value::<String>("incompatibility")
+}
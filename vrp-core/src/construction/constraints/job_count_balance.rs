@@ -0,0 +1,70 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/job_count_balance_test.rs"]
+mod job_count_balance_test;
+
+use crate::construction::constraints::{ConstraintModule, ConstraintVariant, SoftRouteConstraint};
+use crate::construction::heuristics::{RouteContext, SolutionContext};
+use crate::models::common::Cost;
+use crate::models::problem::Job;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// A module which balances amount of jobs between routes. Insertion into a route which already
+/// has more jobs than average of used routes is penalized proportionally to the difference.
+pub struct JobCountBalanceConstraintModule {
+    state_keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+}
+
+impl JobCountBalanceConstraintModule {
+    /// Creates a new instance of [`JobCountBalanceConstraintModule`] with given penalty applied
+    /// per each job above average.
+    pub fn new(penalty: Cost) -> Self {
+        assert!(penalty >= 0.);
+
+        Self {
+            state_keys: vec![],
+            constraints: vec![ConstraintVariant::SoftRoute(Arc::new(JobCountBalanceSoftRouteConstraint { penalty }))],
+        }
+    }
+}
+
+impl ConstraintModule for JobCountBalanceConstraintModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_ctx: &mut RouteContext, _job: &Job) {}
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.state_keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct JobCountBalanceSoftRouteConstraint {
+    penalty: Cost,
+}
+
+impl SoftRouteConstraint for JobCountBalanceSoftRouteConstraint {
+    fn estimate_job(&self, solution_ctx: &SolutionContext, route_ctx: &RouteContext, _job: &Job) -> Cost {
+        let (total, used) = solution_ctx
+            .routes
+            .iter()
+            .map(|rc| rc.route.tour.job_count())
+            .filter(|job_count| *job_count > 0)
+            .fold((0, 0), |(total, used), job_count| (total + job_count, used + 1));
+
+        if used == 0 {
+            return 0.;
+        }
+
+        let mean = total as f64 / used as f64;
+        let imbalance = (route_ctx.route.tour.job_count() as f64 - mean).max(0.);
+
+        imbalance * self.penalty
+    }
+}
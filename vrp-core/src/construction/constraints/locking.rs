@@ -46,14 +46,14 @@ impl StrictLockingModule {
                         condition: condition.clone(),
                         position: detail.position.clone(),
                         index: JobIndex {
-                            first: detail.jobs.first().unwrap().clone(),
-                            last: detail.jobs.last().unwrap().clone(),
-                            jobs: detail.jobs.iter().cloned().collect(),
+                            first: detail.root_jobs().next().unwrap(),
+                            last: detail.root_jobs().last().unwrap(),
+                            jobs: detail.root_jobs().collect(),
                         },
                     }));
                 }
 
-                detail.jobs.iter().cloned().collect::<HashSet<Job>>().into_iter().for_each(|job| {
+                detail.root_jobs().collect::<HashSet<Job>>().into_iter().for_each(|job| {
                     conditions.insert(job, condition.clone());
                 });
             });
@@ -0,0 +1,66 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/max_stops_test.rs"]
+mod max_stops_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use crate::models::common::ValueDimension;
+use crate::models::problem::Job;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// A max stops module provides way to limit amount of stops (job activities, tour start and end
+/// are not counted) served by one vehicle regardless of its capacity. The limit is read from
+/// vehicle's `max_stops` dimension, so vehicle types can have different limits. Vehicles without
+/// the dimension have no limit.
+pub struct MaxStopsConstraintModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl MaxStopsConstraintModule {
+    /// Creates a new instance of [`MaxStopsConstraintModule`].
+    pub fn new(code: i32) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(MaxStopsHardActivityConstraint { code }))],
+            keys: vec![],
+        }
+    }
+}
+
+impl ConstraintModule for MaxStopsConstraintModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_ctx: &mut RouteContext, _job: &Job) {}
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct MaxStopsHardActivityConstraint {
+    code: i32,
+}
+
+impl HardActivityConstraint for MaxStopsHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        _activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let max_stops = route_ctx.route.actor.vehicle.dimens.get_value::<usize>("max_stops")?;
+
+        // NOTE stop evaluation as insertion is not possible at any position of the route
+        if route_ctx.route.tour.activity_count() + 1 > *max_stops {
+            Some(ActivityConstraintViolation { code: self.code, stopped: true })
+        } else {
+            None
+        }
+    }
+}
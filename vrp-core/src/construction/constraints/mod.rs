@@ -59,11 +59,15 @@ pub const LATEST_ARRIVAL_KEY: i32 = 1;
 pub const WAITING_KEY: i32 = 2;
 pub const TOTAL_DISTANCE_KEY: i32 = 3;
 pub const TOTAL_DURATION_KEY: i32 = 4;
+/// A key for extra route costs which are not covered by route distance and duration.
+pub const EXTRA_COST_KEY: i32 = 5;
 
 pub const CURRENT_CAPACITY_KEY: i32 = 11;
 pub const MAX_FUTURE_CAPACITY_KEY: i32 = 12;
 pub const MAX_PAST_CAPACITY_KEY: i32 = 13;
 pub const RELOAD_INTERVALS_KEY: i32 = 14;
+/// A key which keeps whether vehicle carries some load when it leaves the activity.
+pub const IS_LOADED_KEY: i32 = 15;
 
 mod pipeline;
 pub use self::pipeline::*;
@@ -80,8 +84,23 @@ pub use self::capacity::*;
 mod locking;
 pub use self::locking::*;
 
+mod max_stops;
+pub use self::max_stops::*;
+
+mod incompatibility;
+pub use self::incompatibility::*;
+
+mod skills;
+pub use self::skills::*;
+
 mod conditional;
 pub use self::conditional::*;
 
 mod fleet_usage;
 pub use self::fleet_usage::*;
+
+mod job_count_balance;
+pub use self::job_count_balance::*;
+
+mod extra_costs;
+pub use self::extra_costs::*;
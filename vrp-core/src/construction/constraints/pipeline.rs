@@ -155,6 +155,36 @@ impl ConstraintPipeline {
         self
     }
 
+    /// Returns a copy of pipeline which ignores hard constraint violations with given code.
+    /// Constraint modules are not copied, so it can be used only to evaluate insertions into
+    /// routes which state is already accepted by original pipeline.
+    pub fn relaxed(&self, code: i32) -> ConstraintPipeline {
+        ConstraintPipeline {
+            modules: vec![],
+            state_keys: self.state_keys.clone(),
+            hard_route_constraints: self
+                .hard_route_constraints
+                .iter()
+                .map(|constraint| {
+                    let constraint: Arc<dyn HardRouteConstraint + Send + Sync> =
+                        Arc::new(RelaxedHardRouteConstraint { inner: constraint.clone(), code });
+                    constraint
+                })
+                .collect(),
+            hard_activity_constraints: self
+                .hard_activity_constraints
+                .iter()
+                .map(|constraint| {
+                    let constraint: Arc<dyn HardActivityConstraint + Send + Sync> =
+                        Arc::new(RelaxedHardActivityConstraint { inner: constraint.clone(), code });
+                    constraint
+                })
+                .collect(),
+            soft_route_constraints: self.soft_route_constraints.clone(),
+            soft_activity_constraints: self.soft_activity_constraints.clone(),
+        }
+    }
+
     /// Checks whether all hard route constraints are fulfilled.
     /// Returns result of first failed constraint or empty value.
     pub fn evaluate_hard_route(
@@ -187,6 +217,37 @@ impl ConstraintPipeline {
     }
 }
 
+struct RelaxedHardRouteConstraint {
+    inner: Arc<dyn HardRouteConstraint + Send + Sync>,
+    code: i32,
+}
+
+impl HardRouteConstraint for RelaxedHardRouteConstraint {
+    fn evaluate_job(
+        &self,
+        ctx: &SolutionContext,
+        route_ctx: &RouteContext,
+        job: &Job,
+    ) -> Option<RouteConstraintViolation> {
+        self.inner.evaluate_job(ctx, route_ctx, job).filter(|violation| violation.code != self.code)
+    }
+}
+
+struct RelaxedHardActivityConstraint {
+    inner: Arc<dyn HardActivityConstraint + Send + Sync>,
+    code: i32,
+}
+
+impl HardActivityConstraint for RelaxedHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        self.inner.evaluate_activity(route_ctx, activity_ctx).filter(|violation| violation.code != self.code)
+    }
+}
+
 impl PartialEq<RouteConstraintViolation> for RouteConstraintViolation {
     fn eq(&self, other: &RouteConstraintViolation) -> bool {
         self.code == other.code
@@ -1,26 +1,34 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/skills_test.rs"]
+mod skills_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{RouteContext, SolutionContext};
+use crate::models::common::ValueDimension;
+use crate::models::problem::Job;
 use std::collections::HashSet;
 use std::slice::Iter;
 use std::sync::Arc;
-use vrp_core::construction::constraints::*;
-use vrp_core::construction::heuristics::{RouteContext, SolutionContext};
-use vrp_core::models::common::ValueDimension;
-use vrp_core::models::problem::Job;
 
-pub struct SkillsModule {
+/// A skill module provides way to assign jobs only to vehicles which have all skills required
+/// by them. Skills are read from `skills` dimension of job and vehicle as a set of strings.
+/// Jobs without the dimension can be served by any vehicle.
+pub struct SkillConstraintModule {
     constraints: Vec<ConstraintVariant>,
     keys: Vec<i32>,
 }
 
-impl SkillsModule {
+impl SkillConstraintModule {
+    /// Creates a new instance of [`SkillConstraintModule`].
     pub fn new(code: i32) -> Self {
         Self {
-            constraints: vec![ConstraintVariant::HardRoute(Arc::new(SkillsHardRouteConstraint { code }))],
+            constraints: vec![ConstraintVariant::HardRoute(Arc::new(SkillHardRouteConstraint { code }))],
             keys: vec![],
         }
     }
 }
 
-impl ConstraintModule for SkillsModule {
+impl ConstraintModule for SkillConstraintModule {
     fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_ctx: &mut RouteContext, _job: &Job) {}
 
     fn accept_route_state(&self, _ctx: &mut RouteContext) {}
@@ -36,11 +44,11 @@ impl ConstraintModule for SkillsModule {
     }
 }
 
-struct SkillsHardRouteConstraint {
+struct SkillHardRouteConstraint {
     code: i32,
 }
 
-impl HardRouteConstraint for SkillsHardRouteConstraint {
+impl HardRouteConstraint for SkillHardRouteConstraint {
     fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
         if let Some(requirement) = job.dimens().get_value::<HashSet<String>>("skills") {
             if let Some(skills) = ctx.route.actor.vehicle.dimens.get_value::<HashSet<String>>("skills") {
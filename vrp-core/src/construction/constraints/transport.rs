@@ -5,9 +5,11 @@ mod transport_test;
 use crate::construction::constraints::*;
 use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
 use crate::construction::OP_START_MSG;
-use crate::models::common::{Cost, Distance, Duration, Profile, Timestamp};
+use crate::models::common::{Cost, Distance, Duration, Profile, Timestamp, ValueDimension};
 use crate::models::problem::{ActivityCost, Actor, Job, Single, TransportCost};
-use crate::models::solution::{Activity, TourActivity};
+use crate::models::solution::{Activity, Route, TourActivity};
+use crate::utils::compare_floats;
+use std::cmp::Ordering;
 use std::ops::Deref;
 use std::slice::Iter;
 use std::sync::Arc;
@@ -16,6 +18,20 @@ use std::sync::Arc;
 
 pub type TravelLimitFunc = Arc<dyn Fn(&Actor) -> (Option<Distance>, Option<Duration>) + Send + Sync>;
 
+/// Returns for each tour activity whether vehicle carries some load when it leaves the activity.
+pub type LoadStateFunc = Arc<dyn Fn(&Route) -> Vec<bool> + Send + Sync>;
+
+/// Specifies violation codes and time window handling of [`TransportConstraintModule`].
+struct TransportOptions {
+    time_window_code: i32,
+    distance_code: i32,
+    duration_code: i32,
+    /// A penalty per unit of lateness when time windows are soft.
+    tardiness_penalty: Option<Cost>,
+    /// A period after time window end when arrival is still treated as on-time.
+    time_window_grace: Duration,
+}
+
 /// A module which checks whether vehicle can serve activity taking into account their time windows
 /// and traveling constraints. Also it is responsible for transport cost calculations.
 pub struct TransportConstraintModule {
@@ -24,22 +40,29 @@ pub struct TransportConstraintModule {
     activity: Arc<dyn ActivityCost + Send + Sync>,
     transport: Arc<dyn TransportCost + Send + Sync>,
     limit_func: TravelLimitFunc,
+    load_state: Option<LoadStateFunc>,
+    has_soft_time_windows: bool,
+    time_window_grace: Duration,
 }
 
 impl ConstraintModule for TransportConstraintModule {
-    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, route_ctx: &mut RouteContext, _job: &Job) {
-        self.accept_route_state(route_ctx);
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, route_ctx: &mut RouteContext, job: &Job) {
+        // NOTE insertion can change load state, and therefore profile, of any leg before the job
+        // activities, so full recompute is used when load state is tracked.
+        match get_job_activity_range(route_ctx, job) {
+            Some((first, last)) if !self.has_load_state(route_ctx) => {
+                let stable = self.update_route_schedules_from(route_ctx, first, Some(last));
+                self.update_route_states_until(route_ctx, Some(stable.max(last + 1)));
+                self.finish_route_state(route_ctx);
+            }
+            _ => self.accept_route_state(route_ctx),
+        }
     }
 
     fn accept_route_state(&self, ctx: &mut RouteContext) {
         self.update_route_schedules(ctx);
         self.update_route_states(ctx);
-        // NOTE Rescheduling during the insertion process makes sense only if the traveling limit
-        // is set (for duration limit, not for distance).
-        if has_travel_limits(&self.limit_func, ctx) {
-            self.reschedule_departure(ctx)
-        }
-        self.update_statistics(ctx);
+        self.finish_route_state(ctx);
     }
 
     fn accept_solution_state(&self, ctx: &mut SolutionContext) {
@@ -69,77 +92,271 @@ impl TransportConstraintModule {
         distance_code: i32,
         duration_code: i32,
     ) -> Self {
+        Self::create(
+            activity,
+            transport,
+            limit_func,
+            TransportOptions {
+                time_window_code,
+                distance_code,
+                duration_code,
+                tardiness_penalty: None,
+                time_window_grace: 0.,
+            },
+        )
+    }
+
+    /// Creates a module where arrival within given grace period after job time window end is
+    /// still treated as on-time, so small deviations (e.g. caused by rounding) do not make
+    /// insertion infeasible.
+    pub fn new_with_time_window_grace(
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        limit_func: TravelLimitFunc,
+        time_window_code: i32,
+        distance_code: i32,
+        duration_code: i32,
+        time_window_grace: Duration,
+    ) -> Self {
+        Self::create(
+            activity,
+            transport,
+            limit_func,
+            TransportOptions {
+                time_window_code,
+                distance_code,
+                duration_code,
+                tardiness_penalty: None,
+                time_window_grace: time_window_grace.max(0.),
+            },
+        )
+    }
+
+    /// Creates a module where job time window ends are soft: a job can be served late, but each
+    /// time unit of tardiness is penalized with given cost during insertion. Actor's shift time
+    /// is still a hard limit. Use `TotalTardiness` objective to minimize tardiness of the solution.
+    pub fn new_with_soft_time_windows(
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        limit_func: TravelLimitFunc,
+        time_window_code: i32,
+        distance_code: i32,
+        duration_code: i32,
+        tardiness_penalty: Cost,
+    ) -> Self {
+        Self::create(
+            activity,
+            transport,
+            limit_func,
+            TransportOptions {
+                time_window_code,
+                distance_code,
+                duration_code,
+                tardiness_penalty: Some(tardiness_penalty),
+                time_window_grace: 0.,
+            },
+        )
+    }
+
+    fn create(
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        limit_func: TravelLimitFunc,
+        options: TransportOptions,
+    ) -> Self {
+        let TransportOptions { time_window_code, distance_code, duration_code, tardiness_penalty, time_window_grace } =
+            options;
+        let has_soft_time_windows = tardiness_penalty.is_some();
+
+        let mut constraints = vec![
+            ConstraintVariant::HardRoute(Arc::new(TimeHardRouteConstraint { code: time_window_code })),
+            ConstraintVariant::SoftRoute(Arc::new(RouteCostSoftRouteConstraint {})),
+            ConstraintVariant::HardActivity(Arc::new(TimeHardActivityConstraint {
+                code: time_window_code,
+                transport: transport.clone(),
+                activity: activity.clone(),
+                has_soft_time_windows,
+                time_window_grace,
+            })),
+            ConstraintVariant::HardActivity(Arc::new(TravelHardActivityConstraint {
+                limit_func: limit_func.clone(),
+                distance_code,
+                duration_code,
+                transport: transport.clone(),
+            })),
+            ConstraintVariant::SoftActivity(Arc::new(CostSoftActivityConstraint {
+                transport: transport.clone(),
+                activity: activity.clone(),
+            })),
+        ];
+
+        if let Some(penalty) = tardiness_penalty {
+            constraints.push(ConstraintVariant::SoftActivity(Arc::new(TardinessSoftActivityConstraint {
+                penalty,
+                transport: transport.clone(),
+                activity: activity.clone(),
+            })));
+        }
+
         Self {
-            state_keys: vec![LATEST_ARRIVAL_KEY, WAITING_KEY],
-            constraints: vec![
-                ConstraintVariant::HardRoute(Arc::new(TimeHardRouteConstraint { code: time_window_code })),
-                ConstraintVariant::SoftRoute(Arc::new(RouteCostSoftRouteConstraint {})),
-                ConstraintVariant::HardActivity(Arc::new(TimeHardActivityConstraint {
-                    code: time_window_code,
-                    transport: transport.clone(),
-                    activity: activity.clone(),
-                })),
-                ConstraintVariant::HardActivity(Arc::new(TravelHardActivityConstraint {
-                    limit_func: limit_func.clone(),
-                    distance_code,
-                    duration_code,
-                    transport: transport.clone(),
-                })),
-                ConstraintVariant::SoftActivity(Arc::new(CostSoftActivityConstraint {
-                    transport: transport.clone(),
-                    activity: activity.clone(),
-                })),
-            ],
+            state_keys: vec![LATEST_ARRIVAL_KEY, WAITING_KEY, IS_LOADED_KEY],
+            constraints,
             activity,
             transport,
             limit_func,
+            load_state: None,
+            has_soft_time_windows,
+            time_window_grace,
+        }
+    }
+
+    /// Sets a function which determines load state of the vehicle on each leg. It is used to select
+    /// loaded routing profile for vehicles which have "loaded_profile" dimension.
+    pub fn with_load_state(mut self, load_state: LoadStateFunc) -> Self {
+        self.load_state = Some(load_state);
+        self
+    }
+
+    fn has_load_state(&self, ctx: &RouteContext) -> bool {
+        self.load_state.is_some() && ctx.route.actor.vehicle.dimens.get_value::<Profile>("loaded_profile").is_some()
+    }
+
+    fn update_load_states(&self, ctx: &mut RouteContext) {
+        if let Some(load_state) = self.load_state.as_ref().filter(|_| self.has_load_state(ctx)) {
+            let (route, state) = ctx.as_mut();
+
+            route.tour.all_activities().zip(load_state.deref()(route)).for_each(|(activity, is_loaded)| {
+                state.put_activity_state(IS_LOADED_KEY, activity, is_loaded);
+            });
         }
     }
 
     fn update_route_schedules(&self, ctx: &mut RouteContext) {
-        let (init, actor) = {
-            let start = ctx.route.tour.start().unwrap();
-            ((start.place.location, start.schedule.departure), ctx.route.actor.clone())
-        };
+        self.update_load_states(ctx);
+        self.update_route_schedules_from(ctx, 1, None);
+    }
+
+    /// Updates schedules of activities starting from given index. If `stable_after` is set, update
+    /// stops at the first activity after that index which departure is not changed, as schedules
+    /// of its successors stay the same. Returns index of the last updated activity.
+    fn update_route_schedules_from(&self, ctx: &mut RouteContext, start: usize, stable_after: Option<usize>) -> usize {
+        let actor = ctx.route.actor.clone();
+        let mut last = start;
+
+        for index in start..ctx.route.tour.total() {
+            let (profile, setup, prev_loc, prev_dep) = {
+                let prev = ctx.route.tour.get(index - 1).unwrap();
+                let activity = ctx.route.tour.get(index).unwrap();
+
+                (
+                    get_leg_profile(ctx, prev),
+                    self.activity.setup(actor.as_ref(), prev, activity),
+                    prev.place.location,
+                    prev.schedule.departure,
+                )
+            };
+
+            let a = ctx.route_mut().tour.get_mut(index).unwrap();
+            let old_departure = a.schedule.departure;
 
-        ctx.route_mut().tour.all_activities_mut().skip(1).fold(init, |(loc, dep), a| {
-            a.schedule.arrival = dep + self.transport.duration(actor.vehicle.profile, loc, a.place.location, dep);
+            a.schedule.arrival = prev_dep + self.transport.duration(profile, prev_loc, a.place.location, prev_dep);
             a.schedule.departure = a.schedule.arrival.max(a.place.time.start)
-                + self.activity.duration(actor.as_ref(), a.deref(), a.schedule.arrival);
+                + self.activity.duration(actor.as_ref(), a.deref(), a.schedule.arrival)
+                + setup;
 
-            (a.place.location, a.schedule.departure)
-        });
+            last = index;
+
+            let is_stable = compare_floats(old_departure, a.schedule.departure) == Ordering::Equal;
+            if is_stable && stable_after.map_or(false, |stable_after| index > stable_after) {
+                break;
+            }
+        }
+
+        last
+    }
+
+    /// Returns setup times of given amount of the first tour activities, the first one always
+    /// has no setup.
+    fn get_setup_times(&self, ctx: &RouteContext, amount: usize) -> Vec<Duration> {
+        let actor = ctx.route.actor.as_ref();
+        let activities = ctx.route.tour.all_activities().take(amount).collect::<Vec<_>>();
+
+        std::iter::once(0.)
+            .chain(activities.windows(2).map(|pair| self.activity.setup(actor, pair[0], pair[1])))
+            .take(activities.len())
+            .collect()
     }
 
     fn update_route_states(&self, ctx: &mut RouteContext) {
+        self.update_route_states_until(ctx, None);
+    }
+
+    /// Updates latest arrival and waiting states of activities up to given index (inclusive) using
+    /// states of its successors. All activities are updated when index is not specified.
+    fn update_route_states_until(&self, ctx: &mut RouteContext, last: Option<usize>) {
         // update latest arrival and waiting states of non-terminate (jobs) activities
         let actor = ctx.route.actor.clone();
-        let init = (
+        let total = ctx.route.tour.total();
+        let route_init = (
             actor.detail.time.end,
             actor.detail.end.unwrap_or_else(|| actor.detail.start.unwrap_or_else(|| panic!(OP_START_MSG))),
             0_f64,
         );
 
-        let (route, state) = ctx.as_mut();
-
-        route.tour.all_activities().rev().fold(init, |acc, act| {
-            if act.job.is_none() {
-                return acc;
-            }
-
-            let (end_time, prev_loc, waiting) = acc;
-            let potential_latest = end_time
-                - self.transport.duration(actor.vehicle.profile, act.place.location, prev_loc, end_time)
-                - self.activity.duration(actor.as_ref(), act.deref(), end_time);
+        let (last, init) = match last.filter(|last| *last + 1 < total) {
+            Some(last) => match ctx.route.tour.all_activities().skip(last + 1).find(|a| a.job.is_some()) {
+                Some(next) => match (
+                    ctx.state.get_activity_state::<Timestamp>(LATEST_ARRIVAL_KEY, next),
+                    ctx.state.get_activity_state::<Duration>(WAITING_KEY, next),
+                ) {
+                    (Some(&latest_arrival), Some(&waiting)) => (last, (latest_arrival, next.place.location, waiting)),
+                    _ => (total - 1, route_init),
+                },
+                None => (last, route_init),
+            },
+            None => (total - 1, route_init),
+        };
 
-            let latest_arrival_time = act.place.time.end.min(potential_latest);
-            let future_waiting = waiting + (act.place.time.start - act.schedule.arrival).max(0_f64);
+        let profiles =
+            ctx.route.tour.all_activities().take(last + 1).map(|a| get_leg_profile(ctx, a)).collect::<Vec<_>>();
+        let setups = self.get_setup_times(ctx, last + 1);
+        let (route, state) = ctx.as_mut();
 
-            state.put_activity_state(LATEST_ARRIVAL_KEY, &act, latest_arrival_time);
-            state.put_activity_state(WAITING_KEY, &act, future_waiting);
+        route.tour.all_activities().take(last + 1).zip(profiles.into_iter().zip(setups)).rev().fold(
+            init,
+            |acc, (act, (profile, setup))| {
+                if act.job.is_none() {
+                    return acc;
+                }
+
+                let (end_time, prev_loc, waiting) = acc;
+                let potential_latest = end_time
+                    - self.transport.duration(profile, act.place.location, prev_loc, end_time)
+                    - self.activity.duration(actor.as_ref(), act.deref(), end_time)
+                    - setup;
+
+                let latest_arrival_time = if self.has_soft_time_windows {
+                    potential_latest
+                } else {
+                    (act.place.time.end + self.time_window_grace).min(potential_latest)
+                };
+                let future_waiting = waiting + (act.place.time.start - act.schedule.arrival).max(0_f64);
+
+                state.put_activity_state(LATEST_ARRIVAL_KEY, &act, latest_arrival_time);
+                state.put_activity_state(WAITING_KEY, &act, future_waiting);
+
+                (latest_arrival_time, act.place.location, future_waiting)
+            },
+        );
+    }
 
-            (latest_arrival_time, act.place.location, future_waiting)
-        });
+    fn finish_route_state(&self, ctx: &mut RouteContext) {
+        // NOTE Rescheduling during the insertion process makes sense only if the traveling limit
+        // is set (for duration limit, not for distance).
+        if has_travel_limits(&self.limit_func, ctx) {
+            self.reschedule_departure(ctx)
+        }
+        self.update_statistics(ctx);
     }
 
     fn reschedule_departure(&self, ctx: &mut RouteContext) {
@@ -158,7 +375,7 @@ impl TransportConstraintModule {
             let start = ctx.route.tour.start().unwrap();
             let last_departure_time = start.schedule.departure;
             let start_to_first = self.transport.duration(
-                ctx.route.actor.vehicle.profile,
+                get_leg_profile(ctx, start),
                 start.place.location,
                 first.place.location,
                 last_departure_time,
@@ -175,13 +392,13 @@ impl TransportConstraintModule {
 
         let total_dur = end.schedule.arrival - start.schedule.departure;
 
-        let init = (start.place.location, start.schedule.departure, Distance::default());
-        let (_, _, total_dist) = ctx.route.tour.all_activities().skip(1).fold(init, |(loc, dep, total_dist), a| {
-            let total_dist =
-                total_dist + self.transport.distance(ctx.route.actor.vehicle.profile, loc, a.place.location, dep);
+        let init = (start.place.location, start.schedule.departure, Distance::default(), get_leg_profile(ctx, start));
+        let (_, _, total_dist, _) =
+            ctx.route.tour.all_activities().skip(1).fold(init, |(loc, dep, total_dist, profile), a| {
+                let total_dist = total_dist + self.transport.distance(profile, loc, a.place.location, dep);
 
-            (a.place.location, a.schedule.departure, total_dist)
-        });
+                (a.place.location, a.schedule.departure, total_dist, get_leg_profile(ctx, a))
+            });
 
         ctx.state_mut().put_route_state(TOTAL_DISTANCE_KEY, total_dist);
         ctx.state_mut().put_route_state(TOTAL_DURATION_KEY, total_dur);
@@ -216,11 +433,25 @@ impl HardRouteConstraint for TimeHardRouteConstraint {
     }
 }
 
-/// Checks time windows of actor and job.
+/// Checks time windows of actor and job. When job time windows are soft, only their starts
+/// are respected and actor's shift end is used as the latest arrival time. Otherwise, job time
+/// window end is extended by grace period.
 struct TimeHardActivityConstraint {
     code: i32,
     activity: Arc<dyn ActivityCost + Send + Sync>,
     transport: Arc<dyn TransportCost + Send + Sync>,
+    has_soft_time_windows: bool,
+    time_window_grace: Duration,
+}
+
+impl TimeHardActivityConstraint {
+    fn get_time_end(&self, actor: &Actor, activity: &Activity) -> Timestamp {
+        match (self.has_soft_time_windows, activity.job.is_some()) {
+            (true, true) => actor.detail.time.end,
+            (false, true) => activity.place.time.end + self.time_window_grace,
+            _ => activity.place.time.end,
+        }
+    }
 }
 
 impl HardActivityConstraint for TimeHardActivityConstraint {
@@ -236,7 +467,7 @@ impl HardActivityConstraint for TimeHardActivityConstraint {
         let next = activity_ctx.next;
 
         let departure = prev.schedule.departure;
-        let profile = actor.vehicle.profile;
+        let profile = get_leg_profile(route_ctx, prev);
 
         if actor.detail.time.end < prev.place.time.start
             || actor.detail.time.end < target.place.time.start
@@ -252,11 +483,15 @@ impl HardActivityConstraint for TimeHardActivityConstraint {
             }
             (
                 next.place.location,
-                *route_ctx.state.get_activity_state(LATEST_ARRIVAL_KEY, next).unwrap_or(&next.place.time.end),
+                route_ctx
+                    .state
+                    .get_activity_state(LATEST_ARRIVAL_KEY, next)
+                    .cloned()
+                    .unwrap_or_else(|| self.get_time_end(actor, next)),
             )
         } else {
             // open vrp
-            (target.place.location, target.place.time.end.min(actor.detail.time.end))
+            (target.place.location, self.get_time_end(actor, target).min(actor.detail.time.end))
         };
 
         let arr_time_at_next =
@@ -273,9 +508,10 @@ impl HardActivityConstraint for TimeHardActivityConstraint {
             departure + self.transport.duration(profile, prev.place.location, target.place.location, departure);
 
         let end_time_at_new_act = arr_time_at_target_act.max(target.place.time.start)
-            + self.activity.duration(actor, target.deref(), arr_time_at_target_act);
+            + self.activity.duration(actor, target.deref(), arr_time_at_target_act)
+            + self.activity.setup(actor, prev, target);
 
-        let latest_arr_time_at_new_act = target.place.time.end.min(
+        let latest_arr_time_at_new_act = self.get_time_end(actor, target).min(
             latest_arr_time_at_next_act
                 - self.transport.duration(
                     profile,
@@ -290,12 +526,16 @@ impl HardActivityConstraint for TimeHardActivityConstraint {
             return stop(self.code);
         }
 
-        if next.is_none() {
-            return success();
-        }
+        let next = match next {
+            Some(next) => next,
+            None => return success(),
+        };
 
+        // NOTE latest arrival at next activity takes into account its setup after the previous one
+        let setup_change = self.activity.setup(actor, target, next) - self.activity.setup(actor, prev, next);
         let arr_time_at_next_act = end_time_at_new_act
-            + self.transport.duration(profile, target.place.location, next_act_location, end_time_at_new_act);
+            + self.transport.duration(profile, target.place.location, next_act_location, end_time_at_new_act)
+            + setup_change.max(0.);
 
         if arr_time_at_next_act > latest_arr_time_at_next_act {
             stop(self.code)
@@ -342,13 +582,12 @@ impl HardActivityConstraint for TravelHardActivityConstraint {
 
 impl TravelHardActivityConstraint {
     fn calculate_travel(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> (Distance, Duration) {
-        let actor = &route_ctx.route.actor;
-        let profile = actor.vehicle.profile;
-
         let prev = activity_ctx.prev;
         let tar = activity_ctx.target;
         let next = activity_ctx.next;
 
+        let profile = get_leg_profile(route_ctx, prev);
+
         let prev_dep = prev.schedule.departure;
 
         let (prev_to_tar_dis, prev_to_tar_dur) = self.calculate_leg_travel_info(profile, prev, tar, prev_dep);
@@ -385,6 +624,29 @@ impl TravelHardActivityConstraint {
     }
 }
 
+/// Returns routing profile used by vehicle to leave given activity: a loaded profile when it is
+/// specified for the vehicle and the vehicle carries some load, or its default profile otherwise.
+/// Load state is maintained by `TransportConstraintModule` configured with `with_load_state`.
+pub fn get_leg_profile(route_ctx: &RouteContext, activity: &TourActivity) -> Profile {
+    let vehicle = &route_ctx.route.actor.vehicle;
+
+    match vehicle.dimens.get_value::<Profile>("loaded_profile") {
+        Some(profile) if route_ctx.state.get_activity_state::<bool>(IS_LOADED_KEY, activity) == Some(&true) => *profile,
+        _ => vehicle.profile,
+    }
+}
+
+/// Returns indices of the first and the last activity of given job in the tour.
+fn get_job_activity_range(route_ctx: &RouteContext, job: &Job) -> Option<(usize, usize)> {
+    route_ctx.route.tour.all_activities().enumerate().filter(|(_, a)| a.has_same_job(job)).fold(
+        None,
+        |acc, (index, _)| match acc {
+            Some((first, _)) => Some((first, index)),
+            None => Some((index, index)),
+        },
+    )
+}
+
 fn has_travel_limits(limit_func: &TravelLimitFunc, route_ctx: &RouteContext) -> bool {
     match (limit_func)(&route_ctx.route.actor) {
         (Some(_), _) => true,
@@ -416,15 +678,17 @@ impl CostSoftActivityConstraint {
     fn analyze_route_leg(
         &self,
         actor: &Actor,
+        profile: Profile,
         start: &Activity,
         end: &Activity,
         time: Timestamp,
     ) -> (Cost, Cost, Timestamp) {
-        let arrival =
-            time + self.transport.duration(actor.vehicle.profile, start.place.location, end.place.location, time);
-        let departure = arrival.max(end.place.time.start) + self.activity.duration(actor, end, arrival);
+        let arrival = time + self.transport.duration(profile, start.place.location, end.place.location, time);
+        let departure = arrival.max(end.place.time.start)
+            + self.activity.duration(actor, end, arrival)
+            + self.activity.setup(actor, start, end);
 
-        let transport_cost = self.transport.cost(actor, start.place.location, end.place.location, time);
+        let transport_cost = self.transport.cost(actor, profile, start.place.location, end.place.location, time);
         let activity_cost = self.activity.cost(actor, end, arrival);
 
         (transport_cost, activity_cost, departure)
@@ -439,11 +703,13 @@ impl SoftActivityConstraint for CostSoftActivityConstraint {
         let target = activity_ctx.target;
         let next = activity_ctx.next;
 
+        let profile = get_leg_profile(route_ctx, prev);
+
         let (tp_cost_left, act_cost_left, dep_time_left) =
-            self.analyze_route_leg(actor, prev, target, prev.schedule.departure);
+            self.analyze_route_leg(actor, profile, prev, target, prev.schedule.departure);
 
         let (tp_cost_right, act_cost_right, dep_time_right) = if let Some(next) = next {
-            self.analyze_route_leg(actor, target, next, dep_time_left)
+            self.analyze_route_leg(actor, profile, target, next, dep_time_left)
         } else {
             (0., 0., 0.)
         };
@@ -459,7 +725,7 @@ impl SoftActivityConstraint for CostSoftActivityConstraint {
         let waiting_time = *route_ctx.state.get_activity_state(WAITING_KEY, next).unwrap_or(&0_f64);
 
         let (tp_cost_old, act_cost_old, dep_time_old) =
-            self.analyze_route_leg(actor, prev, next, prev.schedule.departure);
+            self.analyze_route_leg(actor, profile, prev, next, prev.schedule.departure);
 
         let waiting_cost =
             waiting_time.min(0_f64.max(dep_time_right - dep_time_old)) * actor.vehicle.costs.per_waiting_time;
@@ -470,6 +736,51 @@ impl SoftActivityConstraint for CostSoftActivityConstraint {
     }
 }
 
+/// Penalizes tardiness of the target activity and additional tardiness of the next one when
+/// job time windows are soft.
+struct TardinessSoftActivityConstraint {
+    penalty: Cost,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl TardinessSoftActivityConstraint {
+    fn get_arrival(&self, profile: Profile, start: &Activity, end: &Activity, departure: Timestamp) -> Timestamp {
+        departure + self.transport.duration(profile, start.place.location, end.place.location, departure)
+    }
+}
+
+impl SoftActivityConstraint for TardinessSoftActivityConstraint {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> f64 {
+        let actor = route_ctx.route.actor.as_ref();
+
+        let prev = activity_ctx.prev;
+        let target = activity_ctx.target;
+
+        let profile = get_leg_profile(route_ctx, prev);
+
+        let arr_time_at_target = self.get_arrival(profile, prev, target, prev.schedule.departure);
+        let target_tardiness = get_tardiness(target, arr_time_at_target);
+
+        let next_tardiness_change = activity_ctx.next.filter(|next| next.job.is_some()).map_or(0., |next| {
+            let dep_time_at_target = arr_time_at_target.max(target.place.time.start)
+                + self.activity.duration(actor, target, arr_time_at_target)
+                + self.activity.setup(actor, prev, target);
+
+            let new_arrival = self.get_arrival(profile, target, next, dep_time_at_target);
+            let old_arrival = self.get_arrival(profile, prev, next, prev.schedule.departure);
+
+            get_tardiness(next, new_arrival) - get_tardiness(next, old_arrival)
+        });
+
+        (target_tardiness + next_tardiness_change) * self.penalty
+    }
+}
+
+fn get_tardiness(activity: &Activity, arrival: Timestamp) -> Duration {
+    (arrival - activity.place.time.end).max(0.)
+}
+
 fn fail(code: i32) -> Option<ActivityConstraintViolation> {
     Some(ActivityConstraintViolation { code, stopped: true })
 }
@@ -0,0 +1,79 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/heuristics/cache_test.rs"]
+mod cache_test;
+
+use crate::construction::heuristics::{InsertionResult, RouteContext};
+use crate::models::problem::Job;
+use hashbrown::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, RwLock};
+
+/// Memoizes best insertion results of jobs into routes during one recreate pass.
+/// A result is keyed by job and route signature, so it is invalidated as soon as the route is changed.
+///
+/// NOTE cached results are valid only if constraints do not depend on solution state outside of
+/// evaluated route.
+pub struct InsertionCache {
+    results: RwLock<HashMap<(Job, u64), InsertionResult>>,
+}
+
+impl Default for InsertionCache {
+    fn default() -> Self {
+        Self { results: RwLock::new(HashMap::default()) }
+    }
+}
+
+impl InsertionCache {
+    /// Returns cached insertion result of the job into given route or evaluates and caches it.
+    pub fn get_or_evaluate<F>(&self, job: &Job, route_ctx: &RouteContext, evaluate: F) -> InsertionResult
+    where
+        F: FnOnce() -> InsertionResult,
+    {
+        let key = (job.clone(), get_route_signature(route_ctx));
+
+        if let Some(result) = self.results.read().unwrap().get(&key) {
+            return copy_result(result);
+        }
+
+        let result = evaluate();
+        self.results.write().unwrap().insert(key, copy_result(&result));
+
+        result
+    }
+
+    /// Returns amount of cached results.
+    pub fn size(&self) -> usize {
+        self.results.read().unwrap().len()
+    }
+}
+
+/// Returns a route signature which changes when actor, activities or their schedules are changed.
+fn get_route_signature(route_ctx: &RouteContext) -> u64 {
+    let route = route_ctx.route.as_ref();
+    let mut hasher = DefaultHasher::new();
+
+    (Arc::as_ptr(&route.actor) as usize).hash(&mut hasher);
+    route.tour.all_activities().for_each(|activity| {
+        activity.job.as_ref().map(|job| Arc::as_ptr(job) as usize).hash(&mut hasher);
+        activity.place.location.hash(&mut hasher);
+        activity.schedule.arrival.to_bits().hash(&mut hasher);
+        activity.schedule.departure.to_bits().hash(&mut hasher);
+    });
+
+    hasher.finish()
+}
+
+fn copy_result(result: &InsertionResult) -> InsertionResult {
+    match result {
+        InsertionResult::Success(success) => InsertionResult::make_success(
+            success.cost,
+            success.job.clone(),
+            success.activities.iter().map(|(activity, index)| (Box::new(activity.deep_copy()), *index)).collect(),
+            success.context.clone(),
+        ),
+        InsertionResult::Failure(failure) => {
+            InsertionResult::make_failure_with_code(failure.constraint, failure.job.clone())
+        }
+    }
+}
@@ -2,8 +2,9 @@
 #[path = "../../../tests/unit/construction/heuristics/context_test.rs"]
 mod context_test;
 
-use crate::construction::constraints::{TOTAL_DISTANCE_KEY, TOTAL_DURATION_KEY};
+use crate::construction::constraints::{get_extra_cost, TOTAL_DISTANCE_KEY, TOTAL_DURATION_KEY};
 use crate::construction::heuristics::factories::*;
+use crate::construction::heuristics::InsertionCache;
 use crate::construction::OP_START_MSG;
 use crate::models::common::{Cost, Schedule};
 use crate::models::problem::*;
@@ -25,6 +26,9 @@ pub struct InsertionContext {
 
     /// Random generator.
     pub random: Arc<dyn Random + Send + Sync>,
+
+    /// Insertion results cache which is used during one recreate pass when enabled.
+    pub cache: Option<InsertionCache>,
 }
 
 impl InsertionContext {
@@ -62,6 +66,7 @@ impl InsertionContext {
             problem: self.problem.clone(),
             solution: self.solution.deep_copy(),
             random: self.random.clone(),
+            cache: None,
         }
     }
 
@@ -136,7 +141,7 @@ impl SolutionContext {
         }
     }
 
-    fn get_route_cost(route_ctx: &RouteContext) -> Cost {
+    pub fn get_route_cost(route_ctx: &RouteContext) -> Cost {
         let get_cost = |costs: &Costs, distance: f64, duration: f64| {
             costs.fixed
                 + costs.per_distance * distance
@@ -147,7 +152,9 @@ impl SolutionContext {
         let distance = route_ctx.state.get_route_state::<f64>(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.);
         let duration = route_ctx.state.get_route_state::<f64>(TOTAL_DURATION_KEY).cloned().unwrap_or(0.);
 
-        get_cost(&actor.vehicle.costs, distance, duration) + get_cost(&actor.driver.costs, distance, duration)
+        get_cost(&actor.vehicle.costs, distance, duration)
+            + get_cost(&actor.driver.costs, distance, duration)
+            + get_extra_cost(route_ctx)
     }
 }
 
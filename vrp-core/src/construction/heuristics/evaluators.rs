@@ -10,6 +10,7 @@ use crate::models::common::Cost;
 use crate::models::problem::{Job, Multi, Single};
 use crate::models::solution::{Activity, Place, TourActivity};
 use crate::models::Problem;
+use crate::utils::compare_floats;
 
 /// Specifies allowed insertion position in route for the job.
 #[derive(Copy, Clone)]
@@ -21,16 +22,27 @@ pub enum InsertionPosition {
 }
 
 /// Evaluates possibility to preform insertion from given insertion context in all available
-/// routes at given position constraint.
+/// routes at given position constraint. When insertion context has a cache, results for routes
+/// which are not changed since previous evaluation are taken from it.
 pub fn evaluate_job_insertion(job: &Job, ctx: &InsertionContext, position: InsertionPosition) -> InsertionResult {
-    ctx.solution
-        .routes
-        .iter()
-        .cloned()
-        .chain(ctx.solution.registry.next().map(RouteContext::new))
-        .fold(InsertionResult::make_failure(), |acc, route_ctx| {
+    let routes = ctx.solution.routes.iter().cloned().chain(ctx.solution.registry.next().map(RouteContext::new));
+
+    match ctx.cache.as_ref() {
+        Some(cache) => routes.fold(InsertionResult::make_failure(), |acc, route_ctx| {
+            let result = cache.get_or_evaluate(job, &route_ctx, || {
+                evaluate_job_insertion_in_route(job, ctx, &route_ctx, position, None)
+            });
+
+            // NOTE keep previously found result on equal cost as uncached evaluation does
+            match (&acc, &result) {
+                (InsertionResult::Success(lhs), InsertionResult::Success(rhs)) if lhs.cost <= rhs.cost => acc,
+                _ => InsertionResult::choose_best_result(acc, result),
+            }
+        }),
+        None => routes.fold(InsertionResult::make_failure(), |acc, route_ctx| {
             evaluate_job_insertion_in_route(job, ctx, &route_ctx, position, Some(acc))
-        })
+        }),
+    }
 }
 
 /// Evaluates possibility to preform insertion from given insertion context in given route
@@ -63,6 +75,10 @@ pub fn evaluate_job_insertion_in_route(
         }
     }
 
+    // NOTE negative route costs make insertion cheaper, so they should be taken into account when
+    // activity costs are compared against the best known one
+    let best_known_cost = best_known_cost.map(|cost| cost - route_costs.min(0.));
+
     InsertionResult::choose_best_result(
         alternative,
         match job {
@@ -74,6 +90,82 @@ pub fn evaluate_job_insertion_in_route(
     )
 }
 
+/// Returns up to `k` cheapest feasible insertion positions of the job across all routes (including
+/// a new route for the next available actor) sorted by marginal cost, cheapest first. Each position
+/// is represented by route index in solution, index of activity after which job is inserted, and
+/// insertion cost. A new route has index equal to the amount of existing routes. For multi job,
+/// only the best position per route is returned with index of its first activity.
+pub fn best_positions(ctx: &InsertionContext, job: &Job, k: usize) -> Vec<(usize, usize, Cost)> {
+    let mut positions = ctx
+        .solution
+        .routes
+        .iter()
+        .cloned()
+        .chain(ctx.solution.registry.next().map(RouteContext::new))
+        .enumerate()
+        .flat_map(|(route_index, route_ctx)| {
+            if ctx.problem.constraint.evaluate_hard_route(&ctx.solution, &route_ctx, job).is_some() {
+                return vec![];
+            }
+
+            let route_costs = ctx.problem.constraint.evaluate_soft_route(&ctx.solution, &route_ctx, job);
+
+            match job {
+                Job::Single(single) => evaluate_single_positions(ctx, &route_ctx, single)
+                    .into_iter()
+                    .map(|(index, cost)| (route_index, index, cost + route_costs))
+                    .collect(),
+                Job::Multi(_) => {
+                    match evaluate_job_insertion_in_route(job, ctx, &route_ctx, InsertionPosition::Any, None) {
+                        InsertionResult::Success(success) => {
+                            vec![(route_index, success.activities.first().unwrap().1, success.cost)]
+                        }
+                        InsertionResult::Failure(_) => vec![],
+                    }
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    positions.sort_by(|(_, _, a), (_, _, b)| compare_floats(*a, *b));
+    positions.truncate(k);
+
+    positions
+}
+
+fn evaluate_single_positions(
+    ctx: &InsertionContext,
+    route_ctx: &RouteContext,
+    single: &Arc<Single>,
+) -> Vec<(usize, Cost)> {
+    let mut activity = Box::new(Activity::new_with_job(single.clone()));
+    let mut positions = vec![];
+
+    for leg in route_ctx.route.tour.legs() {
+        let (result, is_stopped) = match analyze_insertion_in_route_leg(
+            ctx,
+            route_ctx,
+            leg,
+            single,
+            &mut activity,
+            SingleContext::new(None, 0),
+        ) {
+            Ok(result) => (result, false),
+            Err(result) => (result, true),
+        };
+
+        if result.is_success() {
+            positions.push((result.index, result.cost.unwrap()));
+        }
+
+        if is_stopped {
+            break;
+        }
+    }
+
+    positions
+}
+
 fn evaluate_single(
     job: &Job,
     single: &Arc<Single>,
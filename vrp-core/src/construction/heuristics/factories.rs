@@ -20,7 +20,7 @@ pub fn create_insertion_context(problem: Arc<Problem>, random: Arc<dyn Random +
     let mut registry = Registry::new(&problem.fleet);
     let state = Default::default();
 
-    let mut sequence_job_usage: HashMap<Job, usize> = Default::default();
+    let mut sequence_job_usage: HashMap<Job, Vec<Arc<Single>>> = Default::default();
 
     problem.locks.iter().for_each(|lock| {
         let actor = registry.available().find(|a| lock.condition.deref()(a.as_ref()));
@@ -53,17 +53,27 @@ pub fn create_insertion_context(problem: Arc<Problem>, random: Arc<dyn Random +
 
             lock.details.iter().fold(start, |acc, detail| {
                 match detail.order {
-                    LockOrder::Any => reserved.extend(detail.jobs.iter().cloned()),
-                    _ => locked.extend(detail.jobs.iter().cloned()),
+                    LockOrder::Any => reserved.extend(detail.root_jobs()),
+                    _ => locked.extend(detail.root_jobs()),
                 }
 
-                detail.jobs.iter().fold(acc, |acc, job| {
-                    let activity = match job {
-                        Job::Single(single) => create_activity(single.clone(), acc),
-                        Job::Multi(multi) => {
-                            let idx = sequence_job_usage.get(job).cloned().unwrap_or(0);
-                            sequence_job_usage.insert(job.clone(), idx + 1);
-                            create_activity(multi.jobs.get(idx).unwrap().clone(), acc)
+                detail.jobs.iter().zip(detail.root_jobs()).fold(acc, |acc, (job, root)| {
+                    let activity = match (job, &root) {
+                        (Job::Single(single), Job::Single(_)) => create_activity(single.clone(), acc),
+                        (Job::Single(single), Job::Multi(_)) => {
+                            sequence_job_usage.entry(root).or_insert_with(Vec::new).push(single.clone());
+                            create_activity(single.clone(), acc)
+                        }
+                        (Job::Multi(multi), _) => {
+                            let used = sequence_job_usage.entry(root).or_insert_with(Vec::new);
+                            let single = multi
+                                .jobs
+                                .iter()
+                                .find(|single| !used.iter().any(|other| Arc::ptr_eq(other, single)))
+                                .unwrap()
+                                .clone();
+                            used.push(single.clone());
+                            create_activity(single, acc)
                         }
                     };
                     let last_location = activity.place.location;
@@ -78,9 +88,9 @@ pub fn create_insertion_context(problem: Arc<Problem>, random: Arc<dyn Random +
             routes.push(route_ctx);
         } else {
             lock.details.iter().for_each(|detail| {
-                detail.jobs.iter().for_each(|job| {
+                detail.root_jobs().for_each(|job| {
                     // TODO what reason code to use?
-                    unassigned.insert(job.clone(), 0);
+                    unassigned.insert(job, 0);
                 });
             });
         }
@@ -88,7 +98,7 @@ pub fn create_insertion_context(problem: Arc<Problem>, random: Arc<dyn Random +
 
     // NOTE all services from sequence should be used in init route or none of them
     sequence_job_usage.iter().for_each(|(job, usage)| {
-        assert_eq!(job.to_multi().jobs.len(), *usage);
+        assert_eq!(job.to_multi().jobs.len(), usage.len());
     });
 
     let required = problem
@@ -101,6 +111,7 @@ pub fn create_insertion_context(problem: Arc<Problem>, random: Arc<dyn Random +
         problem: problem.clone(),
         solution: SolutionContext { required, ignored: vec![], unassigned, locked, routes, registry, state },
         random,
+        cache: None,
     };
 
     problem.constraint.accept_solution_state(&mut ctx.solution);
@@ -117,7 +128,7 @@ pub fn create_insertion_context_from_solution(
     let jobs: Vec<Job> = solution.0.unassigned.iter().map(|(job, _)| job.clone()).collect();
     let unassigned = Default::default();
     let locked = problem.locks.iter().fold(HashSet::new(), |mut acc, lock| {
-        acc.extend(lock.details.iter().flat_map(|d| d.jobs.iter().cloned()));
+        acc.extend(lock.details.iter().flat_map(|d| d.root_jobs()));
         acc
     });
 
@@ -136,5 +147,5 @@ pub fn create_insertion_context_from_solution(
     let mut solution = SolutionContext { required: jobs, ignored: vec![], unassigned, locked, routes, registry, state };
     problem.constraint.accept_solution_state(&mut solution);
 
-    InsertionContext { problem, solution, random }
+    InsertionContext { problem, solution, random, cache: None }
 }
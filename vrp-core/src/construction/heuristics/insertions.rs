@@ -1,11 +1,16 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/heuristics/insertions_test.rs"]
+mod insertions_test;
+
 use crate::construction::heuristics::evaluators::{evaluate_job_insertion, InsertionPosition};
-use crate::construction::heuristics::{InsertionContext, RouteContext};
+use crate::construction::heuristics::{InsertionCache, InsertionContext, RouteContext};
 use crate::construction::Quota;
-use crate::models::common::Cost;
+use crate::models::common::{Cost, IdDimension, Timestamp};
 use crate::models::problem::Job;
 use crate::models::solution::TourActivity;
-use crate::utils::map_reduce;
+use crate::utils::{compare_floats, map_reduce};
 use std::borrow::Borrow;
+use std::cmp::Ordering;
 use std::ops::Deref;
 
 /// Specifies insertion result variant.
@@ -103,18 +108,52 @@ pub trait ResultSelector {
     fn select(&self, ctx: &InsertionContext, left: InsertionResult, right: InsertionResult) -> InsertionResult;
 }
 
+/// Specifies how to choose between insertion results which costs are equal within epsilon.
+#[derive(Clone, Copy)]
+pub enum TieBreakPolicy {
+    /// Prefers lower vehicle id, then lower insertion position, then lower job id.
+    Deterministic,
+    /// Prefers job with earlier end of its activity time window (most urgent deadline), falls back
+    /// to deterministic policy when deadlines are the same.
+    EarliestDeadlineFirst,
+}
+
 /// Selects best result.
-pub struct BestResultSelector {}
+pub struct BestResultSelector {
+    policy: TieBreakPolicy,
+    epsilon: Cost,
+}
 
 impl Default for BestResultSelector {
     fn default() -> Self {
-        Self {}
+        Self::new(TieBreakPolicy::Deterministic, 0.)
+    }
+}
+
+impl BestResultSelector {
+    /// Creates a new instance of `BestResultSelector` which applies tie break policy when insertion
+    /// costs differ not more than on epsilon.
+    pub fn new(policy: TieBreakPolicy, epsilon: Cost) -> Self {
+        Self { policy, epsilon }
     }
 }
 
 impl ResultSelector for BestResultSelector {
     fn select(&self, _: &InsertionContext, left: InsertionResult, right: InsertionResult) -> InsertionResult {
-        InsertionResult::choose_best_result(left, right)
+        let deadline_order = match (&self.policy, &left, &right) {
+            (TieBreakPolicy::EarliestDeadlineFirst, InsertionResult::Success(lhs), InsertionResult::Success(rhs))
+                if (lhs.cost - rhs.cost).abs() <= self.epsilon =>
+            {
+                compare_floats(get_deadline(lhs), get_deadline(rhs))
+            }
+            _ => Ordering::Equal,
+        };
+
+        match deadline_order {
+            Ordering::Less => left,
+            Ordering::Greater => right,
+            Ordering::Equal => InsertionResult::choose_best_result(left, right),
+        }
     }
 }
 
@@ -123,6 +162,7 @@ impl ResultSelector for BestResultSelector {
 /// there are no jobs left or it is not possible to insert due to constraint limitations.
 pub struct InsertionHeuristic {
     insertion_position: InsertionPosition,
+    use_cache: bool,
 }
 
 impl Default for InsertionHeuristic {
@@ -133,7 +173,13 @@ impl Default for InsertionHeuristic {
 
 impl InsertionHeuristic {
     pub fn new(insertion_position: InsertionPosition) -> Self {
-        Self { insertion_position }
+        Self { insertion_position, use_cache: false }
+    }
+
+    /// Enables or disables caching of insertion results into unchanged routes within one pass.
+    pub fn with_cache(mut self, use_cache: bool) -> Self {
+        self.use_cache = use_cache;
+        self
     }
 }
 
@@ -148,6 +194,7 @@ impl InsertionHeuristic {
         let mut ctx = ctx;
 
         prepare_ctx(&mut ctx);
+        ctx.cache = if self.use_cache { Some(InsertionCache::default()) } else { None };
 
         while !ctx.solution.required.is_empty() && !quota.as_ref().map_or(false, |q| q.is_reached()) {
             let jobs = job_selector.select(&mut ctx).collect::<Vec<Job>>();
@@ -159,6 +206,7 @@ impl InsertionHeuristic {
             insert(result, &mut ctx);
         }
 
+        ctx.cache = None;
         finalize_ctx(&mut ctx);
 
         ctx
@@ -180,13 +228,17 @@ impl InsertionResult {
         Self::Failure(InsertionFailure { constraint: code, job })
     }
 
-    /// Compares two insertion results and returns the cheapest by cost.
+    /// Compares two insertion results and returns the cheapest by cost. When costs are equal,
+    /// the result is chosen deterministically: the one with lower vehicle id wins, then the one
+    /// with lower insertion position. Otherwise, left one is kept, so the order of jobs defined
+    /// by job selector is respected.
     pub fn choose_best_result(left: Self, right: Self) -> Self {
         match (left.borrow(), right.borrow()) {
             (Self::Success(_), Self::Failure(_)) => left,
             (Self::Failure(_), Self::Success(_)) => right,
             (Self::Success(lhs), Self::Success(rhs)) => {
-                if lhs.cost > rhs.cost {
+                let ordering = compare_floats(lhs.cost, rhs.cost).then_with(|| compare_equal_cost(lhs, rhs));
+                if ordering == Ordering::Greater {
                     right
                 } else {
                     left
@@ -197,6 +249,18 @@ impl InsertionResult {
     }
 }
 
+fn compare_equal_cost(lhs: &InsertionSuccess, rhs: &InsertionSuccess) -> Ordering {
+    let get_vehicle_id = |success: &InsertionSuccess| success.context.route.actor.vehicle.dimens.get_id().cloned();
+    let get_position = |success: &InsertionSuccess| success.activities.first().map(|(_, index)| *index);
+
+    get_vehicle_id(lhs).cmp(&get_vehicle_id(rhs)).then_with(|| get_position(lhs).cmp(&get_position(rhs)))
+}
+
+/// Returns the earliest time window end of activities to be inserted.
+fn get_deadline(success: &InsertionSuccess) -> Timestamp {
+    success.activities.iter().map(|(activity, _)| activity.place.time.end).fold(std::f64::MAX, |acc, end| acc.min(end))
+}
+
 fn prepare_ctx(ctx: &mut InsertionContext) {
     ctx.solution.required.extend(ctx.solution.unassigned.drain().map(|(job, _)| job));
     ctx.problem.constraint.accept_solution_state(&mut ctx.solution);
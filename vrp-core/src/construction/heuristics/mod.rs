@@ -3,6 +3,9 @@
 //! # Design
 //!
 
+mod cache;
+pub use self::cache::InsertionCache;
+
 mod context;
 pub use self::context::*;
 
@@ -13,3 +16,6 @@ mod factories;
 
 mod insertions;
 pub use self::insertions::*;
+
+mod validation;
+pub use self::validation::*;
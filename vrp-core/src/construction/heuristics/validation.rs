@@ -0,0 +1,74 @@
+use crate::construction::heuristics::*;
+use crate::models::problem::Job;
+use crate::models::solution::Registry;
+use crate::models::{Problem, Solution};
+
+/// Specifies hard constraint violation found in existing solution.
+#[derive(Clone)]
+pub struct ConstraintViolation {
+    /// Index of route in solution.
+    pub route_idx: usize,
+    /// Index of activity in route's tour.
+    pub activity_idx: usize,
+    /// A job which activity violates constraint.
+    pub job: Job,
+    /// Violation code which is used as marker of specific constraint violated.
+    pub code: i32,
+}
+
+/// Replays all routes of given solution through problem's constraint pipeline, activity by
+/// activity, and returns all found hard constraint violations.
+pub fn get_constraint_violations(problem: &Problem, solution: &Solution) -> Vec<ConstraintViolation> {
+    let constraint = problem.constraint.clone();
+
+    let mut solution_ctx = SolutionContext {
+        required: problem.jobs.all().collect(),
+        ignored: vec![],
+        unassigned: solution.unassigned.clone(),
+        locked: Default::default(),
+        routes: vec![],
+        registry: Registry::new(&problem.fleet),
+        state: Default::default(),
+    };
+    constraint.accept_solution_state(&mut solution_ctx);
+
+    solution.routes.iter().enumerate().fold(vec![], |mut violations, (route_idx, route)| {
+        solution_ctx.registry.use_actor(&route.actor);
+        let mut route_ctx = RouteContext::new(route.actor.clone());
+
+        route.tour.all_activities().enumerate().filter(|(_, activity)| activity.job.is_some()).for_each(
+            |(activity_idx, activity)| {
+                let job = activity.retrieve_job().unwrap();
+                let target = Box::new(activity.deep_copy());
+                let index = route_ctx.route.tour.activity_count();
+                let tour = &route_ctx.route.tour;
+                let activity_ctx = ActivityContext {
+                    index,
+                    prev: tour.get(index).unwrap(),
+                    target: &target,
+                    next: tour.get(index + 1),
+                };
+
+                let route_violation = if tour.contains(&job) {
+                    None
+                } else {
+                    constraint.evaluate_hard_route(&solution_ctx, &route_ctx, &job).map(|violation| violation.code)
+                };
+
+                // NOTE report only one violation per activity, route level has precedence
+                if let Some(code) = route_violation.or_else(|| {
+                    constraint.evaluate_hard_activity(&route_ctx, &activity_ctx).map(|violation| violation.code)
+                }) {
+                    violations.push(ConstraintViolation { route_idx, activity_idx, job: job.clone(), code });
+                }
+
+                route_ctx.route_mut().tour.insert_at(target, index + 1);
+                constraint.accept_route_state(&mut route_ctx);
+            },
+        );
+
+        solution_ctx.routes.push(route_ctx);
+
+        violations
+    })
+}
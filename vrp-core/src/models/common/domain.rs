@@ -172,3 +172,20 @@ impl IdDimension for Dimensions {
         self.get_value("id")
     }
 }
+
+/// A trait to get or set job priority where 1 is the highest one.
+pub trait PriorityDimension {
+    fn set_priority(&mut self, priority: i32) -> &mut Self;
+    fn get_priority(&self) -> Option<&i32>;
+}
+
+impl PriorityDimension for Dimensions {
+    fn set_priority(&mut self, priority: i32) -> &mut Self {
+        self.set_value("priority", priority);
+        self
+    }
+
+    fn get_priority(&self) -> Option<&i32> {
+        self.get_value("priority")
+    }
+}
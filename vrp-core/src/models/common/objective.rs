@@ -24,6 +24,14 @@ pub trait Objective {
 
     /// An objective fitness value for given `solution`.
     fn fitness(&self, solution: &Self::Solution) -> f64;
+
+    /// Returns a short name of the objective. Default is the name of implementing type.
+    fn name(&self) -> String {
+        let name = std::any::type_name::<Self>();
+        let name = name.split('<').next().unwrap_or(name);
+
+        name.rsplit("::").next().unwrap_or(name).to_string()
+    }
 }
 
 /// A multi objective.
@@ -31,4 +39,10 @@ pub trait MultiObjective: Objective {
     fn objectives<'a>(
         &'a self,
     ) -> Box<dyn Iterator<Item = &Box<dyn Objective<Solution = Self::Solution> + Send + Sync>> + 'a>;
+
+    /// Estimates parameters which depend on the scale of objective values, e.g. normalization
+    /// references, using initial solutions. It is called once before search starts.
+    fn estimate(&self, _solutions: &[&Self::Solution]) -> Result<(), String> {
+        Ok(())
+    }
 }
@@ -1,5 +1,7 @@
 use crate::construction::constraints::ConstraintPipeline;
-use crate::models::problem::{ActivityCost, Actor, Fleet, Job, Jobs, ObjectiveCost, TransportCost};
+use crate::construction::heuristics::InsertionContext;
+use crate::models::common::MultiObjective;
+use crate::models::problem::{ActivityCost, Actor, Fleet, Job, Jobs, Multi, TransportCost};
 use crate::models::solution::{Registry, Route};
 use hashbrown::HashMap;
 use std::any::Any;
@@ -29,7 +31,7 @@ pub struct Problem {
     pub transport: Arc<dyn TransportCost + Send + Sync>,
 
     /// Specifies an objective costs..
-    pub objective: Arc<ObjectiveCost>,
+    pub objective: Arc<dyn MultiObjective<Solution = InsertionContext> + Send + Sync>,
 
     /// Specifies index for storing extra parameters of arbitrary type.
     pub extras: Arc<Extras>,
@@ -79,7 +81,7 @@ pub struct LockDetail {
     pub order: LockOrder,
     /// Lock position.
     pub position: LockPosition,
-    /// Jobs affected by the lock.
+    /// Jobs affected by the lock. A sub job of multi job can be used to lock its specific activity.
     pub jobs: Vec<Job>,
 }
 
@@ -96,6 +98,11 @@ impl LockDetail {
     pub fn new(order: LockOrder, position: LockPosition, jobs: Vec<Job>) -> Self {
         Self { order, position, jobs }
     }
+
+    /// Returns jobs affected by the lock where sub jobs are replaced by their multi jobs.
+    pub fn root_jobs(&self) -> impl Iterator<Item = Job> + '_ {
+        self.jobs.iter().map(get_root_job)
+    }
 }
 
 impl Lock {
@@ -104,3 +111,11 @@ impl Lock {
         Self { condition, details }
     }
 }
+
+/// Returns multi job if given job is its sub job, otherwise returns job itself.
+fn get_root_job(job: &Job) -> Job {
+    match job {
+        Job::Single(single) => Multi::roots(single).map_or_else(|| job.clone(), Job::Multi),
+        Job::Multi(_) => job.clone(),
+    }
+}
@@ -5,12 +5,13 @@ mod costs_test;
 use crate::construction::heuristics::InsertionContext;
 use crate::models::common::*;
 use crate::models::problem::{Actor, TargetObjective};
-use crate::models::solution::Activity;
+use crate::models::solution::{tour_similarity, Activity};
 use crate::solver::objectives::{TotalRoutes, TotalTransportCost, TotalUnassignedJobs};
-use crate::utils::CollectGroupBy;
-use hashbrown::HashMap;
+use crate::utils::{compare_floats, CollectGroupBy};
+use hashbrown::{HashMap, HashSet};
 use std::cmp::Ordering;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+use std::sync::{Arc, RwLock};
 
 /// A hierarchical multi objective for vehicle routing problem.
 pub struct ObjectiveCost {
@@ -34,8 +35,14 @@ impl Objective for ObjectiveCost {
         }
     }
 
-    fn distance(&self, _a: &Self::Solution, _b: &Self::Solution) -> f64 {
-        unreachable!()
+    /// Returns a structural distance between solutions defined as `1 - similarity`, where similarity
+    /// is a fraction of shared job adjacency edges (see [`tour_similarity`]). It is zero for solutions
+    /// with the same tours and one for solutions without common edges.
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        let tours_a = a.solution.routes.iter().map(|route_ctx| &route_ctx.route.tour);
+        let tours_b = b.solution.routes.iter().map(|route_ctx| &route_ctx.route.tour);
+
+        1. - tour_similarity(tours_a, tours_b)
     }
 
     fn fitness(&self, solution: &Self::Solution) -> f64 {
@@ -58,6 +65,122 @@ impl Default for ObjectiveCost {
     }
 }
 
+/// Specifies how objective fitness is normalized before it is weighted, so objectives with
+/// disparate scales can be combined.
+#[derive(Clone, Copy, Debug)]
+pub enum ObjectiveNormalization {
+    /// Fitness is mapped from [min, max] range into [0, 1].
+    MinMax(f64, f64),
+    /// Fitness is divided by given reference scale.
+    Reference(f64),
+    /// Fitness is divided by reference scale estimated as average fitness of initial solutions.
+    /// Until it is estimated, raw fitness is used.
+    Estimated,
+}
+
+/// A multi objective for vehicle routing problem which combines objectives into a weighted sum
+/// instead of comparing them in hierarchical order, so their relative importance can be expressed,
+/// e.g. distance is three times more important than amount of routes.
+pub struct WeightedObjectiveCost {
+    objectives: Vec<TargetObjective>,
+    weights: Vec<f64>,
+    normalizations: Vec<Option<ObjectiveNormalization>>,
+    estimates: RwLock<Vec<Option<f64>>>,
+}
+
+impl WeightedObjectiveCost {
+    /// Creates a new instance of `WeightedObjectiveCost` from objectives with their weights.
+    pub fn new(objectives: Vec<(TargetObjective, f64)>) -> Self {
+        let (objectives, weights): (Vec<_>, Vec<_>) = objectives.into_iter().unzip();
+        let size = objectives.len();
+
+        Self { objectives, weights, normalizations: vec![None; size], estimates: RwLock::new(vec![None; size]) }
+    }
+
+    /// Sets normalization for each objective, objectives without normalization use raw fitness.
+    pub fn with_normalizations(mut self, normalizations: Vec<Option<ObjectiveNormalization>>) -> Self {
+        assert_eq!(normalizations.len(), self.objectives.len(), "normalization should be specified for each objective");
+
+        self.normalizations = normalizations;
+        self
+    }
+
+    fn normalize(&self, index: usize, fitness: f64) -> f64 {
+        let scale = |fitness: f64, scale: f64| if scale.abs() > 0. { fitness / scale } else { fitness };
+
+        match self.normalizations.get(index).cloned().flatten() {
+            Some(ObjectiveNormalization::MinMax(min, max)) => scale(fitness - min, max - min),
+            Some(ObjectiveNormalization::Reference(reference)) => scale(fitness, reference),
+            Some(ObjectiveNormalization::Estimated) => {
+                match self.estimates.read().unwrap().get(index).cloned().flatten() {
+                    Some(reference) => scale(fitness, reference),
+                    None => fitness,
+                }
+            }
+            None => fitness,
+        }
+    }
+}
+
+impl Objective for WeightedObjectiveCost {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        compare_floats(self.fitness(a), self.fitness(b))
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        (self.fitness(a) - self.fitness(b)).abs()
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        self.objectives
+            .iter()
+            .zip(self.weights.iter())
+            .enumerate()
+            .map(|(index, (objective, weight))| self.normalize(index, objective.fitness(solution)) * weight)
+            .sum()
+    }
+}
+
+impl MultiObjective for WeightedObjectiveCost {
+    fn objectives<'a>(&'a self) -> Box<dyn Iterator<Item = &TargetObjective> + 'a> {
+        Box::new(self.objectives.iter())
+    }
+
+    fn estimate(&self, solutions: &[&Self::Solution]) -> Result<(), String> {
+        let estimates = self
+            .objectives
+            .iter()
+            .zip(self.normalizations.iter())
+            .map(|(objective, normalization)| match normalization {
+                Some(ObjectiveNormalization::Estimated) => {
+                    if solutions.is_empty() {
+                        return Err(format!(
+                            "cannot estimate reference of '{}' objective without solutions",
+                            objective.name()
+                        ));
+                    }
+
+                    let reference = solutions.iter().map(|solution| objective.fitness(solution)).sum::<f64>()
+                        / solutions.len() as f64;
+
+                    if compare_floats(reference, 0.) == Ordering::Equal {
+                        Err(format!("cannot use zero as estimated reference of '{}' objective", objective.name()))
+                    } else {
+                        Ok(Some(reference))
+                    }
+                }
+                _ => Ok(None),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        *self.estimates.write().unwrap() = estimates;
+
+        Ok(())
+    }
+}
+
 /// Provides the way to get cost information for specific activities done by specific actor.
 pub trait ActivityCost {
     /// Returns cost to perform activity.
@@ -73,6 +196,12 @@ pub trait ActivityCost {
     fn duration(&self, _actor: &Actor, activity: &Activity, _arrival: Timestamp) -> Cost {
         activity.place.duration
     }
+
+    /// Returns setup time spent before activity when it is preceded by given activity.
+    /// It is added to the activity's service duration during scheduling.
+    fn setup(&self, _actor: &Actor, _prev: &Activity, _activity: &Activity) -> Duration {
+        0.
+    }
 }
 
 /// Default activity costs.
@@ -86,12 +215,54 @@ impl Default for SimpleActivityCost {
 
 impl ActivityCost for SimpleActivityCost {}
 
+/// Specifies previous job type, next job type and setup time needed to switch between them.
+pub type JobTypeSetup = (String, String, Duration);
+
+/// An activity cost decorator which adds sequence dependent setup time based on job "type" dimension
+/// of the preceding and the current activities. Transitions which are not specified, or which
+/// involve activities without job type, require no setup. Setup time is not priced separately.
+pub struct SetupTimeActivityCost {
+    inner: Arc<dyn ActivityCost + Send + Sync>,
+    setup_times: HashMap<(String, String), Duration>,
+}
+
+impl SetupTimeActivityCost {
+    /// Creates a new instance of `SetupTimeActivityCost`.
+    pub fn new(inner: Arc<dyn ActivityCost + Send + Sync>, setup_times: Vec<JobTypeSetup>) -> Self {
+        let setup_times = setup_times.into_iter().map(|(from, to, duration)| ((from, to), duration)).collect();
+
+        Self { inner, setup_times }
+    }
+}
+
+impl ActivityCost for SetupTimeActivityCost {
+    fn cost(&self, actor: &Actor, activity: &Activity, arrival: Timestamp) -> Cost {
+        self.inner.cost(actor, activity, arrival)
+    }
+
+    fn duration(&self, actor: &Actor, activity: &Activity, arrival: Timestamp) -> Cost {
+        self.inner.duration(actor, activity, arrival)
+    }
+
+    fn setup(&self, actor: &Actor, prev: &Activity, activity: &Activity) -> Duration {
+        let get_job_type =
+            |activity: &Activity| activity.job.as_ref().and_then(|job| job.dimens.get_value::<String>("type")).cloned();
+
+        let setup = match (get_job_type(prev), get_job_type(activity)) {
+            (Some(from), Some(to)) => self.setup_times.get(&(from, to)).cloned(),
+            _ => None,
+        };
+
+        setup.unwrap_or_else(|| self.inner.setup(actor, prev, activity))
+    }
+}
+
 /// Provides the way to get routing information for specific locations and actor.
 pub trait TransportCost {
-    /// Returns transport cost between two locations.
-    fn cost(&self, actor: &Actor, from: Location, to: Location, departure: Timestamp) -> Cost {
-        let distance = self.distance(actor.vehicle.profile, from, to, departure);
-        let duration = self.duration(actor.vehicle.profile, from, to, departure);
+    /// Returns transport cost between two locations traveled by actor using given routing profile.
+    fn cost(&self, actor: &Actor, profile: Profile, from: Location, to: Location, departure: Timestamp) -> Cost {
+        let distance = self.distance(profile, from, to, departure);
+        let duration = self.duration(profile, from, to, departure);
 
         distance * (actor.driver.costs.per_distance + actor.vehicle.costs.per_distance)
             + duration * (actor.driver.costs.per_driving_time + actor.vehicle.costs.per_driving_time)
@@ -102,6 +273,11 @@ pub trait TransportCost {
 
     /// Returns transport distance between two locations.
     fn distance(&self, profile: Profile, from: Location, to: Location, departure: Timestamp) -> Distance;
+
+    /// Returns true if transport time or distance depends on departure time.
+    fn is_time_aware(&self) -> bool {
+        false
+    }
 }
 
 /// Contains matrix routing data for specific profile and, optionally, time.
@@ -150,6 +326,34 @@ pub fn create_matrix_transport_cost(costs: Vec<MatrixData>) -> Result<Arc<dyn Tr
     })
 }
 
+/// Checks whether routing matrices are symmetric and returns location pairs `(i, j)`, where `i < j`,
+/// for which duration or distance of the leg from `i` to `j` differs from the opposite one more
+/// than on given tolerance relative to the biggest of them. Asymmetric matrices are valid, so
+/// result is supposed to be used as a warning, e.g. to detect missing return legs.
+pub fn check_matrix_symmetry(costs: &[MatrixData], tolerance: f64) -> Vec<(Location, Location)> {
+    let is_asymmetric = |values: &[f64], size: usize, from: usize, to: usize| {
+        let (forward, backward) = (values[from * size + to], values[to * size + from]);
+        (forward - backward).abs() > tolerance * forward.abs().max(backward.abs())
+    };
+
+    let pairs = costs
+        .iter()
+        .flat_map(|matrix| {
+            let size = (matrix.durations.len() as f64).sqrt() as usize;
+
+            (0..size).flat_map(move |from| (from + 1..size).map(move |to| (from, to))).filter(move |&(from, to)| {
+                is_asymmetric(matrix.durations.as_slice(), size, from, to)
+                    || is_asymmetric(matrix.distances.as_slice(), size, from, to)
+            })
+        })
+        .collect::<HashSet<_>>();
+
+    let mut pairs = pairs.into_iter().collect::<Vec<_>>();
+    pairs.sort();
+
+    pairs
+}
+
 /// A time agnostic matrix routing costs.
 struct TimeAgnosticMatrixTransportCost {
     durations: Vec<Vec<Duration>>,
@@ -222,25 +426,30 @@ impl TimeAwareMatrixTransportCost {
 
         Ok(Self { costs, size })
     }
-}
 
-impl TransportCost for TimeAwareMatrixTransportCost {
-    fn duration(&self, profile: Profile, from: Location, to: Location, timestamp: Timestamp) -> Duration {
+    /// Returns matrix value for given timestamp using linear interpolation between the closest
+    /// matrices. The value of the first or the last matrix is used when timestamp is out of range.
+    fn interpolate(
+        &self,
+        profile: Profile,
+        from: Location,
+        to: Location,
+        timestamp: Timestamp,
+        values: impl Fn(&MatrixData) -> &Vec<f64>,
+    ) -> f64 {
         let (timestamps, matrices) = self.costs.get(&profile).unwrap();
         let data_idx = from * self.size + to;
 
         match timestamps.binary_search(&(timestamp as u64)) {
-            Ok(matrix_idx) => *matrices.get(matrix_idx).unwrap().durations.get(data_idx).unwrap(),
-            Err(matrix_idx) if matrix_idx == 0 => *matrices.first().unwrap().durations.get(data_idx).unwrap(),
-            Err(matrix_idx) if matrix_idx == matrices.len() => {
-                *matrices.last().unwrap().durations.get(data_idx).unwrap()
-            }
+            Ok(matrix_idx) => *values(matrices.get(matrix_idx).unwrap()).get(data_idx).unwrap(),
+            Err(matrix_idx) if matrix_idx == 0 => *values(matrices.first().unwrap()).get(data_idx).unwrap(),
+            Err(matrix_idx) if matrix_idx == matrices.len() => *values(matrices.last().unwrap()).get(data_idx).unwrap(),
             Err(matrix_idx) => {
                 let left_matrix = matrices.get(matrix_idx - 1).unwrap();
                 let right_matrix = matrices.get(matrix_idx).unwrap();
 
-                let left_value = *matrices.get(matrix_idx - 1).unwrap().durations.get(data_idx).unwrap();
-                let right_value = *matrices.get(matrix_idx).unwrap().durations.get(data_idx).unwrap();
+                let left_value = *values(left_matrix).get(data_idx).unwrap();
+                let right_value = *values(right_matrix).get(data_idx).unwrap();
 
                 // perform linear interpolation
                 let ratio = (timestamp - left_matrix.timestamp.unwrap())
@@ -250,19 +459,19 @@ impl TransportCost for TimeAwareMatrixTransportCost {
             }
         }
     }
+}
+
+impl TransportCost for TimeAwareMatrixTransportCost {
+    fn duration(&self, profile: Profile, from: Location, to: Location, timestamp: Timestamp) -> Duration {
+        self.interpolate(profile, from, to, timestamp, |matrix| &matrix.durations)
+    }
 
     fn distance(&self, profile: Profile, from: Location, to: Location, timestamp: Timestamp) -> Distance {
-        let (timestamps, matrices) = self.costs.get(&profile).unwrap();
-        let data_idx = from * self.size + to;
+        self.interpolate(profile, from, to, timestamp, |matrix| &matrix.distances)
+    }
 
-        match timestamps.binary_search(&(timestamp as u64)) {
-            Ok(matrix_idx) => *matrices.get(matrix_idx).unwrap().distances.get(data_idx).unwrap(),
-            Err(matrix_idx) if matrix_idx == 0 => *matrices.first().unwrap().distances.get(data_idx).unwrap(),
-            Err(matrix_idx) if matrix_idx == matrices.len() => {
-                *matrices.last().unwrap().distances.get(data_idx).unwrap()
-            }
-            Err(matrix_idx) => *matrices.get(matrix_idx).unwrap().distances.get(data_idx).unwrap(),
-        }
+    fn is_time_aware(&self) -> bool {
+        true
     }
 }
 
@@ -291,3 +500,293 @@ fn dominance_order<S>(a: &S, b: &S, objectives: &Vec<Box<dyn Objective<Solution
         Ordering::Equal
     }
 }
+
+/// A transport cost which needs no routing matrices: distance between two locations is calculated
+/// as great-circle (haversine) distance between their geo coordinates in meters, duration is
+/// derived from it using speed of the profile in meters per time unit.
+///
+/// It is intended for prototyping only: great-circle distance ignores road network, so it is
+/// always shorter than the real route distance, typically by a quarter or more in cities, and
+/// traffic, turn restrictions and road classes are not reflected in durations at all.
+pub struct HaversineTransportCost {
+    coordinates: Vec<(f64, f64)>,
+    speeds: Vec<f64>,
+}
+
+impl HaversineTransportCost {
+    /// Creates a new instance of `HaversineTransportCost`. Coordinates are (lat, lng) pairs in
+    /// degrees indexed by location, speeds are indexed by profile.
+    pub fn new(coordinates: Vec<(f64, f64)>, speeds: Vec<f64>) -> Result<Self, String> {
+        if speeds.is_empty() {
+            return Err("At least one profile speed should be specified".to_string());
+        }
+
+        if speeds.iter().any(|speed| *speed <= 0.) {
+            return Err("Profile speed should be positive".to_string());
+        }
+
+        Ok(Self { coordinates, speeds })
+    }
+}
+
+impl TransportCost for HaversineTransportCost {
+    fn duration(&self, profile: Profile, from: Location, to: Location, departure: Timestamp) -> Duration {
+        self.distance(profile, from, to, departure) / *self.speeds.get(profile as usize).unwrap()
+    }
+
+    fn distance(&self, _: Profile, from: Location, to: Location, _: Timestamp) -> Distance {
+        get_haversine_distance(*self.coordinates.get(from).unwrap(), *self.coordinates.get(to).unwrap())
+    }
+}
+
+/// Gets distance in meters between two (lat, lng) points in degrees using haversine formula.
+pub fn get_haversine_distance(from: (f64, f64), to: (f64, f64)) -> Distance {
+    let (lat1, lng1) = from;
+    let (lat2, lng2) = to;
+
+    let d_lat = degree_rad(lat1 - lat2);
+    let d_lng = degree_rad(lng1 - lng2);
+
+    let lat1 = degree_rad(lat1);
+    let lat2 = degree_rad(lat2);
+
+    let a =
+        (d_lat / 2.).sin() * (d_lat / 2.).sin() + (d_lng / 2.).sin() * (d_lng / 2.).sin() * (lat1).cos() * (lat2).cos();
+    let c = 2. * a.sqrt().atan2((1. - a).sqrt());
+
+    let radius = wgs84_earth_radius(d_lat);
+
+    radius * c
+}
+
+/// Converts degrees to radians.
+#[inline(always)]
+fn degree_rad(degrees: f64) -> f64 {
+    std::f64::consts::PI * degrees / 180.
+}
+
+#[inline(always)]
+fn wgs84_earth_radius(lat: f64) -> f64 {
+    // semi-axes of WGS-84 geoidal reference
+    const WGS84_A: f64 = 6378137.0; // major semiaxis [m]
+    const WGS84_B: f64 = 6356752.3; // minor semiaxis [m]
+
+    // http://en.wikipedia.org/wiki/Earth_radius
+    let an = WGS84_A * WGS84_A * lat.cos();
+    let bn = WGS84_B * WGS84_B * lat.sin();
+    let ad = WGS84_A * lat.cos();
+    let bd = WGS84_B * lat.sin();
+
+    ((an * an + bn * bn) / (ad * ad + bd * bd)).sqrt()
+}
+
+/// A transport cost which needs no routing matrices: distance between two locations is calculated
+/// as straight-line (euclidean) distance between their planar coordinates, duration is derived
+/// from it using speed of the profile. Useful for planar instances, e.g. from TSPLIB.
+pub struct EuclideanTransportCost {
+    coordinates: Vec<(f64, f64)>,
+    speeds: Vec<f64>,
+}
+
+impl EuclideanTransportCost {
+    /// Creates a new instance of `EuclideanTransportCost`. Coordinates are (x, y) pairs indexed by
+    /// location, speeds are indexed by profile.
+    pub fn new(coordinates: Vec<(f64, f64)>, speeds: Vec<f64>) -> Result<Self, String> {
+        if speeds.is_empty() {
+            return Err("At least one profile speed should be specified".to_string());
+        }
+
+        if speeds.iter().any(|speed| *speed <= 0.) {
+            return Err("Profile speed should be positive".to_string());
+        }
+
+        Ok(Self { coordinates, speeds })
+    }
+}
+
+impl TransportCost for EuclideanTransportCost {
+    fn duration(&self, profile: Profile, from: Location, to: Location, departure: Timestamp) -> Duration {
+        self.distance(profile, from, to, departure) / *self.speeds.get(profile as usize).unwrap()
+    }
+
+    fn distance(&self, _: Profile, from: Location, to: Location, _: Timestamp) -> Distance {
+        let (x1, y1) = *self.coordinates.get(from).unwrap();
+        let (x2, y2) = *self.coordinates.get(to).unwrap();
+        let (x, y) = (x1 - x2, y1 - y2);
+
+        (x * x + y * y).sqrt()
+    }
+}
+
+/// Specifies profile, cost multiplier and location pairs (legs) to which multiplier is applied.
+pub type ProfileAvoidance = (Profile, f64, Vec<(Location, Location)>);
+
+/// A transport cost decorator which makes some legs more expensive for specific profiles.
+/// It can be used to model coarse routing preferences, e.g. trucks avoiding narrow roads.
+pub struct AvoidanceTransportCost {
+    inner: Arc<dyn TransportCost + Send + Sync>,
+    avoidance: HashMap<Profile, (f64, HashSet<(Location, Location)>)>,
+}
+
+impl AvoidanceTransportCost {
+    /// Creates a new instance of `AvoidanceTransportCost`.
+    pub fn new(inner: Arc<dyn TransportCost + Send + Sync>, avoidance: Vec<ProfileAvoidance>) -> Self {
+        let avoidance = avoidance
+            .into_iter()
+            .map(|(profile, multiplier, legs)| (profile, (multiplier, legs.into_iter().collect())))
+            .collect();
+
+        Self { inner, avoidance }
+    }
+
+    /// Returns cost multiplier of leg for given profile.
+    pub fn multiplier(&self, profile: Profile, from: Location, to: Location) -> f64 {
+        self.avoidance
+            .get(&profile)
+            .filter(|(_, legs)| legs.contains(&(from, to)))
+            .map_or(1., |(multiplier, _)| *multiplier)
+    }
+
+    /// Returns extra cost applied to given leg on top of the original transport cost.
+    pub fn penalty(&self, actor: &Actor, profile: Profile, from: Location, to: Location, departure: Timestamp) -> Cost {
+        let multiplier = self.multiplier(profile, from, to);
+
+        if multiplier == 1. {
+            0.
+        } else {
+            self.inner.cost(actor, profile, from, to, departure) * (multiplier - 1.)
+        }
+    }
+}
+
+impl TransportCost for AvoidanceTransportCost {
+    fn cost(&self, actor: &Actor, profile: Profile, from: Location, to: Location, departure: Timestamp) -> Cost {
+        self.inner.cost(actor, profile, from, to, departure) + self.penalty(actor, profile, from, to, departure)
+    }
+
+    fn duration(&self, profile: Profile, from: Location, to: Location, departure: Timestamp) -> Duration {
+        self.inner.duration(profile, from, to, departure)
+    }
+
+    fn distance(&self, profile: Profile, from: Location, to: Location, departure: Timestamp) -> Distance {
+        self.inner.distance(profile, from, to, departure)
+    }
+
+    fn is_time_aware(&self) -> bool {
+        self.inner.is_time_aware()
+    }
+}
+
+type TransportCache = RwLock<HashMap<(Profile, Location, Location), (Duration, Distance)>>;
+
+/// A transport cost decorator which memoizes duration and distance of the inner transport cost
+/// per profile and leg. It is useful when the inner cost is expensive to calculate, e.g. not
+/// backed by routing matrix. Only duration and distance are memoized: cost is always delegated to
+/// the inner transport cost, so its custom cost logic (e.g. avoidance penalties) is preserved.
+/// Caching is disabled when the inner cost is time aware as its values depend on departure time.
+/// Memory usage grows up to amount of profiles multiplied by squared amount of locations.
+pub struct CachedTransportCost {
+    inner: Arc<dyn TransportCost + Send + Sync>,
+    cache: Option<TransportCache>,
+    hits: AtomicUsize,
+    misses: AtomicUsize,
+}
+
+impl CachedTransportCost {
+    /// Creates a new instance of `CachedTransportCost`.
+    pub fn new(inner: Arc<dyn TransportCost + Send + Sync>) -> Self {
+        let cache = if inner.is_time_aware() { None } else { Some(RwLock::new(HashMap::new())) };
+
+        Self { inner, cache, hits: AtomicUsize::new(0), misses: AtomicUsize::new(0) }
+    }
+
+    /// Returns amount of cache hits and misses.
+    pub fn statistics(&self) -> (usize, usize) {
+        (self.hits.load(AtomicOrdering::Relaxed), self.misses.load(AtomicOrdering::Relaxed))
+    }
+
+    fn get(&self, profile: Profile, from: Location, to: Location, departure: Timestamp) -> (Duration, Distance) {
+        let cache = match &self.cache {
+            Some(cache) => cache,
+            None => {
+                return (
+                    self.inner.duration(profile, from, to, departure),
+                    self.inner.distance(profile, from, to, departure),
+                )
+            }
+        };
+
+        let key = (profile, from, to);
+
+        if let Some(value) = cache.read().unwrap().get(&key) {
+            self.hits.fetch_add(1, AtomicOrdering::Relaxed);
+            return *value;
+        }
+
+        self.misses.fetch_add(1, AtomicOrdering::Relaxed);
+
+        let value =
+            (self.inner.duration(profile, from, to, departure), self.inner.distance(profile, from, to, departure));
+        cache.write().unwrap().insert(key, value);
+
+        value
+    }
+}
+
+impl TransportCost for CachedTransportCost {
+    fn cost(&self, actor: &Actor, profile: Profile, from: Location, to: Location, departure: Timestamp) -> Cost {
+        self.inner.cost(actor, profile, from, to, departure)
+    }
+
+    fn duration(&self, profile: Profile, from: Location, to: Location, departure: Timestamp) -> Duration {
+        self.get(profile, from, to, departure).0
+    }
+
+    fn distance(&self, profile: Profile, from: Location, to: Location, departure: Timestamp) -> Distance {
+        self.get(profile, from, to, departure).1
+    }
+
+    fn is_time_aware(&self) -> bool {
+        self.inner.is_time_aware()
+    }
+}
+
+/// A transport cost decorator which scales durations and, optionally, distances of the inner
+/// transport cost by constant factors. It can be used to model global traffic conditions, e.g.
+/// rush hour, without recalculating routing matrices.
+pub struct ScaledTransportCost {
+    inner: Arc<dyn TransportCost + Send + Sync>,
+    duration_factor: f64,
+    distance_factor: f64,
+}
+
+impl ScaledTransportCost {
+    /// Creates a new instance of `ScaledTransportCost`. Distances are kept as is when no distance
+    /// factor is specified.
+    pub fn new(
+        inner: Arc<dyn TransportCost + Send + Sync>,
+        duration_factor: f64,
+        distance_factor: Option<f64>,
+    ) -> Self {
+        let distance_factor = distance_factor.unwrap_or(1.);
+
+        assert!(duration_factor > 0.);
+        assert!(distance_factor > 0.);
+
+        Self { inner, duration_factor, distance_factor }
+    }
+}
+
+impl TransportCost for ScaledTransportCost {
+    fn duration(&self, profile: Profile, from: Location, to: Location, departure: Timestamp) -> Duration {
+        self.inner.duration(profile, from, to, departure) * self.duration_factor
+    }
+
+    fn distance(&self, profile: Profile, from: Location, to: Location, departure: Timestamp) -> Distance {
+        self.inner.distance(profile, from, to, departure) * self.distance_factor
+    }
+
+    fn is_time_aware(&self) -> bool {
+        self.inner.is_time_aware()
+    }
+}
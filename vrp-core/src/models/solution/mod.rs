@@ -6,6 +6,9 @@ pub use self::route::Route;
 mod registry;
 pub use self::registry::Registry;
 
+mod similarity;
+pub use self::similarity::{solution_similarity, tour_similarity};
+
 mod tour;
 pub use self::tour::Tour;
 pub use self::tour::TourActivity;
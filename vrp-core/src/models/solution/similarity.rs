@@ -0,0 +1,40 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/models/solution/similarity_test.rs"]
+mod similarity_test;
+
+use crate::models::problem::Job;
+use crate::models::solution::Tour;
+use crate::models::Solution;
+use hashbrown::HashSet;
+
+/// Returns similarity of two solutions in range [0, 1] as a fraction of shared job adjacency edges:
+/// `1` means that solutions have the same edges, `0` means that they have no edges in common.
+/// An edge connects two consecutive activities in a tour, edges from or to depot are counted too.
+/// Edges are directed, so reversed tour is considered as different one.
+pub fn solution_similarity(a: &Solution, b: &Solution) -> f64 {
+    tour_similarity(a.routes.iter().map(|route| &route.tour), b.routes.iter().map(|route| &route.tour))
+}
+
+/// Returns similarity of two sets of tours in the same way as [`solution_similarity`].
+pub fn tour_similarity<'a>(a: impl Iterator<Item = &'a Tour>, b: impl Iterator<Item = &'a Tour>) -> f64 {
+    let edges_a = get_edges(a);
+    let edges_b = get_edges(b);
+
+    let union = edges_a.union(&edges_b).count();
+
+    if union == 0 {
+        1.
+    } else {
+        edges_a.intersection(&edges_b).count() as f64 / union as f64
+    }
+}
+
+fn get_edges<'a>(tours: impl Iterator<Item = &'a Tour>) -> HashSet<(Option<Job>, Option<Job>)> {
+    tours
+        .flat_map(|tour| {
+            let jobs = tour.all_activities().map(|activity| activity.retrieve_job());
+
+            jobs.clone().zip(jobs.skip(1)).filter(|(from, to)| from.is_some() || to.is_some())
+        })
+        .collect()
+}
@@ -4,16 +4,27 @@ use crate::models::{Problem, Solution};
 use crate::solver::evolution::EvolutionConfig;
 use crate::solver::mutation::*;
 use crate::solver::termination::*;
-use crate::solver::Solver;
+use crate::solver::{Solver, SolverConfig};
 use crate::utils::{DefaultRandom, TimeQuota};
 use std::ops::Deref;
 use std::sync::Arc;
 
+/// Max generations used when no termination criteria is specified.
+const DEFAULT_MAX_GENERATIONS: usize = 2000;
+
+/// Max time (in seconds) used when no termination criteria is specified.
+const DEFAULT_MAX_TIME: usize = 300;
+
 /// Provides configurable way to build solver.
 pub struct Builder {
     max_generations: Option<usize>,
     max_time: Option<usize>,
     cost_variation: Option<(usize, f64)>,
+    stop_when_all_assigned: bool,
+    target_cost: Option<f64>,
+    seed: Option<u64>,
+    ruin_limits: Option<RuinLimits>,
+    solutions: Vec<Arc<Solution>>,
     problem: Option<Arc<Problem>>,
     config: EvolutionConfig,
 }
@@ -24,14 +35,21 @@ impl Default for Builder {
             max_generations: None,
             max_time: None,
             cost_variation: None,
+            stop_when_all_assigned: false,
+            target_cost: None,
+            seed: None,
+            ruin_limits: None,
+            solutions: vec![],
             problem: None,
             config: EvolutionConfig {
-                mutation: Box::new(RuinAndRecreateMutation::default()),
-                termination: Box::new(MaxTime::new(300.)),
+                mutation: Box::new(CompositeMutation::default()),
+                termination: Box::new(MaxTime::new(DEFAULT_MAX_TIME as f64)),
                 quota: None,
                 population_size: 4,
                 offspring_size: 4,
                 elite_size: 2,
+                tournament_size: None,
+                diversity: None,
                 initial_size: 2,
                 initial_methods: vec![
                     (Box::new(RecreateWithCheapest::default()), 10),
@@ -39,7 +57,11 @@ impl Default for Builder {
                     (Box::new(RecreateWithBlinks::<i32>::default()), 5),
                 ],
                 initial_individuals: vec![],
+                track_best_cost: false,
+                track_population_fitness: false,
+                pareto_front_size: None,
                 random: Arc::new(DefaultRandom::default()),
+                seed: None,
                 logger: Arc::new(|msg| println!("{}", msg)),
             },
         }
@@ -61,7 +83,23 @@ impl Builder {
         self
     }
 
-    /// Sets max running time limit.
+    /// Sets whether search should stop as soon as solution without unassigned jobs is found.
+    /// Default is false.
+    pub fn with_stop_when_all_assigned(mut self, is_enabled: bool) -> Self {
+        self.stop_when_all_assigned = is_enabled;
+        self
+    }
+
+    /// Sets target cost: solver stops as soon as the best known solution has cost less or equal to it.
+    /// If the target is never reached, other termination criteria still apply.
+    /// Default is none.
+    pub fn with_target_cost(mut self, cost: Option<f64>) -> Self {
+        self.target_cost = cost;
+        self
+    }
+
+    /// Sets max running time limit in seconds. Time is measured since solver starts and, once the
+    /// limit is reached, the best known solution is returned.
     /// Default is 300 seconds.
     pub fn with_max_time(mut self, limit: Option<usize>) -> Self {
         self.max_time = limit;
@@ -84,20 +122,50 @@ impl Builder {
     /// Default is none.
     pub fn with_solutions(mut self, solutions: Vec<Arc<Solution>>) -> Self {
         self.config.logger.deref()(format!("provided {} initial solutions to start with", solutions.len()));
-        self.config.initial_individuals = solutions
-            .iter()
-            .map(|solution| {
-                InsertionContext::new_from_solution(
-                    self.problem.as_ref().unwrap().clone(),
-                    (solution.clone(), None),
-                    Arc::new(DefaultRandom::default()),
-                )
-            })
-            .collect();
+        self.solutions = solutions;
+        self
+    }
+
+    /// Sets seed used to initialize random generator.
+    /// Default is randomly generated seed.
+    pub fn with_seed(mut self, seed: Option<u64>) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Sets limits of jobs removed by default ruin methods relative to amount of assigned jobs.
+    /// Default is none, i.e. fixed ranges of removed jobs are used.
+    pub fn with_ruin_limits(mut self, limits: Option<RuinLimits>) -> Self {
+        self.ruin_limits = limits;
+        self
+    }
+
+    /// Sets whether best cost should be recorded at each generation. Recorded history is returned
+    /// within solver statistics.
+    /// Default is false.
+    pub fn with_best_cost_history(mut self, is_enabled: bool) -> Self {
+        self.config.track_best_cost = is_enabled;
+        self
+    }
+
+    /// Sets whether per-objective fitness of every individual in final population should be recorded.
+    /// Recorded values are returned within solver statistics.
+    /// Default is false.
+    pub fn with_population_fitness(mut self, is_enabled: bool) -> Self {
+        self.config.track_population_fitness = is_enabled;
+        self
+    }
+
+    /// Sets max size of Pareto front: an archive of mutually non-dominated solutions which is
+    /// updated with each new individual. Archived solutions are returned within solver statistics.
+    /// Default is none, i.e. Pareto front is not maintained.
+    pub fn with_pareto_front(mut self, max_size: Option<usize>) -> Self {
+        self.config.pareto_front_size = max_size;
         self
     }
 
-    /// Sets population size.
+    /// Sets population size: amount of solutions retained between generations. Bigger population
+    /// might help to solve harder problems. Should be greater than 1 and elite size.
     /// Default is 4.
     pub fn with_population_size(mut self, size: usize) -> Self {
         self.config.logger.deref()(format!("configured to use population size={} ", size));
@@ -121,6 +189,28 @@ impl Builder {
         self
     }
 
+    /// Sets tournament size used to select individuals for mutation: the best of given amount of
+    /// randomly chosen individuals is selected.
+    /// Default is none, i.e. rank based selection is used.
+    pub fn with_tournament_size(mut self, size: Option<usize>) -> Self {
+        if let Some(size) = size {
+            self.config.logger.deref()(format!("configured to use tournament size={} ", size));
+        }
+        self.config.tournament_size = size;
+        self
+    }
+
+    /// Sets diversity epsilon: a new individual within epsilon distance of an existing one and not
+    /// better than it is not added to population. Distance scale depends on the problem objective.
+    /// Default is none, i.e. any individual is accepted.
+    pub fn with_diversity(mut self, epsilon: Option<f64>) -> Self {
+        if let Some(epsilon) = epsilon {
+            self.config.logger.deref()(format!("configured to use diversity epsilon={} ", epsilon));
+        }
+        self.config.diversity = epsilon;
+        self
+    }
+
     /// Sets initial population size. Each initial individual is constructed separately which
     /// used to take more time than normal refinement process.
     /// Default is 2.
@@ -135,13 +225,33 @@ impl Builder {
         let problem = self.problem.ok_or_else(|| "problem is not specified".to_string())?;
         let mut config = self.config;
 
-        let (criterias, quota): (Vec<Box<dyn Termination>>, _) =
+        if config.population_size < 2 {
+            return Err("population size should be greater than 1".to_string());
+        }
+
+        if config.elite_size >= config.population_size {
+            return Err("elite size should be less than population size".to_string());
+        }
+
+        let (max_generations, max_time) = match (self.max_generations, self.max_time, self.cost_variation) {
+            (None, None, None) => (Some(DEFAULT_MAX_GENERATIONS), Some(DEFAULT_MAX_TIME)),
+            _ => (self.max_generations, self.max_time),
+        };
+
+        let (mut criterias, quota): (Vec<Box<dyn Termination>>, _) =
             match (self.max_generations, self.max_time, self.cost_variation) {
                 (None, None, None) => {
-                    config.logger.deref()(
-                        "configured to use default max-generations (2000) and max-time (300secs)".to_string(),
-                    );
-                    (vec![Box::new(MaxGeneration::new(2000)), Box::new(MaxTime::new(300.))], None)
+                    config.logger.deref()(format!(
+                        "configured to use default max-generations ({}) and max-time ({}secs)",
+                        DEFAULT_MAX_GENERATIONS, DEFAULT_MAX_TIME
+                    ));
+                    (
+                        vec![
+                            Box::new(MaxGeneration::new(DEFAULT_MAX_GENERATIONS)),
+                            Box::new(MaxTime::new(DEFAULT_MAX_TIME as f64)),
+                        ],
+                        None,
+                    )
                 }
                 _ => {
                     let mut criterias: Vec<Box<dyn Termination>> = vec![];
@@ -171,10 +281,72 @@ impl Builder {
                 }
             };
 
+        if self.stop_when_all_assigned {
+            config.logger.deref()("configured to stop when all jobs are assigned".to_string());
+            criterias.push(Box::new(AllJobsAssigned::default()));
+        }
+
+        if let Some(cost) = self.target_cost {
+            config.logger.deref()(format!("configured to use target cost {}", cost));
+            criterias.push(Box::new(TargetCost::new(cost)));
+        }
+
         config.termination = Box::new(CompositeTermination::new(criterias));
         config.quota = quota;
 
-        Ok(Solver { problem, config })
+        let seed = self.seed.unwrap_or_else(rand::random);
+        config.logger.deref()(format!("configured to use seed {}", seed));
+        config.random = Arc::new(DefaultRandom::new_with_seed(seed));
+        config.seed = Some(seed);
+
+        if let Some(limits) = &self.ruin_limits {
+            config.logger.deref()(format!(
+                "configured to use ruin limits min: {}, max: {}, ratio: {}",
+                limits.min, limits.max, limits.ratio
+            ));
+        }
+
+        let recreate = CompositeRecreate::default();
+        let ruin = CompositeRuin::new_with_default_ruins(self.ruin_limits.clone());
+        let (recreate_weights, ruin_weights) = (recreate.weights().to_vec(), ruin.weights().to_vec());
+        let mutation = CompositeMutation::new_with_default_mutations(RuinAndRecreateMutation::new(
+            Box::new(recreate),
+            Box::new(ruin),
+        ));
+        let mutation_weights = mutation.weights().to_vec();
+        config.mutation = Box::new(mutation);
+
+        config.initial_individuals = self
+            .solutions
+            .iter()
+            .map(|solution| {
+                InsertionContext::new_from_solution(problem.clone(), (solution.clone(), None), config.random.clone())
+            })
+            .collect();
+
+        let settings = SolverConfig {
+            max_generations,
+            max_time,
+            cost_variation: self.cost_variation,
+            stop_when_all_assigned: self.stop_when_all_assigned,
+            target_cost: self.target_cost,
+            population_size: config.population_size,
+            offspring_size: config.offspring_size,
+            elite_size: config.elite_size,
+            tournament_size: config.tournament_size,
+            diversity: config.diversity,
+            initial_size: config.initial_size,
+            initial_weights: config.initial_methods.iter().map(|(_, weight)| *weight).collect(),
+            seed,
+            ruin_limits: self.ruin_limits,
+            objectives: problem.objective.objectives().map(|objective| objective.name()).collect(),
+            ruin_weights,
+            recreate_weights,
+            mutation_weights,
+            thread_count: rayon::current_num_threads(),
+        };
+
+        Ok(Solver { problem, config, settings })
     }
 }
 
@@ -1,12 +1,15 @@
+#[cfg(test)]
+#[path = "../../tests/unit/solver/evolution_test.rs"]
+mod evolution_test;
+
 use crate::construction::heuristics::InsertionContext;
 use crate::construction::Quota;
-use crate::models::common::Objective;
-use crate::models::Problem;
+use crate::models::{Problem, Solution};
 use crate::solver::mutation::{Mutation, Recreate};
-use crate::solver::population::DominancePopulation;
+use crate::solver::population::{get_fitness_values, DominancePopulation, ParetoArchive};
 use crate::solver::termination::Termination;
 use crate::solver::Logger;
-use crate::solver::{Population, RefinementContext};
+use crate::solver::{Population, RefinementContext, Statistics};
 use crate::utils::{Random, Timer};
 use std::ops::Deref;
 use std::sync::Arc;
@@ -26,27 +29,50 @@ pub struct EvolutionConfig {
     pub offspring_size: usize,
     /// Elite size.
     pub elite_size: usize,
+    /// Tournament size used to select individuals. When not set, rank based selection is used.
+    pub tournament_size: Option<usize>,
+    /// Distance epsilon used to reject near-identical individuals. When not set, any individual is accepted.
+    pub diversity: Option<f64>,
     /// Initial size of population to be generated.
     pub initial_size: usize,
     /// Create methods to create initial individuals.
     pub initial_methods: Vec<(Box<dyn Recreate>, usize)>,
     /// Initial individuals in population.
     pub initial_individuals: Vec<InsertionContext>,
+    /// Specifies whether best cost should be recorded at each generation.
+    pub track_best_cost: bool,
+    /// Specifies whether per-objective fitness of final population should be recorded.
+    pub track_population_fitness: bool,
+    /// Max size of archive with non-dominated solutions. When set, the archive is maintained
+    /// during evolution and returned as Pareto front.
+    pub pareto_front_size: Option<usize>,
 
     /// Random generator.
     pub random: Arc<dyn Random + Send + Sync>,
+    /// A seed used to initialize random generator, if known.
+    pub seed: Option<u64>,
     /// A logger used to log evolution progress.
     pub logger: Logger,
 }
 
 /// Runs evolution for given `problem` using evolution `config`.
-/// Returns populations filled with solutions.
-pub fn run_evolution(problem: Arc<Problem>, config: EvolutionConfig) -> Result<Box<dyn Population>, String> {
+/// Returns populations filled with solutions and refinement statistics.
+pub fn run_evolution(
+    problem: Arc<Problem>,
+    config: EvolutionConfig,
+) -> Result<(Box<dyn Population>, Statistics), String> {
     let mut config = config;
 
     let evolution_time = Timer::start();
 
     let mut refinement_ctx = create_refinement_ctx(problem.clone(), &mut config, &evolution_time)?;
+    let mut best_cost_history = if config.track_best_cost { Some(vec![]) } else { None };
+    let mut pareto_archive = config.pareto_front_size.map(|size| {
+        refinement_ctx.population.all().fold(ParetoArchive::new(size), |mut archive, individual| {
+            update_pareto_archive(&mut archive, problem.as_ref(), individual);
+            archive
+        })
+    });
 
     // NOTE at the moment, only one solution is produced per generation
     while !config.termination.is_termination(&mut refinement_ctx) {
@@ -58,14 +84,35 @@ pub fn run_evolution(problem: Arc<Problem>, config: EvolutionConfig) -> Result<B
 
         log_progress(&refinement_ctx, &evolution_time, Some(&generation_time), &config.logger);
 
+        if let Some(pareto_archive) = pareto_archive.as_mut() {
+            update_pareto_archive(pareto_archive, problem.as_ref(), &insertion_ctx);
+        }
+
         add_solution(&mut refinement_ctx, insertion_ctx);
 
+        if let Some(best_cost_history) = best_cost_history.as_mut() {
+            track_best_cost(&refinement_ctx, best_cost_history);
+        }
+
         refinement_ctx.generation += 1;
     }
 
     log_result(&refinement_ctx, &evolution_time, &config.logger);
 
-    Ok(refinement_ctx.population)
+    let population_fitness =
+        if config.track_population_fitness { Some(get_population_fitness(&refinement_ctx)) } else { None };
+
+    let pareto_front = pareto_archive.map(|archive| get_pareto_front(problem.as_ref(), &archive));
+
+    let statistics = Statistics {
+        generations: refinement_ctx.generation.saturating_sub(1),
+        best_cost_history,
+        population_fitness,
+        pareto_front,
+        seed: config.seed,
+    };
+
+    Ok((refinement_ctx.population, statistics))
 }
 
 /// Creates refinement context with population containing initial individuals.
@@ -88,20 +135,30 @@ fn create_refinement_ctx(
 
     let mut refinement_ctx = RefinementContext::new(
         problem.clone(),
-        Box::new(DominancePopulation::new(
-            problem.clone(),
-            config.random.clone(),
-            config.population_size,
-            config.offspring_size,
-            config.elite_size,
-        )),
+        Box::new(
+            DominancePopulation::new(
+                problem.clone(),
+                config.random.clone(),
+                config.population_size,
+                config.offspring_size,
+                config.elite_size,
+            )
+            .with_tournament(config.tournament_size)
+            .with_diversity(config.diversity),
+        ),
         std::mem::replace(&mut config.quota, None),
     );
 
-    std::mem::replace(&mut config.initial_individuals, vec![])
+    let initial_individuals = std::mem::replace(&mut config.initial_individuals, vec![])
         .into_iter()
         .take(config.initial_size)
-        .for_each(|ctx| refinement_ctx.population.add(ctx));
+        .collect::<Vec<_>>();
+
+    if !initial_individuals.is_empty() {
+        problem.objective.estimate(initial_individuals.iter().collect::<Vec<_>>().as_slice())?;
+    }
+
+    initial_individuals.into_iter().for_each(|ctx| refinement_ctx.population.add(ctx));
 
     let weights = config.initial_methods.iter().map(|(_, weight)| *weight).collect::<Vec<_>>();
     let empty_ctx = InsertionContext::new(problem.clone(), config.random.clone());
@@ -114,15 +171,21 @@ fn create_refinement_ctx(
             .collect()
     };
 
-    let _ = indices.into_iter().enumerate().try_for_each(|(idx, method_idx)| {
+    let result = indices.into_iter().enumerate().try_for_each(|(idx, method_idx)| {
         let item_time = Timer::start();
 
-        if config.termination.is_termination(&mut refinement_ctx) {
-            return Err(());
+        // NOTE at least one initial solution is always created to have some result to return
+        if config.termination.is_termination(&mut refinement_ctx) && refinement_ctx.population.size() > 0 {
+            return Err(None);
         }
 
         let insertion_ctx = config.initial_methods[method_idx].0.run(&mut refinement_ctx, empty_ctx.deep_copy());
 
+        // NOTE objective is estimated using the first solution when no initial solution is provided
+        if refinement_ctx.population.size() == 0 {
+            problem.objective.estimate(&[&insertion_ctx]).map_err(Some)?;
+        }
+
         add_solution(&mut refinement_ctx, insertion_ctx);
 
         config.logger.deref()(format!(
@@ -136,6 +199,10 @@ fn create_refinement_ctx(
         Ok(())
     });
 
+    if let Err(Some(err)) = result {
+        return Err(err);
+    }
+
     Ok(refinement_ctx)
 }
 
@@ -149,6 +216,46 @@ fn add_solution(refinement_ctx: &mut RefinementContext, insertion_ctx: Insertion
     }
 }
 
+/// Records best fitness the same way as it is seen by termination criteria.
+fn track_best_cost(refinement_ctx: &RefinementContext, best_cost_history: &mut Vec<(usize, f64)>) {
+    if let Some(best) = refinement_ctx.population.best() {
+        best_cost_history.push((refinement_ctx.generation, refinement_ctx.problem.objective.fitness(best)));
+    }
+}
+
+/// Returns fitness values of each objective for every individual in population, ordered by quality.
+fn get_population_fitness(refinement_ctx: &RefinementContext) -> Vec<Vec<f64>> {
+    let objective = refinement_ctx.problem.objective.as_ref();
+
+    refinement_ctx.population.all().map(|individual| get_fitness_values(objective, individual)).collect()
+}
+
+/// Adds a copy of individual to the archive if it is not dominated by archived ones.
+fn update_pareto_archive(
+    archive: &mut ParetoArchive<InsertionContext>,
+    problem: &Problem,
+    individual: &InsertionContext,
+) {
+    let objective = problem.objective.as_ref();
+
+    if archive.can_add(individual, objective) {
+        archive.add(individual.deep_copy(), objective);
+    }
+}
+
+/// Returns archived solutions with their fitness values of each objective.
+fn get_pareto_front(problem: &Problem, archive: &ParetoArchive<InsertionContext>) -> Vec<(Solution, Vec<f64>)> {
+    let objective = problem.objective.as_ref();
+
+    archive
+        .solutions()
+        .iter()
+        .map(|individual| {
+            (individual.solution.to_solution(problem.extras.clone()), get_fitness_values(objective, individual))
+        })
+        .collect()
+}
+
 fn log_progress(
     refinement_ctx: &RefinementContext,
     evolution_time: &Timer,
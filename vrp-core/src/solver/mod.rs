@@ -1,9 +1,10 @@
 extern crate rand;
 use crate::construction::heuristics::InsertionContext;
 use crate::construction::Quota;
-use crate::models::common::{Cost, Objective};
+use crate::models::common::Cost;
 use crate::models::{Problem, Solution};
 use crate::solver::evolution::{run_evolution, EvolutionConfig};
+use crate::solver::mutation::RuinLimits;
 use hashbrown::HashMap;
 use std::any::Any;
 use std::sync::Arc;
@@ -17,7 +18,7 @@ mod evolution;
 mod population;
 
 pub use self::builder::Builder;
-pub use self::population::DominancePopulation;
+pub use self::population::{get_fitness_values, hypervolume, DominancePopulation};
 use std::ops::Deref;
 
 /// Contains information needed to perform refinement.
@@ -70,6 +71,87 @@ impl RefinementContext {
     }
 }
 
+/// Contains statistics of refinement process.
+pub struct Statistics {
+    /// Amount of generations run.
+    pub generations: usize,
+
+    /// Best cost per generation. Recorded only when tracking is enabled.
+    pub best_cost_history: Option<Vec<(usize, f64)>>,
+
+    /// Fitness values of each objective for every individual in final population, ordered from
+    /// the best individual. Recorded only when tracking is enabled.
+    pub population_fitness: Option<Vec<Vec<f64>>>,
+
+    /// Mutually non-dominated solutions discovered during search with fitness values of each
+    /// objective. Recorded only when Pareto front is requested.
+    pub pareto_front: Option<Vec<(Solution, Vec<f64>)>>,
+
+    /// A seed used to initialize random generator. Solving with the same seed reproduces the result.
+    pub seed: Option<u64>,
+}
+
+/// Contains settings used to build solver: they are reported to make a run reproducible.
+#[derive(Clone, Debug, Default)]
+pub struct SolverConfig {
+    /// Max generations termination criteria.
+    pub max_generations: Option<usize>,
+
+    /// Max time (in seconds) termination criteria.
+    pub max_time: Option<usize>,
+
+    /// Cost variation termination criteria specified by sample size and threshold.
+    pub cost_variation: Option<(usize, f64)>,
+
+    /// Specifies whether search stops when all jobs are assigned.
+    pub stop_when_all_assigned: bool,
+
+    /// Target cost termination criteria.
+    pub target_cost: Option<f64>,
+
+    /// Population size.
+    pub population_size: usize,
+
+    /// Offspring size.
+    pub offspring_size: usize,
+
+    /// Elite size.
+    pub elite_size: usize,
+
+    /// Tournament size used to select individuals.
+    pub tournament_size: Option<usize>,
+
+    /// Distance epsilon used to reject near-identical individuals.
+    pub diversity: Option<f64>,
+
+    /// Initial population size.
+    pub initial_size: usize,
+
+    /// Weights of methods used to build initial individuals.
+    pub initial_weights: Vec<usize>,
+
+    /// A seed used to initialize random generator.
+    pub seed: u64,
+
+    /// Limits of jobs removed by ruin methods relative to amount of assigned jobs.
+    pub ruin_limits: Option<RuinLimits>,
+
+    /// Names of objectives used to compare solutions in order of their importance.
+    pub objectives: Vec<String>,
+
+    /// Weights of ruin methods used by mutation.
+    pub ruin_weights: Vec<usize>,
+
+    /// Weights of recreate methods used by mutation.
+    pub recreate_weights: Vec<usize>,
+
+    /// Weights of mutation groups: ruin and recreate alone or followed by waiting reduction.
+    pub mutation_weights: Vec<usize>,
+
+    /// Amount of threads available for parallel processing.
+    pub thread_count: usize,
+}
+
 /// A logger type.
 pub type Logger = Arc<dyn Fn(String) -> ()>;
 
@@ -77,26 +159,62 @@ pub type Logger = Arc<dyn Fn(String) -> ()>;
 pub struct Solver {
     pub problem: Arc<Problem>,
     pub config: EvolutionConfig,
+    /// Settings used to build solver.
+    pub settings: SolverConfig,
 }
 
 impl Solver {
+    /// Solves problem and returns best known solution with its cost.
     pub fn solve(self) -> Result<(Solution, Cost), String> {
-        let logger = self.config.logger.clone();
+        self.solve_with_statistics().map(|(solution, cost, _)| (solution, cost))
+    }
 
-        let population = run_evolution(self.problem.clone(), self.config)?;
+    /// Solves problem and returns best known solution with its cost and refinement statistics.
+    pub fn solve_with_statistics(self) -> Result<(Solution, Cost, Statistics), String> {
+        let problem = self.problem.clone();
+        let (population, statistics) = self.run()?;
 
         // NOTE select first best according to population
         let insertion_ctx = population.best().ok_or_else(|| "cannot find any solution".to_string())?;
-        let solution = insertion_ctx.solution.to_solution(self.problem.extras.clone());
-        let cost = self.problem.objective.fitness(insertion_ctx);
+        let (solution, cost) = to_solution(problem.as_ref(), insertion_ctx);
+
+        Ok((solution, cost, statistics))
+    }
+
+    /// Solves problem and returns all solutions from the final population with their costs and
+    /// refinement statistics. Solutions are sorted according to their quality, so the first one
+    /// is the same as returned by `solve`. Please note, that each individual is converted into a
+    /// solution, so use `solve_with_statistics` when only the best one is needed.
+    pub fn solve_with_alternatives(self) -> Result<(Vec<(Solution, Cost)>, Statistics), String> {
+        let problem = self.problem.clone();
+        let (population, statistics) = self.run()?;
+
+        let solutions =
+            population.all().map(|insertion_ctx| to_solution(problem.as_ref(), insertion_ctx)).collect::<Vec<_>>();
+
+        Ok((solutions, statistics))
+    }
+
+    /// Runs evolution and logs the best known solution.
+    fn run(self) -> Result<(Box<dyn Population>, Statistics), String> {
+        let logger = self.config.logger.clone();
+        let problem = self.problem;
+
+        let (population, statistics) = run_evolution(problem.clone(), self.config)?;
+
+        let insertion_ctx = population.best().ok_or_else(|| "cannot find any solution".to_string())?;
 
         logger.deref()(format!(
             "best solution has cost: {}, tours: {}, unassigned: {}",
-            cost,
-            solution.routes.len(),
-            solution.unassigned.len()
+            problem.objective.fitness(insertion_ctx),
+            insertion_ctx.solution.routes.len(),
+            insertion_ctx.solution.unassigned.len()
         ));
 
-        Ok((solution, cost))
+        Ok((population, statistics))
     }
 }
+
+fn to_solution(problem: &Problem, insertion_ctx: &InsertionContext) -> (Solution, Cost) {
+    (insertion_ctx.solution.to_solution(problem.extras.clone()), problem.objective.fitness(insertion_ctx))
+}
@@ -1,5 +1,6 @@
 use crate::construction::heuristics::InsertionContext;
 use crate::solver::RefinementContext;
+use std::rc::Rc;
 
 mod recreate;
 pub use self::recreate::*;
@@ -7,6 +8,9 @@ pub use self::recreate::*;
 mod ruin;
 pub use self::ruin::*;
 
+mod waiting_reduction;
+pub use self::waiting_reduction::WaitingReduction;
+
 /// Mutates given insertion context.
 pub trait Mutation {
     fn mutate(&self, refinement_ctx: &mut RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext;
@@ -38,3 +42,60 @@ impl Mutation for RuinAndRecreateMutation {
         self.recreate.run(refinement_ctx, insertion_ctx)
     }
 }
+
+/// A group of mutations with probabilities of their application.
+pub type MutationGroup = Vec<(Rc<dyn Mutation>, f64)>;
+
+/// Provides the way to run multiple mutations one by one on the same solution.
+pub struct CompositeMutation {
+    mutations: Vec<MutationGroup>,
+    weights: Vec<usize>,
+}
+
+impl Default for CompositeMutation {
+    fn default() -> Self {
+        Self::new_with_default_mutations(RuinAndRecreateMutation::default())
+    }
+}
+
+impl CompositeMutation {
+    /// Creates a new instance of [`CompositeMutation`] from groups of mutations with their
+    /// probabilities and weights of each group.
+    pub fn new(mutations: Vec<(MutationGroup, usize)>) -> Self {
+        let weights = mutations.iter().map(|(_, weight)| *weight).collect();
+        let mutations = mutations.into_iter().map(|(mutation, _)| mutation).collect();
+
+        Self { mutations, weights }
+    }
+
+    /// Creates a new instance of [`CompositeMutation`] with given ruin and recreate mutation which
+    /// is sometimes followed by waiting reduction.
+    pub fn new_with_default_mutations(ruin_recreate: RuinAndRecreateMutation) -> Self {
+        let ruin_recreate: Rc<dyn Mutation> = Rc::new(ruin_recreate);
+        let waiting_reduction = Rc::new(WaitingReduction::default());
+
+        Self::new(vec![
+            (vec![(ruin_recreate.clone(), 1.)], 100),
+            (vec![(ruin_recreate, 1.), (waiting_reduction, 1.)], 10),
+        ])
+    }
+
+    /// Returns weights of mutation groups.
+    pub fn weights(&self) -> &[usize] {
+        self.weights.as_slice()
+    }
+}
+
+impl Mutation for CompositeMutation {
+    fn mutate(&self, refinement_ctx: &mut RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
+        let random = insertion_ctx.random.clone();
+        let index = random.weighted(self.weights.as_slice());
+
+        self.mutations
+            .get(index)
+            .unwrap()
+            .iter()
+            .filter(|(_, probability)| *probability > random.uniform_real(0., 1.))
+            .fold(insertion_ctx, |ctx, (mutation, _)| mutation.mutate(refinement_ctx, ctx))
+    }
+}
@@ -24,6 +24,9 @@ pub use self::recreate_with_regret::RecreateWithRegret;
 mod recreate_with_nearest_neighbor;
 pub use self::recreate_with_nearest_neighbor::*;
 
+mod recreate_with_savings;
+pub use self::recreate_with_savings::RecreateWithSavings;
+
 /// Provides the way to run one of multiple recreate methods.
 pub struct CompositeRecreate {
     recreates: Vec<Box<dyn Recreate>>,
@@ -37,7 +40,9 @@ impl Default for CompositeRecreate {
             (Box::new(RecreateWithRegret::default()), 90),
             (Box::new(RecreateWithBlinks::<i32>::default()), 30),
             (Box::new(RecreateWithRegret::new((5, 8))), 20),
+            (Box::new(RecreateWithRegret::new_with_k(3)), 15),
             (Box::new(RecreateWithGaps::default()), 10),
+            (Box::new(RecreateWithSavings::default()), 10),
             (Box::new(RecreateWithNearestNeighbor::default()), 5),
         ])
     }
@@ -49,6 +54,11 @@ impl CompositeRecreate {
         let recreates = recreates.into_iter().map(|(recreate, _)| recreate).collect();
         Self { recreates, weights }
     }
+
+    /// Returns weights of recreate methods.
+    pub fn weights(&self) -> &[usize] {
+        self.weights.as_slice()
+    }
 }
 
 impl Recreate for CompositeRecreate {
@@ -19,6 +19,9 @@ use std::marker::PhantomData;
 use std::ops::{Add, Sub};
 use std::sync::Arc;
 
+/// A default probability of a blink.
+const DEFAULT_BLINK_RATIO: f64 = 0.01;
+
 struct DemandJobSelector<Capacity: Add + Sub + Ord + Copy + Default + Send + Sync + 'static> {
     asc_order: bool,
     phantom: PhantomData<Capacity>,
@@ -73,7 +76,7 @@ impl RandomJobSelector {
 
 impl JobSelector for RandomJobSelector {
     fn select<'a>(&'a self, ctx: &'a mut InsertionContext) -> Box<dyn Iterator<Item = Job> + 'a> {
-        ctx.solution.required.shuffle(&mut rand::thread_rng());
+        ctx.solution.required.shuffle(&mut ctx.random.get_rng());
 
         Box::new(ctx.solution.required.iter().cloned())
     }
@@ -120,9 +123,18 @@ struct BlinkResultSelector {
     ratio: f64,
 }
 
+impl BlinkResultSelector {
+    /// Creates a new instance of `BlinkResultSelector` with given probability of a blink.
+    pub fn new(ratio: f64) -> Self {
+        assert!((0. ..1.).contains(&ratio), "blink ratio should be in [0, 1) range");
+
+        Self { ratio }
+    }
+}
+
 impl Default for BlinkResultSelector {
     fn default() -> Self {
-        Self { ratio: 0.01 }
+        Self::new(DEFAULT_BLINK_RATIO)
     }
 }
 
@@ -152,11 +164,27 @@ pub struct RecreateWithBlinks<Capacity: Add + Sub + Ord + Copy + Default + Send
 impl<Capacity: Add<Output = Capacity> + Sub<Output = Capacity> + Ord + Copy + Default + Send + Sync + 'static>
     RecreateWithBlinks<Capacity>
 {
-    pub fn new(selectors: Vec<(Box<dyn JobSelector + Send + Sync>, usize)>) -> Self {
+    /// Creates a new instance of `RecreateWithBlinks` with default job selectors and given
+    /// probability with which a candidate insertion is skipped. Ratio should be in `[0, 1)` range.
+    pub fn new(ratio: f64) -> Self {
+        Self::new_with_selectors(
+            vec![
+                (Box::new(RandomJobSelector::new()), 10),
+                (Box::new(DemandJobSelector::<Capacity>::new(false)), 10),
+                (Box::new(DemandJobSelector::<Capacity>::new(true)), 1),
+                (Box::new(RankedJobSelector::new(true)), 5),
+                (Box::new(RankedJobSelector::new(false)), 1),
+            ],
+            ratio,
+        )
+    }
+
+    /// Creates a new instance of `RecreateWithBlinks` with given weighted job selectors and blink ratio.
+    pub fn new_with_selectors(selectors: Vec<(Box<dyn JobSelector + Send + Sync>, usize)>, ratio: f64) -> Self {
         let weights = selectors.iter().map(|(_, weight)| *weight).collect();
         Self {
             job_selectors: selectors.into_iter().map(|(selector, _)| selector).collect(),
-            job_reducer: Box::new(PairJobMapReducer::new(Box::new(BlinkResultSelector::default()))),
+            job_reducer: Box::new(PairJobMapReducer::new(Box::new(BlinkResultSelector::new(ratio)))),
             weights,
             phantom: PhantomData,
         }
@@ -167,13 +195,7 @@ impl<Capacity: Add<Output = Capacity> + Sub<Output = Capacity> + Ord + Copy + De
     for RecreateWithBlinks<Capacity>
 {
     fn default() -> Self {
-        Self::new(vec![
-            (Box::new(RandomJobSelector::new()), 10),
-            (Box::new(DemandJobSelector::<Capacity>::new(false)), 10),
-            (Box::new(DemandJobSelector::<Capacity>::new(true)), 1),
-            (Box::new(RankedJobSelector::new(true)), 5),
-            (Box::new(RankedJobSelector::new(false)), 1),
-        ])
+        Self::new(DEFAULT_BLINK_RATIO)
     }
 }
 
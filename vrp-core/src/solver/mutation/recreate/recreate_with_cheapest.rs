@@ -1,5 +1,6 @@
 use crate::construction::heuristics::InsertionContext;
 use crate::construction::heuristics::*;
+use crate::models::common::Cost;
 use crate::solver::mutation::recreate::Recreate;
 use crate::solver::RefinementContext;
 
@@ -7,6 +8,21 @@ use crate::solver::RefinementContext;
 pub struct RecreateWithCheapest {
     job_selector: Box<dyn JobSelector + Send + Sync>,
     job_reducer: Box<dyn JobMapReducer + Send + Sync>,
+    use_cache: bool,
+}
+
+impl RecreateWithCheapest {
+    /// Sets tie break policy used when insertion costs differ not more than on epsilon.
+    pub fn with_tie_break(mut self, policy: TieBreakPolicy, epsilon: Cost) -> Self {
+        self.job_reducer = Box::new(PairJobMapReducer::new(Box::new(BestResultSelector::new(policy, epsilon))));
+        self
+    }
+
+    /// Enables or disables caching of insertion results into routes unchanged within one recreate.
+    pub fn with_cache(mut self, use_cache: bool) -> Self {
+        self.use_cache = use_cache;
+        self
+    }
 }
 
 impl Default for RecreateWithCheapest {
@@ -14,13 +30,14 @@ impl Default for RecreateWithCheapest {
         Self {
             job_selector: Box::new(AllJobSelector::default()),
             job_reducer: Box::new(PairJobMapReducer::new(Box::new(BestResultSelector::default()))),
+            use_cache: false,
         }
     }
 }
 
 impl Recreate for RecreateWithCheapest {
     fn run(&self, refinement_ctx: &mut RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
-        InsertionHeuristic::default().process(
+        InsertionHeuristic::default().with_cache(self.use_cache).process(
             &self.job_selector,
             &self.job_reducer,
             insertion_ctx,
@@ -1,9 +1,24 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/mutation/recreate/recreate_with_nearest_neighbor_test.rs"]
+mod recreate_with_nearest_neighbor_test;
+
 use crate::construction::heuristics::InsertionContext;
 use crate::construction::heuristics::*;
+use crate::models::common::{Cost, Distance, Location};
+use crate::models::problem::Job;
 use crate::solver::mutation::Recreate;
 use crate::solver::RefinementContext;
+use crate::utils::compare_floats;
+use std::cmp::Ordering;
 
-/// Recreates solution using nearest neighbor algorithm.
+/// Recreates solution using nearest neighbor algorithm: the geographically closest feasible job
+/// is appended to the end of one of the open routes. A new route is opened only when no job can
+/// be appended to existing ones. Distance is estimated using `TransportCost::distance` with zero
+/// departure time, while feasibility is checked with the usual insertion evaluation.
+///
+/// The method is cheap and produces reasonable routes fast, so it is intended for warm start on
+/// the first generation (e.g. as one of initial methods) when no initial solution is provided.
+/// In `CompositeRecreate`, it has low weight and is used mostly for diversification.
 pub struct RecreateWithNearestNeighbor {
     job_selector: Box<dyn JobSelector + Send + Sync>,
     job_reducer: Box<dyn JobMapReducer + Send + Sync>,
@@ -13,11 +28,20 @@ impl Default for RecreateWithNearestNeighbor {
     fn default() -> Self {
         Self {
             job_selector: Box::new(AllJobSelector::default()),
-            job_reducer: Box::new(PairJobMapReducer::new(Box::new(BestResultSelector::default()))),
+            job_reducer: Box::new(NearestNeighborJobMapReducer::new(Box::new(BestResultSelector::default()))),
         }
     }
 }
 
+impl RecreateWithNearestNeighbor {
+    /// Sets tie break policy used when insertion costs differ not more than on epsilon.
+    pub fn with_tie_break(mut self, policy: TieBreakPolicy, epsilon: Cost) -> Self {
+        self.job_reducer =
+            Box::new(NearestNeighborJobMapReducer::new(Box::new(BestResultSelector::new(policy, epsilon))));
+        self
+    }
+}
+
 impl Recreate for RecreateWithNearestNeighbor {
     fn run(&self, refinement_ctx: &mut RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
         InsertionHeuristic::new(InsertionPosition::Last).process(
@@ -28,3 +52,84 @@ impl Recreate for RecreateWithNearestNeighbor {
         )
     }
 }
+
+/// Selects the closest to the end of some route job which can be appended there. Candidates with
+/// the same distance are compared using result selector.
+struct NearestNeighborJobMapReducer {
+    result_selector: Box<dyn ResultSelector + Send + Sync>,
+}
+
+impl NearestNeighborJobMapReducer {
+    pub fn new(result_selector: Box<dyn ResultSelector + Send + Sync>) -> Self {
+        Self { result_selector }
+    }
+
+    fn reduce_in_routes(
+        &self,
+        ctx: &InsertionContext,
+        jobs: &[Job],
+        routes: &[RouteContext],
+    ) -> Option<InsertionResult> {
+        let mut candidates = routes
+            .iter()
+            .flat_map(|route_ctx| {
+                let last = route_ctx.route.tour.legs().last().and_then(|(items, _)| items.first());
+                let profile = route_ctx.route.actor.vehicle.profile;
+
+                jobs.iter().map(move |job| {
+                    let distance = match (last.map(|activity| activity.place.location), get_job_location(job)) {
+                        (Some(from), Some(to)) => ctx.problem.transport.distance(profile, from, to, 0.),
+                        _ => 0.,
+                    };
+
+                    (distance, job, route_ctx)
+                })
+            })
+            .collect::<Vec<(Distance, &Job, &RouteContext)>>();
+
+        candidates.sort_by(|(a, _, _), (b, _, _)| compare_floats(*a, *b));
+
+        candidates
+            .into_iter()
+            .try_fold(None, |best: Option<(Distance, InsertionResult)>, (distance, job, route_ctx)| match best {
+                Some((best_distance, _)) if compare_floats(distance, best_distance) == Ordering::Greater => Err(best),
+                _ => match evaluate_job_insertion_in_route(job, ctx, route_ctx, InsertionPosition::Last, None) {
+                    InsertionResult::Success(success) => Ok(Some(match best {
+                        Some((_, result)) => {
+                            (distance, self.result_selector.select(ctx, result, InsertionResult::Success(success)))
+                        }
+                        None => (distance, InsertionResult::Success(success)),
+                    })),
+                    InsertionResult::Failure(_) => Ok(best),
+                },
+            })
+            .unwrap_or_else(|best| best)
+            .map(|(_, result)| result)
+    }
+}
+
+impl JobMapReducer for NearestNeighborJobMapReducer {
+    fn reduce<'a>(
+        &'a self,
+        ctx: &'a InsertionContext,
+        jobs: Vec<Job>,
+        map: Box<dyn Fn(&Job) -> InsertionResult + Send + Sync + 'a>,
+    ) -> InsertionResult {
+        self.reduce_in_routes(ctx, jobs.as_slice(), ctx.solution.routes.as_slice())
+            .or_else(|| {
+                let new_routes = ctx.solution.registry.next().map(RouteContext::new).collect::<Vec<_>>();
+                self.reduce_in_routes(ctx, jobs.as_slice(), new_routes.as_slice())
+            })
+            .unwrap_or_else(|| jobs.first().map_or_else(InsertionResult::make_failure, map))
+    }
+}
+
+/// Returns location of the first place of the job.
+fn get_job_location(job: &Job) -> Option<Location> {
+    match job {
+        Job::Single(single) => single.places.first().and_then(|place| place.location),
+        Job::Multi(multi) => {
+            multi.jobs.first().and_then(|single| single.places.first()).and_then(|place| place.location)
+        }
+    }
+}
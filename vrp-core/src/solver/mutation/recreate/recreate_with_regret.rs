@@ -1,9 +1,14 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/mutation/recreate/recreate_with_regret_test.rs"]
+mod recreate_with_regret_test;
+
 use crate::construction::heuristics::*;
 use crate::construction::heuristics::{InsertionContext, InsertionResult};
+use crate::models::common::Cost;
 use crate::models::problem::Job;
 use crate::solver::mutation::Recreate;
 use crate::solver::RefinementContext;
-use crate::utils::parallel_collect;
+use crate::utils::{compare_floats, parallel_collect};
 use std::cmp::Ordering::*;
 use std::ops::Deref;
 
@@ -37,6 +42,14 @@ impl RecreateWithRegret {
             job_reducer: Box::new(RegretJobMapReducer::new(regret_range)),
         }
     }
+
+    /// Creates a new instance of `RecreateWithRegret` which uses k-regret insertion: on each step,
+    /// the job with the largest difference between its best and next k - 1 best insertion costs
+    /// in different routes is inserted. Jobs which can be inserted in less than k routes are
+    /// preferred as postponing them is more likely to leave them unassigned.
+    pub fn new_with_k(k: usize) -> Self {
+        Self { job_selector: Box::new(AllJobSelector::default()), job_reducer: Box::new(KRegretJobMapReducer::new(k)) }
+    }
 }
 
 struct RegretJobMapReducer {
@@ -76,3 +89,63 @@ impl JobMapReducer for RegretJobMapReducer {
         insertion_result
     }
 }
+
+struct KRegretJobMapReducer {
+    k: usize,
+}
+
+impl KRegretJobMapReducer {
+    pub fn new(k: usize) -> Self {
+        Self { k: k.max(2) }
+    }
+
+    /// Returns best insertion result of the job together with amount of routes considered for
+    /// regret (at most k) and its regret value.
+    fn evaluate(&self, ctx: &InsertionContext, job: &Job) -> (InsertionResult, usize, Cost) {
+        let routes = ctx.solution.routes.iter().cloned().chain(ctx.solution.registry.next().map(RouteContext::new));
+        let results = routes
+            .map(|route_ctx| evaluate_job_insertion_in_route(job, ctx, &route_ctx, InsertionPosition::Any, None))
+            .collect::<Vec<_>>();
+
+        let mut costs = results
+            .iter()
+            .filter_map(|result| match result {
+                InsertionResult::Success(success) => Some(success.cost),
+                InsertionResult::Failure(_) => None,
+            })
+            .collect::<Vec<_>>();
+        costs.sort_by(|a, b| compare_floats(*a, *b));
+
+        let options = costs.len().min(self.k);
+        let regret = costs.iter().take(options).skip(1).map(|cost| cost - costs[0]).sum::<Cost>();
+
+        let result = results.into_iter().fold(InsertionResult::make_failure(), InsertionResult::choose_best_result);
+
+        (result, options, regret)
+    }
+}
+
+impl JobMapReducer for KRegretJobMapReducer {
+    fn reduce<'a>(
+        &'a self,
+        ctx: &'a InsertionContext,
+        jobs: Vec<Job>,
+        _map: Box<dyn Fn(&Job) -> InsertionResult + Send + Sync + 'a>,
+    ) -> InsertionResult {
+        let results = parallel_collect(&jobs, |job| self.evaluate(ctx, job));
+
+        results
+            .into_iter()
+            .min_by(|(a, a_options, a_regret), (b, b_options, b_regret)| match (a, b) {
+                (InsertionResult::Success(a), InsertionResult::Success(b)) => a_options
+                    .cmp(b_options)
+                    .then_with(|| compare_floats(*b_regret, *a_regret))
+                    .then_with(|| compare_floats(a.cost, b.cost)),
+                (InsertionResult::Success(_), InsertionResult::Failure(_)) => Less,
+                (InsertionResult::Failure(_), InsertionResult::Success(_)) => Greater,
+                (InsertionResult::Failure(_), InsertionResult::Failure(_)) => Equal,
+            })
+            .map(|(result, _, _)| result)
+            .unwrap_or_else(InsertionResult::make_failure)
+    }
+}
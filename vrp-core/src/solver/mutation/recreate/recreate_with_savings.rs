@@ -0,0 +1,180 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/mutation/recreate/recreate_with_savings_test.rs"]
+mod recreate_with_savings_test;
+
+use crate::construction::heuristics::InsertionContext;
+use crate::construction::heuristics::*;
+use crate::models::common::Location;
+use crate::models::problem::Job;
+use crate::solver::mutation::{Recreate, RecreateWithCheapest};
+use crate::solver::RefinementContext;
+use crate::utils::compare_floats;
+use hashbrown::HashMap;
+
+/// A recreate method which builds routes using Clarke-Wright savings algorithm. Jobs are chained
+/// in order of decreasing savings `s(i, j) = d(i, 0) + d(0, j) - d(i, j)`, where `0` is a start
+/// location of the first actor, and each chain is appended to its own route. Jobs which cannot be
+/// appended feasibly are inserted using cheapest insertion afterwards.
+pub struct RecreateWithSavings {
+    fallback: RecreateWithCheapest,
+}
+
+impl Default for RecreateWithSavings {
+    fn default() -> Self {
+        Self { fallback: RecreateWithCheapest::default() }
+    }
+}
+
+impl Recreate for RecreateWithSavings {
+    fn run(&self, refinement_ctx: &mut RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
+        let chains = get_savings_chains(&insertion_ctx);
+
+        let job_selector: Box<dyn JobSelector + Send + Sync> = Box::new(SavingsJobSelector {
+            order: chains.iter().flat_map(|chain| chain.iter()).cloned().zip(0..).collect(),
+        });
+        let job_reducer: Box<dyn JobMapReducer + Send + Sync> = Box::new(SavingsJobMapReducer {
+            predecessors: chains
+                .iter()
+                .flat_map(|chain| {
+                    chain
+                        .iter()
+                        .enumerate()
+                        .map(move |(idx, job)| (job.clone(), if idx > 0 { chain.get(idx - 1).cloned() } else { None }))
+                })
+                .collect(),
+        });
+
+        let insertion_ctx = InsertionHeuristic::new(InsertionPosition::Last).process(
+            &job_selector,
+            &job_reducer,
+            insertion_ctx,
+            &refinement_ctx.quota,
+        );
+
+        self.fallback.run(refinement_ctx, insertion_ctx)
+    }
+}
+
+/// Selects jobs in order of savings chains, jobs outside of chains are selected last.
+struct SavingsJobSelector {
+    order: HashMap<Job, usize>,
+}
+
+impl JobSelector for SavingsJobSelector {
+    fn select<'a>(&'a self, ctx: &'a mut InsertionContext) -> Box<dyn Iterator<Item = Job> + 'a> {
+        ctx.solution.required.sort_by_key(|job| self.order.get(job).cloned().unwrap_or(std::usize::MAX));
+
+        Box::new(ctx.solution.required.iter().cloned())
+    }
+}
+
+/// Appends the first selected job to the route of its predecessor in savings chain or to the best
+/// new route when there is no predecessor or it is not assigned. Failed jobs are left for fallback.
+struct SavingsJobMapReducer {
+    predecessors: HashMap<Job, Option<Job>>,
+}
+
+impl JobMapReducer for SavingsJobMapReducer {
+    fn reduce<'a>(
+        &'a self,
+        ctx: &'a InsertionContext,
+        jobs: Vec<Job>,
+        _map: Box<dyn Fn(&Job) -> InsertionResult + Send + Sync + 'a>,
+    ) -> InsertionResult {
+        let job = match jobs.first() {
+            Some(job) => job,
+            None => return InsertionResult::make_failure(),
+        };
+
+        let result = self.predecessors.get(job).map(|predecessor| {
+            let route_ctx = predecessor
+                .as_ref()
+                .and_then(|predecessor| ctx.solution.routes.iter().find(|rc| rc.route.tour.contains(predecessor)));
+
+            match route_ctx {
+                Some(route_ctx) => evaluate_job_insertion_in_route(job, ctx, route_ctx, InsertionPosition::Last, None),
+                None => ctx.solution.registry.next().map(RouteContext::new).fold(
+                    InsertionResult::make_failure(),
+                    |acc, route_ctx| {
+                        evaluate_job_insertion_in_route(job, ctx, &route_ctx, InsertionPosition::Last, Some(acc))
+                    },
+                ),
+            }
+        });
+
+        match result {
+            Some(InsertionResult::Success(success)) => InsertionResult::Success(success),
+            Some(InsertionResult::Failure(failure)) => {
+                InsertionResult::make_failure_with_code(failure.constraint, Some(job.clone()))
+            }
+            None => InsertionResult::make_failure_with_code(-1, Some(job.clone())),
+        }
+    }
+}
+
+/// Merges required single jobs into chains using savings relative to start location of the first actor.
+fn get_savings_chains(ctx: &InsertionContext) -> Vec<Vec<Job>> {
+    let (depot, profile) = match ctx.problem.fleet.actors.first() {
+        Some(actor) if actor.detail.start.is_some() => (actor.detail.start.unwrap(), actor.vehicle.profile),
+        _ => return vec![],
+    };
+
+    let jobs = ctx
+        .solution
+        .required
+        .iter()
+        .filter_map(|job| {
+            job.as_single()
+                .and_then(|single| single.places.first())
+                .and_then(|place| place.location)
+                .map(|location| (job.clone(), location))
+        })
+        .collect::<Vec<(Job, Location)>>();
+
+    let distance = |from: Location, to: Location| ctx.problem.transport.distance(profile, from, to, 0.);
+
+    let mut savings =
+        jobs.iter()
+            .enumerate()
+            .flat_map(|(i, (_, from))| {
+                jobs.iter().enumerate().filter(move |(j, _)| i != *j).map(move |(j, (_, to))| {
+                    (i, j, distance(*from, depot) + distance(depot, *to) - distance(*from, *to))
+                })
+            })
+            .filter(|(_, _, saving)| *saving > 0.)
+            .collect::<Vec<_>>();
+    savings.sort_by(|(_, _, a), (_, _, b)| compare_floats(*b, *a));
+
+    let size = jobs.len();
+    let mut next: Vec<Option<usize>> = vec![None; size];
+    let mut prev: Vec<Option<usize>> = vec![None; size];
+    let mut chain_ids = (0..size).collect::<Vec<_>>();
+
+    savings.into_iter().for_each(|(i, j, _)| {
+        if next[i].is_none() && prev[j].is_none() && chain_ids[i] != chain_ids[j] {
+            next[i] = Some(j);
+            prev[j] = Some(i);
+
+            let chain_id = chain_ids[i];
+            let mut current = Some(j);
+            while let Some(idx) = current {
+                chain_ids[idx] = chain_id;
+                current = next[idx];
+            }
+        }
+    });
+
+    (0..size)
+        .filter(|idx| prev[*idx].is_none())
+        .map(|head| {
+            let mut chain = vec![];
+            let mut current = Some(head);
+            while let Some(idx) = current {
+                chain.push(jobs[idx].0.clone());
+                current = next[idx];
+            }
+
+            chain
+        })
+        .collect()
+}
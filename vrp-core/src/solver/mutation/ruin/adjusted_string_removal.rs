@@ -4,7 +4,7 @@ mod adjusted_string_removal_test;
 
 use std::sync::{Arc, RwLock};
 
-use super::{select_seed_jobs, Ruin};
+use super::{get_assigned_jobs, select_seed_jobs, Ruin, RuinLimits};
 use crate::construction::heuristics::{InsertionContext, RouteContext};
 use crate::models::problem::{Actor, Job};
 use crate::models::solution::Tour;
@@ -24,21 +24,34 @@ pub struct AdjustedStringRemoval {
     cavg: usize,
     /// Preserved customers ratio.
     alpha: f64,
+    /// Specifies limits of removed jobs relative to problem size, used instead of average number.
+    limits: Option<RuinLimits>,
 }
 
 impl AdjustedStringRemoval {
     /// Creates a new instance of [`AdjustedStringRemoval`].
     pub fn new(lmax: usize, cavg: usize, alpha: f64) -> Self {
-        Self { lmax, cavg, alpha }
+        Self { lmax, cavg, alpha, limits: None }
+    }
+
+    /// Sets limits of removed jobs relative to problem size.
+    pub fn with_limits(mut self, limits: Option<RuinLimits>) -> Self {
+        self.limits = limits;
+        self
     }
 
     /// Calculates initial parameters from paper using 5,6,7 equations.
-    fn calculate_limits(&self, routes: &[RouteContext], random: &Arc<dyn Random + Send + Sync>) -> (usize, usize) {
+    fn calculate_limits(
+        &self,
+        routes: &[RouteContext],
+        cavg: usize,
+        random: &Arc<dyn Random + Send + Sync>,
+    ) -> (usize, usize) {
         // Equation 5: max removed string cardinality for each tour
         let lsmax = calculate_average_tour_cardinality(routes).min(self.lmax as f64);
 
         // Equation 6: max number of strings
-        let ksmax = 4. * (self.cavg as f64) / (1. + lsmax) - 1.;
+        let ksmax = (4. * (cavg as f64) / (1. + lsmax) - 1.).max(1.);
 
         // Equation 7: number of string to be removed
         let ks = random.uniform_real(1., ksmax + 1.).floor() as usize;
@@ -64,7 +77,9 @@ impl Ruin for AdjustedStringRemoval {
         let locked = insertion_ctx.solution.locked.clone();
         let random = insertion_ctx.random.clone();
 
-        let (lsmax, ks) = self.calculate_limits(&routes, &random);
+        let cavg =
+            self.limits.as_ref().map_or(self.cavg, |limits| limits.get_chunk_size(get_assigned_jobs(&insertion_ctx)));
+        let (lsmax, ks) = self.calculate_limits(&routes, cavg, &random);
 
         select_seed_jobs(&problem, &routes, &random)
             .filter(|job| !jobs.read().unwrap().contains(job))
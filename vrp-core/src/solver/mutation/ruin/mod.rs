@@ -1,5 +1,9 @@
 //! A various strategies to destroy parts of an existing solution.
 
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/mutation/ruin/ruin_test.rs"]
+mod ruin_test;
+
 use crate::construction::heuristics::{InsertionContext, RouteContext};
 use crate::models::problem::Job;
 use crate::models::Problem;
@@ -23,12 +27,52 @@ pub use self::neighbour_removal::NeighbourRemoval;
 mod random_route_removal;
 pub use self::random_route_removal::RandomRouteRemoval;
 
+mod route_removal;
+pub use self::route_removal::RouteRemoval;
+
 mod random_job_removal;
 pub use self::random_job_removal::RandomJobRemoval;
 
+mod related_jobs_removal;
+pub use self::related_jobs_removal::RelatedJobRemoval;
+
+mod time_window_removal;
+pub use self::time_window_removal::TimeWindowRemoval;
+
 mod worst_jobs_removal;
 pub use self::worst_jobs_removal::WorstJobRemoval;
 
+/// Specifies amount of jobs removed by ruin method as a fraction of all assigned jobs. This keeps
+/// ruin size adaptive to problem scale: removing fixed amount of jobs is too destructive for small
+/// problems and has almost no effect on large ones.
+#[derive(Clone, Debug)]
+pub struct RuinLimits {
+    /// Minimum amount of removed jobs.
+    pub min: usize,
+    /// Maximum amount of removed jobs.
+    pub max: usize,
+    /// A fraction of assigned jobs to be removed.
+    pub ratio: f64,
+}
+
+impl RuinLimits {
+    /// Creates a new instance of [`RuinLimits`].
+    pub fn new(min: usize, max: usize, ratio: f64) -> Self {
+        assert!(min <= max);
+        assert!(ratio > 0. && ratio <= 1.);
+
+        Self { min, max, ratio }
+    }
+
+    /// Returns amount of jobs to be removed for given amount of assigned jobs. It never exceeds
+    /// amount of assigned jobs.
+    pub fn get_chunk_size(&self, assigned: usize) -> usize {
+        let size = (assigned as f64 * self.ratio).round() as usize;
+
+        size.max(self.min).min(self.max).min(assigned)
+    }
+}
+
 /// Provides the way to run multiple ruin methods one by one on the same solution.
 pub struct CompositeRuin {
     ruins: Vec<Vec<(Arc<dyn Ruin>, f64)>>,
@@ -37,15 +81,40 @@ pub struct CompositeRuin {
 
 impl Default for CompositeRuin {
     fn default() -> Self {
-        let adjusted_string_default = Arc::new(AdjustedStringRemoval::default());
+        Self::new_with_default_ruins(None)
+    }
+}
+
+impl CompositeRuin {
+    pub fn new(ruins: Vec<(Vec<(Arc<dyn Ruin>, f64)>, usize)>) -> Self {
+        let weights = ruins.iter().map(|(_, weight)| *weight).collect();
+        let ruins = ruins.into_iter().map(|(ruin, _)| ruin).collect();
+
+        Self { ruins, weights }
+    }
+
+    /// Returns weights of ruin method groups.
+    pub fn weights(&self) -> &[usize] {
+        self.weights.as_slice()
+    }
+
+    /// Creates a new instance of [`CompositeRuin`] with default ruin methods. When limits are
+    /// specified, they are used by default (not aggressive) job removal methods instead of fixed
+    /// removal range.
+    pub fn new_with_default_ruins(limits: Option<RuinLimits>) -> Self {
+        let adjusted_string_default = Arc::new(AdjustedStringRemoval::default().with_limits(limits.clone()));
         let adjusted_string_aggressive = Arc::new(AdjustedStringRemoval::new(30, 120, 0.02));
 
-        let neighbour_removal = Arc::new(NeighbourRemoval::default());
+        let neighbour_removal = Arc::new(NeighbourRemoval::default().with_limits(limits.clone()));
         let neighbour_aggressive = Arc::new(NeighbourRemoval::new(30, 120, 0.5));
 
+        let related_job_default = Arc::new(RelatedJobRemoval::<i32>::default().with_limits(limits.clone()));
+        let random_job_default = Arc::new(RandomJobRemoval::default().with_limits(limits));
+
+        let time_window_default = Arc::new(TimeWindowRemoval::default());
         let worst_job_default = Arc::new(WorstJobRemoval::default());
-        let random_job_default = Arc::new(RandomJobRemoval::default());
         let random_route_default = Arc::new(RandomRouteRemoval::default());
+        let route_default = Arc::new(RouteRemoval::default());
 
         Self::new(vec![
             (
@@ -62,22 +131,16 @@ impl Default for CompositeRuin {
                 50,
             ),
             (vec![(neighbour_aggressive, 1.)], 10),
+            (vec![(related_job_default, 1.), (random_job_default.clone(), 0.05)], 10),
+            (vec![(time_window_default, 1.), (random_job_default.clone(), 0.05)], 5),
             (vec![(worst_job_default, 1.), (adjusted_string_default, 0.1)], 10),
             (vec![(random_job_default.clone(), 1.), (random_route_default.clone(), 0.1)], 10),
-            (vec![(random_route_default, 1.), (random_job_default, 0.1)], 10),
+            (vec![(random_route_default, 1.), (random_job_default.clone(), 0.1)], 10),
+            (vec![(route_default, 1.), (random_job_default, 0.1)], 5),
         ])
     }
 }
 
-impl CompositeRuin {
-    pub fn new(ruins: Vec<(Vec<(Arc<dyn Ruin>, f64)>, usize)>) -> Self {
-        let weights = ruins.iter().map(|(_, weight)| *weight).collect();
-        let ruins = ruins.into_iter().map(|(ruin, _)| ruin).collect();
-
-        Self { ruins, weights }
-    }
-}
-
 impl Ruin for CompositeRuin {
     fn run(&self, refinement_ctx: &mut RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
         if insertion_ctx.solution.routes.is_empty() {
@@ -102,16 +165,29 @@ impl Ruin for CompositeRuin {
     }
 }
 
-fn get_chunk_size(ctx: &InsertionContext, range: &(usize, usize), threshold: f64) -> usize {
+fn get_chunk_size(
+    ctx: &InsertionContext,
+    range: &(usize, usize),
+    threshold: f64,
+    limits: Option<&RuinLimits>,
+) -> usize {
     let &(min, max) = range;
 
-    let assigned = ctx.problem.jobs.size() - ctx.solution.unassigned.len() - ctx.solution.ignored.len();
+    let assigned = get_assigned_jobs(ctx);
+
+    if let Some(limits) = limits {
+        return limits.get_chunk_size(assigned);
+    }
 
     let max_limit = (assigned as f64 * threshold).min(max as f64).round() as usize;
 
     ctx.random.uniform_int(min as i32, max as i32).min(max_limit as i32) as usize
 }
 
+fn get_assigned_jobs(ctx: &InsertionContext) -> usize {
+    ctx.problem.jobs.size() - ctx.solution.unassigned.len() - ctx.solution.ignored.len()
+}
+
 /// Returns randomly selected job within all its neighbours.
 fn select_seed_jobs<'a>(
     problem: &'a Problem,
@@ -1,4 +1,4 @@
-use super::{get_chunk_size, select_seed_jobs, Ruin};
+use super::{get_chunk_size, select_seed_jobs, Ruin, RuinLimits};
 use crate::construction::heuristics::InsertionContext;
 use crate::solver::RefinementContext;
 
@@ -8,12 +8,20 @@ pub struct NeighbourRemoval {
     range: (usize, usize),
     /// Specifies threshold ratio of maximum removed jobs.
     threshold: f64,
+    /// Specifies limits of removed jobs relative to problem size, used instead of range and threshold.
+    limits: Option<RuinLimits>,
 }
 
 impl NeighbourRemoval {
     /// Creates a new instance of [`NeighbourRemoval`].
     pub fn new(min: usize, max: usize, threshold: f64) -> Self {
-        Self { range: (min, max), threshold }
+        Self { range: (min, max), threshold, limits: None }
+    }
+
+    /// Sets limits of removed jobs relative to problem size.
+    pub fn with_limits(mut self, limits: Option<RuinLimits>) -> Self {
+        self.limits = limits;
+        self
     }
 }
 
@@ -27,7 +35,7 @@ impl Ruin for NeighbourRemoval {
     fn run(&self, _refinement_ctx: &mut RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
         let mut insertion_ctx = insertion_ctx;
 
-        let affected = get_chunk_size(&insertion_ctx, &self.range, self.threshold);
+        let affected = get_chunk_size(&insertion_ctx, &self.range, self.threshold, self.limits.as_ref());
 
         let problem = insertion_ctx.problem.clone();
         let random = insertion_ctx.random.clone();
@@ -1,4 +1,4 @@
-use super::{get_chunk_size, select_seed_job, Ruin};
+use super::{get_chunk_size, select_seed_job, Ruin, RuinLimits};
 use crate::construction::heuristics::InsertionContext;
 use crate::solver::RefinementContext;
 
@@ -8,12 +8,20 @@ pub struct RandomJobRemoval {
     range: (usize, usize),
     /// Specifies threshold ratio of maximum removed jobs.
     threshold: f64,
+    /// Specifies limits of removed jobs relative to problem size, used instead of range and threshold.
+    limits: Option<RuinLimits>,
 }
 
 impl RandomJobRemoval {
     /// Creates a new instance of [`RandomJobRemoval`].
     pub fn new(min: usize, max: usize, threshold: f64) -> Self {
-        Self { range: (min, max), threshold }
+        Self { range: (min, max), threshold, limits: None }
+    }
+
+    /// Sets limits of removed jobs relative to problem size.
+    pub fn with_limits(mut self, limits: Option<RuinLimits>) -> Self {
+        self.limits = limits;
+        self
     }
 }
 
@@ -31,7 +39,7 @@ impl Ruin for RandomJobRemoval {
             return insertion_ctx;
         }
 
-        let affected = get_chunk_size(&insertion_ctx, &self.range, self.threshold);
+        let affected = get_chunk_size(&insertion_ctx, &self.range, self.threshold, self.limits.as_ref());
 
         (0..affected).for_each(|_| {
             let solution = &mut insertion_ctx.solution;
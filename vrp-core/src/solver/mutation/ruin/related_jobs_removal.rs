@@ -0,0 +1,227 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/mutation/ruin/related_jobs_removal_test.rs"]
+mod related_jobs_removal_test;
+
+use super::{get_chunk_size, select_seed_job, Ruin, RuinLimits};
+use crate::construction::constraints::{Demand, DemandDimension};
+use crate::construction::heuristics::InsertionContext;
+use crate::models::common::{Distance, Location, Profile, TimeSpan, TimeWindow};
+use crate::models::problem::{Job, Single, TransportCost};
+use crate::solver::RefinementContext;
+use crate::utils::compare_floats;
+use std::iter::once;
+use std::marker::PhantomData;
+use std::ops::{Add, Sub};
+use std::sync::Arc;
+
+/// A ruin strategy which removes jobs related to randomly selected one (aka Shaw removal).
+/// Relatedness of two jobs is estimated as weighted sum of normalized distance between them,
+/// lack of their time windows overlap and difference in their demand, so the lower the value,
+/// the more related jobs are.
+pub struct RelatedJobRemoval<Capacity: Add + Sub + Ord + Copy + Default + Send + Sync + 'static> {
+    /// Specifies minimum and maximum amount of removed jobs.
+    range: (usize, usize),
+    /// Specifies threshold ratio of maximum removed jobs.
+    threshold: f64,
+    /// Specifies weights of distance, time window and demand relatedness.
+    weights: (f64, f64, f64),
+    /// Specifies limits of removed jobs relative to problem size, used instead of range and threshold.
+    limits: Option<RuinLimits>,
+    phantom: PhantomData<Capacity>,
+}
+
+impl<Capacity: Add<Output = Capacity> + Sub<Output = Capacity> + Ord + Copy + Default + Send + Sync + 'static>
+    RelatedJobRemoval<Capacity>
+{
+    /// Creates a new instance of [`RelatedJobRemoval`] with given weights of distance, time window
+    /// and demand relatedness.
+    pub fn new(min: usize, max: usize, threshold: f64, distance: f64, time_window: f64, demand: f64) -> Self {
+        Self {
+            range: (min, max),
+            threshold,
+            weights: (distance, time_window, demand),
+            limits: None,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Sets limits of removed jobs relative to problem size.
+    pub fn with_limits(mut self, limits: Option<RuinLimits>) -> Self {
+        self.limits = limits;
+        self
+    }
+}
+
+impl<Capacity: Add<Output = Capacity> + Sub<Output = Capacity> + Ord + Copy + Default + Send + Sync + 'static> Default
+    for RelatedJobRemoval<Capacity>
+{
+    fn default() -> Self {
+        Self::new(10, 30, 0.3, 9., 3., 2.)
+    }
+}
+
+impl<Capacity: Add<Output = Capacity> + Sub<Output = Capacity> + Ord + Copy + Default + Send + Sync + 'static> Ruin
+    for RelatedJobRemoval<Capacity>
+{
+    fn run(&self, _refinement_ctx: &mut RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
+        let mut insertion_ctx = insertion_ctx;
+
+        let affected = get_chunk_size(&insertion_ctx, &self.range, self.threshold, self.limits.as_ref());
+
+        let (route_index, seed) = match select_seed_job(&insertion_ctx.solution.routes, &insertion_ctx.random) {
+            Some(seed) => seed,
+            None => return insertion_ctx,
+        };
+
+        let profile = insertion_ctx.solution.routes.get(route_index).unwrap().route.actor.vehicle.profile;
+        let locked = &insertion_ctx.solution.locked;
+        let jobs = insertion_ctx
+            .solution
+            .routes
+            .iter()
+            .flat_map(|rc| rc.route.tour.jobs())
+            .filter(|job| !locked.contains(job))
+            .collect::<Vec<_>>();
+
+        let related = self.get_related_jobs(&seed, jobs, profile, insertion_ctx.problem.transport.as_ref());
+
+        related.into_iter().take(affected).for_each(|job| {
+            let route = insertion_ctx.solution.routes.iter_mut().find(|rc| rc.route.tour.contains(&job));
+
+            if let Some(route) = route {
+                route.route_mut().tour.remove(&job);
+                insertion_ctx.solution.required.push(job);
+            }
+        });
+
+        insertion_ctx
+    }
+}
+
+impl<Capacity: Add<Output = Capacity> + Sub<Output = Capacity> + Ord + Copy + Default + Send + Sync + 'static>
+    RelatedJobRemoval<Capacity>
+{
+    /// Returns jobs sorted by their relatedness to the seed job, the most related first.
+    fn get_related_jobs(
+        &self,
+        seed: &Job,
+        jobs: Vec<Job>,
+        profile: Profile,
+        transport: &(dyn TransportCost + Send + Sync),
+    ) -> Vec<Job> {
+        let (distance_weight, time_weight, demand_weight) = self.weights;
+
+        let distances = jobs.iter().map(|job| get_distance(seed, job, profile, transport)).collect::<Vec<_>>();
+        let max_distance = distances.iter().cloned().fold(0., f64::max);
+
+        // NOTE seed job is added to the end as it can be excluded from jobs if it is locked
+        let demand_ranks = get_demand_ranks::<Capacity>(jobs.iter().chain(once(seed)));
+        let seed_rank = demand_ranks.last().cloned().unwrap_or(0.);
+
+        let seed_time = get_time_window(seed);
+
+        let mut related = jobs
+            .into_iter()
+            .zip(distances.into_iter().zip(demand_ranks))
+            .map(|(job, (distance, demand_rank))| {
+                let distance = if max_distance > 0. { distance / max_distance } else { 0. };
+                let time = 1. - get_overlap_ratio(&seed_time, &get_time_window(&job));
+                let demand = (demand_rank - seed_rank).abs();
+
+                let relatedness = distance_weight * distance + time_weight * time + demand_weight * demand;
+
+                (job, relatedness)
+            })
+            .collect::<Vec<_>>();
+
+        related.sort_by(|(a_job, a), (b_job, b)| {
+            compare_floats(*a, *b).then_with(|| (b_job == seed).cmp(&(a_job == seed)))
+        });
+
+        related.into_iter().map(|(job, _)| job).collect()
+    }
+}
+
+/// Returns minimal distance between places of two jobs.
+fn get_distance(lhs: &Job, rhs: &Job, profile: Profile, transport: &(dyn TransportCost + Send + Sync)) -> Distance {
+    let to_locations = get_locations(rhs);
+
+    get_locations(lhs)
+        .into_iter()
+        .flat_map(|from| to_locations.iter().map(move |to| (from, *to)))
+        .map(|(from, to)| transport.distance(profile, from, to, 0.))
+        .min_by(|a, b| compare_floats(*a, *b))
+        .unwrap_or(0.)
+}
+
+fn get_locations(job: &Job) -> Vec<Location> {
+    get_singles(job).flat_map(|single| single.places.iter()).filter_map(|place| place.location).collect()
+}
+
+/// Returns time window which covers all time windows of the job. Time offsets are not considered.
+fn get_time_window(job: &Job) -> TimeWindow {
+    get_singles(job)
+        .flat_map(|single| single.places.iter())
+        .flat_map(|place| place.times.iter())
+        .filter_map(|time| match time {
+            TimeSpan::Window(tw) => Some(tw),
+            TimeSpan::Offset(_) => None,
+        })
+        .fold(None, |acc: Option<TimeWindow>, tw| match acc {
+            Some(acc) => Some(TimeWindow::new(acc.start.min(tw.start), acc.end.max(tw.end))),
+            None => Some(tw.clone()),
+        })
+        .unwrap_or_else(TimeWindow::max)
+}
+
+/// Returns ratio of time windows overlap to the shortest of them.
+fn get_overlap_ratio(lhs: &TimeWindow, rhs: &TimeWindow) -> f64 {
+    if !lhs.intersects(rhs) {
+        return 0.;
+    }
+
+    let overlap = lhs.end.min(rhs.end) - lhs.start.max(rhs.start);
+    let shortest = (lhs.end - lhs.start).min(rhs.end - rhs.start);
+
+    if shortest > 0. {
+        (overlap / shortest).min(1.)
+    } else {
+        1.
+    }
+}
+
+/// Returns normalized rank of each job demand: jobs with the same demand have the same rank.
+fn get_demand_ranks<
+    'a,
+    Capacity: Add<Output = Capacity> + Sub<Output = Capacity> + Ord + Copy + Default + Send + Sync + 'static,
+>(
+    jobs: impl Iterator<Item = &'a Job>,
+) -> Vec<f64> {
+    let demands = jobs.map(get_demand::<Capacity>).collect::<Vec<_>>();
+
+    let mut unique = demands.clone();
+    unique.sort();
+    unique.dedup();
+
+    let max_rank = (unique.len().max(2) - 1) as f64;
+
+    demands.iter().map(|demand| unique.binary_search(demand).unwrap_or(0) as f64 / max_rank).collect()
+}
+
+fn get_demand<
+    Capacity: Add<Output = Capacity> + Sub<Output = Capacity> + Ord + Copy + Default + Send + Sync + 'static,
+>(
+    job: &Job,
+) -> Option<Capacity> {
+    get_singles(job)
+        .next()
+        .and_then(|single| single.dimens.get_demand())
+        .map(|demand: &Demand<Capacity>| demand.pickup.0 + demand.delivery.0 + demand.pickup.1 + demand.delivery.1)
+}
+
+fn get_singles<'a>(job: &'a Job) -> Box<dyn Iterator<Item = &'a Arc<Single>> + 'a> {
+    match job {
+        Job::Single(single) => Box::new(once(single)),
+        Job::Multi(multi) => Box::new(multi.jobs.iter()),
+    }
+}
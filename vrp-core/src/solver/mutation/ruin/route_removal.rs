@@ -0,0 +1,62 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/mutation/ruin/route_removal_test.rs"]
+mod route_removal_test;
+
+use super::Ruin;
+use crate::construction::heuristics::InsertionContext;
+use crate::models::problem::Job;
+use crate::solver::RefinementContext;
+
+/// A ruin strategy which removes the smallest routes (with the fewest jobs) from solution, so
+/// their jobs can be redistributed among other routes. This helps to reduce amount of used
+/// vehicles. Locked jobs are kept in their routes and routes without jobs to remove are skipped.
+pub struct RouteRemoval {
+    /// Specifies amount of removed routes.
+    routes: usize,
+}
+
+impl RouteRemoval {
+    /// Creates a new instance of [`RouteRemoval`].
+    pub fn new(routes: usize) -> Self {
+        Self { routes }
+    }
+}
+
+impl Default for RouteRemoval {
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
+
+impl Ruin for RouteRemoval {
+    fn run(&self, _refinement_ctx: &mut RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
+        let mut insertion_ctx = insertion_ctx;
+        let solution = &mut insertion_ctx.solution;
+        let locked = solution.locked.clone();
+
+        let mut candidates = solution
+            .routes
+            .iter()
+            .filter(|rc| rc.route.tour.jobs().any(|job| !locked.contains(&job)))
+            .cloned()
+            .collect::<Vec<_>>();
+        candidates.sort_by_key(|rc| rc.route.tour.job_count());
+
+        candidates.into_iter().take(self.routes).for_each(|mut route_ctx| {
+            let jobs: Vec<Job> = route_ctx.route.tour.jobs().filter(|job| !locked.contains(job)).collect();
+
+            if jobs.len() == route_ctx.route.tour.job_count() {
+                solution.routes.retain(|rc| *rc != route_ctx);
+                solution.registry.free_actor(&route_ctx.route.actor);
+            } else {
+                jobs.iter().for_each(|job| {
+                    route_ctx.route_mut().tour.remove(job);
+                });
+            }
+
+            solution.required.extend(jobs);
+        });
+
+        insertion_ctx
+    }
+}
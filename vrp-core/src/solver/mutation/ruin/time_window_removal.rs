@@ -0,0 +1,93 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/mutation/ruin/time_window_removal_test.rs"]
+mod time_window_removal_test;
+
+use super::Ruin;
+use crate::construction::heuristics::{InsertionContext, RouteContext};
+use crate::models::common::TimeWindow;
+use crate::models::problem::Job;
+use crate::solver::RefinementContext;
+use hashbrown::HashSet;
+
+/// A ruin strategy which removes all jobs scheduled to be served within random time interval.
+/// Interval covers part of planning horizon, which is defined by the earliest departure and the
+/// latest arrival of all routes. Scheduled arrival times are taken from the current solution
+/// as they are maintained by timing constraint module.
+pub struct TimeWindowRemoval {
+    /// Specifies minimum and maximum ratio of planning horizon covered by interval.
+    range: (f64, f64),
+}
+
+impl TimeWindowRemoval {
+    /// Creates a new instance of [`TimeWindowRemoval`].
+    pub fn new(min: f64, max: f64) -> Self {
+        assert!(min >= 0. && min <= max && max <= 1.);
+
+        Self { range: (min, max) }
+    }
+}
+
+impl Default for TimeWindowRemoval {
+    fn default() -> Self {
+        Self::new(0.05, 0.2)
+    }
+}
+
+impl Ruin for TimeWindowRemoval {
+    fn run(&self, _refinement_ctx: &mut RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
+        let mut insertion_ctx = insertion_ctx;
+
+        let horizon = match get_planning_horizon(&insertion_ctx.solution.routes) {
+            Some(horizon) => horizon,
+            None => return insertion_ctx,
+        };
+
+        let random = insertion_ctx.random.clone();
+        let (min, max) = self.range;
+        let ratio = if min < max { random.uniform_real(min, max) } else { min };
+
+        let length = (horizon.end - horizon.start) * ratio;
+        let start = horizon.start + (horizon.end - horizon.start - length) * random.uniform_real(0., 1.);
+        let interval = TimeWindow::new(start, start + length);
+
+        let solution = &mut insertion_ctx.solution;
+        let locked = &solution.locked;
+        let jobs = solution
+            .routes
+            .iter()
+            .flat_map(|rc| rc.route.tour.all_activities())
+            .filter(|activity| activity.schedule.arrival >= interval.start && activity.schedule.arrival <= interval.end)
+            .filter_map(|activity| activity.retrieve_job())
+            .filter(|job| !locked.contains(job))
+            .collect::<HashSet<Job>>();
+
+        let required = &mut solution.required;
+        solution.routes.iter_mut().for_each(|rc| {
+            let route_jobs = rc.route.tour.jobs().filter(|job| jobs.contains(job)).collect::<Vec<_>>();
+            route_jobs.into_iter().for_each(|job| {
+                rc.route_mut().tour.remove(&job);
+                required.push(job);
+            });
+        });
+
+        insertion_ctx
+    }
+}
+
+/// Returns time window between the earliest departure and the latest arrival of all routes.
+fn get_planning_horizon(routes: &[RouteContext]) -> Option<TimeWindow> {
+    routes
+        .iter()
+        .filter(|rc| rc.route.tour.has_jobs())
+        .filter_map(|rc| {
+            let tour = &rc.route.tour;
+            let start = tour.start()?.schedule.departure;
+            let end = tour.end()?.schedule.arrival;
+
+            Some(TimeWindow::new(start, end))
+        })
+        .fold(None, |acc: Option<TimeWindow>, tw| match acc {
+            Some(acc) => Some(TimeWindow::new(acc.start.min(tw.start), acc.end.max(tw.end))),
+            None => Some(tw),
+        })
+}
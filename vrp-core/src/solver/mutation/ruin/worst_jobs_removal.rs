@@ -47,7 +47,7 @@ impl Ruin for WorstJobRemoval {
         let mut routes_savings = get_routes_cost_savings(&insertion_ctx);
         let removed_jobs: RwLock<HashSet<Job>> = RwLock::new(HashSet::default());
 
-        routes_savings.shuffle(&mut rand::thread_rng());
+        routes_savings.shuffle(&mut random.get_rng());
 
         routes_savings.iter().take_while(|_| removed_jobs.read().unwrap().len() <= self.threshold).for_each(
             |(rc, savings)| {
@@ -103,21 +103,21 @@ fn get_route_jobs(solution: &SolutionContext) -> HashMap<Job, RouteContext> {
 fn get_routes_cost_savings(insertion_ctx: &InsertionContext) -> Vec<(RouteContext, Vec<(Job, Cost)>)> {
     parallel_collect(&insertion_ctx.solution.routes, |rc| {
         let actor = rc.route.actor.as_ref();
-        let mut savings: Vec<(Job, Cost)> = rc
-            .route
-            .tour
-            .all_activities()
-            .as_slice()
-            .windows(3)
-            .fold(HashMap::<Job, Cost>::default(), |mut acc, iter| match iter {
-                [start, eval, end] => {
-                    let savings = get_cost_savings(actor, start, eval, end, &insertion_ctx.problem.transport);
-                    let job = eval.retrieve_job().unwrap_or_else(|| panic!("Unexpected activity without job"));
-                    *acc.entry(job).or_insert(0.) += savings;
-
-                    acc
-                }
-                _ => panic!("Unexpected activity window"),
+        let activities = rc.route.tour.all_activities().as_slice();
+        // NOTE the last activity of open tour has no next one, so it is evaluated separately from
+        // the rest: otherwise, a job in single job route without end would never be removed
+        let mut savings: Vec<(Job, Cost)> = activities
+            .iter()
+            .enumerate()
+            .skip(1)
+            .filter_map(|(idx, eval)| eval.retrieve_job().map(|job| (idx, eval, job)))
+            .fold(HashMap::<Job, Cost>::default(), |mut acc, (idx, eval, job)| {
+                let start = &activities[idx - 1];
+                let end = activities.get(idx + 1);
+                let savings = get_cost_savings(actor, start, eval, end, &insertion_ctx.problem.transport);
+                *acc.entry(job).or_insert(0.) += savings;
+
+                acc
             })
             .drain()
             .collect();
@@ -132,11 +132,11 @@ fn get_cost_savings(
     actor: &Actor,
     start: &TourActivity,
     middle: &TourActivity,
-    end: &TourActivity,
+    end: Option<&TourActivity>,
     transport: &Arc<dyn TransportCost + Send + Sync>,
 ) -> Cost {
-    get_cost(actor, start, middle, transport) + get_cost(actor, middle, end, transport)
-        - get_cost(actor, start, end, transport)
+    get_cost(actor, start, middle, transport)
+        + end.map_or(0., |end| get_cost(actor, middle, end, transport) - get_cost(actor, start, end, transport))
 }
 
 #[inline(always)]
@@ -146,5 +146,5 @@ fn get_cost(
     to: &TourActivity,
     transport: &Arc<dyn TransportCost + Send + Sync>,
 ) -> Cost {
-    transport.cost(actor, from.place.location, to.place.location, from.schedule.departure)
+    transport.cost(actor, actor.vehicle.profile, from.place.location, to.place.location, from.schedule.departure)
 }
@@ -0,0 +1,177 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/mutation/waiting_reduction_test.rs"]
+mod waiting_reduction_test;
+
+use super::Mutation;
+use crate::construction::constraints::{get_leg_profile, WAITING_KEY};
+use crate::construction::heuristics::{ActivityContext, InsertionContext, RouteContext, SolutionContext};
+use crate::models::common::Duration;
+use crate::models::problem::Job;
+use crate::models::Problem;
+use crate::solver::RefinementContext;
+use crate::utils::compare_floats;
+use hashbrown::HashSet;
+use std::cmp::Ordering;
+
+/// A mutation which targets the largest waiting at a single stop of each route: it tries to move
+/// the stop to another position within the same route, so the vehicle serves other jobs first or
+/// departs from the depot later. A move is accepted only if it reduces total waiting of the route
+/// without increasing its total cost.
+pub struct WaitingReduction {
+    /// Minimum waiting at a single stop to be considered for reduction.
+    threshold: Duration,
+}
+
+impl Default for WaitingReduction {
+    fn default() -> Self {
+        Self::new(0.)
+    }
+}
+
+impl WaitingReduction {
+    /// Creates a new instance of [`WaitingReduction`].
+    pub fn new(threshold: Duration) -> Self {
+        Self { threshold }
+    }
+
+    /// Returns route with moved activity if total waiting is reduced.
+    fn reduce_waiting(
+        &self,
+        problem: &Problem,
+        locked: &HashSet<Job>,
+        route_ctx: &RouteContext,
+    ) -> Option<RouteContext> {
+        let original_waiting = get_total_waiting(route_ctx);
+        let original_cost = SolutionContext::get_route_cost(route_ctx);
+        let index = get_largest_waiting_index(route_ctx, locked, self.threshold)?;
+        let target = Box::new(route_ctx.route.tour.get(index)?.deep_copy());
+
+        let mut reduced_ctx = route_ctx.deep_copy();
+        reduced_ctx.route_mut().tour.remove_activity_at(index);
+        problem.constraint.accept_route_state(&mut reduced_ctx);
+
+        reduced_ctx
+            .route
+            .tour
+            .legs()
+            .filter(|(_, leg_index)| *leg_index + 1 != index)
+            .filter_map(|(items, leg_index)| {
+                let activity_ctx =
+                    ActivityContext { index: leg_index, prev: &items[0], target: &target, next: items.get(1) };
+
+                if problem.constraint.evaluate_hard_activity(&reduced_ctx, &activity_ctx).is_some() {
+                    return None;
+                }
+
+                let mut candidate_ctx = reduced_ctx.deep_copy();
+                candidate_ctx.route_mut().tour.insert_at(Box::new(target.deep_copy()), leg_index + 1);
+                problem.constraint.accept_route_state(&mut candidate_ctx);
+                reschedule_departure(problem, &mut candidate_ctx);
+
+                Some((get_total_waiting(&candidate_ctx), candidate_ctx))
+            })
+            .filter(|(_, candidate_ctx)| {
+                compare_floats(SolutionContext::get_route_cost(candidate_ctx), original_cost) != Ordering::Greater
+            })
+            .min_by(|(a, _), (b, _)| compare_floats(*a, *b))
+            .filter(|(waiting, _)| compare_floats(*waiting, original_waiting) == Ordering::Less)
+            .map(|(_, candidate_ctx)| candidate_ctx)
+    }
+}
+
+impl Mutation for WaitingReduction {
+    fn mutate(&self, _refinement_ctx: &mut RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
+        let mut insertion_ctx = insertion_ctx;
+        let problem = insertion_ctx.problem.clone();
+        let solution = &mut insertion_ctx.solution;
+        let locked = &solution.locked;
+
+        let is_changed = solution.routes.iter_mut().fold(false, |is_changed, route_ctx| {
+            match self.reduce_waiting(problem.as_ref(), locked, route_ctx) {
+                Some(reduced_ctx) => {
+                    *route_ctx = reduced_ctx;
+                    true
+                }
+                None => is_changed,
+            }
+        });
+
+        if is_changed {
+            problem.constraint.accept_solution_state(&mut insertion_ctx.solution);
+        }
+
+        insertion_ctx
+    }
+}
+
+/// Shifts departure from the depot the same way as it is done when solution state is accepted, so
+/// waiting at the first stop does not contribute to the cost of the route.
+fn reschedule_departure(problem: &Problem, route_ctx: &mut RouteContext) {
+    let departures = match (route_ctx.route.tour.start(), route_ctx.route.tour.get(1)) {
+        (Some(start), Some(first)) => {
+            let last_departure = start.schedule.departure;
+            let duration = problem.transport.duration(
+                get_leg_profile(route_ctx, start),
+                start.place.location,
+                first.place.location,
+                last_departure,
+            );
+            Some((last_departure, first.place.time.start - duration))
+        }
+        _ => None,
+    };
+
+    if let Some((last_departure, new_departure)) = departures {
+        if new_departure > last_departure {
+            route_ctx.route_mut().tour.get_mut(0).unwrap().schedule.departure = new_departure;
+            problem.constraint.accept_route_state(route_ctx);
+        }
+    }
+}
+
+/// Returns total waiting of the route except waiting at the first stop as it is eliminated by
+/// rescheduling departure from the depot. Waiting state keeps waiting of activity and all
+/// activities after it, so the state of the second job activity is used.
+fn get_total_waiting(route_ctx: &RouteContext) -> Duration {
+    route_ctx
+        .route
+        .tour
+        .all_activities()
+        .filter(|activity| activity.job.is_some())
+        .nth(1)
+        .and_then(|activity| route_ctx.state.get_activity_state::<Duration>(WAITING_KEY, activity))
+        .cloned()
+        .unwrap_or(0.)
+}
+
+/// Returns index of single job activity with the largest waiting which exceeds threshold. The first
+/// stop is skipped as its waiting is eliminated by rescheduling departure from the depot.
+fn get_largest_waiting_index(route_ctx: &RouteContext, locked: &HashSet<Job>, threshold: Duration) -> Option<usize> {
+    let waitings = route_ctx
+        .route
+        .tour
+        .all_activities()
+        .enumerate()
+        .filter(|(_, activity)| activity.job.is_some())
+        .map(|(index, activity)| {
+            let waiting = route_ctx.state.get_activity_state::<Duration>(WAITING_KEY, activity).cloned().unwrap_or(0.);
+            (index, activity, waiting)
+        })
+        .collect::<Vec<_>>();
+
+    waitings
+        .iter()
+        .enumerate()
+        .map(|(idx, (index, activity, waiting))| {
+            let next_waiting = waitings.get(idx + 1).map_or(0., |(_, _, waiting)| *waiting);
+            (*index, activity, waiting - next_waiting)
+        })
+        .skip(1)
+        .filter(|(_, _, waiting)| *waiting > threshold)
+        .filter(|(_, activity, _)| match activity.retrieve_job() {
+            Some(job @ Job::Single(_)) => !locked.contains(&job),
+            _ => false,
+        })
+        .max_by(|(_, _, a), (_, _, b)| compare_floats(*a, *b))
+        .map(|(index, _, _)| index)
+}
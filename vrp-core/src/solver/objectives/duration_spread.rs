@@ -0,0 +1,45 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/duration_spread_test.rs"]
+mod duration_spread_test;
+
+use super::*;
+use crate::construction::constraints::TOTAL_DURATION_KEY;
+use crate::models::common::Objective;
+use crate::utils::compare_floats;
+
+/// An objective function which minimizes the spread of route durations, i.e. difference between
+/// the longest and the shortest route. A solution with less than two routes has no spread.
+/// Unlike [`WorkBalance`], it does not add any constraint module and can be combined with
+/// other objectives as is.
+///
+/// [`WorkBalance`]: ./struct.WorkBalance.html
+pub struct DurationSpread {}
+
+impl Default for DurationSpread {
+    fn default() -> Self {
+        Self {}
+    }
+}
+
+impl Objective for DurationSpread {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        compare_floats(self.fitness(a), self.fitness(b))
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        self.fitness(a) - self.fitness(b)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        let (min, max) = solution
+            .solution
+            .routes
+            .iter()
+            .map(|rc| rc.state.get_route_state::<f64>(TOTAL_DURATION_KEY).cloned().unwrap_or(0.))
+            .fold((std::f64::MAX, 0_f64), |(min, max), duration| (min.min(duration), max.max(duration)));
+
+        (max - min).max(0.)
+    }
+}
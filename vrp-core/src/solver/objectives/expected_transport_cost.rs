@@ -0,0 +1,121 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/expected_transport_cost_test.rs"]
+mod expected_transport_cost_test;
+
+use super::*;
+use crate::construction::constraints::get_extra_cost;
+use crate::construction::heuristics::RouteContext;
+use crate::models::common::{IdDimension, Objective, ValueDimension};
+use crate::models::problem::{ActivityCost, Job, TransportCost};
+use crate::models::solution::{Activity, Route};
+use crate::utils::compare_floats;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// A default amount of sampled scenarios.
+const DEFAULT_SAMPLES: usize = 32;
+
+/// An objective function which calculates expected transport cost over sampled scenarios where
+/// each job is present with probability specified by its "probability" dimension (default is one).
+/// In each scenario, activities of absent jobs are skipped while the rest of the tour is kept in
+/// the same order, so the solution is evaluated as a robust base plan. Job presence is sampled
+/// deterministically from the job id and the scenario index, so different solutions are compared
+/// using the same realizations. A job without id is always present.
+pub struct ExpectedTransportCost {
+    samples: usize,
+}
+
+impl ExpectedTransportCost {
+    /// Creates a new instance of `ExpectedTransportCost` with given amount of sampled scenarios.
+    pub fn new(samples: usize) -> Self {
+        Self { samples: samples.max(1) }
+    }
+}
+
+impl Default for ExpectedTransportCost {
+    fn default() -> Self {
+        Self::new(DEFAULT_SAMPLES)
+    }
+}
+
+impl Objective for ExpectedTransportCost {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        compare_floats(self.fitness(a), self.fitness(b))
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        self.fitness(a) - self.fitness(b)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        let problem = &solution.problem;
+
+        let total = (0..self.samples)
+            .map(|scenario| {
+                solution
+                    .solution
+                    .routes
+                    .iter()
+                    .map(|route_ctx| {
+                        get_scenario_cost(route_ctx, problem.activity.as_ref(), problem.transport.as_ref(), &|job| {
+                            is_present(job, scenario)
+                        })
+                    })
+                    .sum::<f64>()
+            })
+            .sum::<f64>();
+
+        total / self.samples as f64
+    }
+}
+
+/// Returns cost of the route where only activities of present jobs are visited. Fixed and extra
+/// costs are always paid.
+fn get_scenario_cost(
+    route_ctx: &RouteContext,
+    activity: &(dyn ActivityCost + Send + Sync),
+    transport: &(dyn TransportCost + Send + Sync),
+    is_present: &dyn Fn(&Job) -> bool,
+) -> f64 {
+    let route: &Route = route_ctx.route.as_ref();
+    let actor = route.actor.as_ref();
+    let fixed = actor.vehicle.costs.fixed + actor.driver.costs.fixed + get_extra_cost(route_ctx);
+
+    let mut activities = route.tour.all_activities().filter(|a| a.retrieve_job().map_or(true, |job| is_present(&job)));
+
+    let start: &Activity = match activities.next() {
+        Some(start) => start,
+        None => return fixed,
+    };
+
+    let (_, _, cost) =
+        activities.fold((start.place.location, start.schedule.departure, fixed), |(location, time, cost), a| {
+            let arrival = time + transport.duration(actor.vehicle.profile, location, a.place.location, time);
+            let departure = arrival.max(a.place.time.start) + activity.duration(actor, a, arrival);
+            let cost = cost
+                + transport.cost(actor, actor.vehicle.profile, location, a.place.location, time)
+                + activity.cost(actor, a, arrival);
+
+            (a.place.location, departure, cost)
+        });
+
+    cost
+}
+
+/// Checks whether job is present in given scenario. Job id is used instead of job itself as job's
+/// hash depends on its memory address which is not stable between runs.
+fn is_present(job: &Job, scenario: usize) -> bool {
+    let probability = job.dimens().get_value::<f64>("probability").cloned().unwrap_or(1.);
+    let id = match job.dimens().get_id() {
+        Some(id) if probability < 1. => id,
+        _ => return true,
+    };
+
+    let mut hasher = DefaultHasher::new();
+    id.hash(&mut hasher);
+    scenario.hash(&mut hasher);
+
+    (hasher.finish() as f64 / u64::MAX as f64) < probability
+}
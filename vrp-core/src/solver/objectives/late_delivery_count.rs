@@ -0,0 +1,41 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/late_delivery_count_test.rs"]
+mod late_delivery_count_test;
+
+use super::*;
+use crate::models::common::Objective;
+use crate::utils::compare_floats;
+
+/// An objective function which minimizes amount of activities served late, i.e. with arrival after
+/// their time window end. Unlike [`MaxLateness`], it does not take into account lateness magnitude.
+///
+/// [`MaxLateness`]: ./struct.MaxLateness.html
+pub struct LateDeliveryCount {}
+
+impl Default for LateDeliveryCount {
+    fn default() -> Self {
+        Self {}
+    }
+}
+
+impl Objective for LateDeliveryCount {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        compare_floats(self.fitness(a), self.fitness(b))
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        self.fitness(a) - self.fitness(b)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution
+            .solution
+            .routes
+            .iter()
+            .flat_map(|rc| rc.route.tour.all_activities())
+            .filter(|activity| activity.job.is_some() && activity.schedule.arrival > activity.place.time.end)
+            .count() as f64
+    }
+}
@@ -0,0 +1,40 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/max_lateness_test.rs"]
+mod max_lateness_test;
+
+use super::*;
+use crate::models::common::Objective;
+use crate::utils::compare_floats;
+
+/// An objective function which minimizes the worst lateness across all activities, where lateness
+/// is a positive difference between activity's arrival and its time window end.
+pub struct MaxLateness {}
+
+impl Default for MaxLateness {
+    fn default() -> Self {
+        Self {}
+    }
+}
+
+impl Objective for MaxLateness {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        compare_floats(self.fitness(a), self.fitness(b))
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        self.fitness(a) - self.fitness(b)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution
+            .solution
+            .routes
+            .iter()
+            .flat_map(|rc| rc.route.tour.all_activities())
+            .filter(|activity| activity.job.is_some())
+            .map(|activity| (activity.schedule.arrival - activity.place.time.end).max(0.))
+            .fold(0., f64::max)
+    }
+}
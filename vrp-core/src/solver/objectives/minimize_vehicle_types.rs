@@ -0,0 +1,49 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/minimize_vehicle_types_test.rs"]
+mod minimize_vehicle_types_test;
+
+use super::*;
+use crate::models::common::{IdDimension, Objective, ValueDimension};
+use crate::utils::compare_floats;
+use hashbrown::HashSet;
+
+/// An objective function which counts distinct vehicle types used by non-empty routes, so an
+/// expensive vehicle category is activated only when it is necessary. The type is read from
+/// "type_id" vehicle dimension, a vehicle without it is considered as a type on its own. Vehicles of
+/// the same type are counted once, including the same vehicle used within different shifts.
+pub struct MinimizeVehicleTypes {}
+
+impl Default for MinimizeVehicleTypes {
+    fn default() -> Self {
+        Self {}
+    }
+}
+
+impl Objective for MinimizeVehicleTypes {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        compare_floats(self.fitness(a), self.fitness(b))
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        self.fitness(a) - self.fitness(b)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution
+            .solution
+            .routes
+            .iter()
+            .filter(|route_ctx| route_ctx.route.tour.has_jobs())
+            .map(|route_ctx| {
+                let dimens = &route_ctx.route.actor.vehicle.dimens;
+                match dimens.get_value::<String>("type_id") {
+                    Some(type_id) => (true, type_id),
+                    None => (false, dimens.get_id().expect("vehicle without id")),
+                }
+            })
+            .collect::<HashSet<_>>()
+            .len() as f64
+    }
+}
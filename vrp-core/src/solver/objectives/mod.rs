@@ -8,14 +8,44 @@ pub const BALANCE_ACTIVITY_KEY: i32 = 21;
 pub const BALANCE_DISTANCE_KEY: i32 = 22;
 pub const BALANCE_DURATION_KEY: i32 = 23;
 
+mod duration_spread;
+pub use self::duration_spread::DurationSpread;
+
+mod expected_transport_cost;
+pub use self::expected_transport_cost::ExpectedTransportCost;
+
+mod late_delivery_count;
+pub use self::late_delivery_count::LateDeliveryCount;
+
+mod max_lateness;
+pub use self::max_lateness::MaxLateness;
+
+mod minimize_vehicle_types;
+pub use self::minimize_vehicle_types::MinimizeVehicleTypes;
+
+mod total_emissions;
+pub use self::total_emissions::TotalEmissions;
+
+mod total_fleet_cost;
+pub use self::total_fleet_cost::{FleetCostCurve, TotalFleetCost};
+
+mod total_priority_value;
+pub use self::total_priority_value::TotalPriorityValue;
+
 mod total_routes;
 pub use self::total_routes::TotalRoutes;
 
+mod total_tardiness;
+pub use self::total_tardiness::TotalTardiness;
+
 mod total_transport_cost;
 pub use self::total_transport_cost::TotalTransportCost;
 
 mod total_unassigned_jobs;
 pub use self::total_unassigned_jobs::TotalUnassignedJobs;
 
+mod total_waiting_time;
+pub use self::total_waiting_time::TotalWaitingTime;
+
 mod work_balance;
 pub use self::work_balance::WorkBalance;
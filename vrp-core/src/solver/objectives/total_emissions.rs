@@ -0,0 +1,58 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/total_emissions_test.rs"]
+mod total_emissions_test;
+
+use super::*;
+use crate::construction::constraints::get_leg_profile;
+use crate::construction::heuristics::RouteContext;
+use crate::models::common::{Objective, Profile};
+use crate::models::problem::TransportCost;
+use crate::utils::compare_floats;
+use hashbrown::HashMap;
+
+/// An objective function which calculates total emissions (e.g. grams of CO2) of all routes as
+/// traveled distance multiplied by emission factor of the routing profile used on each leg. A
+/// profile without specified factor is considered as emission free.
+pub struct TotalEmissions {
+    factors: HashMap<Profile, f64>,
+}
+
+impl TotalEmissions {
+    /// Creates a new instance of `TotalEmissions` with emission factors per distance unit.
+    pub fn new(factors: HashMap<Profile, f64>) -> Self {
+        Self { factors }
+    }
+
+    fn get_route_emissions(&self, route_ctx: &RouteContext, transport: &(dyn TransportCost + Send + Sync)) -> f64 {
+        let activities = route_ctx.route.tour.all_activities().collect::<Vec<_>>();
+
+        activities
+            .windows(2)
+            .map(|leg| {
+                let (from, to) = (leg[0], leg[1]);
+                let profile = get_leg_profile(route_ctx, from);
+                let factor = self.factors.get(&profile).cloned().unwrap_or(0.);
+
+                transport.distance(profile, from.place.location, to.place.location, from.schedule.departure) * factor
+            })
+            .sum()
+    }
+}
+
+impl Objective for TotalEmissions {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        compare_floats(self.fitness(a), self.fitness(b))
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        self.fitness(a) - self.fitness(b)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        let transport = solution.problem.transport.as_ref();
+
+        solution.solution.routes.iter().map(|route_ctx| self.get_route_emissions(route_ctx, transport)).sum()
+    }
+}
@@ -0,0 +1,94 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/total_fleet_cost_test.rs"]
+mod total_fleet_cost_test;
+
+use super::*;
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{RouteContext, SolutionContext};
+use crate::models::common::{Cost, Objective};
+use crate::models::problem::{Job, TargetConstraint, TargetObjective};
+use crate::utils::compare_floats;
+use std::ops::Deref;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// A function which maps amount of used routes to total fleet cost.
+pub type FleetCostCurve = Arc<dyn Fn(usize) -> Cost + Send + Sync>;
+
+/// An objective function which minimizes total cost where cost of used fleet is defined by a curve
+/// which maps amount of routes to cost. Unlike flat fixed cost per vehicle, it allows to model
+/// economies or diseconomies of scale. Fleet cost is added on top of total transport cost.
+pub struct TotalFleetCost {
+    curve: FleetCostCurve,
+}
+
+impl TotalFleetCost {
+    /// Creates constraint and objective which minimize total fleet cost defined by given curve.
+    pub fn new_with_curve(curve: FleetCostCurve) -> (TargetConstraint, TargetObjective) {
+        let constraint = FleetCostModule {
+            constraints: vec![ConstraintVariant::SoftRoute(Arc::new(FleetCostSoftRouteConstraint {
+                curve: curve.clone(),
+            }))],
+            keys: vec![],
+        };
+
+        (Box::new(constraint), Box::new(TotalFleetCost { curve }))
+    }
+}
+
+impl Objective for TotalFleetCost {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        compare_floats(self.fitness(a), self.fitness(b))
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        self.fitness(a) - self.fitness(b)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution.solution.get_total_cost() + self.curve.deref()(get_used_routes(&solution.solution))
+    }
+}
+
+struct FleetCostModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl ConstraintModule for FleetCostModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_ctx: &mut RouteContext, _job: &Job) {}
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+/// Estimates marginal fleet cost of opening a new route.
+struct FleetCostSoftRouteConstraint {
+    curve: FleetCostCurve,
+}
+
+impl SoftRouteConstraint for FleetCostSoftRouteConstraint {
+    fn estimate_job(&self, solution_ctx: &SolutionContext, route_ctx: &RouteContext, _job: &Job) -> Cost {
+        if route_ctx.route.tour.has_jobs() {
+            0.
+        } else {
+            let used = get_used_routes(solution_ctx);
+            self.curve.deref()(used + 1) - self.curve.deref()(used)
+        }
+    }
+}
+
+fn get_used_routes(solution_ctx: &SolutionContext) -> usize {
+    solution_ctx.routes.iter().filter(|rc| rc.route.tour.has_jobs()).count()
+}
@@ -0,0 +1,49 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/total_priority_value_test.rs"]
+mod total_priority_value_test;
+
+use super::*;
+use crate::models::common::{Objective, PriorityDimension};
+use crate::models::problem::Job;
+use crate::utils::compare_floats;
+
+/// A penalty for each unassigned job with the highest priority.
+const HIGH_PRIORITY_PENALTY: f64 = 1000.;
+
+/// An objective function which calculates a weighted sum of unassigned jobs based on their
+/// "priority" dimension, where 1 is the highest priority and used as default one. A job with
+/// priority `p` contributes `1000 / p` penalty, so leaving a high-priority job unassigned is more
+/// expensive than leaving a few low-priority ones.
+///
+/// Different sets of unassigned jobs can have the same value, e.g. one job with priority 1 and two
+/// jobs with priority 2. To break such ties, use it as a primary objective in `ObjectiveCost` with
+/// `TotalUnassignedJobs` as a secondary one: then a solution with less unassigned jobs is preferred.
+pub struct TotalPriorityValue {}
+
+impl Default for TotalPriorityValue {
+    fn default() -> Self {
+        Self {}
+    }
+}
+
+impl Objective for TotalPriorityValue {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        compare_floats(self.fitness(a), self.fitness(b))
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        self.fitness(a) - self.fitness(b)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution.solution.unassigned.keys().map(get_priority_penalty).sum()
+    }
+}
+
+fn get_priority_penalty(job: &Job) -> f64 {
+    let priority = job.dimens().get_priority().cloned().unwrap_or(1).max(1);
+
+    HIGH_PRIORITY_PENALTY / priority as f64
+}
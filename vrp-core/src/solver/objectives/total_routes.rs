@@ -1,9 +1,18 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/total_routes_test.rs"]
+mod total_routes_test;
+
 use super::*;
 
-use crate::models::common::Objective;
+use crate::models::common::{Objective, ValueDimension};
+use crate::models::problem::{Actor, Vehicle};
 use crate::utils::compare_floats;
+use hashbrown::HashSet;
+use std::sync::Arc;
 
-/// An objective function which counts total amount of routes.
+/// An objective function which counts total amount of routes. A route of vehicle with "must_use"
+/// dimension is not counted, but such vehicle is counted when it is left idle. When amount of
+/// routes is maximized, idle "must_use" vehicles are still penalized.
 pub struct TotalRoutes {
     is_minimization: bool,
 }
@@ -28,20 +37,51 @@ impl Objective for TotalRoutes {
     type Solution = InsertionContext;
 
     fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
-        let fitness_a = a.solution.routes.len() as f64;
-        let fitness_b = b.solution.routes.len() as f64;
-
-        let (fitness_a, fitness_b) =
-            if self.is_minimization { (fitness_a, fitness_b) } else { (-1. * fitness_a, -1. * fitness_b) };
-
-        compare_floats(fitness_a, fitness_b)
+        compare_floats(self.fitness(a), self.fitness(b))
     }
 
     fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
-        a.solution.routes.len() as f64 - b.solution.routes.len() as f64
+        self.fitness(a) - self.fitness(b)
     }
 
     fn fitness(&self, solution: &Self::Solution) -> f64 {
-        solution.solution.routes.len() as f64
+        let routes = self.get_routes(solution);
+        let routes = if self.is_minimization { routes } else { -1. * routes };
+
+        routes + get_idle_must_use(solution)
     }
 }
+
+impl TotalRoutes {
+    fn get_routes(&self, solution: &InsertionContext) -> f64 {
+        if self.is_minimization {
+            solution.solution.routes.iter().filter(|route_ctx| !is_must_use(&route_ctx.route.actor)).count() as f64
+        } else {
+            solution.solution.routes.len() as f64
+        }
+    }
+}
+
+/// Counts distinct "must_use" vehicles which are not used by any route.
+fn get_idle_must_use(solution: &InsertionContext) -> f64 {
+    let used = solution
+        .solution
+        .routes
+        .iter()
+        .map(|route_ctx| Arc::as_ptr(&route_ctx.route.actor.vehicle))
+        .collect::<HashSet<*const Vehicle>>();
+
+    solution
+        .solution
+        .registry
+        .all()
+        .filter(|actor| is_must_use(actor))
+        .map(|actor| Arc::as_ptr(&actor.vehicle))
+        .filter(|vehicle| !used.contains(vehicle))
+        .collect::<HashSet<_>>()
+        .len() as f64
+}
+
+fn is_must_use(actor: &Actor) -> bool {
+    actor.vehicle.dimens.get_value::<bool>("must_use").cloned().unwrap_or(false)
+}
@@ -0,0 +1,65 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/total_tardiness_test.rs"]
+mod total_tardiness_test;
+
+use super::*;
+use crate::models::common::{Objective, Timestamp};
+use crate::models::solution::Activity;
+use crate::utils::compare_floats;
+
+/// An objective function which minimizes total tardiness across all activities, where tardiness
+/// is a positive difference between activity's arrival and the end of its nearest time window.
+/// It is intended to be used together with soft time windows, see
+/// `TransportConstraintModule::new_with_soft_time_windows`.
+pub struct TotalTardiness {}
+
+impl Default for TotalTardiness {
+    fn default() -> Self {
+        Self {}
+    }
+}
+
+impl Objective for TotalTardiness {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        compare_floats(self.fitness(a), self.fitness(b))
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        self.fitness(a) - self.fitness(b)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution
+            .solution
+            .routes
+            .iter()
+            .flat_map(|rc| {
+                let date = rc.route.tour.start().map_or(0., |start| start.schedule.departure);
+                rc.route.tour.all_activities().map(move |activity| get_tardiness(activity, date))
+            })
+            .sum()
+    }
+}
+
+/// Returns tardiness of the activity measured against the nearest time window of its job's place:
+/// when arrival fits one of the windows or happens before it, there is no tardiness.
+fn get_tardiness(activity: &Activity, date: Timestamp) -> f64 {
+    let single = match activity.job.as_ref() {
+        Some(single) => single,
+        None => return 0.,
+    };
+
+    single
+        .places
+        .iter()
+        .filter(|place| {
+            place.location.map_or(true, |location| location == activity.place.location)
+                && compare_floats(place.duration, activity.place.duration) == Ordering::Equal
+        })
+        .flat_map(|place| place.times.iter().map(|time| time.to_time_window(date).end))
+        .chain(std::iter::once(activity.place.time.end))
+        .map(|end| (activity.schedule.arrival - end).max(0.))
+        .fold(f64::MAX, f64::min)
+}
@@ -1,7 +1,19 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/total_unassigned_jobs_test.rs"]
+mod total_unassigned_jobs_test;
+
 use super::*;
-use crate::models::common::Objective;
+use crate::models::common::{Objective, ValueDimension};
+use crate::models::problem::Job;
+use crate::utils::compare_floats;
+
+/// A penalty for each unassigned job marked as required.
+const REQUIRED_JOB_PENALTY: f64 = 1000.;
 
-/// An objective function which counts total amount of unassigned jobs.
+/// An objective function which counts total amount of unassigned jobs. A job which has "required"
+/// dimension set to true is counted with much bigger penalty than optional one. A job with
+/// "unassigned_penalty" dimension is counted with its value instead of one. A penalty of a job with
+/// "probability" dimension is weighted by its occurrence probability.
 pub struct TotalUnassignedJobs {}
 
 impl Default for TotalUnassignedJobs {
@@ -14,17 +26,24 @@ impl Objective for TotalUnassignedJobs {
     type Solution = InsertionContext;
 
     fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
-        let fitness_a = a.solution.unassigned.len();
-        let fitness_b = b.solution.unassigned.len();
-
-        fitness_a.cmp(&fitness_b)
+        compare_floats(self.fitness(a), self.fitness(b))
     }
 
     fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
-        a.solution.unassigned.len() as f64 - b.solution.unassigned.len() as f64
+        self.fitness(a) - self.fitness(b)
     }
 
     fn fitness(&self, solution: &Self::Solution) -> f64 {
-        solution.solution.unassigned.len() as f64
+        solution.solution.unassigned.keys().map(get_unassigned_penalty).sum()
     }
 }
+
+fn get_unassigned_penalty(job: &Job) -> f64 {
+    let penalty = if job.dimens().get_value::<bool>("required").cloned().unwrap_or(false) {
+        REQUIRED_JOB_PENALTY
+    } else {
+        job.dimens().get_value::<f64>("unassigned_penalty").cloned().unwrap_or(1.)
+    };
+
+    penalty * job.dimens().get_value::<f64>("probability").cloned().unwrap_or(1.)
+}
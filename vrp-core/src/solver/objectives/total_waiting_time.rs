@@ -0,0 +1,49 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/total_waiting_time_test.rs"]
+mod total_waiting_time_test;
+
+use super::*;
+use crate::construction::constraints::WAITING_KEY;
+use crate::construction::heuristics::RouteContext;
+use crate::models::common::{Duration, Objective};
+use crate::utils::compare_floats;
+
+/// An objective function which minimizes total time vehicles wait at early arrivals. It reads
+/// waiting state stored by `TransportConstraintModule`. As the state is refreshed after departure
+/// rescheduling, the value reflects the actual schedule of the solution.
+pub struct TotalWaitingTime {}
+
+impl Default for TotalWaitingTime {
+    fn default() -> Self {
+        Self {}
+    }
+}
+
+impl Objective for TotalWaitingTime {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        compare_floats(self.fitness(a), self.fitness(b))
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        self.fitness(a) - self.fitness(b)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution.solution.routes.iter().map(get_route_waiting).sum()
+    }
+}
+
+/// Returns total waiting time of the route. Waiting state keeps waiting of activity and all
+/// activities after it, so the state of the first job activity is used.
+fn get_route_waiting(route_ctx: &RouteContext) -> Duration {
+    route_ctx
+        .route
+        .tour
+        .all_activities()
+        .find(|activity| activity.job.is_some())
+        .and_then(|activity| route_ctx.state.get_activity_state::<Duration>(WAITING_KEY, activity))
+        .cloned()
+        .unwrap_or(0.)
+}
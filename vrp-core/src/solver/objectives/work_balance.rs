@@ -261,4 +261,15 @@ impl Objective for WorkBalanceObjectives {
             value
         }
     }
+
+    fn name(&self) -> String {
+        match self.state_key {
+            BALANCE_MAX_LOAD_KEY => "BalanceMaxLoad",
+            BALANCE_ACTIVITY_KEY => "BalanceActivities",
+            BALANCE_DISTANCE_KEY => "BalanceDistance",
+            BALANCE_DURATION_KEY => "BalanceDuration",
+            _ => "WorkBalance",
+        }
+        .to_string()
+    }
 }
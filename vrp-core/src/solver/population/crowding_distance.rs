@@ -23,7 +23,7 @@ pub struct ObjectiveStat {
 /// Assigns a crowding distance to each solution in `front`.
 pub fn assign_crowding_distance<'a, S>(
     front: &Front<'a, S>,
-    multi_objective: &impl MultiObjective<Solution = S>,
+    multi_objective: &(impl MultiObjective<Solution = S> + ?Sized),
 ) -> (Vec<AssignedCrowdingDistance<'a, S>>, Vec<ObjectiveStat>) {
     let mut a: Vec<_> = front
         .iter()
@@ -0,0 +1,51 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/population/hypervolume_test.rs"]
+mod hypervolume_test;
+
+use crate::models::common::MultiObjective;
+use crate::utils::compare_floats;
+
+/// Calculates hypervolume indicator of the `front`: a volume of objective space which is dominated
+/// by front points and bounded by `reference` point. All objectives are minimized, so points which
+/// do not strictly dominate reference point do not contribute to the volume.
+pub fn hypervolume(front: &[Vec<f64>], reference: &[f64]) -> f64 {
+    let points = front
+        .iter()
+        .filter(|point| point.len() == reference.len() && point.iter().zip(reference.iter()).all(|(p, r)| p < r))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    get_volume(points, reference)
+}
+
+/// Returns fitness values of the solution for each objective of `multi_objective`.
+pub fn get_fitness_values<S>(multi_objective: &(impl MultiObjective<Solution = S> + ?Sized), solution: &S) -> Vec<f64> {
+    multi_objective.objectives().map(|objective| objective.fitness(solution)).collect()
+}
+
+/// Calculates volume by slicing objective space along the last dimension.
+fn get_volume(points: Vec<Vec<f64>>, reference: &[f64]) -> f64 {
+    let dimension = reference.len();
+
+    if points.is_empty() || dimension == 0 {
+        return 0.;
+    }
+
+    if dimension == 1 {
+        return points.iter().map(|point| reference[0] - point[0]).fold(0., f64::max);
+    }
+
+    let last = dimension - 1;
+    let mut points = points;
+    points.sort_by(|a, b| compare_floats(a[last], b[last]));
+
+    (0..points.len()).fold(0., |volume, idx| {
+        let depth = points.get(idx + 1).map_or(reference[last], |next| next[last]) - points[idx][last];
+        if depth > 0. {
+            let slice = points.iter().take(idx + 1).map(|point| point[..last].to_vec()).collect();
+            volume + depth * get_volume(slice, &reference[..last])
+        } else {
+            volume
+        }
+    })
+}
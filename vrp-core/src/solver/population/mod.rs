@@ -14,21 +14,30 @@
 #[path = "../../../tests/unit/solver/population/population_test.rs"]
 mod population_test;
 
-use crate::models::common::Objective;
 use crate::models::Problem;
 use crate::solver::{Individual, Population};
 use crate::utils::{compare_floats, Random};
+use std::cmp::Ordering;
 use std::cmp::Ordering::Equal;
 use std::sync::Arc;
 
 mod crowding_distance;
 use self::crowding_distance::*;
 
+mod hypervolume;
+pub use self::hypervolume::*;
+
+mod pareto_archive;
+pub use self::pareto_archive::ParetoArchive;
+
 mod non_dominated_sort;
 use self::non_dominated_sort::*;
 
 mod nsga2;
 use self::nsga2::select_and_rank;
+
+mod selection;
+pub use self::selection::select_tournament;
 use hashbrown::HashSet;
 
 /// An evolution aware implementation of `[Population]` trait.
@@ -39,6 +48,8 @@ pub struct DominancePopulation {
     weights: Vec<usize>,
     offspring_size: usize,
     population_size: usize,
+    tournament_size: Option<usize>,
+    diversity: Option<f64>,
 }
 
 impl DominancePopulation {
@@ -66,12 +77,52 @@ impl DominancePopulation {
                 .collect(),
             population_size,
             offspring_size,
+            tournament_size: None,
+            diversity: None,
         }
     }
+
+    /// Sets tournament size: when specified, individuals are selected using tournament
+    /// selection instead of rank based one.
+    pub fn with_tournament(mut self, size: Option<usize>) -> Self {
+        assert!(size.map_or(true, |size| size > 0));
+
+        self.tournament_size = size;
+        self
+    }
+
+    /// Sets diversity epsilon: a new individual which is within epsilon distance of an existing
+    /// one, according to the objective distance metric, and is not better than it, is rejected.
+    /// If all individuals collapse to near-identical ones, population does not grow anymore and
+    /// accepts only individuals which improve on them, so diversity relies on mutation only.
+    /// Please note, that epsilon is compared with distance as is, so its scale depends on the
+    /// objective: hierarchical `ObjectiveCost` uses structural distance in `[0, 1]` range, while
+    /// `WeightedObjectiveCost` uses absolute difference of weighted fitness values.
+    pub fn with_diversity(mut self, epsilon: Option<f64>) -> Self {
+        assert!(epsilon.map_or(true, |epsilon| epsilon >= 0.));
+
+        self.diversity = epsilon;
+        self
+    }
+
+    fn is_duplicate(&self, individual: &Individual) -> bool {
+        let objective = self.problem.objective.as_ref();
+
+        self.diversity.map_or(false, |epsilon| {
+            self.individuals.iter().any(|other| {
+                objective.distance(individual, other).abs() < epsilon
+                    && objective.total_order(individual, other) != Ordering::Less
+            })
+        })
+    }
 }
 
 impl Population for DominancePopulation {
     fn add(&mut self, individual: Individual) {
+        if self.is_duplicate(&individual) {
+            return;
+        }
+
         self.individuals.push(individual);
 
         let max_size = self.population_size + self.offspring_size;
@@ -134,6 +185,16 @@ impl Population for DominancePopulation {
     }
 
     fn select(&self) -> &Individual {
+        if let Some(size) = self.tournament_size {
+            return select_tournament(
+                self.individuals.as_slice(),
+                self.problem.objective.as_ref(),
+                self.random.as_ref(),
+                size,
+            )
+            .unwrap();
+        }
+
         let idx = self.random.weighted(&self.weights[0..self.individuals.len()]);
 
         self.individuals.get(idx).unwrap()
@@ -92,7 +92,7 @@ impl<'f, 's: 'f, S: 's> Iterator for FrontElemIter<'f, 's, S> {
 /// Performs a non-dominated sort of `solutions`. Returns the first Pareto front.
 pub fn non_dominated_sort<'s, S, O>(solutions: &'s [S], objective: &O) -> Front<'s, S>
 where
-    O: Objective<Solution = S>,
+    O: Objective<Solution = S> + ?Sized,
 {
     // the indices of the solutions that are dominated by this `solution`
     let mut dominated_solutions: Vec<Vec<SolutionIdx>> = solutions.iter().map(|_| Vec::new()).collect();
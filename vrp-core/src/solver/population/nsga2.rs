@@ -16,7 +16,7 @@ use crate::models::common::MultiObjective;
 pub fn select_and_rank<'a, S: 'a>(
     solutions: &'a [S],
     n: usize,
-    multi_objective: &impl MultiObjective<Solution = S>,
+    multi_objective: &(impl MultiObjective<Solution = S> + ?Sized),
 ) -> Vec<AssignedCrowdingDistance<'a, S>> {
     // cannot select more solutions than we actually have
     let n = solutions.len().min(n);
@@ -0,0 +1,63 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/population/pareto_archive_test.rs"]
+mod pareto_archive_test;
+
+use super::get_fitness_values;
+use crate::models::common::MultiObjective;
+use crate::utils::compare_floats;
+use std::cmp::Ordering;
+
+/// An archive of mutually non-dominated solutions (a Pareto front approximation) discovered during
+/// search. Dominance is defined by `total_order` of the multi objective. The archive is bounded:
+/// when it is full, a new non-dominated solution is accepted only if it dominates some archived one.
+pub struct ParetoArchive<S> {
+    solutions: Vec<S>,
+    max_size: usize,
+}
+
+impl<S> ParetoArchive<S> {
+    /// Creates a new instance of `ParetoArchive` with given max size.
+    pub fn new(max_size: usize) -> Self {
+        Self { solutions: vec![], max_size: max_size.max(1) }
+    }
+
+    /// Checks whether solution is accepted by the archive: it is not dominated by any archived
+    /// solution, does not duplicate one of them and there is a space for it.
+    pub fn can_add<O>(&self, solution: &S, objective: &O) -> bool
+    where
+        O: MultiObjective<Solution = S> + ?Sized,
+    {
+        let fitness = get_fitness_values(objective, solution);
+        let mut dominates_any = false;
+
+        for archived in self.solutions.iter() {
+            match objective.total_order(archived, solution) {
+                Ordering::Less => return false,
+                Ordering::Greater => dominates_any = true,
+                Ordering::Equal if is_same_fitness(&get_fitness_values(objective, archived), &fitness) => return false,
+                Ordering::Equal => {}
+            }
+        }
+
+        dominates_any || self.solutions.len() < self.max_size
+    }
+
+    /// Adds solution to the archive removing all archived solutions dominated by it. The solution
+    /// is expected to be checked by `can_add` method.
+    pub fn add<O>(&mut self, solution: S, objective: &O)
+    where
+        O: MultiObjective<Solution = S> + ?Sized,
+    {
+        self.solutions.retain(|archived| objective.total_order(&solution, archived) != Ordering::Less);
+        self.solutions.push(solution);
+    }
+
+    /// Returns archived solutions.
+    pub fn solutions(&self) -> &[S] {
+        self.solutions.as_slice()
+    }
+}
+
+fn is_same_fitness(a: &[f64], b: &[f64]) -> bool {
+    a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| compare_floats(*a, *b) == Ordering::Equal)
+}
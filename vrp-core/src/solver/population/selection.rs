@@ -0,0 +1,29 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/population/selection_test.rs"]
+mod selection_test;
+
+use crate::models::common::MultiObjective;
+use crate::solver::Individual;
+use crate::utils::Random;
+use std::cmp::Ordering;
+
+/// Selects individual using tournament selection: `size` randomly chosen (with repetition)
+/// individuals compete and the best one according to objective's total order wins. Bigger tournament
+/// size increases selection pressure. Returns none if there are no individuals.
+pub fn select_tournament<'a>(
+    individuals: &'a [Individual],
+    objective: &(dyn MultiObjective<Solution = Individual> + Send + Sync),
+    random: &(dyn Random + Send + Sync),
+    size: usize,
+) -> Option<&'a Individual> {
+    if individuals.is_empty() {
+        return None;
+    }
+
+    (0..size.max(1))
+        .map(|_| individuals.get(random.uniform_int(0, individuals.len() as i32 - 1) as usize).unwrap())
+        .fold(None, |best: Option<&Individual>, individual| match best {
+            Some(best) if objective.total_order(individual, best) != Ordering::Less => Some(best),
+            _ => Some(individual),
+        })
+}
@@ -0,0 +1,19 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/termination/all_jobs_assigned_test.rs"]
+mod all_jobs_assigned_test;
+
+use crate::solver::termination::Termination;
+use crate::solver::RefinementContext;
+
+/// Stops as soon as the best known solution has no unassigned jobs regardless of its cost.
+#[derive(Default)]
+pub struct AllJobsAssigned {}
+
+impl Termination for AllJobsAssigned {
+    fn is_termination(&self, refinement_ctx: &mut RefinementContext) -> bool {
+        refinement_ctx
+            .population
+            .best()
+            .map_or(false, |best| best.solution.unassigned.is_empty() && best.solution.required.is_empty())
+    }
+}
@@ -2,12 +2,13 @@
 #[path = "../../../tests/unit/solver/termination/cost_variation_test.rs"]
 mod cost_variation_test;
 
-use crate::models::common::{Cost, Objective};
+use crate::models::common::Cost;
 use crate::solver::termination::Termination;
 use crate::solver::RefinementContext;
 use crate::utils::get_cv;
 
-/// Stops when maximum amount of generations is exceeded.
+/// Stops when coefficient of variation of the best known cost within sample of the last
+/// generations is lower than threshold.
 pub struct CostVariation {
     sample: usize,
     threshold: f64,
@@ -21,16 +22,19 @@ impl CostVariation {
     }
 
     fn update_and_check(&self, refinement_ctx: &mut RefinementContext, cost: Cost) -> bool {
-        let costs = refinement_ctx
+        // NOTE keep amount of written samples as generation index is not changed when
+        // termination is checked while initial population is built
+        let (costs, written) = refinement_ctx
             .state
             .entry(self.key.clone())
-            .or_insert_with(|| Box::new(vec![0.; self.sample]))
-            .downcast_mut::<Vec<f64>>()
+            .or_insert_with(|| Box::new((vec![0.; self.sample], 0_usize)))
+            .downcast_mut::<(Vec<f64>, usize)>()
             .unwrap();
 
-        costs[refinement_ctx.generation % self.sample] = cost;
+        costs[*written % self.sample] = cost;
+        *written += 1;
 
-        refinement_ctx.generation >= (self.sample - 1) && self.check_threshold(costs)
+        *written >= self.sample && self.check_threshold(costs)
     }
 
     fn check_threshold(&self, costs: &[f64]) -> bool {
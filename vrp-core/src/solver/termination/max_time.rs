@@ -1,22 +1,33 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/termination/max_time_test.rs"]
+mod max_time_test;
+
 use crate::solver::termination::Termination;
 use crate::solver::RefinementContext;
 use crate::utils::Timer;
 
-/// Stops when max time elapsed.
+/// Stops when max time elapsed since the first check, which happens when solver starts.
 pub struct MaxTime {
-    start: Timer,
     limit_in_secs: f64,
+    key: String,
 }
 
 impl MaxTime {
-    /// Creates a new instance of [`QuotaReached`].
+    /// Creates a new instance of [`MaxTime`].
     pub fn new(limit_in_secs: f64) -> Self {
-        Self { start: Timer::start(), limit_in_secs }
+        Self { limit_in_secs, key: "max_time".to_string() }
     }
 }
 
 impl Termination for MaxTime {
-    fn is_termination(&self, _: &mut RefinementContext) -> bool {
-        self.start.elapsed_secs_as_f64() > self.limit_in_secs
+    fn is_termination(&self, refinement_ctx: &mut RefinementContext) -> bool {
+        let start = refinement_ctx
+            .state
+            .entry(self.key.clone())
+            .or_insert_with(|| Box::new(Timer::start()))
+            .downcast_ref::<Timer>()
+            .unwrap();
+
+        start.elapsed_secs_as_f64() > self.limit_in_secs
     }
 }
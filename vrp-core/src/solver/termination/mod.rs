@@ -8,6 +8,9 @@ pub trait Termination {
     fn is_termination(&self, refinement_ctx: &mut RefinementContext) -> bool;
 }
 
+mod all_jobs_assigned;
+pub use self::all_jobs_assigned::AllJobsAssigned;
+
 mod cost_variation;
 pub use self::cost_variation::CostVariation;
 
@@ -17,6 +20,12 @@ pub use self::max_generation::MaxGeneration;
 mod max_time;
 pub use self::max_time::MaxTime;
 
+mod stagnation;
+pub use self::stagnation::Stagnation;
+
+mod target_cost;
+pub use self::target_cost::TargetCost;
+
 /// A trait which encapsulates multiple termination criteria.
 pub struct CompositeTermination {
     terminations: Vec<Box<dyn Termination>>,
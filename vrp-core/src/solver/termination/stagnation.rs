@@ -0,0 +1,50 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/termination/stagnation_test.rs"]
+mod stagnation_test;
+
+use crate::models::common::Cost;
+use crate::solver::termination::Termination;
+use crate::solver::RefinementContext;
+use crate::utils::compare_floats;
+use std::cmp::Ordering;
+
+/// Stops when the best known cost is not improved within given amount of generations.
+pub struct Stagnation {
+    limit: usize,
+    key: String,
+}
+
+impl Stagnation {
+    /// Creates a new instance of [`Stagnation`].
+    pub fn new(limit: usize) -> Self {
+        Self { limit, key: "stagnation".to_string() }
+    }
+
+    fn update_and_check(&self, refinement_ctx: &mut RefinementContext, cost: Cost) -> bool {
+        let generation = refinement_ctx.generation;
+        let (best_cost, last_improvement) = refinement_ctx
+            .state
+            .entry(self.key.clone())
+            .or_insert_with(|| Box::new((cost, generation)))
+            .downcast_mut::<(Cost, usize)>()
+            .unwrap();
+
+        if compare_floats(cost, *best_cost) == Ordering::Less {
+            *best_cost = cost;
+            *last_improvement = generation;
+        }
+
+        generation - *last_improvement >= self.limit
+    }
+}
+
+impl Termination for Stagnation {
+    fn is_termination(&self, refinement_ctx: &mut RefinementContext) -> bool {
+        if let Some(best) = refinement_ctx.population.best() {
+            let cost = refinement_ctx.problem.objective.fitness(best);
+            self.update_and_check(refinement_ctx, cost)
+        } else {
+            false
+        }
+    }
+}
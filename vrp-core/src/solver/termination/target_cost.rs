@@ -0,0 +1,28 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/termination/target_cost_test.rs"]
+mod target_cost_test;
+
+use crate::models::common::Cost;
+use crate::solver::termination::Termination;
+use crate::solver::RefinementContext;
+
+/// Stops as soon as fitness of the best known solution is less or equal to the target cost.
+pub struct TargetCost {
+    cost: Cost,
+}
+
+impl TargetCost {
+    /// Creates a new instance of [`TargetCost`].
+    pub fn new(cost: Cost) -> Self {
+        Self { cost }
+    }
+}
+
+impl Termination for TargetCost {
+    fn is_termination(&self, refinement_ctx: &mut RefinementContext) -> bool {
+        refinement_ctx
+            .population
+            .best()
+            .map_or(false, |best| refinement_ctx.problem.objective.fitness(best) <= self.cost)
+    }
+}
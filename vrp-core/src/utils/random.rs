@@ -1,6 +1,7 @@
 extern crate rand;
 
-use self::rand::Rng;
+use self::rand::prelude::*;
+use std::sync::Mutex;
 
 /// Provides the way to use randomized values in generic way.
 pub trait Random {
@@ -41,14 +42,59 @@ pub trait Random {
             .unwrap()
             .1
     }
+
+    /// Returns random generator which can be used with functionality of rand crate (e.g. shuffle).
+    fn get_rng(&self) -> StdRng {
+        StdRng::from_rng(rand::thread_rng()).expect("cannot create random generator")
+    }
+}
+
+/// A default random implementation. When seed is specified, it produces the same sequence
+/// of values for the same sequence of calls.
+pub struct DefaultRandom {
+    rng: Option<Mutex<StdRng>>,
+}
+
+impl DefaultRandom {
+    /// Creates an instance of `DefaultRandom` initialized with given seed.
+    pub fn new_with_seed(seed: u64) -> Self {
+        Self { rng: Some(Mutex::new(StdRng::seed_from_u64(seed))) }
+    }
+
+    fn sample<T>(&self, func: impl FnOnce(&mut dyn RngCore) -> T) -> T {
+        match &self.rng {
+            Some(rng) => func(&mut *rng.lock().unwrap()),
+            None => func(&mut rand::thread_rng()),
+        }
+    }
 }
 
-pub struct DefaultRandom {}
+impl Random for DefaultRandom {
+    fn uniform_int(&self, min: i32, max: i32) -> i32 {
+        if min == max {
+            return min;
+        }
 
-impl Random for DefaultRandom {}
+        assert!(min < max);
+        self.sample(|rng| rng.gen_range(min, max + 1))
+    }
+
+    fn uniform_real(&self, min: f64, max: f64) -> f64 {
+        if (min - max).abs() < std::f64::EPSILON {
+            return min;
+        }
+
+        assert!(min < max);
+        self.sample(|rng| rng.gen_range(min, max))
+    }
+
+    fn get_rng(&self) -> StdRng {
+        self.sample(|rng| StdRng::seed_from_u64(rng.gen()))
+    }
+}
 
 impl Default for DefaultRandom {
     fn default() -> Self {
-        Self {}
+        Self { rng: None }
     }
 }
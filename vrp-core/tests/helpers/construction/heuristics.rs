@@ -15,6 +15,7 @@ pub fn create_insertion_context(
         problem: create_empty_problem_with_constraint(constraint),
         solution: SolutionContext { routes, registry, ..create_empty_solution_context() },
         random: Arc::new(DefaultRandom::default()),
+        cache: None,
     }
 }
 
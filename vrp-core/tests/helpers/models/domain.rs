@@ -58,6 +58,7 @@ pub fn create_empty_insertion_context() -> InsertionContext {
         problem: create_empty_problem(),
         solution: create_empty_solution_context(),
         random: Arc::new(DefaultRandom::default()),
+        cache: None,
     }
 }
 
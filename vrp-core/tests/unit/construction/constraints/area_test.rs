@@ -136,3 +136,43 @@ fn can_check_location_in_area() {
         vec![(52.481171, 13.4107070), (52.480248, 13.4101200), (52.480237, 13.4062790), (52.481161, 13.4062610)];
     assert_eq!(is_location_in_area(&(52.480890, 13.4081030), &polygon), true);
 }
+
+parameterized_test! {can_check_location_on_area_edge, (location, expected), {
+    can_check_location_on_area_edge_impl(location, expected);
+}}
+
+can_check_location_on_area_edge! {
+    case01_inside: ((0., 0.), true),
+    case02_left_edge: ((-1., 0.), true),
+    case03_right_edge: ((1., 0.), true),
+    case04_top_edge: ((0., 1.), true),
+    case05_bottom_edge: ((0., -1.), true),
+    case06_vertex: ((1., 1.), true),
+    case07_outside_near_edge: ((1.001, 0.), false),
+    case08_outside_on_edge_line: ((3., 1.), false),
+    case09_outside: ((2., 2.), false),
+}
+
+fn can_check_location_on_area_edge_impl(location: (f64, f64), expected: bool) {
+    let polygon = vec![(-1., -1.), (-1., 1.), (1., 1.), (1., -1.)];
+
+    assert_eq!(is_location_in_area(&location, &polygon), expected);
+}
+
+parameterized_test! {can_check_location_on_geo_area_edge, (location, expected), {
+    can_check_location_on_geo_area_edge_impl(location, expected);
+}}
+
+can_check_location_on_geo_area_edge! {
+    case01_edge_middle: ((52.4807095, 13.4104135), true),
+    case02_vertex: ((52.480248, 13.4101200), true),
+    case03_outside_near_edge: ((52.4807105, 13.4104125), false),
+    case04_outside_on_edge_line: ((52.479325, 13.4095330), false),
+}
+
+fn can_check_location_on_geo_area_edge_impl(location: (f64, f64), expected: bool) {
+    let polygon =
+        vec![(52.481171, 13.4107070), (52.480248, 13.4101200), (52.480237, 13.4062790), (52.481161, 13.4062610)];
+
+    assert_eq!(is_location_on_edge(&location, &polygon), expected);
+}
@@ -0,0 +1,48 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_module;
+use crate::helpers::models::domain::create_empty_solution_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::problem::{Fleet, Single};
+
+fn create_fleet() -> Fleet {
+    FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build()
+}
+
+fn create_single_with_group(group: Option<&str>) -> Arc<Single> {
+    let mut single = test_single();
+    if let Some(group) = group {
+        single.dimens.set_value("incompatibility", group.to_string());
+    }
+
+    Arc::new(single)
+}
+
+parameterized_test! {can_check_incompatible_groups, (route_groups, job_group, expected), {
+    can_check_incompatible_groups_impl(route_groups, job_group, expected);
+}}
+
+can_check_incompatible_groups! {
+    case01_empty_route: (vec![], Some("food"), None),
+    case02_same_group: (vec![Some("food")], Some("food"), None),
+    case03_different_group: (vec![Some("food")], Some("chemicals"), Some(())),
+    case04_reverse_different_group: (vec![Some("chemicals")], Some("food"), Some(())),
+    case05_job_without_group: (vec![Some("food"), Some("chemicals")], None, None),
+    case06_route_without_group: (vec![None, None], Some("food"), None),
+    case07_mixed_route: (vec![None, Some("food")], Some("chemicals"), Some(())),
+}
+
+fn can_check_incompatible_groups_impl(route_groups: Vec<Option<&str>>, job_group: Option<&str>, expected: Option<()>) {
+    let solution_ctx = create_empty_solution_context();
+    let route_ctx = create_route_context_with_activities(
+        &create_fleet(),
+        "v1",
+        route_groups.into_iter().map(|group| test_tour_activity_with_job(create_single_with_group(group))).collect(),
+    );
+    let job = Job::Single(create_single_with_group(job_group));
+    let pipeline = create_constraint_pipeline_with_module(Box::new(IncompatibilityConstraintModule::new(1)));
+
+    let result = pipeline.evaluate_hard_route(&solution_ctx, &route_ctx, &job);
+
+    assert_eq!(result.map(|violation| violation.code), expected.map(|_| 1));
+}
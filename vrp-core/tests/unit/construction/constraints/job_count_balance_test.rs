@@ -0,0 +1,50 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_module;
+use crate::helpers::models::domain::create_empty_solution_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::utils::compare_floats;
+use std::cmp::Ordering;
+
+parameterized_test! {can_estimate_job_count_imbalance, (job_counts, route_index, penalty, expected), {
+    can_estimate_job_count_imbalance_impl(job_counts, route_index, penalty, expected);
+}}
+
+can_estimate_job_count_imbalance! {
+    case01_above_average: (vec![1, 3, 5], 2, 10., 20.),
+    case02_at_average: (vec![1, 3, 5], 1, 10., 0.),
+    case03_below_average: (vec![1, 3, 5], 0, 10., 0.),
+    case04_unused_route_ignored: (vec![1, 3, 5, 0], 2, 10., 20.),
+    case05_empty_route: (vec![1, 3, 5, 0], 3, 10., 0.),
+    case06_all_empty: (vec![0, 0], 0, 10., 0.),
+    case07_custom_penalty: (vec![2, 6], 1, 0.5, 1.),
+}
+
+fn can_estimate_job_count_imbalance_impl(job_counts: Vec<usize>, route_index: usize, penalty: f64, expected: f64) {
+    let vehicles = (0..job_counts.len()).map(|idx| format!("v{}", idx + 1)).collect::<Vec<_>>();
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vehicles.iter().map(|id| test_vehicle_with_id(id.as_str())).collect())
+        .build();
+    let mut solution_ctx = create_empty_solution_context();
+    solution_ctx.routes = job_counts
+        .iter()
+        .zip(vehicles.iter())
+        .map(|(job_count, vehicle)| {
+            create_route_context_with_activities(
+                &fleet,
+                vehicle.as_str(),
+                (0..*job_count).map(|_| test_tour_activity_with_default_job()).collect(),
+            )
+        })
+        .collect();
+    let pipeline = create_constraint_pipeline_with_module(Box::new(JobCountBalanceConstraintModule::new(penalty)));
+
+    let result = pipeline.evaluate_soft_route(
+        &solution_ctx,
+        solution_ctx.routes.get(route_index).unwrap(),
+        &Job::Single(test_single_with_id("job")),
+    );
+
+    assert_eq!(compare_floats(result, expected), Ordering::Equal);
+}
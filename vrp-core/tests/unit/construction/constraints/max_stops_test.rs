@@ -0,0 +1,47 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_module;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::problem::Fleet;
+
+fn create_fleet(max_stops: Option<usize>) -> Fleet {
+    let mut vehicle = test_vehicle_with_id("v1");
+    if let Some(max_stops) = max_stops {
+        vehicle.dimens.set_value("max_stops", max_stops);
+    }
+
+    FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle).build()
+}
+
+parameterized_test! {can_limit_stops_in_route, (max_stops, activities, expected), {
+    can_limit_stops_in_route_impl(max_stops, activities, expected);
+}}
+
+can_limit_stops_in_route! {
+    case01_below_limit: (Some(3), 1, None),
+    case02_one_before_limit: (Some(3), 2, None),
+    case03_at_limit: (Some(3), 3, Some(())),
+    case04_above_limit: (Some(3), 4, Some(())),
+    case05_empty_route: (Some(1), 0, None),
+    case06_zero_limit: (Some(0), 0, Some(())),
+    case07_no_limit: (None, 10, None),
+}
+
+fn can_limit_stops_in_route_impl(max_stops: Option<usize>, activities: usize, expected: Option<()>) {
+    let route_ctx = create_route_context_with_activities(
+        &create_fleet(max_stops),
+        "v1",
+        (0..activities).map(|_| test_tour_activity_with_default_job()).collect(),
+    );
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: &test_tour_activity_without_job(),
+        target: &test_tour_activity_with_default_job(),
+        next: None,
+    };
+    let pipeline = create_constraint_pipeline_with_module(Box::new(MaxStopsConstraintModule::new(1)));
+
+    let result = pipeline.evaluate_hard_activity(&route_ctx, &activity_ctx);
+
+    assert_eq!(result.map(|violation| (violation.code, violation.stopped)), expected.map(|_| (1, true)));
+}
@@ -111,3 +111,37 @@ fn can_estimate_hard_activity_constraints() {
 
     assert_eq!(result, 12.0);
 }
+
+#[test]
+fn can_ignore_relaxed_hard_activity_constraint() {
+    let mut pipeline = ConstraintPipeline::default();
+    pipeline.add_module(Box::new(TestConstraintModule {
+        state_keys: vec![1, 2],
+        constraints: vec![ConstraintVariant::HardActivity(Arc::new(TestHardActivityConstraint {
+            violation: Some(ActivityConstraintViolation { code: 5, stopped: true }),
+        }))],
+    }));
+    pipeline.add_module(Box::new(TestConstraintModule {
+        state_keys: vec![3, 4],
+        constraints: vec![ConstraintVariant::HardActivity(Arc::new(TestHardActivityConstraint {
+            violation: Some(ActivityConstraintViolation { code: 6, stopped: false }),
+        }))],
+    }));
+    let evaluate = |pipeline: &ConstraintPipeline| {
+        pipeline
+            .evaluate_hard_activity(
+                &RouteContext::new(test_actor()),
+                &ActivityContext {
+                    index: 0,
+                    prev: &test_tour_activity_without_job(),
+                    target: &test_tour_activity_without_job(),
+                    next: None,
+                },
+            )
+            .map(|violation| violation.code)
+    };
+
+    assert_eq!(evaluate(&pipeline), Some(5));
+    assert_eq!(evaluate(&pipeline.relaxed(5)), Some(6));
+    assert_eq!(evaluate(&pipeline.relaxed(6)), Some(5));
+}
@@ -0,0 +1,48 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_module;
+use crate::helpers::models::domain::create_empty_solution_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::problem::Fleet;
+use std::iter::FromIterator;
+
+fn create_skills(skills: Option<Vec<&str>>) -> Option<HashSet<String>> {
+    skills.map(|skills| HashSet::from_iter(skills.into_iter().map(|skill| skill.to_string())))
+}
+
+fn create_fleet(skills: Option<Vec<&str>>) -> Fleet {
+    let mut vehicle = test_vehicle_with_id("v1");
+    if let Some(skills) = create_skills(skills) {
+        vehicle.dimens.set_value("skills", skills);
+    }
+
+    FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle).build()
+}
+
+parameterized_test! {can_check_skills, (job_skills, vehicle_skills, expected), {
+    can_check_skills_impl(job_skills, vehicle_skills, expected);
+}}
+
+can_check_skills! {
+    case01_no_skills: (None, None, None),
+    case02_job_without_skills: (None, Some(vec!["s1"]), None),
+    case03_vehicle_without_skills: (Some(vec!["s1"]), None, Some(())),
+    case04_same_skills: (Some(vec!["s1", "s2"]), Some(vec!["s1", "s2"]), None),
+    case05_subset_of_skills: (Some(vec!["s1"]), Some(vec!["s1", "s2"]), None),
+    case06_partially_missing_skills: (Some(vec!["s1", "s3"]), Some(vec!["s1", "s2"]), Some(())),
+    case07_different_skills: (Some(vec!["s3"]), Some(vec!["s1", "s2"]), Some(())),
+}
+
+fn can_check_skills_impl(job_skills: Option<Vec<&str>>, vehicle_skills: Option<Vec<&str>>, expected: Option<()>) {
+    let solution_ctx = create_empty_solution_context();
+    let route_ctx = create_route_context_with_activities(&create_fleet(vehicle_skills), "v1", vec![]);
+    let mut single = test_single();
+    if let Some(skills) = create_skills(job_skills) {
+        single.dimens.set_value("skills", skills);
+    }
+    let pipeline = create_constraint_pipeline_with_module(Box::new(SkillConstraintModule::new(1)));
+
+    let result = pipeline.evaluate_hard_route(&solution_ctx, &route_ctx, &Job::Single(Arc::new(single)));
+
+    assert_eq!(result.map(|violation| violation.code), expected.map(|_| 1));
+}
@@ -327,6 +327,9 @@ mod traveling {
         case04: ("v1", "v1", 76, (None, Some(100.)), stop(3)),
         case05: ("v1", "v1", 74, (None, Some(100.)), None),
         case06: ("v1", "v2", 76, (None, Some(100.)), None),
+
+        case07_distance_only: ("v1", "v1", 76, (Some(100.), Some(1000.)), stop(2)),
+        case08_both_within: ("v1", "v1", 74, (Some(100.), Some(1000.)), None),
     }
 
     fn can_check_traveling_limits_impl(
@@ -368,3 +371,501 @@ mod traveling {
         assert_eq!(result, stop(3));
     }
 }
+
+mod setup {
+    use super::super::*;
+    use crate::construction::heuristics::*;
+    use crate::helpers::construction::constraints::create_constraint_pipeline_with_module;
+    use crate::helpers::models::domain::create_empty_solution_context;
+    use crate::helpers::models::problem::*;
+    use crate::helpers::models::solution::*;
+    use crate::models::common::{Location, Schedule, TimeWindow};
+    use crate::models::problem::{Fleet, SetupTimeActivityCost};
+    use crate::models::solution::{Place, Registry};
+    use std::sync::Arc;
+
+    fn create_pipeline() -> ConstraintPipeline {
+        let activity = Arc::new(SetupTimeActivityCost::new(
+            Arc::new(TestActivityCost::default()),
+            vec![("A".to_string(), "B".to_string(), 5.), ("B".to_string(), "A".to_string(), 7.)],
+        ));
+
+        create_constraint_pipeline_with_module(Box::new(TransportConstraintModule::new(
+            activity,
+            TestTransportCost::new_shared(),
+            Arc::new(|_| (None, None)),
+            1,
+            2,
+            3,
+        )))
+    }
+
+    fn create_activity(location: Location, job_type: &str, tw: TimeWindow) -> TourActivity {
+        let mut single = test_single();
+        single.dimens.set_value("type", job_type.to_string());
+
+        Box::new(
+            ActivityBuilder::default()
+                .place(Place { location, duration: 1., time: tw })
+                .job(Some(Arc::new(single)))
+                .build(),
+        )
+    }
+
+    fn create_fleet() -> Fleet {
+        FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build()
+    }
+
+    #[test]
+    fn can_add_setup_time_when_job_type_changes() {
+        let fleet = create_fleet();
+        let mut solution_ctx = SolutionContext {
+            routes: vec![create_route_context_with_activities(
+                &fleet,
+                "v1",
+                vec![
+                    create_activity(10, "A", DEFAULT_ACTIVITY_TIME_WINDOW),
+                    create_activity(20, "B", DEFAULT_ACTIVITY_TIME_WINDOW),
+                    create_activity(30, "B", DEFAULT_ACTIVITY_TIME_WINDOW),
+                    create_activity(40, "A", DEFAULT_ACTIVITY_TIME_WINDOW),
+                ],
+            )],
+            registry: Registry::new(&fleet),
+            ..create_empty_solution_context()
+        };
+
+        create_pipeline().accept_solution_state(&mut solution_ctx);
+
+        let tour = &solution_ctx.routes.first().unwrap().route.tour;
+        // no setup after departure
+        assert_eq!(tour.get(1).unwrap().schedule, Schedule { arrival: 10., departure: 11. });
+        // A -> B: setup 5
+        assert_eq!(tour.get(2).unwrap().schedule, Schedule { arrival: 21., departure: 27. });
+        // B -> B: no setup
+        assert_eq!(tour.get(3).unwrap().schedule, Schedule { arrival: 37., departure: 38. });
+        // B -> A: setup 7
+        assert_eq!(tour.get(4).unwrap().schedule, Schedule { arrival: 48., departure: 56. });
+    }
+
+    parameterized_test! {can_check_time_window_with_setup_time, (target_type, expected), {
+        can_check_time_window_with_setup_time_impl(target_type, expected);
+    }}
+
+    can_check_time_window_with_setup_time! {
+        case01_same_type: ("A", None),
+        case02_different_type: ("B", Some(ActivityConstraintViolation { code: 1, stopped: false })),
+    }
+
+    fn can_check_time_window_with_setup_time_impl(target_type: &str, expected: Option<ActivityConstraintViolation>) {
+        let fleet = create_fleet();
+        let pipeline = create_pipeline();
+        let mut route_ctx = create_route_context_with_activities(
+            &fleet,
+            "v1",
+            vec![
+                create_activity(10, "A", DEFAULT_ACTIVITY_TIME_WINDOW),
+                create_activity(20, "A", TimeWindow::new(0., 22.)),
+            ],
+        );
+        pipeline.accept_route_state(&mut route_ctx);
+
+        // without setup, next activity is reached at 21
+        let target = create_activity(10, target_type, DEFAULT_ACTIVITY_TIME_WINDOW);
+        let activity_ctx = ActivityContext {
+            index: 1,
+            prev: route_ctx.route.tour.get(1).unwrap(),
+            target: &target,
+            next: route_ctx.route.tour.get(2),
+        };
+
+        let result = pipeline.evaluate_hard_activity(&route_ctx, &activity_ctx);
+
+        assert_eq!(result, expected);
+    }
+}
+
+mod soft_time_windows {
+    use super::super::*;
+    use crate::construction::heuristics::*;
+    use crate::helpers::construction::constraints::create_constraint_pipeline_with_module;
+    use crate::helpers::models::problem::*;
+    use crate::helpers::models::solution::*;
+    use crate::models::common::{Location, TimeWindow};
+    use crate::models::problem::Fleet;
+    use crate::models::solution::Place;
+    use std::sync::Arc;
+
+    fn create_pipeline(tardiness_penalty: Option<Cost>) -> ConstraintPipeline {
+        let activity = Arc::new(TestActivityCost::default());
+        let transport = TestTransportCost::new_shared();
+        let limit_func = Arc::new(|_: &Actor| (None, None));
+
+        create_constraint_pipeline_with_module(Box::new(match tardiness_penalty {
+            Some(penalty) => {
+                TransportConstraintModule::new_with_soft_time_windows(activity, transport, limit_func, 1, 2, 3, penalty)
+            }
+            None => TransportConstraintModule::new(activity, transport, limit_func, 1, 2, 3),
+        }))
+    }
+
+    fn create_activity(location: Location, tw: TimeWindow) -> TourActivity {
+        Box::new(
+            ActivityBuilder::default()
+                .place(Place { location, duration: 1., time: tw })
+                .job(Some(test_single_with_location(Some(location))))
+                .build(),
+        )
+    }
+
+    fn create_fleet() -> Fleet {
+        FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build()
+    }
+
+    fn evaluate(
+        tardiness_penalty: Option<Cost>,
+        target: (Location, TimeWindow),
+        next_end: f64,
+    ) -> (Option<ActivityConstraintViolation>, Cost) {
+        let fleet = create_fleet();
+        let pipeline = create_pipeline(tardiness_penalty);
+        let mut route_ctx = create_route_context_with_activities(
+            &fleet,
+            "v1",
+            vec![create_activity(10, DEFAULT_ACTIVITY_TIME_WINDOW), create_activity(20, TimeWindow::new(0., next_end))],
+        );
+        pipeline.accept_route_state(&mut route_ctx);
+
+        let target = create_activity(target.0, target.1);
+        let activity_ctx = ActivityContext {
+            index: 1,
+            prev: route_ctx.route.tour.get(1).unwrap(),
+            target: &target,
+            next: route_ctx.route.tour.get(2),
+        };
+
+        (
+            pipeline.evaluate_hard_activity(&route_ctx, &activity_ctx),
+            pipeline.evaluate_soft_activity(&route_ctx, &activity_ctx),
+        )
+    }
+
+    parameterized_test! {can_penalize_tardiness, (target, next_end, expected), {
+        can_penalize_tardiness_impl(target, next_end, expected);
+    }}
+
+    can_penalize_tardiness! {
+        case01_no_tardiness: ((15, DEFAULT_ACTIVITY_TIME_WINDOW), 22., 0.),
+        case02_late_target: ((15, TimeWindow::new(0., 5.)), 22., 110.),
+        case03_late_next: ((25, DEFAULT_ACTIVITY_TIME_WINDOW), 22., 100.),
+        case04_late_both: ((25, TimeWindow::new(0., 20.)), 22., 160.),
+    }
+
+    fn can_penalize_tardiness_impl(target: (Location, TimeWindow), next_end: f64, expected: Cost) {
+        let (hard_result, hard_cost) = evaluate(None, target.clone(), next_end);
+        let (soft_result, soft_cost) = evaluate(Some(10.), target, next_end);
+
+        assert_eq!(hard_result.is_some(), expected > 0.);
+        assert_eq!(soft_result, None);
+        assert_eq!(soft_cost - hard_cost, expected);
+    }
+
+    #[test]
+    fn can_keep_shift_time_as_hard_limit() {
+        let (result, _) = evaluate(Some(10.), (2000, DEFAULT_ACTIVITY_TIME_WINDOW), 22.);
+
+        assert_eq!(result, Some(ActivityConstraintViolation { code: 1, stopped: false }));
+    }
+}
+
+mod time_window_grace {
+    use super::super::*;
+    use crate::construction::heuristics::*;
+    use crate::helpers::construction::constraints::create_constraint_pipeline_with_module;
+    use crate::helpers::models::problem::*;
+    use crate::helpers::models::solution::*;
+    use crate::models::common::{Location, TimeWindow};
+    use crate::models::solution::Place;
+    use std::sync::Arc;
+
+    fn create_activity(location: Location, tw: TimeWindow) -> TourActivity {
+        Box::new(
+            ActivityBuilder::default()
+                .place(Place { location, duration: 0., time: tw })
+                .job(Some(test_single_with_location(Some(location))))
+                .build(),
+        )
+    }
+
+    parameterized_test! {can_accept_late_arrival_within_grace, (grace, target_end, next_end, expected), {
+        can_accept_late_arrival_within_grace_impl(grace, target_end, next_end, expected);
+    }}
+
+    can_accept_late_arrival_within_grace! {
+        case01_late_target_no_grace: (0., 19., 100., stop(1)),
+        case02_late_target_with_grace: (5., 19., 100., None),
+        case03_late_target_beyond_grace: (5., 14., 100., stop(1)),
+        case04_on_time: (0., 20., 30., None),
+    }
+
+    fn can_accept_late_arrival_within_grace_impl(
+        grace: Duration,
+        target_end: f64,
+        next_end: f64,
+        expected: Option<ActivityConstraintViolation>,
+    ) {
+        // activities are at 10, 20 (target) and 30, arrival time is equal to location
+        let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+        let pipeline =
+            create_constraint_pipeline_with_module(Box::new(TransportConstraintModule::new_with_time_window_grace(
+                Arc::new(TestActivityCost::default()),
+                TestTransportCost::new_shared(),
+                Arc::new(|_| (None, None)),
+                1,
+                2,
+                3,
+                grace,
+            )));
+        let mut route_ctx = create_route_context_with_activities(
+            &fleet,
+            "v1",
+            vec![create_activity(10, DEFAULT_ACTIVITY_TIME_WINDOW), create_activity(30, TimeWindow::new(0., next_end))],
+        );
+        pipeline.accept_route_state(&mut route_ctx);
+        let target = create_activity(20, TimeWindow::new(0., target_end));
+        let activity_ctx = ActivityContext {
+            index: 1,
+            prev: route_ctx.route.tour.get(1).unwrap(),
+            target: &target,
+            next: route_ctx.route.tour.get(2),
+        };
+
+        let result = pipeline.evaluate_hard_activity(&route_ctx, &activity_ctx);
+
+        assert_eq!(result, expected);
+    }
+}
+
+mod incremental {
+    use super::super::*;
+    use crate::construction::heuristics::*;
+    use crate::helpers::construction::constraints::create_constraint_pipeline_with_module;
+    use crate::helpers::models::domain::create_empty_solution_context;
+    use crate::helpers::models::problem::*;
+    use crate::helpers::models::solution::*;
+    use crate::models::common::{Location, TimeWindow};
+    use crate::models::problem::{Fleet, SetupTimeActivityCost, VehicleDetail};
+    use crate::models::solution::Place;
+    use std::sync::Arc;
+    use std::time::Instant;
+
+    fn create_pipeline() -> ConstraintPipeline {
+        let activity = Arc::new(SetupTimeActivityCost::new(
+            Arc::new(TestActivityCost::default()),
+            vec![("A".to_string(), "B".to_string(), 5.), ("B".to_string(), "A".to_string(), 7.)],
+        ));
+
+        create_constraint_pipeline_with_module(Box::new(TransportConstraintModule::new(
+            activity,
+            TestTransportCost::new_shared(),
+            Arc::new(|_| (None, None)),
+            1,
+            2,
+            3,
+        )))
+    }
+
+    fn create_activity(location: Location, job_type: &str, tw: TimeWindow) -> TourActivity {
+        let mut single = test_single();
+        single.dimens.set_value("type", job_type.to_string());
+
+        Box::new(
+            ActivityBuilder::default()
+                .place(Place { location, duration: 1., time: tw })
+                .job(Some(Arc::new(single)))
+                .build(),
+        )
+    }
+
+    fn create_fleet() -> Fleet {
+        FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicle(
+                VehicleBuilder::default()
+                    .id("v1")
+                    .details(vec![VehicleDetail {
+                        start: Some(0),
+                        end: Some(0),
+                        time: Some(TimeWindow::new(0., 100000.)),
+                    }])
+                    .build(),
+            )
+            .build()
+    }
+
+    fn create_route_context(fleet: &Fleet, size: usize) -> RouteContext {
+        create_route_context_with_activities(
+            fleet,
+            "v1",
+            (0..size)
+                .map(|idx| {
+                    let start = if idx % 7 == 0 { idx as f64 * 30. } else { 0. };
+                    let job_type = if idx % 3 == 0 { "A" } else { "B" };
+
+                    create_activity((idx % 20) as Location * 5, job_type, TimeWindow::new(start, 100000.))
+                })
+                .collect(),
+        )
+    }
+
+    parameterized_test! {can_update_route_state_incrementally_on_insertion, (index, location, job_type), {
+        can_update_route_state_incrementally_on_insertion_impl(index, location, job_type);
+    }}
+
+    can_update_route_state_incrementally_on_insertion! {
+        case01_first: (1, 3, "A"),
+        case02_middle: (250, 97, "B"),
+        case03_before_waiting: (253, 12, "A"),
+        case04_last: (500, 42, "B"),
+    }
+
+    fn can_update_route_state_incrementally_on_insertion_impl(index: usize, location: Location, job_type: &str) {
+        let fleet = create_fleet();
+        let pipeline = create_pipeline();
+        let mut solution_ctx = create_empty_solution_context();
+        let mut route_ctx = create_route_context(&fleet, 500);
+        pipeline.accept_route_state(&mut route_ctx);
+
+        let activity = create_activity(location, job_type, TimeWindow::new(0., 100000.));
+        let job = Job::Single(activity.job.clone().unwrap());
+        route_ctx.route_mut().tour.insert_at(activity, index);
+        let mut expected_ctx = route_ctx.deep_copy();
+
+        pipeline.accept_insertion(&mut solution_ctx, &mut route_ctx, &job);
+        pipeline.accept_route_state(&mut expected_ctx);
+
+        route_ctx.route.tour.all_activities().zip(expected_ctx.route.tour.all_activities()).for_each(
+            |(actual, expected)| {
+                assert_eq!(actual.schedule, expected.schedule);
+                assert_eq!(
+                    route_ctx.state.get_activity_state::<f64>(LATEST_ARRIVAL_KEY, actual),
+                    expected_ctx.state.get_activity_state::<f64>(LATEST_ARRIVAL_KEY, expected)
+                );
+                assert_eq!(
+                    route_ctx.state.get_activity_state::<f64>(WAITING_KEY, actual),
+                    expected_ctx.state.get_activity_state::<f64>(WAITING_KEY, expected)
+                );
+            },
+        );
+        assert_eq!(
+            route_ctx.state.get_route_state::<f64>(TOTAL_DISTANCE_KEY),
+            expected_ctx.state.get_route_state::<f64>(TOTAL_DISTANCE_KEY)
+        );
+        assert_eq!(
+            route_ctx.state.get_route_state::<f64>(TOTAL_DURATION_KEY),
+            expected_ctx.state.get_route_state::<f64>(TOTAL_DURATION_KEY)
+        );
+    }
+
+    #[test]
+    #[ignore]
+    fn can_update_route_state_incrementally_faster_than_full_recompute() {
+        let fleet = create_fleet();
+        let pipeline = create_pipeline();
+        let mut solution_ctx = create_empty_solution_context();
+        let mut route_ctx = create_route_context(&fleet, 500);
+        pipeline.accept_route_state(&mut route_ctx);
+
+        let (incremental, full) = (1..=500).step_by(5).fold(
+            (std::time::Duration::default(), std::time::Duration::default()),
+            |(incremental, full), index| {
+                let activity = create_activity((index % 20) as Location * 5 + 2, "A", TimeWindow::new(0., 100000.));
+                let job = Job::Single(activity.job.clone().unwrap());
+                let mut incremental_ctx = route_ctx.deep_copy();
+                incremental_ctx.route_mut().tour.insert_at(activity, index);
+                let mut full_ctx = incremental_ctx.deep_copy();
+
+                let started = Instant::now();
+                pipeline.accept_insertion(&mut solution_ctx, &mut incremental_ctx, &job);
+                let incremental = incremental + started.elapsed();
+
+                let started = Instant::now();
+                pipeline.accept_route_state(&mut full_ctx);
+                let full = full + started.elapsed();
+
+                (incremental, full)
+            },
+        );
+
+        println!("500 stops tour, 100 insertions: incremental {:?}, full recompute {:?}", incremental, full);
+        assert!(incremental < full);
+    }
+}
+
+mod loaded_profile {
+    use crate::construction::constraints::*;
+    use crate::helpers::construction::constraints::create_simple_demand;
+    use crate::helpers::models::problem::*;
+    use crate::helpers::models::solution::*;
+    use crate::models::common::*;
+    use crate::models::problem::TransportCost;
+    use crate::models::solution::{Place, TourActivity};
+    use std::sync::Arc;
+
+    struct ProfileTransportCost {}
+
+    impl TransportCost for ProfileTransportCost {
+        fn duration(&self, profile: Profile, from: Location, to: Location, _: Timestamp) -> Duration {
+            fake_routing(from, to) * if profile == 1 { 2. } else { 1. }
+        }
+
+        fn distance(&self, _: Profile, from: Location, to: Location, _: Timestamp) -> Distance {
+            fake_routing(from, to)
+        }
+    }
+
+    fn create_activity(location: Location, size: i32) -> TourActivity {
+        Box::new(
+            ActivityBuilder::default()
+                .place(Place { location, duration: 0., time: DEFAULT_ACTIVITY_TIME_WINDOW })
+                .job(Some(test_single_with_simple_demand(create_simple_demand(size))))
+                .build(),
+        )
+    }
+
+    parameterized_test! {can_use_loaded_profile_for_loaded_legs, (has_load_state, expected), {
+        can_use_loaded_profile_for_loaded_legs_impl(has_load_state, expected);
+    }}
+
+    can_use_loaded_profile_for_loaded_legs! {
+        case01_with_load_state: (true, vec![20., 30., 70.]),
+        case02_without_load_state: (false, vec![10., 20., 40.]),
+    }
+
+    fn can_use_loaded_profile_for_loaded_legs_impl(has_load_state: bool, expected: Vec<Timestamp>) {
+        let mut vehicle = test_vehicle_with_id("v1");
+        vehicle.dimens.set_value("loaded_profile", 1 as Profile);
+        let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle).build();
+        let capacity = CapacityConstraintModule::<i32>::new(4);
+        let transport = TransportConstraintModule::new(
+            Arc::new(TestActivityCost::default()),
+            Arc::new(ProfileTransportCost {}),
+            Arc::new(|_| (None, None)),
+            1,
+            2,
+            3,
+        );
+        let transport = if has_load_state { transport.with_load_state(capacity.get_load_state()) } else { transport };
+        // NOTE capacity module is intentionally added after transport one
+        let mut pipeline = ConstraintPipeline::default();
+        pipeline.add_module(Box::new(transport));
+        pipeline.add_module(Box::new(capacity));
+        // 0 -> 10 loaded (delivery), 10 -> 20 empty, 20 -> 0 loaded (pickup)
+        let mut route_ctx =
+            create_route_context_with_activities(&fleet, "v1", vec![create_activity(10, -1), create_activity(20, 1)]);
+
+        pipeline.accept_route_state(&mut route_ctx);
+
+        let arrivals = route_ctx.route.tour.all_activities().skip(1).map(|a| a.schedule.arrival).collect::<Vec<_>>();
+        assert_eq!(arrivals, expected);
+    }
+}
@@ -0,0 +1,53 @@
+use super::*;
+use crate::construction::heuristics::*;
+use crate::helpers::models::domain::get_customer_ids_from_routes;
+use crate::helpers::solver::generate_matrix_routes;
+use crate::utils::DefaultRandom;
+
+fn run_insertion(use_cache: bool) -> Vec<Vec<String>> {
+    let (problem, _) = generate_matrix_routes(4, 3);
+    let insertion_ctx = InsertionContext::new(Arc::new(problem), Arc::new(DefaultRandom::default()));
+    let job_selector: Box<dyn JobSelector + Send + Sync> = Box::new(AllJobSelector::default());
+    let job_reducer: Box<dyn JobMapReducer + Send + Sync> =
+        Box::new(PairJobMapReducer::new(Box::new(BestResultSelector::default())));
+
+    let insertion_ctx =
+        InsertionHeuristic::default().with_cache(use_cache).process(&job_selector, &job_reducer, insertion_ctx, &None);
+
+    assert!(insertion_ctx.cache.is_none());
+    assert!(insertion_ctx.solution.required.is_empty());
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+
+    get_customer_ids_from_routes(&insertion_ctx)
+}
+
+#[test]
+fn can_produce_same_assignments_with_and_without_cache() {
+    let uncached = run_insertion(false);
+    let cached = run_insertion(true);
+
+    assert!(!cached.is_empty());
+    assert_eq!(cached, uncached);
+}
+
+#[test]
+fn can_reuse_cached_result_for_unchanged_route() {
+    let (problem, _) = generate_matrix_routes(2, 1);
+    let insertion_ctx = InsertionContext::new(Arc::new(problem), Arc::new(DefaultRandom::default()));
+    let job = insertion_ctx.solution.required.first().cloned().unwrap();
+    let route_ctx = RouteContext::new(insertion_ctx.solution.registry.next().next().unwrap());
+    let cache = InsertionCache::default();
+    let evaluate = || evaluate_job_insertion_in_route(&job, &insertion_ctx, &route_ctx, InsertionPosition::Any, None);
+
+    let first = cache.get_or_evaluate(&job, &route_ctx, evaluate);
+    let second = cache.get_or_evaluate(&job, &route_ctx, || unreachable!());
+
+    assert_eq!(cache.size(), 1);
+    match (first, second) {
+        (InsertionResult::Success(first), InsertionResult::Success(second)) => {
+            assert_eq!(first.cost, second.cost);
+            assert_eq!(first.activities.len(), second.activities.len());
+        }
+        _ => unreachable!(),
+    }
+}
@@ -287,3 +287,22 @@ mod multi {
         }
     }
 }
+
+#[test]
+fn can_return_k_best_positions_sorted_by_cost() {
+    let registry = create_test_registry();
+    let mut route_ctx = RouteContext::new(registry.next().next().unwrap());
+    route_ctx.route_mut().tour.insert_at(create_tour_activity_at(5), 1).insert_at(create_tour_activity_at(10), 2);
+    let ctx = create_insertion_context(registry, create_constraint_pipeline_with_transport(), vec![route_ctx]);
+    let job = Job::Single(test_single_with_id_and_location("job1", Some(3)));
+
+    let positions = best_positions(&ctx, &job, 3);
+
+    assert_eq!(positions.len(), 3);
+    assert!(positions.windows(2).all(|pair| compare_floats(pair[0].2, pair[1].2) != Ordering::Greater));
+    assert_eq!((positions[0].0, positions[0].1), (0, 0));
+    match evaluate_job_insertion(&job, &ctx, InsertionPosition::Any) {
+        InsertionResult::Success(success) => assert_eq!(compare_floats(success.cost, positions[0].2), Ordering::Equal),
+        InsertionResult::Failure(_) => unreachable!(),
+    }
+}
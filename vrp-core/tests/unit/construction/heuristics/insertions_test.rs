@@ -0,0 +1,115 @@
+use super::*;
+use crate::construction::heuristics::RouteContext;
+use crate::helpers::models::domain::create_empty_insertion_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::TimeWindow;
+use crate::models::problem::Fleet;
+
+fn create_success(fleet: &Fleet, vehicle_id: &str, position: usize, job_id: &str) -> InsertionResult {
+    let actor = get_test_actor_from_fleet(fleet, vehicle_id);
+    let job = test_single_with_id(job_id);
+
+    InsertionResult::make_success(
+        10.,
+        Job::Single(job.clone()),
+        vec![(test_tour_activity_with_job(job), position)],
+        RouteContext::new(actor),
+    )
+}
+
+parameterized_test! {can_choose_best_result_deterministically_on_equal_cost, (left, right, expected), {
+    can_choose_best_result_deterministically_on_equal_cost_impl(left, right, expected);
+}}
+
+can_choose_best_result_deterministically_on_equal_cost! {
+    case01_vehicle: (("v2", 0, "job1"), ("v1", 1, "job2"), ("v1", "job2")),
+    case02_position: (("v1", 2, "job1"), ("v1", 1, "job2"), ("v1", "job2")),
+}
+
+fn can_choose_best_result_deterministically_on_equal_cost_impl(
+    left: (&str, usize, &str),
+    right: (&str, usize, &str),
+    expected: (&str, &str),
+) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(test_vehicle_with_id("v1"))
+        .add_vehicle(test_vehicle_with_id("v2"))
+        .build();
+    let create =
+        |(vehicle_id, position, job_id): (&str, usize, &str)| create_success(&fleet, vehicle_id, position, job_id);
+
+    vec![(create(left), create(right)), (create(right), create(left))].into_iter().for_each(|(left, right)| {
+        match InsertionResult::choose_best_result(left, right) {
+            InsertionResult::Success(success) => {
+                assert_eq!(success.context.route.actor.vehicle.dimens.get_id().unwrap(), expected.0);
+                assert_eq!(success.job.dimens().get_id().unwrap(), expected.1);
+            }
+            InsertionResult::Failure(_) => unreachable!(),
+        }
+    });
+}
+
+#[test]
+fn can_keep_left_result_when_vehicle_and_position_are_equal() {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+
+    vec![("job1", "job2"), ("job2", "job1")].into_iter().for_each(|(left, right)| {
+        let left_result = create_success(&fleet, "v1", 1, left);
+        let right_result = create_success(&fleet, "v1", 1, right);
+
+        match InsertionResult::choose_best_result(left_result, right_result) {
+            InsertionResult::Success(success) => assert_eq!(success.job.dimens().get_id().unwrap(), left),
+            InsertionResult::Failure(_) => unreachable!(),
+        }
+    });
+}
+
+fn create_success_with_deadline(
+    fleet: &Fleet,
+    job_id: &str,
+    position: usize,
+    cost: Cost,
+    deadline: f64,
+) -> InsertionResult {
+    let actor = get_test_actor_from_fleet(fleet, "v1");
+    let job = test_single_with_id(job_id);
+    let mut activity = test_tour_activity_with_job(job.clone());
+    activity.place.time = TimeWindow::new(0., deadline);
+
+    InsertionResult::make_success(cost, Job::Single(job), vec![(activity, position)], RouteContext::new(actor))
+}
+
+parameterized_test! {can_break_ties_by_earliest_deadline, (policy, left, right, expected), {
+    can_break_ties_by_earliest_deadline_impl(policy, left, right, expected);
+}}
+
+can_break_ties_by_earliest_deadline! {
+    case01_deterministic: (TieBreakPolicy::Deterministic, (10., 100.), (10., 50.), "job1"),
+    case02_edf_equal_cost: (TieBreakPolicy::EarliestDeadlineFirst, (10., 100.), (10., 50.), "job2"),
+    case03_edf_within_epsilon: (TieBreakPolicy::EarliestDeadlineFirst, (10., 100.), (10.5, 50.), "job2"),
+    case04_edf_outside_epsilon: (TieBreakPolicy::EarliestDeadlineFirst, (10., 100.), (12., 50.), "job1"),
+    case05_edf_equal_deadline: (TieBreakPolicy::EarliestDeadlineFirst, (10., 50.), (10., 50.), "job1"),
+}
+
+fn can_break_ties_by_earliest_deadline_impl(
+    policy: TieBreakPolicy,
+    left: (Cost, f64),
+    right: (Cost, f64),
+    expected: &str,
+) {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let selector = BestResultSelector::new(policy, 1.);
+    let ctx = create_empty_insertion_context();
+    let create = |job_id: &str, position: usize, (cost, deadline): (Cost, f64)| {
+        create_success_with_deadline(&fleet, job_id, position, cost, deadline)
+    };
+
+    vec![(create("job1", 0, left), create("job2", 1, right)), (create("job2", 1, right), create("job1", 0, left))]
+        .into_iter()
+        .for_each(|(left, right)| match selector.select(&ctx, left, right) {
+            InsertionResult::Success(success) => assert_eq!(success.job.dimens().get_id().unwrap(), expected),
+            InsertionResult::Failure(_) => unreachable!(),
+        });
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::helpers::models::solution::test_actor;
 use crate::helpers::solver::population::*;
 use std::cmp::Ordering;
 
@@ -58,6 +59,35 @@ fn can_return_error_when_mixing_timestamps() {
     );
 }
 
+parameterized_test! {can_check_matrix_symmetry, (durations, distances, tolerance, expected), {
+    can_check_matrix_symmetry_impl(durations, distances, tolerance, expected);
+}}
+
+can_check_matrix_symmetry! {
+    case01_symmetric: (vec![0., 10., 20., 10., 0., 5., 20., 5., 0.], vec![0.; 9], 0.1, vec![]),
+    case02_missing_return_leg: (vec![0., 10., 20., 0., 0., 5., 20., 5., 0.], vec![0.; 9], 0.1, vec![(0, 1)]),
+    case03_within_tolerance: (vec![0., 10., 20., 11., 0., 5., 20., 5., 0.], vec![0.; 9], 0.1, vec![]),
+    case04_above_tolerance: (vec![0., 10., 20., 12., 0., 5., 20., 5., 0.], vec![0.; 9], 0.1, vec![(0, 1)]),
+    case05_distances: (vec![0.; 9], vec![0., 10., 20., 10., 0., 5., 20., 50., 0.], 0.1, vec![(1, 2)]),
+    case06_many_pairs: (vec![0., 1., 2., 3., 0., 5., 6., 7., 0.], vec![0.; 9], 0., vec![(0, 1), (0, 2), (1, 2)]),
+}
+
+fn can_check_matrix_symmetry_impl(
+    durations: Vec<f64>,
+    distances: Vec<f64>,
+    tolerance: f64,
+    expected: Vec<(Location, Location)>,
+) {
+    let costs = vec![
+        MatrixData::new(0, durations, distances),
+        MatrixData::new(1, vec![0.; 9], vec![0.; 9]), //
+    ];
+
+    let result = check_matrix_symmetry(costs.as_slice(), tolerance);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn can_interpolate_durations() {
     let costs = TimeAwareMatrixTransportCost::new(
@@ -83,6 +113,31 @@ fn can_interpolate_durations() {
     assert_eq!(costs.distance(1, 0, 1, 0.), 5.);
 }
 
+#[test]
+fn can_interpolate_distances() {
+    let costs = TimeAwareMatrixTransportCost::new(
+        vec![
+            create_matrix_data(0, Some(0.), (1., 2), (100., 2)),
+            create_matrix_data(0, Some(10.), (1., 2), (200., 2)),
+            create_matrix_data(1, Some(0.), (5., 2), (300., 2)),
+            create_matrix_data(1, Some(10.), (5., 2), (400., 2)),
+        ],
+        2,
+    )
+    .unwrap();
+
+    for &(timestamp, distance) in &[(0., 100.), (10., 200.), (15., 200.), (3., 130.), (5., 150.), (7., 170.)] {
+        assert_eq!(costs.distance(0, 0, 1, timestamp), distance);
+    }
+
+    for &(timestamp, distance) in &[(0., 300.), (10., 400.), (15., 400.), (3., 330.), (5., 350.), (7., 370.)] {
+        assert_eq!(costs.distance(1, 0, 1, timestamp), distance);
+    }
+
+    assert_eq!(costs.duration(0, 0, 1, 5.), 1.);
+    assert_eq!(costs.duration(1, 0, 1, 5.), 5.);
+}
+
 #[test]
 fn can_compare_non_dominant_relations() {
     let objective = TupleMultiObjective::new(vec![]);
@@ -97,3 +152,393 @@ fn can_compare_non_dominant_relations() {
     assert_eq!(Ordering::Equal, objective.total_order(a, b));
     assert_eq!(Ordering::Equal, objective.total_order(b, a));
 }
+
+mod weighted {
+    use super::*;
+    use crate::construction::constraints::TOTAL_DISTANCE_KEY;
+    use crate::construction::heuristics::{InsertionContext, SolutionContext};
+    use crate::helpers::models::domain::{create_empty_insertion_context, create_empty_solution_context};
+    use crate::helpers::models::problem::*;
+    use crate::helpers::models::solution::create_route_context_with_activities;
+    use crate::models::problem::Job;
+    use crate::solver::objectives::{TotalRoutes, TotalTransportCost, TotalUnassignedJobs};
+
+    fn create_insertion_ctx(routes: usize, unassigned: usize) -> InsertionContext {
+        let fleet = FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+            .build();
+
+        InsertionContext {
+            solution: SolutionContext {
+                routes: vec!["v1", "v2"]
+                    .into_iter()
+                    .take(routes)
+                    .map(|id| create_route_context_with_activities(&fleet, id, vec![]))
+                    .collect(),
+                unassigned: (0..unassigned)
+                    .map(|idx| (Job::Single(test_single_with_id(format!("job{}", idx).as_str())), 0))
+                    .collect(),
+                ..create_empty_solution_context()
+            },
+            ..create_empty_insertion_context()
+        }
+    }
+
+    fn create_objectives() -> Vec<TargetObjective> {
+        vec![Box::new(TotalUnassignedJobs::default()), Box::new(TotalRoutes::default())]
+    }
+
+    #[test]
+    fn can_use_weighted_sum_of_objectives() {
+        let objective = WeightedObjectiveCost::new(create_objectives().into_iter().zip(vec![1., 3.]).collect());
+        let a = create_insertion_ctx(1, 2);
+        let b = create_insertion_ctx(2, 0);
+
+        assert_eq!(objective.fitness(&a), 5.);
+        assert_eq!(objective.fitness(&b), 6.);
+        assert_eq!(objective.distance(&a, &b), 1.);
+        assert_eq!(objective.distance(&b, &a), 1.);
+        assert_eq!(objective.total_order(&a, &b), Ordering::Less);
+        assert_eq!(objective.objectives().count(), 2);
+    }
+
+    fn create_insertion_ctx_with_distance(routes: usize, distance: f64) -> InsertionContext {
+        let mut insertion_ctx = create_insertion_ctx(routes, 0);
+        insertion_ctx.solution.routes.iter_mut().for_each(|route_ctx| {
+            route_ctx.state_mut().put_route_state(TOTAL_DISTANCE_KEY, distance / routes as f64);
+        });
+
+        insertion_ctx
+    }
+
+    parameterized_test! {can_normalize_objectives_with_disparate_scales, (normalizations, expected), {
+        can_normalize_objectives_with_disparate_scales_impl(normalizations, expected);
+    }}
+
+    can_normalize_objectives_with_disparate_scales! {
+        case01_no_normalization: (None, Ordering::Less),
+        case02_reference: (Some(vec![Some(ObjectiveNormalization::Reference(2.)),
+                                     Some(ObjectiveNormalization::Reference(100.))]), Ordering::Greater),
+        case03_min_max: (Some(vec![Some(ObjectiveNormalization::MinMax(0., 2.)),
+                                   Some(ObjectiveNormalization::MinMax(0., 200.))]), Ordering::Greater),
+        case04_estimated: (Some(vec![Some(ObjectiveNormalization::Estimated),
+                                     Some(ObjectiveNormalization::Estimated)]), Ordering::Greater),
+        case05_partial: (Some(vec![Some(ObjectiveNormalization::Reference(2.)), None]), Ordering::Less),
+    }
+
+    fn can_normalize_objectives_with_disparate_scales_impl(
+        normalizations: Option<Vec<Option<ObjectiveNormalization>>>,
+        expected: Ordering,
+    ) {
+        let objectives: Vec<TargetObjective> =
+            vec![Box::new(TotalRoutes::default()), Box::new(TotalTransportCost::default())];
+        let objective = WeightedObjectiveCost::new(objectives.into_iter().zip(vec![1., 1.]).collect());
+        let objective = match normalizations {
+            Some(normalizations) => objective.with_normalizations(normalizations),
+            None => objective,
+        };
+        // NOTE cost is two times distance as both vehicle and driver have unit distance cost
+        let initial = create_insertion_ctx_with_distance(2, 50.);
+        let fewer_routes = create_insertion_ctx_with_distance(1, 55.);
+
+        objective.estimate(&[&initial]).unwrap();
+
+        assert_eq!(objective.total_order(&initial, &fewer_routes), expected);
+    }
+
+    fn create_estimated_objective() -> WeightedObjectiveCost {
+        WeightedObjectiveCost::new(create_objectives().into_iter().zip(vec![1., 1.]).collect())
+            .with_normalizations(vec![Some(ObjectiveNormalization::Estimated), None])
+    }
+
+    #[test]
+    fn can_estimate_reference_from_initial_solutions() {
+        let objective = create_estimated_objective();
+        let solution = create_insertion_ctx(1, 2);
+
+        assert_eq!(objective.fitness(&solution), 3.);
+
+        objective.estimate(&[&create_insertion_ctx(1, 1), &create_insertion_ctx(1, 3)]).unwrap();
+
+        assert_eq!(objective.fitness(&solution), 2.);
+    }
+
+    #[test]
+    fn can_reject_zero_estimated_reference() {
+        let objective = create_estimated_objective();
+
+        let result = objective.estimate(&[&create_insertion_ctx(1, 0)]);
+
+        assert_eq!(
+            result,
+            Err("cannot use zero as estimated reference of 'TotalUnassignedJobs' objective".to_string())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "normalization should be specified for each objective")]
+    fn can_reject_normalizations_with_wrong_size() {
+        WeightedObjectiveCost::new(create_objectives().into_iter().zip(vec![1., 1.]).collect())
+            .with_normalizations(vec![Some(ObjectiveNormalization::Estimated)]);
+    }
+
+    #[test]
+    fn can_keep_hierarchical_order_in_objective_cost() {
+        let mut objectives = create_objectives();
+        let secondary = objectives.split_off(1);
+        let objective = ObjectiveCost::new(objectives, secondary);
+        let a = create_insertion_ctx(1, 2);
+        let b = create_insertion_ctx(2, 0);
+
+        assert_eq!(objective.total_order(&a, &b), Ordering::Greater);
+    }
+}
+
+mod distance {
+    use super::*;
+    use crate::construction::heuristics::InsertionContext;
+    use crate::helpers::models::domain::get_customer_id;
+    use crate::helpers::solver::generate_matrix_routes;
+    use crate::utils::DefaultRandom;
+    use std::sync::Arc;
+
+    fn create_insertion_ctx(insertion_ctx: &InsertionContext, removed_ids: Vec<&str>) -> InsertionContext {
+        let mut insertion_ctx = insertion_ctx.deep_copy();
+
+        insertion_ctx.solution.routes.iter_mut().for_each(|route_ctx| {
+            route_ctx.route.tour.jobs().collect::<Vec<_>>().into_iter().for_each(|job| {
+                if removed_ids.contains(&get_customer_id(&job).as_str()) {
+                    route_ctx.route_mut().tour.remove(&job);
+                }
+            })
+        });
+
+        insertion_ctx
+    }
+
+    parameterized_test! {can_calculate_structural_distance_in_objective_cost, (lhs_removed, rhs_removed, expected), {
+        can_calculate_structural_distance_in_objective_cost_impl(lhs_removed, rhs_removed, expected);
+    }}
+
+    can_calculate_structural_distance_in_objective_cost! {
+        case01_same: (vec![], vec![], 0.),
+        case02_one_removed: (vec![], vec!["c1"], 3. / 7.),
+        case03_symmetric: (vec!["c1"], vec![], 3. / 7.),
+        case04_different: (vec!["c0", "c2"], vec!["c1", "c3"], 1.),
+    }
+
+    fn can_calculate_structural_distance_in_objective_cost_impl(
+        lhs_removed: Vec<&str>,
+        rhs_removed: Vec<&str>,
+        expected: f64,
+    ) {
+        let (problem, solution) = generate_matrix_routes(2, 2);
+        let insertion_ctx = InsertionContext::new_from_solution(
+            Arc::new(problem),
+            (Arc::new(solution), None),
+            Arc::new(DefaultRandom::default()),
+        );
+        let objective = ObjectiveCost::default();
+        let lhs = create_insertion_ctx(&insertion_ctx, lhs_removed);
+        let rhs = create_insertion_ctx(&insertion_ctx, rhs_removed);
+
+        let distance = objective.distance(&lhs, &rhs);
+
+        assert!((distance - expected).abs() < 1E-6);
+    }
+}
+
+mod haversine {
+    use super::*;
+
+    const BERLIN: (f64, f64) = (52.520008, 13.404954);
+    const MUNICH: (f64, f64) = (48.135125, 11.581981);
+    const PARIS: (f64, f64) = (48.856613, 2.352222);
+
+    #[test]
+    fn can_calculate_distance_between_cities() {
+        let costs = HaversineTransportCost::new(vec![BERLIN, MUNICH, PARIS], vec![10.]).unwrap();
+
+        // NOTE known great-circle distances are ~504km and ~878km, the difference comes from earth radius
+        vec![(1, 504_000.), (2, 878_000.)].into_iter().for_each(|(to, expected)| {
+            let distance = costs.distance(0, 0, to, 0.);
+            assert!((distance - expected).abs() / expected < 0.01);
+        });
+        assert_eq!(costs.distance(0, 0, 1, 0.), costs.distance(0, 1, 0, 0.));
+        assert_eq!(costs.distance(0, 1, 1, 0.), 0.);
+    }
+
+    #[test]
+    fn can_derive_duration_from_profile_speed() {
+        let costs = HaversineTransportCost::new(vec![BERLIN, MUNICH], vec![10., 20.]).unwrap();
+        let distance = costs.distance(0, 0, 1, 0.);
+
+        assert_eq!(costs.distance(1, 0, 1, 0.), distance);
+        assert_eq!(costs.duration(0, 0, 1, 0.), distance / 10.);
+        assert_eq!(costs.duration(1, 0, 1, 0.), distance / 20.);
+    }
+
+    #[test]
+    fn can_validate_speeds() {
+        assert_eq!(
+            HaversineTransportCost::new(vec![BERLIN], vec![]).err(),
+            Some("At least one profile speed should be specified".to_string())
+        );
+        assert_eq!(
+            HaversineTransportCost::new(vec![BERLIN], vec![10., 0.]).err(),
+            Some("Profile speed should be positive".to_string())
+        );
+    }
+}
+
+mod euclidean {
+    use super::*;
+
+    #[test]
+    fn can_calculate_distance_and_duration() {
+        let costs = EuclideanTransportCost::new(vec![(0., 0.), (3., 4.), (-3., 0.)], vec![1., 2.]).unwrap();
+
+        vec![(0, 1, 5.), (1, 0, 5.), (0, 2, 3.), (1, 2, 7.2111), (2, 2, 0.)].into_iter().for_each(
+            |(from, to, expected)| {
+                let distance = costs.distance(0, from, to, 0.);
+
+                assert!((distance - expected).abs() < 1E-4);
+                assert_eq!(costs.distance(1, from, to, 0.), distance);
+                assert_eq!(costs.duration(0, from, to, 0.), distance);
+                assert_eq!(costs.duration(1, from, to, 0.), distance / 2.);
+            },
+        );
+    }
+
+    #[test]
+    fn can_match_matrix_transport_cost() {
+        let coordinates = vec![(0., 0.), (10., 5.), (-7., 3.)];
+        let values = coordinates
+            .iter()
+            .flat_map(|&(x1, y1)| {
+                coordinates.iter().map(move |&(x2, y2): &(f64, f64)| ((x1 - x2).powi(2) + (y1 - y2).powi(2)).sqrt())
+            })
+            .collect::<Vec<_>>();
+        let matrix = create_matrix_transport_cost(vec![MatrixData::new(0, values.clone(), values)]).unwrap();
+        let euclidean: Arc<dyn TransportCost + Send + Sync> =
+            Arc::new(EuclideanTransportCost::new(coordinates, vec![1.]).unwrap());
+
+        (0..3).flat_map(|from| (0..3).map(move |to| (from, to))).for_each(|(from, to)| {
+            assert!((euclidean.distance(0, from, to, 0.) - matrix.distance(0, from, to, 0.)).abs() < 1E-9);
+            assert!((euclidean.duration(0, from, to, 0.) - matrix.duration(0, from, to, 0.)).abs() < 1E-9);
+        });
+    }
+
+    #[test]
+    fn can_validate_speeds() {
+        assert_eq!(
+            EuclideanTransportCost::new(vec![(0., 0.)], vec![-1.]).err(),
+            Some("Profile speed should be positive".to_string())
+        );
+    }
+}
+
+mod cached {
+    use super::*;
+
+    fn create_time_aware_costs() -> Arc<dyn TransportCost + Send + Sync> {
+        create_matrix_transport_cost(vec![
+            create_matrix_data(0, Some(0.), (100., 4), (10., 4)),
+            create_matrix_data(0, Some(10.), (200., 4), (20., 4)),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn can_cache_time_agnostic_costs() {
+        let inner = Arc::new(EuclideanTransportCost::new(vec![(0., 0.), (3., 4.)], vec![1., 2.]).unwrap());
+        let costs: Arc<dyn TransportCost + Send + Sync> = Arc::new(CachedTransportCost::new(inner));
+
+        assert_eq!(costs.distance(0, 0, 1, 0.), 5.);
+        assert_eq!(costs.duration(0, 0, 1, 100.), 5.);
+        assert_eq!(costs.duration(1, 0, 1, 0.), 2.5);
+        assert_eq!(costs.distance(1, 1, 0, 0.), 5.);
+        assert_eq!(costs.distance(1, 0, 1, 0.), 5.);
+        assert!(!costs.is_time_aware());
+    }
+
+    #[test]
+    fn can_count_hits_and_misses() {
+        let inner = Arc::new(EuclideanTransportCost::new(vec![(0., 0.), (3., 4.)], vec![1.]).unwrap());
+        let costs = CachedTransportCost::new(inner);
+
+        costs.distance(0, 0, 1, 0.);
+        costs.duration(0, 0, 1, 0.);
+        costs.distance(0, 1, 0, 0.);
+        costs.distance(0, 0, 1, 0.);
+
+        assert_eq!(costs.statistics(), (2, 2));
+    }
+
+    #[test]
+    fn can_disable_cache_for_time_aware_costs() {
+        let costs = CachedTransportCost::new(create_time_aware_costs());
+
+        assert_eq!(costs.duration(0, 0, 1, 0.), 100.);
+        assert_eq!(costs.duration(0, 0, 1, 5.), 150.);
+        assert_eq!(costs.distance(0, 0, 1, 10.), 20.);
+        assert_eq!(costs.statistics(), (0, 0));
+        assert!(costs.is_time_aware());
+    }
+
+    #[test]
+    fn can_propagate_time_awareness_through_decorators() {
+        let avoidance = AvoidanceTransportCost::new(create_time_aware_costs(), vec![]);
+
+        assert!(avoidance.is_time_aware());
+        assert!(CachedTransportCost::new(Arc::new(avoidance)).is_time_aware());
+    }
+
+    #[test]
+    fn can_delegate_cost_to_inner() {
+        let actor = test_actor();
+        let euclidean = Arc::new(EuclideanTransportCost::new(vec![(0., 0.), (3., 4.)], vec![1.]).unwrap());
+        let avoidance = Arc::new(AvoidanceTransportCost::new(euclidean.clone(), vec![(0, 2., vec![(0, 1)])]));
+        let costs = CachedTransportCost::new(avoidance.clone());
+
+        let cost = costs.cost(&actor, 0, 0, 1, 0.);
+
+        assert_eq!(cost, avoidance.cost(&actor, 0, 0, 1, 0.));
+        assert_eq!(cost, 2. * euclidean.cost(&actor, 0, 0, 1, 0.));
+    }
+}
+
+mod scaled {
+    use super::*;
+
+    #[test]
+    fn can_scale_time_agnostic_costs() {
+        let inner = Arc::new(EuclideanTransportCost::new(vec![(0., 0.), (3., 4.)], vec![1.]).unwrap());
+
+        let costs = ScaledTransportCost::new(inner.clone(), 1.5, None);
+        assert_eq!(costs.duration(0, 0, 1, 0.), 7.5);
+        assert_eq!(costs.distance(0, 0, 1, 0.), 5.);
+        assert!(!costs.is_time_aware());
+
+        let costs = ScaledTransportCost::new(inner, 2., Some(0.5));
+        assert_eq!(costs.duration(0, 0, 1, 0.), 10.);
+        assert_eq!(costs.distance(0, 0, 1, 0.), 2.5);
+    }
+
+    #[test]
+    fn can_scale_time_aware_costs() {
+        let inner = create_matrix_transport_cost(vec![
+            create_matrix_data(0, Some(0.), (100., 4), (10., 4)),
+            create_matrix_data(0, Some(10.), (200., 4), (20., 4)),
+        ])
+        .unwrap();
+
+        let costs = ScaledTransportCost::new(inner, 2., Some(3.));
+
+        assert_eq!(costs.duration(0, 0, 1, 0.), 200.);
+        assert_eq!(costs.duration(0, 0, 1, 5.), 300.);
+        assert_eq!(costs.distance(0, 0, 1, 10.), 60.);
+        assert!(costs.is_time_aware());
+    }
+}
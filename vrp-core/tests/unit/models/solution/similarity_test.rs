@@ -0,0 +1,48 @@
+use super::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::problem::{Fleet, Single};
+use crate::models::solution::Registry;
+use crate::utils::compare_floats;
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+fn create_solution(fleet: &Fleet, jobs: &[Arc<Single>], tours: Vec<Vec<usize>>) -> Solution {
+    let routes = tours
+        .into_iter()
+        .enumerate()
+        .map(|(idx, tour)| {
+            let activities = tour.into_iter().map(|job_idx| test_tour_activity_with_job(jobs[job_idx].clone()));
+            create_route_with_activities(fleet, format!("v{}", idx + 1).as_str(), activities.collect())
+        })
+        .collect();
+
+    Solution { registry: Registry::new(fleet), routes, unassigned: Default::default(), extras: Default::default() }
+}
+
+parameterized_test! {can_calculate_solution_similarity, (first, second, expected), {
+    can_calculate_solution_similarity_impl(first, second, expected);
+}}
+
+can_calculate_solution_similarity! {
+    case01: (vec![vec![0, 1], vec![2, 3]], vec![vec![0, 1], vec![2, 3]], 1.),
+    case02: (vec![vec![0, 1], vec![2, 3]], vec![vec![1, 0], vec![3, 2]], 0.),
+    case03: (vec![vec![0, 1], vec![2, 3]], vec![vec![0, 1], vec![3, 2]], 1. / 3.),
+    case04: (vec![vec![0, 1, 2, 3]], vec![vec![0, 1], vec![2, 3]], 4. / 7.),
+    case05: (vec![], vec![], 1.),
+}
+
+fn can_calculate_solution_similarity_impl(first: Vec<Vec<usize>>, second: Vec<Vec<usize>>, expected: f64) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build();
+    let jobs = (0..4).map(|idx| test_single_with_id(format!("job{}", idx).as_str())).collect::<Vec<_>>();
+    let first = create_solution(&fleet, &jobs, first);
+    let second = create_solution(&fleet, &jobs, second);
+
+    let similarity = solution_similarity(&first, &second);
+
+    assert_eq!(compare_floats(similarity, expected), Ordering::Equal);
+    assert_eq!(compare_floats(solution_similarity(&second, &first), expected), Ordering::Equal);
+}
@@ -0,0 +1,299 @@
+use crate::helpers::solver::generate_matrix_routes;
+use crate::models::common::IdDimension;
+use crate::models::problem::{ObjectiveCost, ObjectiveNormalization, TargetObjective, WeightedObjectiveCost};
+use crate::models::{Problem, Solution};
+use crate::solver::objectives::{DurationSpread, TotalTransportCost, TotalUnassignedJobs};
+use crate::solver::Builder;
+use crate::utils::compare_floats;
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+#[test]
+fn can_track_best_cost_history() {
+    let (problem, _) = generate_matrix_routes(3, 3);
+
+    let (_, _, statistics) = Builder::default()
+        .with_problem(Arc::new(problem))
+        .with_max_generations(Some(10))
+        .with_best_cost_history(true)
+        .build()
+        .unwrap()
+        .solve_with_statistics()
+        .unwrap();
+
+    let history = statistics.best_cost_history.expect("no best cost history");
+    assert_eq!(history.len(), statistics.generations);
+    assert_eq!(history.first().map(|(generation, _)| *generation), Some(1));
+    assert!(history.windows(2).all(|pair| compare_floats(pair[1].1, pair[0].1) != Ordering::Greater));
+}
+
+#[test]
+fn can_skip_best_cost_history_by_default() {
+    let (problem, _) = generate_matrix_routes(2, 2);
+
+    let (_, _, statistics) = Builder::default()
+        .with_problem(Arc::new(problem))
+        .with_max_generations(Some(3))
+        .build()
+        .unwrap()
+        .solve_with_statistics()
+        .unwrap();
+
+    assert!(statistics.best_cost_history.is_none());
+}
+
+#[test]
+fn can_solve_with_waiting_reduction_in_default_mutation() {
+    let (problem, _) = generate_matrix_routes(3, 3);
+
+    let solver = Builder::default()
+        .with_problem(Arc::new(problem))
+        .with_max_generations(Some(20))
+        .with_seed(Some(0))
+        .build()
+        .unwrap();
+    assert_eq!(solver.settings.mutation_weights, vec![100, 10]);
+
+    let (solution, _) = solver.solve().unwrap();
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.routes.iter().map(|route| route.tour.job_count()).sum::<usize>(), 9);
+}
+
+parameterized_test! {can_estimate_objective_when_evolution_starts, (objective, expected), {
+    can_estimate_objective_when_evolution_starts_impl(objective, expected);
+}}
+
+can_estimate_objective_when_evolution_starts! {
+    case01_non_zero_reference: (Box::new(TotalTransportCost::default()), None),
+    case02_zero_reference: (Box::new(TotalUnassignedJobs::default()),
+                            Some("cannot use zero as estimated reference of 'TotalUnassignedJobs' objective")),
+}
+
+fn can_estimate_objective_when_evolution_starts_impl(objective: TargetObjective, expected: Option<&str>) {
+    let (problem, _) = generate_matrix_routes(2, 2);
+    let objective = WeightedObjectiveCost::new(vec![(objective, 1.)])
+        .with_normalizations(vec![Some(ObjectiveNormalization::Estimated)]);
+    let problem = Problem { objective: Arc::new(objective), ..problem };
+
+    let result =
+        Builder::default().with_problem(Arc::new(problem)).with_max_generations(Some(3)).build().unwrap().solve();
+
+    assert_eq!(result.err(), expected.map(|err| err.to_string()));
+}
+
+fn get_route_job_ids(solution: &Solution) -> Vec<(String, Vec<String>)> {
+    let mut routes = solution
+        .routes
+        .iter()
+        .map(|route| {
+            let vehicle_id = route.actor.vehicle.dimens.get_id().cloned().unwrap();
+            let job_ids = route
+                .tour
+                .all_activities()
+                .filter_map(|activity| activity.retrieve_job())
+                .filter_map(|job| job.dimens().get_id().cloned())
+                .collect::<Vec<_>>();
+
+            (vehicle_id, job_ids)
+        })
+        .collect::<Vec<_>>();
+    routes.sort();
+
+    routes
+}
+
+#[test]
+fn can_reproduce_result_with_reported_seed() {
+    // NOTE vehicles are identical and matrix is symmetric, so there are many solutions with the
+    // same cost which differ in vehicle assignment and visiting order
+    let problem = Arc::new(generate_matrix_routes(5, 5).0);
+    let solve = |seed: Option<u64>| {
+        Builder::default()
+            .with_problem(problem.clone())
+            .with_max_generations(Some(50))
+            .with_seed(seed)
+            .build()
+            .unwrap()
+            .solve_with_statistics()
+            .unwrap()
+    };
+
+    let (solution, cost, statistics) = solve(None);
+    let seed = statistics.seed.expect("no seed reported");
+    let (reproduced_solution, reproduced_cost, reproduced_statistics) = solve(Some(seed));
+
+    assert_eq!(reproduced_statistics.seed, Some(seed));
+    assert_eq!(compare_floats(cost, reproduced_cost), Ordering::Equal);
+    assert_eq!(get_route_job_ids(&solution), get_route_job_ids(&reproduced_solution));
+}
+
+#[test]
+fn can_record_population_fitness() {
+    let problem = Arc::new(generate_matrix_routes(5, 5).0);
+    let objective_count = problem.objective.objectives().count();
+
+    let (_, cost, statistics) = Builder::default()
+        .with_problem(problem)
+        .with_max_generations(Some(10))
+        .with_population_fitness(true)
+        .build()
+        .unwrap()
+        .solve_with_statistics()
+        .unwrap();
+
+    let population_fitness = statistics.population_fitness.expect("no population fitness");
+    assert!(!population_fitness.is_empty());
+    assert!(population_fitness.iter().all(|values| values.len() == objective_count));
+    assert!(population_fitness.iter().flatten().all(|value| value.is_finite()));
+    assert!(statistics.best_cost_history.is_none());
+    assert!(cost.is_finite());
+}
+
+#[test]
+fn can_return_pareto_front_of_non_dominated_solutions() {
+    let (problem, _) = generate_matrix_routes(5, 5);
+    let problem = Arc::new(Problem {
+        objective: Arc::new(ObjectiveCost::new(
+            vec![Box::new(TotalTransportCost::default()), Box::new(DurationSpread::default())],
+            vec![],
+        )),
+        ..problem
+    });
+    let dominates = |a: &Vec<f64>, b: &Vec<f64>| {
+        a.iter().zip(b.iter()).all(|(a, b)| compare_floats(*a, *b) != Ordering::Greater)
+            && a.iter().zip(b.iter()).any(|(a, b)| compare_floats(*a, *b) == Ordering::Less)
+    };
+
+    let (_, _, statistics) = Builder::default()
+        .with_problem(problem)
+        .with_max_generations(Some(100))
+        .with_seed(Some(1))
+        .with_population_fitness(true)
+        .with_pareto_front(Some(10))
+        .build()
+        .unwrap()
+        .solve_with_statistics()
+        .unwrap();
+
+    let pareto_front = statistics.pareto_front.expect("no pareto front");
+    let population_fitness = statistics.population_fitness.unwrap();
+    assert!(!pareto_front.is_empty() && pareto_front.len() <= 10);
+    pareto_front.iter().for_each(|(solution, fitness)| {
+        assert_eq!(fitness.len(), 2);
+        assert!(solution.unassigned.is_empty());
+        assert!(pareto_front.iter().all(|(_, other)| !dominates(other, fitness)));
+        assert!(population_fitness.iter().all(|other| !dominates(other, fitness)));
+    });
+}
+
+#[test]
+fn can_skip_pareto_front_by_default() {
+    let (problem, _) = generate_matrix_routes(2, 2);
+
+    let (_, _, statistics) = Builder::default()
+        .with_problem(Arc::new(problem))
+        .with_max_generations(Some(3))
+        .build()
+        .unwrap()
+        .solve_with_statistics()
+        .unwrap();
+
+    assert!(statistics.pareto_front.is_none());
+}
+
+#[test]
+fn can_return_best_known_solution_when_time_is_over() {
+    let (problem, _) = generate_matrix_routes(3, 3);
+
+    let (solution, _, statistics) = Builder::default()
+        .with_problem(Arc::new(problem))
+        .with_max_time(Some(0))
+        .build()
+        .unwrap()
+        .solve_with_statistics()
+        .unwrap();
+
+    let assigned = solution.routes.iter().map(|route| route.tour.job_count()).sum::<usize>();
+    assert_eq!(statistics.generations, 0);
+    assert_eq!(assigned + solution.unassigned.len(), 9);
+}
+
+parameterized_test! {can_validate_population_size, (population_size, elite_size, expected), {
+    can_validate_population_size_impl(population_size, elite_size, expected);
+}}
+
+can_validate_population_size! {
+    case01_too_small: (1, 0, Some("population size should be greater than 1")),
+    case02_elite_equal: (2, 2, Some("elite size should be less than population size")),
+    case03_valid: (8, 2, None),
+}
+
+fn can_validate_population_size_impl(population_size: usize, elite_size: usize, expected: Option<&str>) {
+    let (problem, _) = generate_matrix_routes(2, 2);
+
+    let result = Builder::default()
+        .with_problem(Arc::new(problem))
+        .with_population_size(population_size)
+        .with_elite_size(elite_size)
+        .with_initial_size(1)
+        .build();
+
+    assert_eq!(result.err(), expected.map(|err| err.to_string()));
+}
+
+#[test]
+fn can_return_alternative_solutions() {
+    let problem = Arc::new(generate_matrix_routes(5, 5).0);
+    let create_solver = || {
+        Builder::default()
+            .with_problem(problem.clone())
+            .with_max_generations(Some(50))
+            .with_population_size(8)
+            .with_seed(Some(42))
+            .build()
+            .unwrap()
+    };
+
+    let (solutions, _) = create_solver().solve_with_alternatives().unwrap();
+    let (_, best_cost, _) = create_solver().solve_with_statistics().unwrap();
+
+    assert!(solutions.len() > 1);
+    assert_eq!(solutions.first().map(|(_, cost)| compare_floats(*cost, best_cost)), Some(Ordering::Equal));
+}
+
+parameterized_test! {can_reject_near_identical_individuals_during_solve, (diversity, expected), {
+    can_reject_near_identical_individuals_during_solve_impl(diversity, expected);
+}}
+
+can_reject_near_identical_individuals_during_solve! {
+    case01_no_diversity: (None, 2),
+    case02_with_diversity: (Some(0.5), 1),
+}
+
+fn can_reject_near_identical_individuals_during_solve_impl(diversity: Option<f64>, expected: usize) {
+    let (problem, solution) = generate_matrix_routes(5, 5);
+    // NOTE a cheaper solution which differs from the original one only by one job moved to another
+    // route, so the original solution is its near identical, but worse version
+    let mut better = Solution {
+        registry: solution.registry.deep_copy(),
+        routes: solution.routes.iter().map(|route| route.deep_copy()).collect(),
+        unassigned: Default::default(),
+        extras: solution.extras.clone(),
+    };
+    let activity = better.routes[0].tour.get(5).unwrap().deep_copy();
+    better.routes[0].tour.remove_activity_at(5);
+    better.routes[1].tour.insert_last(Box::new(activity));
+
+    let (solutions, _) = Builder::default()
+        .with_problem(Arc::new(problem))
+        .with_solutions(vec![Arc::new(better), Arc::new(solution)])
+        .with_diversity(diversity)
+        .with_max_time(Some(0))
+        .build()
+        .unwrap()
+        .solve_with_alternatives()
+        .unwrap();
+
+    assert_eq!(solutions.len(), expected);
+}
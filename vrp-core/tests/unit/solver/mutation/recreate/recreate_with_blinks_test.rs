@@ -1,10 +1,10 @@
-use super::DemandJobSelector;
-use crate::construction::heuristics::JobSelector;
+use super::*;
 use crate::helpers::construction::constraints::create_simple_demand;
 use crate::helpers::construction::heuristics::create_test_insertion_context;
-use crate::helpers::models::problem::test_single_with_simple_demand;
-use crate::helpers::models::solution::create_test_registry;
-use crate::models::problem::Job;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::{Cost, IdDimension};
+use crate::models::problem::Fleet;
 
 parameterized_test! {can_sort_jobs_by_demand, (demands, is_asc_order, expected), {
         can_sort_jobs_by_demand_impl(demands, is_asc_order, expected);
@@ -29,3 +29,44 @@ fn can_sort_jobs_by_demand_impl(demands: Vec<i32>, is_asc_order: bool, expected:
 
     assert_eq!(result, expected);
 }
+
+fn create_success(fleet: &Fleet, job_id: &str, cost: Cost) -> InsertionResult {
+    let job = test_single_with_id(job_id);
+
+    InsertionResult::make_success(
+        cost,
+        Job::Single(job.clone()),
+        vec![(test_tour_activity_with_job(job), 0)],
+        RouteContext::new(get_test_actor_from_fleet(fleet, "v1")),
+    )
+}
+
+#[test]
+fn can_select_best_result_without_blinks() {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let insertion_ctx = create_test_insertion_context(create_test_registry());
+    let selector = BlinkResultSelector::new(0.);
+
+    (0..100).for_each(|_| {
+        match selector.select(&insertion_ctx, create_success(&fleet, "job1", 10.), create_success(&fleet, "job2", 5.)) {
+            InsertionResult::Success(success) => assert_eq!(success.job.dimens().get_id().unwrap(), "job2"),
+            InsertionResult::Failure(_) => unreachable!(),
+        }
+    });
+}
+
+parameterized_test! {can_reject_invalid_blink_ratio, ratio, {
+    can_reject_invalid_blink_ratio_impl(ratio);
+}}
+
+can_reject_invalid_blink_ratio! {
+    case01_negative: -0.1,
+    case02_one: 1.,
+    case03_greater_than_one: 1.5,
+}
+
+fn can_reject_invalid_blink_ratio_impl(ratio: f64) {
+    let result = std::panic::catch_unwind(|| RecreateWithBlinks::<i32>::new(ratio));
+
+    assert!(result.is_err());
+}
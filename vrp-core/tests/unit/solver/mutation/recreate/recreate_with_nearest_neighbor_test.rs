@@ -0,0 +1,70 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::models::domain::get_customer_ids_from_routes_sorted;
+use crate::helpers::models::problem::*;
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::models::problem::{Jobs, ObjectiveCost, VehicleDetail};
+use crate::models::Problem;
+use crate::utils::DefaultRandom;
+use std::sync::Arc;
+
+fn create_problem(depot: Location, locations: Vec<Location>) -> Problem {
+    let fleet = Arc::new(
+        FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles(
+                vec!["v1", "v2"]
+                    .into_iter()
+                    .map(|id| {
+                        VehicleBuilder::default()
+                            .id(id)
+                            .details(vec![VehicleDetail {
+                                start: Some(depot),
+                                end: Some(depot),
+                                ..test_vehicle_detail()
+                            }])
+                            .build()
+                    })
+                    .collect(),
+            )
+            .build(),
+    );
+    let jobs = locations
+        .into_iter()
+        .map(|location| Job::Single(test_single_with_id_and_location(&format!("c{}", location), Some(location))))
+        .collect();
+    let transport = TestTransportCost::new_shared();
+    let jobs = Jobs::new(&fleet, jobs, &transport);
+
+    Problem {
+        fleet,
+        jobs: Arc::new(jobs),
+        locks: vec![],
+        constraint: Arc::new(create_constraint_pipeline_with_transport()),
+        activity: Arc::new(TestActivityCost::default()),
+        transport,
+        objective: Arc::new(ObjectiveCost::default()),
+        extras: Arc::new(Default::default()),
+    }
+}
+
+parameterized_test! {can_append_nearest_jobs, (depot, locations, expected), {
+    can_append_nearest_jobs_impl(depot, locations, expected);
+}}
+
+can_append_nearest_jobs! {
+    case01_one_side_of_depot: (0, vec![5, 1, 3, 2, 4], vec![vec!["c1", "c2", "c3", "c4", "c5"]]),
+    case02_both_sides_of_depot: (5, vec![1, 7, 2, 9], vec![vec!["c7", "c9", "c2", "c1"]]),
+}
+
+fn can_append_nearest_jobs_impl(depot: Location, locations: Vec<Location>, expected: Vec<Vec<&str>>) {
+    let problem = Arc::new(create_problem(depot, locations));
+    let insertion_ctx = InsertionContext::new(problem.clone(), Arc::new(DefaultRandom::default()));
+
+    let insertion_ctx =
+        RecreateWithNearestNeighbor::default().run(&mut create_default_refinement_ctx(problem), insertion_ctx);
+
+    assert!(insertion_ctx.solution.required.is_empty());
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+    assert_eq!(get_customer_ids_from_routes_sorted(&insertion_ctx), expected);
+}
@@ -0,0 +1,53 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::construction::heuristics::create_insertion_context;
+use crate::helpers::models::problem::*;
+use crate::models::common::{IdDimension, Location};
+use crate::models::problem::{Vehicle, VehicleDetail};
+use crate::models::solution::Registry;
+
+fn create_vehicle(id: &str, depot: Location) -> Vehicle {
+    VehicleBuilder::default()
+        .id(id)
+        .details(vec![VehicleDetail { start: Some(depot), end: Some(depot), ..test_vehicle_detail() }])
+        .build()
+}
+
+parameterized_test! {can_select_job_with_largest_regret, (job_locations, expected), {
+    can_select_job_with_largest_regret_impl(job_locations, expected);
+}}
+
+can_select_job_with_largest_regret! {
+    case01_cheapest_has_no_regret: (vec![("job1", 2), ("job2", 7)], "job2"),
+    case02_both_have_regret: (vec![("job1", 3), ("job2", 7)], "job2"),
+    case03_same_regret_prefers_cheapest: (vec![("job1", 5), ("job2", 0)], "job2"),
+    case04_single_job: (vec![("job1", 2)], "job1"),
+}
+
+fn can_select_job_with_largest_regret_impl(job_locations: Vec<(&str, Location)>, expected: &str) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(create_vehicle("v1", 0))
+        .add_vehicle(create_vehicle("v2", 4))
+        .build();
+    let mut registry = Registry::new(&fleet);
+    let routes = vec![get_test_actor_from_fleet(&fleet, "v1"), get_test_actor_from_fleet(&fleet, "v2")]
+        .into_iter()
+        .map(|actor| {
+            registry.use_actor(&actor);
+            RouteContext::new(actor)
+        })
+        .collect();
+    let ctx = create_insertion_context(registry, create_constraint_pipeline_with_transport(), routes);
+    let jobs = job_locations
+        .into_iter()
+        .map(|(id, location)| Job::Single(test_single_with_id_and_location(id, Some(location))))
+        .collect();
+
+    let result = KRegretJobMapReducer::new(2).reduce(&ctx, jobs, Box::new(|_| InsertionResult::make_failure()));
+
+    match result {
+        InsertionResult::Success(success) => assert_eq!(success.job.dimens().get_id().unwrap(), expected),
+        InsertionResult::Failure(_) => unreachable!(),
+    }
+}
@@ -0,0 +1,92 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::models::domain::get_customer_ids_from_routes_sorted;
+use crate::helpers::models::problem::*;
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::models::common::IdDimension;
+use crate::models::problem::{Jobs, ObjectiveCost, VehicleDetail};
+use crate::models::Problem;
+use crate::utils::DefaultRandom;
+use std::sync::Arc;
+
+fn create_problem(depot: Location, locations: Vec<Location>) -> Problem {
+    let fleet = Arc::new(
+        FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles(
+                vec!["v1", "v2"]
+                    .into_iter()
+                    .map(|id| {
+                        VehicleBuilder::default()
+                            .id(id)
+                            .details(vec![VehicleDetail {
+                                start: Some(depot),
+                                end: Some(depot),
+                                ..test_vehicle_detail()
+                            }])
+                            .build()
+                    })
+                    .collect(),
+            )
+            .build(),
+    );
+    let jobs = locations
+        .into_iter()
+        .map(|location| Job::Single(test_single_with_id_and_location(&format!("c{}", location), Some(location))))
+        .collect();
+    let transport = TestTransportCost::new_shared();
+    let jobs = Jobs::new(&fleet, jobs, &transport);
+
+    Problem {
+        fleet,
+        jobs: Arc::new(jobs),
+        locks: vec![],
+        constraint: Arc::new(create_constraint_pipeline_with_transport()),
+        activity: Arc::new(TestActivityCost::default()),
+        transport,
+        objective: Arc::new(ObjectiveCost::default()),
+        extras: Arc::new(Default::default()),
+    }
+}
+
+parameterized_test! {can_build_routes_using_savings, (depot, locations, expected), {
+    can_build_routes_using_savings_impl(depot, locations, expected);
+}}
+
+can_build_routes_using_savings! {
+    case01_one_side_of_depot: (0, vec![3, 1, 5, 2, 4], vec![vec!["c1", "c2", "c3", "c4", "c5"]]),
+    case02_both_sides_of_depot: (5, vec![1, 8, 2, 9], vec![vec!["c1", "c2"], vec!["c8", "c9"]]),
+    case03_job_at_depot: (0, vec![0, 2, 3], vec![vec!["c0"], vec!["c2", "c3"]]),
+    case04_fallback_when_no_vehicles: (0, vec![0, 0, 0], vec![vec!["c0"], vec!["c0", "c0"]]),
+}
+
+fn can_build_routes_using_savings_impl(depot: Location, locations: Vec<Location>, expected: Vec<Vec<&str>>) {
+    let problem = Arc::new(create_problem(depot, locations));
+    let insertion_ctx = InsertionContext::new(problem.clone(), Arc::new(DefaultRandom::default()));
+
+    let insertion_ctx = RecreateWithSavings::default().run(&mut create_default_refinement_ctx(problem), insertion_ctx);
+
+    assert!(insertion_ctx.solution.required.is_empty());
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+    let routes = get_customer_ids_from_routes_sorted(&insertion_ctx)
+        .into_iter()
+        .map(|mut route| {
+            route.sort();
+            route
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(routes, expected);
+}
+
+#[test]
+fn can_chain_jobs_in_savings_order() {
+    let problem = create_problem(5, vec![1, 8, 2, 9]);
+    let insertion_ctx = InsertionContext::new(Arc::new(problem), Arc::new(DefaultRandom::default()));
+
+    let chains = get_savings_chains(&insertion_ctx)
+        .iter()
+        .map(|chain| chain.iter().map(|job| job.dimens().get_id().unwrap().clone()).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    assert_eq!(chains, vec![vec!["c1", "c2"], vec!["c8", "c9"]]);
+}
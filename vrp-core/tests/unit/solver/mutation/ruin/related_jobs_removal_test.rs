@@ -0,0 +1,126 @@
+use super::*;
+use crate::helpers::construction::constraints::{create_constraint_pipeline_with_transport, create_simple_demand};
+use crate::helpers::models::domain::get_sorted_customer_ids_from_jobs;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::helpers::utils::random::FakeRandom;
+use crate::models::common::{IdDimension, Location};
+use crate::models::problem::{Jobs, ObjectiveCost};
+use crate::models::solution::Registry;
+use crate::models::{Problem, Solution};
+
+fn create_problem_and_solution(routes: Vec<Vec<(Location, i32)>>) -> (Problem, Solution) {
+    let fleet = Arc::new(
+        FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles((0..routes.len()).map(|idx| test_vehicle_with_id(&format!("v{}", idx))).collect())
+            .build(),
+    );
+
+    let mut jobs = vec![];
+    let routes = routes
+        .into_iter()
+        .enumerate()
+        .map(|(idx, locations)| {
+            let activities = locations
+                .into_iter()
+                .map(|(location, demand)| {
+                    let mut single = test_single_with_id_and_location(&format!("c{}", location), Some(location));
+                    Arc::get_mut(&mut single).unwrap().dimens.set_demand(create_simple_demand(demand));
+                    jobs.push(Job::Single(single.clone()));
+
+                    let mut activity = test_tour_activity_with_job(single);
+                    activity.place.location = location;
+
+                    activity
+                })
+                .collect();
+
+            create_route_with_activities(&fleet, &format!("v{}", idx), activities)
+        })
+        .collect();
+
+    let transport = TestTransportCost::new_shared();
+    let jobs = Jobs::new(&fleet, jobs, &transport);
+    let registry = Registry::new(&fleet);
+
+    let problem = Problem {
+        fleet,
+        jobs: Arc::new(jobs),
+        locks: vec![],
+        constraint: Arc::new(create_constraint_pipeline_with_transport()),
+        activity: Arc::new(TestActivityCost::default()),
+        transport,
+        objective: Arc::new(ObjectiveCost::default()),
+        extras: Arc::new(Default::default()),
+    };
+    let solution = Solution { registry, routes, unassigned: Default::default(), extras: Arc::new(Default::default()) };
+
+    (problem, solution)
+}
+
+parameterized_test! {can_remove_related_jobs, (routes, weights, ints, expected_ids), {
+    can_remove_related_jobs_impl(routes, weights, ints, expected_ids);
+}}
+
+can_remove_related_jobs! {
+    case01_close_jobs_in_different_routes: (
+        vec![vec![(1, 1), (10, 1), (2, 1)], vec![(11, 1), (3, 1), (12, 1)]],
+        (1., 0., 0.), vec![3, 0, 1], vec!["c1", "c2", "c3"]
+    ),
+    case02_close_jobs_of_other_cluster: (
+        vec![vec![(1, 1), (10, 1), (2, 1)], vec![(11, 1), (3, 1), (12, 1)]],
+        (1., 0., 0.), vec![3, 1, 1], vec!["c10", "c11", "c12"]
+    ),
+    case03_similar_demand: (
+        vec![vec![(1, 1), (10, 5), (2, 1)], vec![(11, 5), (3, 1), (12, 5)]],
+        (0., 0., 1.), vec![3, 0, 2], vec!["c10", "c11", "c12"]
+    ),
+}
+
+fn can_remove_related_jobs_impl(
+    routes: Vec<Vec<(Location, i32)>>,
+    weights: (f64, f64, f64),
+    ints: Vec<i32>,
+    expected_ids: Vec<&str>,
+) {
+    let (problem, solution) = create_problem_and_solution(routes);
+    let insertion_ctx = InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (Arc::new(solution), None),
+        Arc::new(FakeRandom::new(ints, vec![])),
+    );
+
+    let insertion_ctx = RelatedJobRemoval::<i32>::new(3, 3, 1., weights.0, weights.1, weights.2)
+        .run(&mut create_default_refinement_ctx(insertion_ctx.problem.clone()), insertion_ctx);
+
+    assert_eq!(get_sorted_customer_ids_from_jobs(&insertion_ctx.solution.required), expected_ids);
+    assert_eq!(
+        insertion_ctx
+            .solution
+            .routes
+            .iter()
+            .flat_map(|rc| rc.route.tour.jobs())
+            .filter(|job| expected_ids.contains(&job.dimens().get_id().unwrap().as_str()))
+            .count(),
+        0
+    );
+}
+
+parameterized_test! {can_calculate_time_window_overlap, (lhs, rhs, expected), {
+    can_calculate_time_window_overlap_impl(lhs, rhs, expected);
+}}
+
+can_calculate_time_window_overlap! {
+    case01_same: ((0., 10.), (0., 10.), 1.),
+    case02_inner: ((0., 10.), (2., 4.), 1.),
+    case03_half: ((0., 10.), (5., 15.), 0.5),
+    case04_disjoint: ((0., 10.), (11., 15.), 0.),
+}
+
+fn can_calculate_time_window_overlap_impl(lhs: (f64, f64), rhs: (f64, f64), expected: f64) {
+    let result = get_overlap_ratio(&TimeWindow::new(lhs.0, lhs.1), &TimeWindow::new(rhs.0, rhs.1));
+
+    assert_eq!(result, expected);
+}
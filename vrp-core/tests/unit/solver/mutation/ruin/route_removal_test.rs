@@ -0,0 +1,64 @@
+use super::*;
+use crate::helpers::models::domain::*;
+use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes};
+use crate::helpers::utils::random::FakeRandom;
+use crate::models::{Lock, LockDetail, LockOrder, LockPosition, Problem};
+use std::sync::Arc;
+
+fn create_insertion_context(locked_ids: Vec<&str>) -> InsertionContext {
+    let (problem, mut solution) = generate_matrix_routes(4, 3);
+
+    // NOTE make routes of different size: 4, 2 and 3 jobs
+    solution.routes.iter_mut().for_each(|route| {
+        route.tour.jobs().collect::<Vec<_>>().into_iter().for_each(|job| {
+            if ["c5", "c6", "c8"].contains(&get_customer_id(&job).as_str()) {
+                route.tour.remove(&job);
+            }
+        })
+    });
+
+    let problem = Problem {
+        locks: vec![Arc::new(Lock {
+            condition: Arc::new(|_| false),
+            details: vec![LockDetail {
+                order: LockOrder::Any,
+                position: LockPosition::Any,
+                jobs: problem.jobs.all().filter(|job| locked_ids.contains(&get_customer_id(job).as_str())).collect(),
+            }],
+        })],
+        ..problem
+    };
+
+    InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (Arc::new(solution), None),
+        Arc::new(FakeRandom::new(vec![], vec![])),
+    )
+}
+
+parameterized_test! {can_remove_smallest_routes, (routes, locked_ids, expected_removed, expected_routes), {
+    can_remove_smallest_routes_impl(routes, locked_ids, expected_removed, expected_routes);
+}}
+
+can_remove_smallest_routes! {
+    case01_one_route: (1, vec![], vec!["c4", "c7"], vec![vec!["c0", "c1", "c2", "c3"], vec!["c9", "c10", "c11"]]),
+    case02_two_routes: (2, vec![], vec!["c10", "c11", "c4", "c7", "c9"], vec![vec!["c0", "c1", "c2", "c3"]]),
+    case03_too_many_routes: (5, vec![], vec!["c0", "c1", "c10", "c11", "c2", "c3", "c4", "c7", "c9"], vec![]),
+    case04_partially_locked: (1, vec!["c4"], vec!["c7"], vec![vec!["c0", "c1", "c2", "c3"], vec!["c4"], vec!["c9", "c10", "c11"]]),
+    case05_fully_locked: (1, vec!["c4", "c7"], vec!["c10", "c11", "c9"], vec![vec!["c0", "c1", "c2", "c3"], vec!["c4", "c7"]]),
+}
+
+fn can_remove_smallest_routes_impl(
+    routes: usize,
+    locked_ids: Vec<&str>,
+    expected_removed: Vec<&str>,
+    expected_routes: Vec<Vec<&str>>,
+) {
+    let insertion_ctx = create_insertion_context(locked_ids);
+
+    let insertion_ctx =
+        RouteRemoval::new(routes).run(&mut create_default_refinement_ctx(insertion_ctx.problem.clone()), insertion_ctx);
+
+    assert_eq!(get_sorted_customer_ids_from_jobs(&insertion_ctx.solution.required), expected_removed);
+    assert_eq!(get_customer_ids_from_routes_sorted(&insertion_ctx), expected_routes);
+}
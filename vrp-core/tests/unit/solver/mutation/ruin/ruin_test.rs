@@ -0,0 +1,68 @@
+use super::*;
+use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes};
+use crate::helpers::utils::random::FakeRandom;
+
+fn create_insertion_context(ints: Vec<i32>) -> InsertionContext {
+    let (problem, solution) = generate_matrix_routes(4, 3);
+
+    InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (Arc::new(solution), None),
+        Arc::new(FakeRandom::new(ints, vec![])),
+    )
+}
+
+parameterized_test! {can_get_chunk_size_from_limits, (min, max, ratio, assigned, expected), {
+    can_get_chunk_size_from_limits_impl(min, max, ratio, assigned, expected);
+}}
+
+can_get_chunk_size_from_limits! {
+    case01_ratio: (1, 100, 0.1, 200, 20),
+    case02_min: (5, 100, 0.1, 20, 5),
+    case03_max: (1, 10, 0.1, 500, 10),
+    case04_assigned: (5, 10, 0.1, 3, 3),
+    case05_round: (1, 100, 0.1, 25, 3),
+}
+
+fn can_get_chunk_size_from_limits_impl(min: usize, max: usize, ratio: f64, assigned: usize, expected: usize) {
+    let limits = RuinLimits::new(min, max, ratio);
+
+    assert_eq!(limits.get_chunk_size(assigned), expected);
+}
+
+parameterized_test! {can_reject_invalid_limits, (min, max, ratio), {
+    can_reject_invalid_limits_impl(min, max, ratio);
+}}
+
+can_reject_invalid_limits! {
+    case01_min_greater_max: (10, 5, 0.1),
+    case02_zero_ratio: (1, 5, 0.),
+    case03_ratio_greater_one: (1, 5, 1.5),
+}
+
+fn can_reject_invalid_limits_impl(min: usize, max: usize, ratio: f64) {
+    let result = std::panic::catch_unwind(|| RuinLimits::new(min, max, ratio));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn can_use_limits_instead_of_range_in_chunk_size() {
+    // NOTE no random values are provided, so range is not used
+    let insertion_ctx = create_insertion_context(vec![]);
+    let limits = RuinLimits::new(1, 10, 0.25);
+
+    assert_eq!(get_chunk_size(&insertion_ctx, &(5, 10), 0.5, Some(&limits)), 3);
+}
+
+#[test]
+fn can_remove_jobs_with_limits() {
+    let limits = RuinLimits::new(1, 10, 0.25);
+    let insertion_ctx = create_insertion_context(vec![0, 1]);
+
+    let insertion_ctx = NeighbourRemoval::new(10, 10, 1.)
+        .with_limits(Some(limits))
+        .run(&mut create_default_refinement_ctx(insertion_ctx.problem.clone()), insertion_ctx);
+
+    assert_eq!(insertion_ctx.solution.required.len(), 3);
+}
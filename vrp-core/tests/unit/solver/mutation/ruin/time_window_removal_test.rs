@@ -0,0 +1,87 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::models::domain::{get_customer_ids_from_routes, get_sorted_customer_ids_from_jobs};
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::helpers::utils::random::FakeRandom;
+use crate::models::common::Location;
+use crate::models::problem::{Jobs, ObjectiveCost};
+use crate::models::solution::Registry;
+use crate::models::{Problem, Solution};
+use std::sync::Arc;
+
+fn create_insertion_context(routes: Vec<Vec<Location>>, reals: Vec<f64>) -> InsertionContext {
+    let fleet = Arc::new(
+        FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles((0..routes.len()).map(|idx| test_vehicle_with_id(&format!("v{}", idx))).collect())
+            .build(),
+    );
+
+    let mut jobs = vec![];
+    let routes = routes
+        .into_iter()
+        .enumerate()
+        .map(|(idx, locations)| {
+            let activities = locations
+                .into_iter()
+                .map(|location| {
+                    let single = test_single_with_id_and_location(&format!("c{}", location), Some(location));
+                    jobs.push(Job::Single(single.clone()));
+
+                    let mut activity = test_tour_activity_with_job(single);
+                    activity.place.location = location;
+
+                    activity
+                })
+                .collect();
+
+            create_route_with_activities(&fleet, &format!("v{}", idx), activities)
+        })
+        .collect();
+
+    let transport = TestTransportCost::new_shared();
+    let jobs = Jobs::new(&fleet, jobs, &transport);
+    let registry = Registry::new(&fleet);
+
+    let problem = Problem {
+        fleet,
+        jobs: Arc::new(jobs),
+        locks: vec![],
+        constraint: Arc::new(create_constraint_pipeline_with_transport()),
+        activity: Arc::new(TestActivityCost::default()),
+        transport,
+        objective: Arc::new(ObjectiveCost::default()),
+        extras: Arc::new(Default::default()),
+    };
+    let solution = Solution { registry, routes, unassigned: Default::default(), extras: Arc::new(Default::default()) };
+
+    InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (Arc::new(solution), None),
+        Arc::new(FakeRandom::new(vec![], reals)),
+    )
+}
+
+parameterized_test! {can_remove_jobs_in_time_window, (reals, expected_removed, expected_routes), {
+    can_remove_jobs_in_time_window_impl(reals, expected_removed, expected_routes);
+}}
+
+can_remove_jobs_in_time_window! {
+    case01_part_of_route: (vec![0.1, 0.5], vec!["c30"], vec![vec!["c10", "c20"], vec!["c5", "c15"]]),
+    case02_no_jobs: (vec![0.05, 0.6], vec![], vec![vec!["c10", "c20", "c30"], vec!["c5", "c15"]]),
+    case03_entire_route: (vec![0.25, 0.], vec!["c10", "c15", "c5"], vec![vec!["c20", "c30"], vec![]]),
+    case04_entire_horizon: (vec![1., 0.], vec!["c10", "c15", "c20", "c30", "c5"], vec![vec![], vec![]]),
+}
+
+fn can_remove_jobs_in_time_window_impl(reals: Vec<f64>, expected_removed: Vec<&str>, expected_routes: Vec<Vec<&str>>) {
+    // NOTE planning horizon is [0, 60] as v0 returns back to depot at 60
+    let insertion_ctx = create_insertion_context(vec![vec![10, 20, 30], vec![5, 15]], reals);
+
+    let insertion_ctx = TimeWindowRemoval::new(0., 1.)
+        .run(&mut create_default_refinement_ctx(insertion_ctx.problem.clone()), insertion_ctx);
+
+    assert_eq!(get_sorted_customer_ids_from_jobs(&insertion_ctx.solution.required), expected_removed);
+    assert_eq!(get_customer_ids_from_routes(&insertion_ctx), expected_routes);
+}
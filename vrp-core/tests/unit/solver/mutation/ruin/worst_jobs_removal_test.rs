@@ -1,10 +1,14 @@
 use std::sync::Arc;
 
-use super::{Ruin, WorstJobRemoval};
+use super::{get_routes_cost_savings, Ruin, WorstJobRemoval};
 use crate::construction::heuristics::InsertionContext;
-use crate::helpers::models::domain::get_sorted_customer_ids_from_jobs;
+use crate::helpers::models::domain::{create_empty_insertion_context, get_sorted_customer_ids_from_jobs};
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
 use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes};
 use crate::helpers::utils::random::FakeRandom;
+use crate::models::common::{IdDimension, Location};
+use crate::models::problem::VehicleDetail;
 
 parameterized_test! {can_ruin_solution_with_matrix_routes, (matrix, ints, expected_ids), {
     can_ruin_solution_with_matrix_routes_impl(matrix, ints, expected_ids);
@@ -30,3 +34,66 @@ fn can_ruin_solution_with_matrix_routes_impl(matrix: (usize, usize), ints: Vec<i
 
     assert_eq!(get_sorted_customer_ids_from_jobs(&insertion_ctx.solution.required), expected_ids);
 }
+
+parameterized_test! {can_calculate_job_savings_in_route, (end, locations, expected), {
+    can_calculate_job_savings_in_route_impl(end, locations, expected);
+}}
+
+can_calculate_job_savings_in_route! {
+    case01_closed_single_job: (Some(0), vec![5], vec![("c5", (0, 5, Some(0)))]),
+    case02_open_single_job: (None, vec![5], vec![("c5", (0, 5, None))]),
+    case03_open_last_job: (None, vec![3, 5], vec![("c3", (0, 3, Some(5))), ("c5", (3, 5, None))]),
+}
+
+fn can_calculate_job_savings_in_route_impl(
+    end: Option<Location>,
+    locations: Vec<Location>,
+    expected: Vec<(&str, (Location, Location, Option<Location>))>,
+) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(
+            VehicleBuilder::default()
+                .id("v1")
+                .details(vec![VehicleDetail { start: Some(0), end, ..test_vehicle_detail() }])
+                .build(),
+        )
+        .build();
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes.push(create_route_context_with_activities(
+        &fleet,
+        "v1",
+        locations
+            .into_iter()
+            .map(|location| {
+                let mut activity = test_tour_activity_with_job(test_single_with_id_and_location(
+                    &format!("c{}", location),
+                    Some(location),
+                ));
+                activity.place.location = location;
+
+                activity
+            })
+            .collect(),
+    ));
+    let actor = get_test_actor_from_fleet(&fleet, "v1");
+    let transport = insertion_ctx.problem.transport.clone();
+    let get_cost = |from: Location, to: Location| transport.cost(actor.as_ref(), actor.vehicle.profile, from, to, 0.);
+
+    let savings = get_routes_cost_savings(&insertion_ctx);
+
+    assert_eq!(savings.len(), 1);
+    let mut savings =
+        savings[0].1.iter().map(|(job, cost)| (job.dimens().get_id().unwrap().clone(), *cost)).collect::<Vec<_>>();
+    savings.sort_by(|(a, _), (b, _)| a.cmp(b));
+    let expected = expected
+        .into_iter()
+        .map(|(id, (prev, middle, next))| {
+            (
+                id.to_string(),
+                get_cost(prev, middle) + next.map_or(0., |next| get_cost(middle, next) - get_cost(prev, next)),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(savings, expected);
+}
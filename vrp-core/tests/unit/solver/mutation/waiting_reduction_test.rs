@@ -0,0 +1,91 @@
+use super::*;
+use crate::construction::heuristics::InsertionContext;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::construction::heuristics::create_insertion_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::models::common::{Location, TimeWindow};
+use crate::models::solution::Registry;
+use crate::solver::mutation::CompositeMutation;
+use std::rc::Rc;
+
+fn create_test_insertion_context(activities: Vec<(Location, (f64, f64))>) -> InsertionContext {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        activities
+            .into_iter()
+            .map(|(location, (start, end))| {
+                test_tour_activity_with_location_and_tw(location, TimeWindow::new(start, end))
+            })
+            .collect(),
+    );
+
+    let mut insertion_ctx =
+        create_insertion_context(Registry::new(&fleet), create_constraint_pipeline_with_transport(), vec![route_ctx]);
+    insertion_ctx.problem.constraint.accept_solution_state(&mut insertion_ctx.solution);
+
+    insertion_ctx
+}
+
+fn get_route_waiting(insertion_ctx: &InsertionContext) -> Duration {
+    insertion_ctx.solution.routes[0]
+        .route
+        .tour
+        .all_activities()
+        .map(|activity| (activity.place.time.start - activity.schedule.arrival).max(0.))
+        .sum()
+}
+
+fn get_route_locations(insertion_ctx: &InsertionContext) -> Vec<Location> {
+    insertion_ctx.solution.routes[0].route.tour.all_activities().map(|activity| activity.place.location).collect()
+}
+
+parameterized_test! {can_reduce_waiting_at_single_stop, (activities, threshold, expected_locations, expected_waiting), {
+    can_reduce_waiting_at_single_stop_impl(activities, threshold, expected_locations, expected_waiting);
+}}
+
+can_reduce_waiting_at_single_stop! {
+    case01_move_to_start: (vec![(10, (0., 1000.)), (20, (100., 200.)), (30, (0., 1000.))], 10., vec![0, 20, 10, 30, 0], 0.),
+    case02_below_threshold: (vec![(10, (0., 1000.)), (20, (100., 200.)), (30, (0., 1000.))], 100., vec![0, 10, 20, 30, 0], 80.),
+    case03_no_waiting: (vec![(10, (0., 1000.)), (20, (0., 1000.)), (30, (0., 1000.))], 0., vec![0, 10, 20, 30, 0], 0.),
+    case04_cost_increase: (vec![(5, (0., 1000.)), (20, (25., 1000.)), (10, (0., 1000.))], 0., vec![0, 5, 20, 10, 0], 5.),
+}
+
+fn can_reduce_waiting_at_single_stop_impl(
+    activities: Vec<(Location, (f64, f64))>,
+    threshold: Duration,
+    expected_locations: Vec<Location>,
+    expected_waiting: Duration,
+) {
+    let insertion_ctx = create_test_insertion_context(activities);
+    let original_waiting = get_route_waiting(&insertion_ctx);
+
+    let insertion_ctx = WaitingReduction::new(threshold)
+        .mutate(&mut create_default_refinement_ctx(insertion_ctx.problem.clone()), insertion_ctx);
+
+    assert_eq!(get_route_locations(&insertion_ctx), expected_locations);
+    assert_eq!(get_route_waiting(&insertion_ctx), expected_waiting);
+    assert!(get_route_waiting(&insertion_ctx) <= original_waiting);
+    insertion_ctx.solution.routes[0].route.tour.all_activities().for_each(|activity| {
+        assert!(activity.schedule.arrival <= activity.place.time.end);
+        assert!(activity.schedule.departure >= activity.place.time.start);
+    });
+}
+
+#[test]
+fn can_reduce_waiting_after_other_mutation_within_composite() {
+    let insertion_ctx = create_test_insertion_context(vec![(10, (0., 1000.)), (20, (100., 200.)), (30, (0., 1000.))]);
+    let mutation = CompositeMutation::new(vec![(
+        vec![(Rc::new(WaitingReduction::new(1000.)), 1.), (Rc::new(WaitingReduction::default()), 1.)],
+        1,
+    )]);
+
+    let insertion_ctx =
+        mutation.mutate(&mut create_default_refinement_ctx(insertion_ctx.problem.clone()), insertion_ctx);
+
+    assert_eq!(get_route_locations(&insertion_ctx), vec![0, 20, 10, 30, 0]);
+    assert_eq!(get_route_waiting(&insertion_ctx), 0.);
+}
@@ -0,0 +1,74 @@
+use super::*;
+use crate::construction::heuristics::{RouteContext, RouteState, SolutionContext};
+use crate::helpers::models::domain::{create_empty_insertion_context, create_empty_solution_context};
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::problem::ObjectiveCost;
+use std::sync::Arc;
+
+fn create_insertion_ctx(durations: Vec<f64>) -> InsertionContext {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(
+            (0..durations.len().max(1)).map(|idx| test_vehicle_with_id(format!("v{}", idx).as_str())).collect(),
+        )
+        .build();
+
+    let routes = durations
+        .into_iter()
+        .enumerate()
+        .map(|(idx, duration)| {
+            let mut state = RouteState::default();
+            state.put_route_state(TOTAL_DURATION_KEY, duration);
+
+            RouteContext {
+                state: Arc::new(state),
+                ..create_route_context_with_activities(&fleet, format!("v{}", idx).as_str(), vec![])
+            }
+        })
+        .collect();
+
+    InsertionContext {
+        solution: SolutionContext { routes, ..create_empty_solution_context() },
+        ..create_empty_insertion_context()
+    }
+}
+
+parameterized_test! {can_calculate_duration_spread, (durations, expected), {
+    can_calculate_duration_spread_impl(durations, expected);
+}}
+
+can_calculate_duration_spread! {
+    case01_no_routes: (vec![], 0.),
+    case02_single_route: (vec![100.], 0.),
+    case03_two_routes: (vec![100., 20.], 80.),
+    case04_three_routes: (vec![50., 90., 60.], 40.),
+}
+
+fn can_calculate_duration_spread_impl(durations: Vec<f64>, expected: f64) {
+    let insertion_ctx = create_insertion_ctx(durations);
+
+    assert_eq!(DurationSpread::default().fitness(&insertion_ctx), expected);
+}
+
+#[test]
+fn can_compare_solutions_with_different_route_count() {
+    let objective = DurationSpread::default();
+    let two_routes = create_insertion_ctx(vec![60., 40.]);
+    let three_routes = create_insertion_ctx(vec![30., 35., 35.]);
+
+    assert_eq!(objective.total_order(&three_routes, &two_routes), Ordering::Less);
+    assert_eq!(objective.distance(&two_routes, &three_routes), 15.);
+}
+
+#[test]
+fn can_be_used_as_secondary_objective() {
+    let objective =
+        ObjectiveCost::new(vec![Box::new(TotalTransportCost::default())], vec![Box::new(DurationSpread::default())]);
+    // both have the same total duration, so transport cost is the same
+    let balanced = create_insertion_ctx(vec![50., 50.]);
+    let skewed = create_insertion_ctx(vec![90., 10.]);
+
+    assert_eq!(objective.total_order(&balanced, &skewed), Ordering::Less);
+    assert_eq!(objective.total_order(&skewed, &balanced), Ordering::Greater);
+}
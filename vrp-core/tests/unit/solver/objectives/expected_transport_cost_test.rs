@@ -0,0 +1,77 @@
+use super::*;
+use crate::construction::heuristics::SolutionContext;
+use crate::helpers::models::domain::{create_empty_insertion_context, create_empty_solution_context};
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::{IdDimension, Location};
+use crate::models::problem::Single;
+use crate::models::solution::Place;
+use std::sync::Arc;
+
+fn create_activity(location: Location, probability: Option<f64>) -> Box<Activity> {
+    let mut single = Single { places: vec![test_place_with_location(Some(location))], dimens: Default::default() };
+    single.dimens.set_id(format!("job{}", location).as_str());
+    if let Some(probability) = probability {
+        single.dimens.set_value("probability", probability);
+    }
+
+    Box::new(
+        ActivityBuilder::default()
+            .place(Place { location, duration: DEFAULT_JOB_DURATION, time: DEFAULT_ACTIVITY_TIME_WINDOW })
+            .job(Some(Arc::new(single)))
+            .build(),
+    )
+}
+
+fn create_insertion_ctx(probability: Option<f64>) -> InsertionContext {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        vec![create_activity(10, None), create_activity(20, probability)],
+    );
+
+    InsertionContext {
+        solution: SolutionContext { routes: vec![route_ctx], ..create_empty_solution_context() },
+        ..create_empty_insertion_context()
+    }
+}
+
+parameterized_test! {can_calculate_expected_cost, (probability, samples, expected, tolerance), {
+    can_calculate_expected_cost_impl(probability, samples, expected, tolerance);
+}}
+
+can_calculate_expected_cost! {
+    case01_certain_job: (None, 10, 160., 0.),
+    case02_always_present: (Some(1.), 10, 160., 0.),
+    case03_never_present: (Some(0.), 10, 80., 0.),
+    case04_half_present: (Some(0.5), 1000, 120., 10.),
+}
+
+fn can_calculate_expected_cost_impl(probability: Option<f64>, samples: usize, expected: f64, tolerance: f64) {
+    // vehicle + driver costs per distance and driving time: 4 per unit
+    // locations with all jobs: 0 10 20 0, distance: 40
+    // locations without probable job: 0 10 0, distance: 20
+    let insertion_ctx = create_insertion_ctx(probability);
+
+    let result = ExpectedTransportCost::new(samples).fitness(&insertion_ctx);
+
+    assert!((result - expected).abs() <= tolerance, "expected {} +/- {}, got {}", expected, tolerance, result);
+}
+
+#[test]
+fn can_use_same_scenarios_for_the_same_solution() {
+    let insertion_ctx = create_insertion_ctx(Some(0.5));
+    let objective = ExpectedTransportCost::default();
+
+    assert_eq!(objective.fitness(&insertion_ctx), objective.fitness(&insertion_ctx));
+}
+
+#[test]
+fn can_use_same_scenarios_for_jobs_with_the_same_ids() {
+    let objective = ExpectedTransportCost::new(5);
+
+    let fitness = (0..10).map(|_| objective.fitness(&create_insertion_ctx(Some(0.5)))).collect::<Vec<_>>();
+
+    assert!(fitness.windows(2).all(|pair| pair[0] == pair[1]));
+}
@@ -0,0 +1,41 @@
+use super::*;
+use crate::construction::heuristics::SolutionContext;
+use crate::helpers::models::domain::{create_empty_insertion_context, create_empty_solution_context};
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::TimeWindow;
+
+fn create_insertion_ctx(activities: Vec<(usize, f64)>) -> InsertionContext {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let activities = activities
+        .into_iter()
+        .map(|(location, end)| test_tour_activity_with_location_and_tw(location, TimeWindow::new(0., end)))
+        .collect();
+
+    InsertionContext {
+        solution: SolutionContext {
+            routes: vec![create_route_context_with_activities(&fleet, "v1", activities)],
+            ..create_empty_solution_context()
+        },
+        ..create_empty_insertion_context()
+    }
+}
+
+#[test]
+fn can_calculate_late_delivery_count() {
+    let insertion_ctx = create_insertion_ctx(vec![(10, 20.), (20, 15.), (30, 22.), (40, 40.)]);
+
+    assert_eq!(LateDeliveryCount::default().fitness(&insertion_ctx), 2.);
+}
+
+#[test]
+fn can_prefer_solution_with_less_late_deliveries_regardless_of_lateness() {
+    // total lateness is 2 with two late stops and 100 with one late stop
+    let two_late = create_insertion_ctx(vec![(20, 19.), (30, 29.)]);
+    let one_late = create_insertion_ctx(vec![(20, 20.), (130, 30.)]);
+    let objective = LateDeliveryCount::default();
+
+    assert_eq!(objective.fitness(&two_late), 2.);
+    assert_eq!(objective.fitness(&one_late), 1.);
+    assert_eq!(objective.total_order(&one_late, &two_late), Ordering::Less);
+}
@@ -0,0 +1,41 @@
+use super::*;
+use crate::construction::heuristics::SolutionContext;
+use crate::helpers::models::domain::{create_empty_insertion_context, create_empty_solution_context};
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::TimeWindow;
+
+fn create_insertion_ctx(activities: Vec<(usize, f64)>) -> InsertionContext {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let activities = activities
+        .into_iter()
+        .map(|(location, end)| test_tour_activity_with_location_and_tw(location, TimeWindow::new(0., end)))
+        .collect();
+
+    InsertionContext {
+        solution: SolutionContext {
+            routes: vec![create_route_context_with_activities(&fleet, "v1", activities)],
+            ..create_empty_solution_context()
+        },
+        ..create_empty_insertion_context()
+    }
+}
+
+#[test]
+fn can_calculate_max_lateness() {
+    let insertion_ctx = create_insertion_ctx(vec![(10, 20.), (20, 15.), (30, 22.)]);
+
+    assert_eq!(MaxLateness::default().fitness(&insertion_ctx), 8.);
+}
+
+#[test]
+fn can_prefer_solution_with_smaller_worst_lateness() {
+    // both have total lateness 10
+    let balanced = create_insertion_ctx(vec![(20, 15.), (30, 25.)]);
+    let skewed = create_insertion_ctx(vec![(20, 20.), (30, 20.)]);
+    let objective = MaxLateness::default();
+
+    assert_eq!(objective.fitness(&balanced), 5.);
+    assert_eq!(objective.fitness(&skewed), 10.);
+    assert_eq!(objective.total_order(&balanced, &skewed), Ordering::Less);
+}
@@ -0,0 +1,70 @@
+use super::*;
+use crate::construction::heuristics::SolutionContext;
+use crate::helpers::models::domain::{create_empty_insertion_context, create_empty_solution_context};
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::problem::{ObjectiveCost, Vehicle};
+use crate::solver::objectives::TotalTransportCost;
+
+fn create_vehicle(id: &str, type_id: Option<&str>, shift_index: usize) -> Vehicle {
+    let mut vehicle = test_vehicle_with_id(id);
+    if let Some(type_id) = type_id {
+        vehicle.dimens.set_value("type_id", type_id.to_string());
+    }
+    vehicle.dimens.set_value("shift_index", shift_index);
+
+    vehicle
+}
+
+fn create_insertion_ctx(vehicles: Vec<(&str, Option<&str>, usize, usize)>) -> InsertionContext {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(
+            vehicles.iter().map(|&(id, type_id, shift_index, _)| create_vehicle(id, type_id, shift_index)).collect(),
+        )
+        .build();
+
+    let routes = vehicles
+        .iter()
+        .map(|&(id, _, _, jobs)| {
+            let activities = (0..jobs).map(|idx| test_tour_activity_with_location(idx + 1)).collect();
+            create_route_context_with_activities(&fleet, id, activities)
+        })
+        .collect();
+
+    InsertionContext {
+        solution: SolutionContext { routes, ..create_empty_solution_context() },
+        ..create_empty_insertion_context()
+    }
+}
+
+parameterized_test! {can_count_vehicle_types, (vehicles, expected), {
+    can_count_vehicle_types_impl(vehicles, expected);
+}}
+
+can_count_vehicle_types! {
+    case01_single_type: (vec![("v1", Some("van"), 0, 1), ("v2", Some("van"), 0, 2)], 1.),
+    case02_two_types: (vec![("v1", Some("van"), 0, 1), ("v2", Some("truck"), 0, 1)], 2.),
+    case03_different_shifts: (vec![("v1", Some("van"), 0, 1), ("v2", Some("van"), 1, 1)], 1.),
+    case04_empty_route: (vec![("v1", Some("van"), 0, 1), ("v2", Some("truck"), 0, 0)], 1.),
+    case05_no_type: (vec![("v1", None, 0, 1), ("v2", None, 0, 1), ("v3", Some("v1"), 0, 1)], 3.),
+    case06_no_jobs: (vec![("v1", Some("van"), 0, 0)], 0.),
+}
+
+fn can_count_vehicle_types_impl(vehicles: Vec<(&str, Option<&str>, usize, usize)>, expected: f64) {
+    let insertion_ctx = create_insertion_ctx(vehicles);
+
+    assert_eq!(MinimizeVehicleTypes::default().fitness(&insertion_ctx), expected);
+}
+
+#[test]
+fn can_prefer_less_vehicle_types_over_transport_cost() {
+    let objective = ObjectiveCost::new(
+        vec![Box::new(MinimizeVehicleTypes::default())],
+        vec![Box::new(TotalTransportCost::default())],
+    );
+    let one_type = create_insertion_ctx(vec![("v1", Some("van"), 0, 3), ("v2", Some("van"), 0, 3)]);
+    let two_types = create_insertion_ctx(vec![("v1", Some("van"), 0, 1), ("v2", Some("truck"), 0, 1)]);
+
+    assert_eq!(objective.total_order(&one_type, &two_types), Ordering::Less);
+}
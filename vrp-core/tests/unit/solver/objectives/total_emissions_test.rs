@@ -0,0 +1,65 @@
+use super::*;
+use crate::construction::constraints::IS_LOADED_KEY;
+use crate::construction::heuristics::{RouteState, SolutionContext};
+use crate::helpers::models::domain::{create_empty_insertion_context, create_empty_solution_context};
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::ValueDimension;
+use crate::models::problem::Vehicle;
+use std::sync::Arc;
+
+fn create_insertion_ctx(is_loaded: bool) -> InsertionContext {
+    let mut van = Vehicle { profile: 0, ..test_vehicle_with_id("v1") };
+    van.dimens.set_value("loaded_profile", 2 as Profile);
+    let truck = Vehicle { profile: 1, ..test_vehicle_with_id("v2") };
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicles(vec![van, truck]).build();
+
+    // v1: 0 -> 10 -> 20 -> 0, distance is 40, the last 20 is driven loaded when flag is set
+    let mut van_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        vec![test_tour_activity_with_location(10), test_tour_activity_with_location(20)],
+    );
+    if is_loaded {
+        let mut state = RouteState::default();
+        state.put_activity_state(IS_LOADED_KEY, van_ctx.route.tour.get(2).unwrap(), true);
+        van_ctx.state = Arc::new(state);
+    }
+    // v2: 0 -> 5 -> 0, distance is 10
+    let truck_ctx = create_route_context_with_activities(&fleet, "v2", vec![test_tour_activity_with_location(5)]);
+
+    InsertionContext {
+        solution: SolutionContext { routes: vec![van_ctx, truck_ctx], ..create_empty_solution_context() },
+        ..create_empty_insertion_context()
+    }
+}
+
+parameterized_test! {can_calculate_emissions, (factors, is_loaded, expected), {
+    can_calculate_emissions_impl(factors, is_loaded, expected);
+}}
+
+can_calculate_emissions! {
+    case01_all_profiles: (vec![(0, 2.), (1, 3.)], false, 110.),
+    case02_missing_profile: (vec![(0, 2.)], false, 80.),
+    case03_no_factors: (vec![], false, 0.),
+    case04_loaded_profile: (vec![(0, 2.), (1, 3.), (2, 5.)], true, 170.),
+    case05_loaded_without_factor: (vec![(0, 2.), (1, 3.)], true, 70.),
+}
+
+fn can_calculate_emissions_impl(factors: Vec<(Profile, f64)>, is_loaded: bool, expected: f64) {
+    let insertion_ctx = create_insertion_ctx(is_loaded);
+
+    let result = TotalEmissions::new(factors.into_iter().collect()).fitness(&insertion_ctx);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_compare_solutions_by_emissions() {
+    let objective = TotalEmissions::new(vec![(0, 1.), (2, 10.)].into_iter().collect());
+    let empty = create_insertion_ctx(false);
+    let loaded = create_insertion_ctx(true);
+
+    assert_eq!(objective.total_order(&empty, &loaded), Ordering::Less);
+    assert_eq!(objective.distance(&empty, &loaded), -180.);
+}
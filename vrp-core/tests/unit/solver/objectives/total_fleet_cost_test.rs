@@ -0,0 +1,79 @@
+use super::*;
+use crate::helpers::models::domain::{create_empty_insertion_context, create_empty_solution_context};
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+fn create_curve() -> FleetCostCurve {
+    Arc::new(|routes| match routes {
+        0..=3 => routes as f64 * 10.,
+        _ => 30. + (routes - 3) as f64 * 1000.,
+    })
+}
+
+fn create_solution_ctx(used: usize) -> SolutionContext {
+    let ids = (0..=used).map(|idx| format!("v{}", idx)).collect::<Vec<_>>();
+    let fleet = ids
+        .iter()
+        .fold(FleetBuilder::default().add_driver(test_driver()), |mut builder, id| {
+            builder.add_vehicle(test_vehicle_with_id(id.as_str()));
+            builder
+        })
+        .build();
+
+    let routes = ids
+        .iter()
+        .enumerate()
+        .map(|(idx, id)| {
+            let activities = if idx < used { vec![test_tour_activity_with_default_job()] } else { vec![] };
+            create_route_context_with_activities(&fleet, id.as_str(), activities)
+        })
+        .collect();
+
+    SolutionContext { routes, ..create_empty_solution_context() }
+}
+
+fn estimate_route(constraint: &TargetConstraint, solution_ctx: &SolutionContext, route_idx: usize) -> Cost {
+    let job = Job::Single(test_single_with_id("job"));
+
+    constraint
+        .get_constraints()
+        .map(|variant| match variant {
+            ConstraintVariant::SoftRoute(soft) => {
+                soft.estimate_job(solution_ctx, solution_ctx.routes.get(route_idx).unwrap(), &job)
+            }
+            _ => 0.,
+        })
+        .sum()
+}
+
+parameterized_test! {can_estimate_opening_new_route, (used, expected), {
+    can_estimate_opening_new_route_impl(used, expected);
+}}
+
+can_estimate_opening_new_route! {
+    case01: (0, 10.),
+    case02: (2, 10.),
+    case03: (3, 1000.),
+    case04: (4, 1000.),
+}
+
+fn can_estimate_opening_new_route_impl(used: usize, expected: Cost) {
+    let (constraint, _) = TotalFleetCost::new_with_curve(create_curve());
+    let solution_ctx = create_solution_ctx(used);
+
+    assert_eq!(estimate_route(&constraint, &solution_ctx, used), expected);
+    if used > 0 {
+        assert_eq!(estimate_route(&constraint, &solution_ctx, 0), 0.);
+    }
+}
+
+#[test]
+fn can_calculate_fleet_cost_fitness() {
+    let (_, objective) = TotalFleetCost::new_with_curve(create_curve());
+    let three_routes = InsertionContext { solution: create_solution_ctx(3), ..create_empty_insertion_context() };
+    let four_routes = InsertionContext { solution: create_solution_ctx(4), ..create_empty_insertion_context() };
+
+    assert_eq!(objective.fitness(&three_routes), three_routes.solution.get_total_cost() + 30.);
+    assert_eq!(objective.fitness(&four_routes), four_routes.solution.get_total_cost() + 1030.);
+    assert_eq!(objective.total_order(&three_routes, &four_routes), Ordering::Less);
+}
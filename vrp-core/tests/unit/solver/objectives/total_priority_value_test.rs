@@ -0,0 +1,68 @@
+use super::*;
+use crate::construction::heuristics::SolutionContext;
+use crate::helpers::models::domain::{create_empty_insertion_context, create_empty_solution_context};
+use crate::helpers::models::problem::*;
+use crate::models::problem::ObjectiveCost;
+use crate::solver::objectives::TotalUnassignedJobs;
+use std::sync::Arc;
+
+fn create_job(id: &str, priority: Option<i32>) -> Job {
+    let mut single = SingleBuilder::default().id(id).build();
+    if let Some(priority) = priority {
+        single.dimens.set_priority(priority);
+    }
+
+    Job::Single(Arc::new(single))
+}
+
+fn create_insertion_ctx(unassigned: Vec<Job>) -> InsertionContext {
+    InsertionContext {
+        solution: SolutionContext {
+            unassigned: unassigned.into_iter().map(|job| (job, 0)).collect(),
+            ..create_empty_solution_context()
+        },
+        ..create_empty_insertion_context()
+    }
+}
+
+parameterized_test! {can_calculate_priority_value, (priorities, expected), {
+    can_calculate_priority_value_impl(priorities, expected);
+}}
+
+can_calculate_priority_value! {
+    case01_no_unassigned: (vec![], 0.),
+    case02_highest: (vec![Some(1)], 1000.),
+    case03_default_is_highest: (vec![None], 1000.),
+    case04_low: (vec![Some(4)], 250.),
+    case05_mixed: (vec![Some(1), Some(2), None], 2500.),
+    case06_invalid: (vec![Some(0)], 1000.),
+}
+
+fn can_calculate_priority_value_impl(priorities: Vec<Option<i32>>, expected: f64) {
+    let jobs = priorities.into_iter().enumerate().map(|(idx, priority)| create_job(&format!("job{}", idx), priority));
+    let insertion_ctx = create_insertion_ctx(jobs.collect());
+
+    assert_eq!(TotalPriorityValue::default().fitness(&insertion_ctx), expected);
+}
+
+#[test]
+fn can_prefer_leaving_low_priority_jobs_unassigned() {
+    let objective = TotalPriorityValue::default();
+    let high_omitted = create_insertion_ctx(vec![create_job("job1", Some(1))]);
+    let low_omitted = create_insertion_ctx(vec![create_job("job2", Some(3)), create_job("job3", Some(3))]);
+
+    assert_eq!(objective.total_order(&low_omitted, &high_omitted), Ordering::Less);
+}
+
+#[test]
+fn can_fallback_to_unassigned_jobs_on_tie() {
+    let objective = ObjectiveCost::new(
+        vec![Box::new(TotalPriorityValue::default())],
+        vec![Box::new(TotalUnassignedJobs::default())],
+    );
+    let one_omitted = create_insertion_ctx(vec![create_job("job1", Some(1))]);
+    let two_omitted = create_insertion_ctx(vec![create_job("job2", Some(2)), create_job("job3", Some(2))]);
+
+    assert_eq!(TotalPriorityValue::default().total_order(&one_omitted, &two_omitted), Ordering::Equal);
+    assert_eq!(objective.total_order(&one_omitted, &two_omitted), Ordering::Less);
+}
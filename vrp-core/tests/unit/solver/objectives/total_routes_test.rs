@@ -0,0 +1,65 @@
+use super::*;
+use crate::construction::heuristics::SolutionContext;
+use crate::helpers::models::domain::{create_empty_insertion_context, create_empty_solution_context};
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::problem::VehicleDetail;
+use crate::models::solution::Registry;
+
+fn create_must_use_vehicle(id: &str, details: Vec<VehicleDetail>) -> Vehicle {
+    let mut vehicle = VehicleBuilder::default().id(id).details(details).build();
+    vehicle.dimens.set_value("must_use", true);
+
+    vehicle
+}
+
+fn create_insertion_ctx(used: &[&str]) -> InsertionContext {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(create_must_use_vehicle(
+            "v1",
+            vec![test_vehicle_detail(), VehicleDetail { start: Some(1), ..test_vehicle_detail() }],
+        ))
+        .add_vehicle(create_must_use_vehicle("v2", vec![test_vehicle_detail()]))
+        .add_vehicle(test_vehicle_with_id("v3"))
+        .build();
+
+    let routes = used
+        .iter()
+        .map(|id| create_route_context_with_activities(&fleet, id, vec![test_tour_activity_with_default_job()]))
+        .collect();
+
+    InsertionContext {
+        solution: SolutionContext { routes, registry: Registry::new(&fleet), ..create_empty_solution_context() },
+        ..create_empty_insertion_context()
+    }
+}
+
+parameterized_test! {can_count_idle_must_use_vehicles_once, (used, is_minimization, expected), {
+    can_count_idle_must_use_vehicles_once_impl(used, is_minimization, expected);
+}}
+
+can_count_idle_must_use_vehicles_once! {
+    case01: (&["v3"], true, 3.),
+    case02: (&["v1", "v3"], true, 2.),
+    case03: (&["v1", "v2"], true, 0.),
+    case04: (&["v3"], false, 1.),
+    case05: (&["v1", "v3"], false, -1.),
+    case06: (&["v1", "v2", "v3"], false, -3.),
+}
+
+fn can_count_idle_must_use_vehicles_once_impl(used: &[&str], is_minimization: bool, expected: f64) {
+    let objective = if is_minimization { TotalRoutes::new_minimized() } else { TotalRoutes::new_maximized() };
+
+    assert_eq!(objective.fitness(&create_insertion_ctx(used)), expected);
+}
+
+#[test]
+fn can_order_solutions_by_fitness_when_maximized() {
+    let objective = TotalRoutes::new_maximized();
+    let one_route = create_insertion_ctx(&["v3"]);
+    let two_routes = create_insertion_ctx(&["v1", "v3"]);
+
+    assert_eq!(objective.total_order(&two_routes, &one_route), Ordering::Less);
+    assert_eq!(objective.total_order(&one_route, &two_routes), Ordering::Greater);
+}
@@ -0,0 +1,77 @@
+use super::*;
+use crate::construction::heuristics::SolutionContext;
+use crate::helpers::models::domain::{create_empty_insertion_context, create_empty_solution_context};
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::{Location, Schedule, TimeSpan, TimeWindow};
+use crate::models::problem::{Place as JobPlace, Single};
+use crate::models::solution::{Place, TourActivity};
+use std::sync::Arc;
+
+fn create_activity(location: Location, arrival: f64, windows: Vec<(f64, f64)>) -> TourActivity {
+    let times = windows.iter().map(|&(start, end)| TimeSpan::Window(TimeWindow::new(start, end))).collect();
+    let single = Single {
+        places: vec![JobPlace { location: Some(location), duration: DEFAULT_JOB_DURATION, times }],
+        dimens: Default::default(),
+    };
+    let (start, end) = windows.first().cloned().unwrap();
+
+    Box::new(
+        ActivityBuilder::default()
+            .place(Place { location, duration: DEFAULT_JOB_DURATION, time: TimeWindow::new(start, end) })
+            .schedule(Schedule::new(arrival, arrival + DEFAULT_JOB_DURATION))
+            .job(Some(Arc::new(single)))
+            .build(),
+    )
+}
+
+fn create_insertion_ctx(activities: Vec<TourActivity>) -> InsertionContext {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+
+    InsertionContext {
+        solution: SolutionContext {
+            routes: vec![create_route_context_with_activities(&fleet, "v1", activities)],
+            ..create_empty_solution_context()
+        },
+        ..create_empty_insertion_context()
+    }
+}
+
+#[test]
+fn can_calculate_total_tardiness() {
+    let insertion_ctx = create_insertion_ctx(vec![
+        create_activity(10, 10., vec![(0., 20.)]),
+        create_activity(20, 20., vec![(0., 15.)]),
+        create_activity(30, 30., vec![(0., 22.)]),
+    ]);
+
+    assert_eq!(TotalTardiness::default().fitness(&insertion_ctx), 13.);
+}
+
+parameterized_test! {can_use_nearest_time_window, (arrival, windows, expected), {
+    can_use_nearest_time_window_impl(arrival, windows, expected);
+}}
+
+can_use_nearest_time_window! {
+    case01_late_for_both: (50., vec![(0., 10.), (20., 30.)], 20.),
+    case02_inside_second: (25., vec![(0., 10.), (20., 30.)], 0.),
+    case03_between_windows: (15., vec![(0., 10.), (20., 30.)], 0.),
+    case04_unsorted_windows: (35., vec![(20., 30.), (0., 10.)], 5.),
+    case05_early: (5., vec![(10., 20.)], 0.),
+}
+
+fn can_use_nearest_time_window_impl(arrival: f64, windows: Vec<(f64, f64)>, expected: f64) {
+    let insertion_ctx = create_insertion_ctx(vec![create_activity(10, arrival, windows)]);
+
+    assert_eq!(TotalTardiness::default().fitness(&insertion_ctx), expected);
+}
+
+#[test]
+fn can_prefer_solution_with_smaller_total_tardiness() {
+    let better = create_insertion_ctx(vec![create_activity(20, 20., vec![(0., 15.)])]);
+    let worse = create_insertion_ctx(vec![create_activity(20, 20., vec![(0., 10.)])]);
+    let objective = TotalTardiness::default();
+
+    assert_eq!(objective.total_order(&better, &worse), Ordering::Less);
+    assert_eq!(objective.distance(&better, &worse), -5.);
+}
@@ -68,6 +68,7 @@ fn can_calculate_transport_cost() {
             ..create_empty_solution_context()
         },
         random: Arc::new(DefaultRandom::default()),
+        cache: None,
     };
     constraint.accept_solution_state(&mut insertion_ctx.solution);
 
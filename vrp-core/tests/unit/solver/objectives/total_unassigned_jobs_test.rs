@@ -0,0 +1,66 @@
+use super::*;
+use crate::construction::heuristics::SolutionContext;
+use crate::helpers::models::domain::{create_empty_insertion_context, create_empty_solution_context};
+use crate::helpers::models::problem::*;
+use std::sync::Arc;
+
+fn create_job(id: &str, is_required: bool) -> Job {
+    let mut single = SingleBuilder::default().id(id).build();
+    if is_required {
+        single.dimens.set_value("required", true);
+    }
+
+    Job::Single(Arc::new(single))
+}
+
+fn create_job_with_penalty(id: &str, penalty: f64) -> Job {
+    let mut single = SingleBuilder::default().id(id).build();
+    single.dimens.set_value("unassigned_penalty", penalty);
+
+    Job::Single(Arc::new(single))
+}
+
+fn create_insertion_ctx(unassigned: Vec<Job>) -> InsertionContext {
+    InsertionContext {
+        solution: SolutionContext {
+            unassigned: unassigned.into_iter().map(|job| (job, 0)).collect(),
+            ..create_empty_solution_context()
+        },
+        ..create_empty_insertion_context()
+    }
+}
+
+#[test]
+fn can_penalize_required_job_more_than_optional_ones() {
+    let objective = TotalUnassignedJobs::default();
+    let required_omitted = create_insertion_ctx(vec![create_job("job1", true)]);
+    let optional_omitted = create_insertion_ctx(vec![create_job("job2", false), create_job("job3", false)]);
+
+    assert_eq!(objective.fitness(&optional_omitted), 2.);
+    assert!(objective.fitness(&required_omitted) > objective.fitness(&optional_omitted));
+    assert_eq!(objective.total_order(&optional_omitted, &required_omitted), Ordering::Less);
+}
+
+#[test]
+fn can_prefer_dropping_low_penalty_jobs() {
+    let objective = TotalUnassignedJobs::default();
+    let high_omitted = create_insertion_ctx(vec![create_job_with_penalty("job1", 10.)]);
+    let low_omitted =
+        create_insertion_ctx(vec![create_job_with_penalty("job2", 2.), create_job_with_penalty("job3", 3.)]);
+
+    assert_eq!(objective.fitness(&high_omitted), 10.);
+    assert_eq!(objective.fitness(&low_omitted), 5.);
+    assert_eq!(objective.total_order(&low_omitted, &high_omitted), Ordering::Less);
+}
+
+#[test]
+fn can_weight_penalty_by_job_probability() {
+    let objective = TotalUnassignedJobs::default();
+    let mut probable = SingleBuilder::default().id("job1").build();
+    probable.dimens.set_value("probability", 0.5);
+    let probable_omitted = create_insertion_ctx(vec![Job::Single(Arc::new(probable))]);
+    let certain_omitted = create_insertion_ctx(vec![create_job("job2", false)]);
+
+    assert_eq!(objective.fitness(&probable_omitted), 0.5);
+    assert_eq!(objective.total_order(&probable_omitted, &certain_omitted), Ordering::Less);
+}
@@ -0,0 +1,63 @@
+use super::*;
+use crate::construction::constraints::ConstraintPipeline;
+use crate::construction::heuristics::SolutionContext;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::models::domain::{create_empty_insertion_context, create_empty_solution_context};
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::{Location, TimeWindow};
+use crate::models::solution::{Place, TourActivity};
+
+fn create_activity(location: Location, start: f64) -> TourActivity {
+    Box::new(
+        ActivityBuilder::default()
+            .place(Place { location, duration: 0., time: TimeWindow::new(start, 1000.) })
+            .job(Some(test_single_with_location(Some(location))))
+            .build(),
+    )
+}
+
+fn create_insertion_ctx(pipeline: &ConstraintPipeline, activities: Vec<TourActivity>) -> InsertionContext {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let mut route_ctx = create_route_context_with_activities(&fleet, "v1", activities);
+    pipeline.accept_route_state(&mut route_ctx);
+
+    InsertionContext {
+        solution: SolutionContext { routes: vec![route_ctx], ..create_empty_solution_context() },
+        ..create_empty_insertion_context()
+    }
+}
+
+parameterized_test! {can_calculate_total_waiting_time, (starts, expected), {
+    can_calculate_total_waiting_time_impl(starts, expected);
+}}
+
+can_calculate_total_waiting_time! {
+    case01_no_waiting: ((0., 0.), 0.),
+    case02_waiting_at_second: ((0., 50.), 30.),
+    case03_waiting_at_both: ((20., 50.), 30.),
+}
+
+fn can_calculate_total_waiting_time_impl(starts: (f64, f64), expected: f64) {
+    // activities are at 10 and 20, driving time is equal to distance
+    let pipeline = create_constraint_pipeline_with_transport();
+    let insertion_ctx =
+        create_insertion_ctx(&pipeline, vec![create_activity(10, starts.0), create_activity(20, starts.1)]);
+
+    assert_eq!(TotalWaitingTime::default().fitness(&insertion_ctx), expected);
+}
+
+#[test]
+fn can_use_waiting_time_after_departure_rescheduling() {
+    let pipeline = create_constraint_pipeline_with_transport();
+    let mut insertion_ctx = create_insertion_ctx(&pipeline, vec![create_activity(10, 20.), create_activity(20, 50.)]);
+    let objective = TotalWaitingTime::default();
+    assert_eq!(objective.fitness(&insertion_ctx), 30.);
+
+    pipeline.accept_solution_state(&mut insertion_ctx.solution);
+
+    // departure is moved to 10, so vehicle waits only at the second activity
+    assert_eq!(objective.fitness(&insertion_ctx), 20.);
+    pipeline.accept_solution_state(&mut insertion_ctx.solution);
+    assert_eq!(objective.fitness(&insertion_ctx), 20.);
+}
@@ -0,0 +1,24 @@
+use super::*;
+
+#[test]
+fn can_calculate_hypervolume_of_two_objective_front() {
+    let front = vec![vec![1., 5.], vec![2., 3.], vec![4., 2.]];
+
+    // slices: [1,2)x[5,6) = 1, [2,4)x[3,6) = 6, [4,6)x[2,6) = 8
+    assert_eq!(hypervolume(&front, &[6., 6.]), 15.);
+}
+
+#[test]
+fn can_ignore_dominated_and_out_of_reference_points() {
+    let front = vec![vec![1., 5.], vec![2., 3.], vec![4., 2.], vec![3., 4.], vec![7., 1.]];
+
+    assert_eq!(hypervolume(&front, &[6., 6.]), 15.);
+}
+
+#[test]
+fn can_calculate_hypervolume_of_three_objective_front() {
+    let front = vec![vec![0., 0., 1.], vec![1., 1., 0.]];
+
+    // union of 2x2x1 and 1x1x2 boxes overlapping in 1x1x1
+    assert_eq!(hypervolume(&front, &[2., 2., 2.]), 5.);
+}
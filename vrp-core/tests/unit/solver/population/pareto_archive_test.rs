@@ -0,0 +1,59 @@
+use super::*;
+use crate::helpers::solver::population::*;
+use crate::models::common::Objective;
+
+fn create_objective() -> TupleMultiObjective {
+    TupleMultiObjective::new(vec![Box::new(Objective1), Box::new(Objective2)])
+}
+
+fn fill_archive(max_size: usize, solutions: Vec<Tuple>) -> ParetoArchive<Tuple> {
+    let objective = create_objective();
+
+    solutions.into_iter().fold(ParetoArchive::new(max_size), |mut archive, solution| {
+        if archive.can_add(&solution, &objective) {
+            archive.add(solution, &objective);
+        }
+        archive
+    })
+}
+
+fn get_sorted(archive: &ParetoArchive<Tuple>) -> Vec<Tuple> {
+    let mut solutions = archive.solutions().to_vec();
+    solutions.sort_by_key(|solution| solution.0);
+    solutions
+}
+
+parameterized_test! {can_keep_non_dominated_solutions, (max_size, solutions, expected), {
+    can_keep_non_dominated_solutions_impl(max_size, solutions, expected);
+}}
+
+can_keep_non_dominated_solutions! {
+    case01_trade_off: (10, vec![Tuple(1, 5), Tuple(3, 3), Tuple(5, 1)], vec![Tuple(1, 5), Tuple(3, 3), Tuple(5, 1)]),
+    case02_dominated_rejected: (10, vec![Tuple(1, 5), Tuple(2, 6), Tuple(5, 1)], vec![Tuple(1, 5), Tuple(5, 1)]),
+    case03_dominated_removed: (10, vec![Tuple(2, 6), Tuple(5, 1), Tuple(1, 5)], vec![Tuple(1, 5), Tuple(5, 1)]),
+    case04_duplicate_rejected: (10, vec![Tuple(1, 5), Tuple(1, 5)], vec![Tuple(1, 5)]),
+    case05_single_best: (10, vec![Tuple(3, 3), Tuple(1, 5), Tuple(0, 0)], vec![Tuple(0, 0)]),
+    case06_full_archive: (2, vec![Tuple(1, 5), Tuple(5, 1), Tuple(3, 3)], vec![Tuple(1, 5), Tuple(5, 1)]),
+    case07_full_archive_dominant: (2, vec![Tuple(1, 5), Tuple(5, 1), Tuple(4, 1)], vec![Tuple(1, 5), Tuple(4, 1)]),
+}
+
+fn can_keep_non_dominated_solutions_impl(max_size: usize, solutions: Vec<Tuple>, expected: Vec<Tuple>) {
+    let archive = fill_archive(max_size, solutions);
+
+    assert_eq!(get_sorted(&archive), expected);
+    assert_eq!(archive.solutions().len(), expected.len());
+}
+
+#[test]
+fn can_keep_archive_mutually_non_dominated() {
+    let objective = create_objective();
+    // each next front dominates the previous one
+    let solutions = (0..5).flat_map(|front| (0..4).map(move |i| Tuple(4 - front + i, 8 - front - i))).collect();
+
+    let archive = fill_archive(100, solutions);
+
+    assert_eq!(get_sorted(&archive), vec![Tuple(0, 4), Tuple(1, 3), Tuple(2, 2), Tuple(3, 1)]);
+    archive.solutions().iter().for_each(|a| {
+        archive.solutions().iter().for_each(|b| assert_eq!(objective.total_order(a, b), Ordering::Equal));
+    });
+}
@@ -3,7 +3,7 @@ use crate::helpers::construction::constraints::create_constraint_pipeline_with_t
 use crate::helpers::models::domain::*;
 use crate::helpers::models::problem::*;
 use crate::helpers::models::solution::create_route_context_with_activities;
-use crate::models::common::Objective;
+use crate::helpers::utils::random::FakeRandom;
 use crate::models::Problem;
 use crate::solver::{DominancePopulation, Individual, Population};
 use crate::utils::DefaultRandom;
@@ -89,3 +89,60 @@ fn can_maintain_diversity() {
     population.add(create_individual(&problem, 200.));
     assert_eq!(get_all_fitness(&population), &[50., 100., 200.]);
 }
+
+#[test]
+fn can_select_individual_using_tournament() {
+    let problem = create_problem();
+    let random = Arc::new(FakeRandom::new(vec![2, 1, 2, 2], vec![]));
+    let mut population = DominancePopulation::new(problem.clone(), random, 4, 0, 1).with_tournament(Some(2));
+
+    population.add(create_individual(&problem, 100.));
+    population.add(create_individual(&problem, 90.));
+    population.add(create_individual(&problem, 120.));
+
+    assert_eq!(population.problem.objective.fitness(population.select()), 100.);
+    assert_eq!(population.problem.objective.fitness(population.select()), 120.);
+    assert_eq!(get_best_fitness(&population), 90.);
+}
+
+#[test]
+fn can_reject_near_identical_individuals_with_diversity() {
+    let problem = create_problem();
+    let mut population = DominancePopulation::new(problem.clone(), Arc::new(DefaultRandom::default()), 4, 2, 1)
+        .with_diversity(Some(0.1));
+
+    population.add(create_individual(&problem, 100.));
+    assert_eq!(get_all_fitness(&population), &[100.]);
+
+    // NOTE all individuals have the same tours, so they are within epsilon distance
+    population.add(create_individual(&problem, 120.));
+    assert_eq!(get_all_fitness(&population), &[100.]);
+
+    population.add(create_individual(&problem, 90.));
+    assert_eq!(get_all_fitness(&population), &[90., 100.]);
+
+    population.add(create_individual(&problem, 95.));
+    assert_eq!(get_all_fitness(&population), &[90., 100.]);
+}
+
+parameterized_test! {can_retain_more_individuals_with_bigger_population_size, (population_size, expected), {
+    can_retain_more_individuals_with_bigger_population_size_impl(population_size, expected);
+}}
+
+can_retain_more_individuals_with_bigger_population_size! {
+    case01_small: (2, 2),
+    case02_big: (4, 4),
+}
+
+fn can_retain_more_individuals_with_bigger_population_size_impl(population_size: usize, expected: usize) {
+    let problem = create_problem();
+    let mut population =
+        DominancePopulation::new(problem.clone(), Arc::new(DefaultRandom::default()), population_size, 1, 1);
+
+    vec![100., 90., 120., 80., 110., 70.].into_iter().for_each(|fitness| {
+        population.add(create_individual(&problem, fitness));
+    });
+
+    assert_eq!(population.size(), expected);
+    assert_eq!(get_best_fitness(&population), 70.);
+}
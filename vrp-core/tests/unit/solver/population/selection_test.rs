@@ -0,0 +1,76 @@
+use super::*;
+use crate::construction::constraints::{TOTAL_DISTANCE_KEY, TOTAL_DURATION_KEY};
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::create_route_context_with_activities;
+use crate::helpers::utils::random::FakeRandom;
+use crate::models::problem::Job;
+use crate::models::Problem;
+use std::sync::Arc;
+
+fn create_problem() -> Arc<Problem> {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver_with_costs(empty_costs()))
+        .add_vehicle(test_vehicle_with_id("v1"))
+        .build();
+    create_empty_problem_with_constraint_and_fleet(create_constraint_pipeline_with_transport(), fleet)
+}
+
+fn create_individuals(problem: &Arc<Problem>, fitness: Vec<f64>) -> Vec<Individual> {
+    fitness
+        .into_iter()
+        .map(|fitness| {
+            let mut insertion_ctx = create_empty_insertion_context();
+            let mut route_ctx = create_route_context_with_activities(problem.fleet.as_ref(), "v1", vec![]);
+
+            route_ctx.state_mut().put_route_state(TOTAL_DISTANCE_KEY, fitness);
+            route_ctx.state_mut().put_route_state(TOTAL_DURATION_KEY, 0.);
+            insertion_ctx.solution.routes.push(route_ctx);
+
+            insertion_ctx
+        })
+        .collect()
+}
+
+parameterized_test! {can_select_best_individual_in_tournament, (size, indices, expected), {
+    can_select_best_individual_in_tournament_impl(size, indices, expected);
+}}
+
+can_select_best_individual_in_tournament! {
+    case01_single: (1, vec![2], 120.),
+    case02_two: (2, vec![2, 3], 80.),
+    case03_three: (3, vec![0, 3, 1], 80.),
+    case04_repeated: (2, vec![2, 2], 120.),
+    case05_zero_size: (0, vec![1], 90.),
+}
+
+fn can_select_best_individual_in_tournament_impl(size: usize, indices: Vec<i32>, expected: f64) {
+    let problem = create_problem();
+    let individuals = create_individuals(&problem, vec![100., 90., 120., 80.]);
+    let random = FakeRandom::new(indices, vec![]);
+
+    let result = select_tournament(individuals.as_slice(), problem.objective.as_ref(), &random, size);
+
+    assert_eq!(result.map(|individual| problem.objective.fitness(individual)), Some(expected));
+}
+
+#[test]
+fn can_use_total_order_instead_of_fitness_in_tournament() {
+    let problem = create_problem();
+    let mut individuals = create_individuals(&problem, vec![100., 80.]);
+    individuals[1].solution.unassigned.insert(Job::Single(test_single_with_id("job1")), 0);
+    let random = FakeRandom::new(vec![0, 1], vec![]);
+
+    let result = select_tournament(individuals.as_slice(), problem.objective.as_ref(), &random, 2);
+
+    assert_eq!(result.map(|individual| problem.objective.fitness(individual)), Some(100.));
+}
+
+#[test]
+fn can_return_none_for_empty_individuals() {
+    let problem = create_problem();
+    let random = FakeRandom::new(vec![], vec![]);
+
+    assert!(select_tournament(&[], problem.objective.as_ref(), &random, 2).is_none());
+}
@@ -0,0 +1,59 @@
+use crate::construction::heuristics::InsertionContext;
+use crate::helpers::models::domain::create_empty_problem;
+use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes};
+use crate::solver::termination::{AllJobsAssigned, Termination};
+use crate::solver::Builder;
+use crate::utils::DefaultRandom;
+use std::sync::Arc;
+
+parameterized_test! {can_detect_termination, (unassigned, expected), {
+    can_detect_termination_impl(unassigned, expected);
+}}
+
+can_detect_termination! {
+    case_01: (0, true),
+    case_02: (1, false),
+}
+
+fn can_detect_termination_impl(unassigned: usize, expected: bool) {
+    let (problem, solution) = generate_matrix_routes(2, 2);
+    let mut insertion_ctx = InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (Arc::new(solution), None),
+        Arc::new(DefaultRandom::default()),
+    );
+    let jobs = insertion_ctx.problem.jobs.all().take(unassigned).collect::<Vec<_>>();
+    jobs.into_iter().for_each(|job| {
+        insertion_ctx.solution.unassigned.insert(job, 0);
+    });
+    let mut refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+    refinement_ctx.population.add(insertion_ctx);
+
+    let result = AllJobsAssigned::default().is_termination(&mut refinement_ctx);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_skip_termination_with_empty_population() {
+    let mut refinement_ctx = create_default_refinement_ctx(create_empty_problem());
+
+    assert!(!AllJobsAssigned::default().is_termination(&mut refinement_ctx));
+}
+
+#[test]
+fn can_stop_solver_at_first_fully_assigned_generation() {
+    let (problem, _) = generate_matrix_routes(3, 3);
+
+    let (solution, _, statistics) = Builder::default()
+        .with_problem(Arc::new(problem))
+        .with_max_generations(Some(100))
+        .with_stop_when_all_assigned(true)
+        .build()
+        .unwrap()
+        .solve_with_statistics()
+        .unwrap();
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(statistics.generations, 0);
+}
@@ -26,3 +26,30 @@ fn can_detect_termination_impl(capacity: usize, threshold: f64, delta: f64, expe
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn can_skip_termination_until_sample_is_filled() {
+    let mut refinement_ctx = create_default_refinement_ctx(create_empty_problem());
+    let termination = CostVariation::new(5, 0.1);
+
+    // NOTE generation is not changed as it happens while initial population is built
+    let result = (0..5).map(|_| termination.update_and_check(&mut refinement_ctx, 1.)).collect::<Vec<bool>>();
+
+    assert_eq!(result, vec![false, false, false, false, true]);
+}
+
+#[test]
+fn can_skip_termination_before_sample_generations() {
+    let mut refinement_ctx = create_default_refinement_ctx(create_empty_problem());
+    let termination = CostVariation::new(5, 0.1);
+
+    let result = (0..5)
+        .map(|generation| {
+            refinement_ctx.generation = generation + 4;
+
+            termination.update_and_check(&mut refinement_ctx, 1.)
+        })
+        .collect::<Vec<bool>>();
+
+    assert_eq!(result, vec![false, false, false, false, true]);
+}
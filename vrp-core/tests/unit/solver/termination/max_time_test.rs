@@ -0,0 +1,32 @@
+use crate::helpers::models::domain::*;
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::solver::termination::max_time::MaxTime;
+use crate::solver::termination::Termination;
+use crate::utils::Timer;
+use std::thread::sleep;
+use std::time::Duration;
+
+#[test]
+fn can_start_timer_on_first_check() {
+    let mut refinement_ctx = create_default_refinement_ctx(create_empty_problem());
+    let termination = MaxTime::new(1000.);
+
+    assert!(refinement_ctx.state.get("max_time").is_none());
+
+    let result = termination.is_termination(&mut refinement_ctx);
+
+    assert!(!result);
+    assert!(refinement_ctx.state.get("max_time").and_then(|timer| timer.downcast_ref::<Timer>()).is_some());
+}
+
+#[test]
+fn can_detect_termination_when_time_elapsed() {
+    let mut refinement_ctx = create_default_refinement_ctx(create_empty_problem());
+    let termination = MaxTime::new(0.01);
+
+    assert!(!termination.is_termination(&mut refinement_ctx));
+
+    sleep(Duration::from_millis(20));
+
+    assert!(termination.is_termination(&mut refinement_ctx));
+}
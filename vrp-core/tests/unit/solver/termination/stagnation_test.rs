@@ -0,0 +1,38 @@
+use crate::helpers::models::domain::create_empty_problem;
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::solver::termination::{Stagnation, Termination};
+
+parameterized_test! {can_detect_termination, (limit, costs, expected), {
+    can_detect_termination_impl(limit, costs, expected);
+}}
+
+can_detect_termination! {
+    case_01_flat: (3, vec![1., 1., 1., 1., 1.], vec![false, false, false, true, true]),
+    case_02_improved: (3, vec![5., 4., 3., 2., 1.], vec![false, false, false, false, false]),
+    case_03_improved_once: (2, vec![5., 5., 4., 4., 4.], vec![false, false, false, false, true]),
+    case_04_worse: (2, vec![3., 4., 5., 2., 6.], vec![false, false, true, false, false]),
+}
+
+fn can_detect_termination_impl(limit: usize, costs: Vec<f64>, expected: Vec<bool>) {
+    let mut refinement_ctx = create_default_refinement_ctx(create_empty_problem());
+    let termination = Stagnation::new(limit);
+
+    let result = costs
+        .into_iter()
+        .enumerate()
+        .map(|(generation, cost)| {
+            refinement_ctx.generation = generation;
+
+            termination.update_and_check(&mut refinement_ctx, cost)
+        })
+        .collect::<Vec<bool>>();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_skip_termination_with_empty_population() {
+    let mut refinement_ctx = create_default_refinement_ctx(create_empty_problem());
+
+    assert!(!Stagnation::new(0).is_termination(&mut refinement_ctx));
+}
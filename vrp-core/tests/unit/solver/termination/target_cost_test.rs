@@ -0,0 +1,73 @@
+use crate::construction::heuristics::InsertionContext;
+use crate::helpers::models::domain::create_empty_problem;
+use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes};
+use crate::solver::termination::{TargetCost, Termination};
+use crate::solver::Builder;
+use crate::utils::DefaultRandom;
+use std::sync::Arc;
+
+parameterized_test! {can_detect_termination, (delta, expected), {
+    can_detect_termination_impl(delta, expected);
+}}
+
+can_detect_termination! {
+    case_01_above: (1., true),
+    case_02_equal: (0., true),
+    case_03_below: (-1., false),
+}
+
+fn can_detect_termination_impl(delta: f64, expected: bool) {
+    let (problem, solution) = generate_matrix_routes(2, 2);
+    let insertion_ctx = InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (Arc::new(solution), None),
+        Arc::new(DefaultRandom::default()),
+    );
+    let cost = insertion_ctx.problem.objective.fitness(&insertion_ctx);
+    let mut refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+    refinement_ctx.population.add(insertion_ctx);
+
+    let result = TargetCost::new(cost + delta).is_termination(&mut refinement_ctx);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_skip_termination_with_empty_population() {
+    let mut refinement_ctx = create_default_refinement_ctx(create_empty_problem());
+
+    assert!(!TargetCost::new(std::f64::MAX).is_termination(&mut refinement_ctx));
+}
+
+#[test]
+fn can_stop_solver_when_target_cost_is_reached() {
+    let (problem, _) = generate_matrix_routes(3, 3);
+
+    let (_, cost, statistics) = Builder::default()
+        .with_problem(Arc::new(problem))
+        .with_max_generations(Some(100))
+        .with_target_cost(Some(std::f64::MAX))
+        .build()
+        .unwrap()
+        .solve_with_statistics()
+        .unwrap();
+
+    assert!(cost < std::f64::MAX);
+    assert_eq!(statistics.generations, 0);
+}
+
+#[test]
+fn can_use_other_criteria_when_target_cost_is_not_reached() {
+    let (problem, _) = generate_matrix_routes(3, 3);
+
+    let (_, _, statistics) = Builder::default()
+        .with_problem(Arc::new(problem))
+        .with_max_generations(Some(10))
+        .with_target_cost(Some(0.))
+        .build()
+        .unwrap()
+        .solve_with_statistics()
+        .unwrap();
+
+    assert!(statistics.generations > 0);
+}
@@ -80,6 +80,48 @@ pub fn check_breaks(context: &CheckerContext) -> Result<(), String> {
     })
 }
 
+/// Checks that breaks marked as mandatory are assigned.
+pub fn check_mandatory_breaks(context: &CheckerContext) -> Result<(), String> {
+    context.solution.tours.iter().try_for_each(|tour| {
+        let vehicle_shift = context.get_vehicle_shift(tour)?;
+
+        let arrival = tour
+            .stops
+            .last()
+            .map(|stop| parse_time(&stop.time.arrival))
+            .ok_or_else(|| format!("Cannot get arrival for tour '{}'", tour.vehicle_id))?;
+
+        let expected_break_count = vehicle_shift
+            .breaks
+            .iter()
+            .flat_map(|breaks| breaks.iter())
+            .filter(|vehicle_break| vehicle_break.mandatory.unwrap_or(false))
+            .map(|vehicle_break| get_break_time_window(tour, vehicle_break))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .filter(|break_time| break_time.start < arrival)
+            .count();
+
+        let actual_break_count = tour
+            .stops
+            .iter()
+            .flat_map(|stop| {
+                stop.activities.windows(2).flat_map(move |leg| as_leg_with_break(context, tour, stop, leg))
+            })
+            .filter(|(_, _, vehicle_break)| vehicle_break.mandatory.unwrap_or(false))
+            .count();
+
+        if actual_break_count < expected_break_count {
+            Err(format!(
+                "Mandatory break is not assigned for tour '{}', expected: '{}', got '{}'",
+                tour.vehicle_id, expected_break_count, actual_break_count
+            ))
+        } else {
+            Ok(())
+        }
+    })
+}
+
 fn as_leg_with_break<'a>(
     context: &CheckerContext,
     tour: &Tour,
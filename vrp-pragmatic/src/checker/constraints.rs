@@ -0,0 +1,57 @@
+#[cfg(test)]
+#[path = "../../tests/unit/checker/constraints_test.rs"]
+mod constraints_test;
+
+use crate::format::map_code_reason;
+use crate::format::solution::{map_to_solution, Solution as ApiSolution};
+use std::sync::Arc;
+use vrp_core::construction::heuristics::get_constraint_violations;
+use vrp_core::models::common::{IdDimension, ValueDimension};
+use vrp_core::models::problem::Job;
+use vrp_core::models::Problem;
+
+/// Checks that solution does not violate any hard constraint of the problem. Tours are replayed
+/// activity by activity through problem's constraint pipeline and each violation is reported.
+pub fn check_constraints(problem: Arc<Problem>, solution: &ApiSolution) -> Result<(), Vec<String>> {
+    let core_solution = map_to_solution(solution, problem.clone()).map_err(|err| vec![err])?;
+
+    let violations = get_constraint_violations(problem.as_ref(), &core_solution)
+        .into_iter()
+        .map(|violation| {
+            let tour = solution.tours.get(violation.route_idx).unwrap();
+            let activity = core_solution.routes.get(violation.route_idx).unwrap().tour.get(violation.activity_idx);
+            let activity_type = activity
+                .and_then(|activity| activity.job.as_ref())
+                .and_then(|single| single.dimens.get_value::<String>("type"))
+                .cloned()
+                .unwrap_or_else(|| "unknown".to_string());
+            let (code, description) = map_code_reason(violation.code);
+
+            format!(
+                "tour '{}' (shift {}), activity {} '{}' of job '{}': constraint violation code {} ({})",
+                tour.vehicle_id,
+                tour.shift_index,
+                violation.activity_idx,
+                activity_type,
+                get_job_id(&violation.job),
+                code,
+                description
+            )
+        })
+        .collect::<Vec<_>>();
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(violations)
+    }
+}
+
+fn get_job_id(job: &Job) -> String {
+    match job {
+        Job::Single(single) => single.dimens.get_id(),
+        Job::Multi(multi) => multi.dimens.get_id(),
+    }
+    .cloned()
+    .unwrap_or_else(|| "unknown".to_string())
+}
@@ -39,6 +39,7 @@ impl CheckerContext {
             // TODO break is soft constraint and can be violated, how to improve checker?
             println!("break is violated: {}", err);
         }
+        check_mandatory_breaks(&self)?;
 
         check_assignment(&self)?;
 
@@ -220,7 +221,7 @@ fn get_location(stop: &Stop, activity: &Activity) -> Location {
 }
 
 fn same_locations(left: &Location, right: &Location) -> bool {
-    left.lat == right.lat && left.lng == right.lng
+    left == right
 }
 
 mod assignment;
@@ -230,7 +231,10 @@ mod capacity;
 use crate::checker::capacity::check_vehicle_load;
 
 mod breaks;
-use crate::checker::breaks::check_breaks;
+use crate::checker::breaks::{check_breaks, check_mandatory_breaks};
 
 mod relations;
 use crate::checker::relations::check_relations;
+
+mod constraints;
+pub use crate::checker::constraints::check_constraints;
@@ -3,7 +3,7 @@
 mod relations_test;
 
 use super::*;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 /// Checks relation rules.
 pub fn check_relations(context: &CheckerContext) -> Result<(), String> {
@@ -23,23 +23,26 @@ pub fn check_relations(context: &CheckerContext) -> Result<(), String> {
                 tour.unwrap()
             };
 
-            let activity_ids = get_activity_ids(&tour);
-
-            let relation_ids = relation.jobs.iter().collect::<HashSet<_>>();
-
-            let expected_relation_count = relation_ids.iter().try_fold(0, |acc, job_id| {
-                if let Some(job) = context.get_job_by_id(job_id) {
-                    Ok(acc
-                        + job.pickups.as_ref().map_or(0, |t| t.len())
-                        + job.deliveries.as_ref().map_or(0, |t| t.len())
-                        + job.replacements.as_ref().map_or(0, |t| t.len())
-                        + job.services.as_ref().map_or(0, |t| t.len()))
-                } else if reserved_ids.contains(job_id.as_str()) {
-                    Ok(acc + 1)
-                } else {
-                    Err(format!("Relation has unknown job id: {}", job_id))
-                }
-            })?;
+            let tags = get_relation_tags(relation);
+            let activity_ids = get_activity_ids(&tour, &tags);
+
+            let relation_keys = get_relation_keys(relation);
+            let relation_ids = relation_keys.iter().collect::<HashSet<_>>();
+
+            let expected_relation_count =
+                relation.jobs.iter().collect::<HashSet<_>>().iter().try_fold(0, |acc, job_id| {
+                    if let Some(job) = context.get_job_by_id(job_id) {
+                        Ok(acc
+                            + job.pickups.as_ref().map_or(0, |t| t.len())
+                            + job.deliveries.as_ref().map_or(0, |t| t.len())
+                            + job.replacements.as_ref().map_or(0, |t| t.len())
+                            + job.services.as_ref().map_or(0, |t| t.len()))
+                    } else if reserved_ids.contains(job_id.as_str()) {
+                        Ok(acc + 1)
+                    } else {
+                        Err(format!("Relation has unknown job id: {}", job_id))
+                    }
+                })?;
 
             if expected_relation_count != relation.jobs.len() {
                 return Err(format!("Relation {} contains duplicated ids: {:?}", idx, relation.jobs));
@@ -47,11 +50,11 @@ pub fn check_relations(context: &CheckerContext) -> Result<(), String> {
 
             match relation.type_field {
                 RelationType::Strict => {
-                    let common = intersection(activity_ids.clone(), relation.jobs.clone());
-                    if common != relation.jobs {
+                    let common = intersection(activity_ids.clone(), relation_keys.clone());
+                    if common != relation_keys {
                         Err(format!(
                             "Relation {} does not follow strict rule: expected {:?}, got {:?}, common: {:?}",
-                            idx, relation.jobs, activity_ids, common
+                            idx, relation_keys, activity_ids, common
                         ))
                     } else {
                         Ok(())
@@ -59,10 +62,10 @@ pub fn check_relations(context: &CheckerContext) -> Result<(), String> {
                 }
                 RelationType::Sequence => {
                     let ids = activity_ids.iter().filter(|id| relation_ids.contains(id)).cloned().collect::<Vec<_>>();
-                    if ids != relation.jobs {
+                    if ids != relation_keys {
                         Err(format!(
                             "Relation {} does not follow sequence rule: expected {:?}, got {:?}, common: {:?}",
-                            idx, relation.jobs, activity_ids, ids
+                            idx, relation_keys, activity_ids, ids
                         ))
                     } else {
                         Ok(())
@@ -74,7 +77,7 @@ pub fn check_relations(context: &CheckerContext) -> Result<(), String> {
                         .tours
                         .iter()
                         .filter(|other| tour.vehicle_id != other.vehicle_id)
-                        .any(|tour| get_activity_ids(tour).iter().any(|id| relation_ids.contains(id)));
+                        .any(|tour| get_activity_ids(tour, &tags).iter().any(|id| relation_ids.contains(id)));
 
                     if has_wrong_assignment {
                         Err(format!("Relation {} has jobs assigned to another tour", idx))
@@ -97,16 +100,50 @@ fn get_tour_by_vehicle_id(vehicle_id: &str, shift_index: Option<usize>, solution
         .ok_or_else(|| format!("Cannot find tour for '{}'", vehicle_id))
 }
 
-fn get_activity_ids(tour: &Tour) -> Vec<String> {
+/// Returns tags used in relation grouped by job id.
+fn get_relation_tags(relation: &Relation) -> HashMap<String, HashSet<String>> {
+    relation.tags.iter().flat_map(|tags| relation.jobs.iter().zip(tags.iter())).fold(
+        HashMap::new(),
+        |mut acc, (job_id, tag)| {
+            if let Some(tag) = tag {
+                acc.entry(job_id.clone()).or_insert_with(HashSet::new).insert(tag.clone());
+            }
+            acc
+        },
+    )
+}
+
+/// Returns relation job ids combined with their tags, if specified.
+fn get_relation_keys(relation: &Relation) -> Vec<String> {
+    let tags = relation.tags.clone().unwrap_or_default();
+
+    relation
+        .jobs
+        .iter()
+        .enumerate()
+        .map(|(idx, job_id)| match tags.get(idx).cloned().flatten() {
+            Some(tag) => get_tagged_key(job_id, &tag),
+            None => job_id.clone(),
+        })
+        .collect()
+}
+
+fn get_activity_ids(tour: &Tour, tags: &HashMap<String, HashSet<String>>) -> Vec<String> {
     tour.stops
         .iter()
         .flat_map(|stop| {
-            // TODO consider job tags within multi jobs
-            stop.activities.iter().map(|a| a.job_id.clone())
+            stop.activities.iter().map(|a| match (tags.get(&a.job_id), a.job_tag.as_ref()) {
+                (Some(job_tags), Some(tag)) if job_tags.contains(tag) => get_tagged_key(&a.job_id, tag),
+                _ => a.job_id.clone(),
+            })
         })
         .collect()
 }
 
+fn get_tagged_key(job_id: &str, tag: &str) -> String {
+    format!("{}#{}", job_id, tag)
+}
+
 fn intersection<T>(left: Vec<T>, right: Vec<T>) -> Vec<T>
 where
     T: PartialEq,
@@ -0,0 +1,292 @@
+//! This module contains logic to aggregate nearby jobs into super stops in order to reduce problem
+//! size and expand them back in the solution.
+
+#[cfg(test)]
+#[path = "../tests/unit/clustering_test.rs"]
+mod clustering_test;
+
+use crate::format::problem::{Job, JobPlace, JobTask, Matrix, Problem};
+use crate::format::solution::{Activity, Interval, Solution, UnassignedJob};
+use crate::format::Location;
+use crate::utils::get_haversine_distance;
+use crate::{format_time, parse_time};
+use std::collections::{HashMap, HashSet};
+
+/// Keeps information about jobs aggregated into super stops.
+pub struct JobClusters {
+    clusters: HashMap<String, Vec<Job>>,
+}
+
+impl JobClusters {
+    /// Returns amount of super stops.
+    pub fn size(&self) -> usize {
+        self.clusters.len()
+    }
+
+    /// Returns jobs aggregated into super stop with given id.
+    pub fn get(&self, job_id: &String) -> Option<&Vec<Job>> {
+        self.clusters.get(job_id)
+    }
+}
+
+/// Aggregates jobs which are located within given radius (in meters) and have the same time windows
+/// and other properties into a single super stop with combined demand and summed service time.
+/// Only jobs with one pickup or delivery task and one place are considered. Jobs used in relations
+/// or hints are left as is. A super stop gets id of the first aggregated job.
+///
+/// Jobs with locations represented by matrix references are aggregated only if they share the same
+/// location, use `[aggregate_jobs_with_matrix]` to cluster them by routing matrix distance.
+pub fn aggregate_jobs(problem: &Problem, radius: f64) -> (Problem, JobClusters) {
+    aggregate(problem, None, radius)
+}
+
+/// Aggregates jobs as `[aggregate_jobs]` does, but uses routing matrix distance (max of both
+/// directions) for locations represented by matrix references.
+pub fn aggregate_jobs_with_matrix(problem: &Problem, matrix: &Matrix, radius: f64) -> (Problem, JobClusters) {
+    aggregate(problem, Some(matrix), radius)
+}
+
+fn aggregate(problem: &Problem, matrix: Option<&Matrix>, radius: f64) -> (Problem, JobClusters) {
+    let locked = problem
+        .plan
+        .relations
+        .iter()
+        .flatten()
+        .flat_map(|relation| relation.jobs.iter())
+        .chain(problem.plan.hints.iter().flatten().map(|hint| &hint.job_id))
+        .collect::<HashSet<_>>();
+    let max_capacity = get_max_capacity(problem);
+
+    let mut used = vec![false; problem.plan.jobs.len()];
+    let mut clusters = HashMap::new();
+    let mut jobs = vec![];
+
+    for (seed_idx, seed) in problem.plan.jobs.iter().enumerate() {
+        if used[seed_idx] {
+            continue;
+        }
+        used[seed_idx] = true;
+
+        let seed_task = match get_single_task(seed).filter(|_| !locked.contains(&seed.id)) {
+            Some(task) => task,
+            None => {
+                jobs.push(seed.clone());
+                continue;
+            }
+        };
+
+        let mut demand = seed_task.1.demand.clone().unwrap_or_default();
+        let mut members = vec![seed.clone()];
+
+        for (idx, job) in problem.plan.jobs.iter().enumerate().skip(seed_idx + 1) {
+            if used[idx] || locked.contains(&job.id) {
+                continue;
+            }
+
+            let task = match get_single_task(job) {
+                Some(task) if is_compatible(seed, &seed_task, job, &task, matrix, radius) => task,
+                _ => continue,
+            };
+
+            let new_demand = sum_demand(&demand, task.1.demand.as_ref());
+            if !max_capacity.as_ref().map_or(true, |capacity| fits_capacity(capacity, &new_demand)) {
+                continue;
+            }
+
+            used[idx] = true;
+            demand = new_demand;
+            members.push(job.clone());
+        }
+
+        if members.len() > 1 {
+            jobs.push(create_super_job(seed, &seed_task, &members, demand));
+            clusters.insert(seed.id.clone(), members);
+        } else {
+            jobs.push(seed.clone());
+        }
+    }
+
+    let mut problem = problem.clone();
+    problem.plan.jobs = jobs;
+
+    (problem, JobClusters { clusters })
+}
+
+/// Expands super stops in the solution back into individual job visits.
+pub fn expand_solution(solution: &Solution, clusters: &JobClusters) -> Solution {
+    let mut solution = solution.clone();
+
+    solution.tours.iter_mut().flat_map(|tour| tour.stops.iter_mut()).for_each(|stop| {
+        if !stop.activities.iter().any(|activity| clusters.get(&activity.job_id).is_some()) {
+            return;
+        }
+
+        let stop_location = stop.location.clone();
+        let stop_time = Interval { start: stop.time.arrival.clone(), end: stop.time.departure.clone() };
+
+        stop.activities = stop
+            .activities
+            .iter()
+            .flat_map(|activity| {
+                let location = activity.location.clone().unwrap_or_else(|| stop_location.clone());
+                let time = activity.time.clone().unwrap_or_else(|| stop_time.clone());
+
+                match clusters.get(&activity.job_id) {
+                    Some(members) => {
+                        let mut start = parse_time(&time.start);
+                        members
+                            .iter()
+                            .map(|job| {
+                                let (_, task) = get_single_task(job).unwrap();
+                                let end = start + task.places.first().unwrap().duration;
+                                let activity = Activity {
+                                    job_id: job.id.clone(),
+                                    activity_type: activity.activity_type.clone(),
+                                    location: Some(task.places.first().unwrap().location.clone()),
+                                    time: Some(Interval { start: format_time(start), end: format_time(end) }),
+                                    load: None,
+                                    job_tag: task.tag.clone(),
+                                    metadata: job.metadata.clone(),
+                                };
+                                start = end;
+
+                                activity
+                            })
+                            .collect::<Vec<_>>()
+                    }
+                    None => vec![Activity { location: Some(location), time: Some(time), ..activity.clone() }],
+                }
+            })
+            .collect();
+    });
+
+    solution.unassigned = solution
+        .unassigned
+        .iter()
+        .flat_map(|unassigned| match clusters.get(&unassigned.job_id) {
+            Some(members) => members
+                .iter()
+                .map(|job| UnassignedJob {
+                    job_id: job.id.clone(),
+                    reasons: unassigned.reasons.clone(),
+                    suggestion: unassigned.suggestion.clone(),
+                })
+                .collect(),
+            None => vec![unassigned.clone()],
+        })
+        .collect();
+
+    solution
+}
+
+/// Task kind (true for pickup) and task itself.
+type SingleTask<'a> = (bool, &'a JobTask);
+
+fn get_single_task(job: &Job) -> Option<SingleTask<'_>> {
+    if job.replacements.is_some() || job.services.is_some() {
+        return None;
+    }
+
+    match (&job.pickups, &job.deliveries) {
+        (Some(tasks), None) => Some((true, tasks)),
+        (None, Some(tasks)) => Some((false, tasks)),
+        _ => None,
+    }
+    .filter(|(_, tasks)| tasks.len() == 1)
+    .map(|(is_pickup, tasks)| (is_pickup, tasks.first().unwrap()))
+    .filter(|(_, task)| task.places.len() == 1)
+}
+
+fn is_compatible(
+    seed: &Job,
+    seed_task: &SingleTask,
+    job: &Job,
+    task: &SingleTask,
+    matrix: Option<&Matrix>,
+    radius: f64,
+) -> bool {
+    let seed_place = seed_task.1.places.first().unwrap();
+    let place = task.1.places.first().unwrap();
+
+    seed_task.0 == task.0
+        && seed.priority == job.priority
+        && seed.skills == job.skills
+        && seed.required == job.required
+        && seed.metadata == job.metadata
+        && seed.probability == job.probability
+        && seed.unassigned_penalty == job.unassigned_penalty
+        && seed.customer == job.customer
+        && seed_place.times == place.times
+        && seed_task.1.demand.as_ref().map(|d| d.len()) == task.1.demand.as_ref().map(|d| d.len())
+        && get_distance(&seed_place.location, &place.location, matrix).map_or(false, |distance| distance <= radius)
+}
+
+/// Returns distance between locations if it can be determined.
+fn get_distance(from: &Location, to: &Location, matrix: Option<&Matrix>) -> Option<f64> {
+    match (from, to) {
+        (Location::Coordinate { .. }, Location::Coordinate { .. }) => Some(get_haversine_distance(from, to)),
+        (Location::Reference { index: from }, Location::Reference { index: to }) if from == to => Some(0.),
+        (Location::Reference { index: from }, Location::Reference { index: to }) => matrix.and_then(|matrix| {
+            let size = (matrix.distances.len() as f64).sqrt() as usize;
+            let forward = *matrix.distances.get(from * size + to)?;
+            let backward = *matrix.distances.get(to * size + from)?;
+
+            if forward < 0 || backward < 0 {
+                None
+            } else {
+                Some(forward.max(backward) as f64)
+            }
+        }),
+        _ => None,
+    }
+}
+
+fn create_super_job(seed: &Job, seed_task: &SingleTask, members: &[Job], demand: Vec<i32>) -> Job {
+    let seed_place = seed_task.1.places.first().unwrap();
+    let duration = members
+        .iter()
+        .filter_map(|job| get_single_task(job))
+        .map(|(_, task)| task.places.first().unwrap().duration)
+        .sum();
+
+    let tasks = Some(vec![JobTask {
+        places: vec![JobPlace {
+            location: seed_place.location.clone(),
+            duration,
+            times: seed_place.times.clone(),
+            durations: None,
+        }],
+        demand: if seed_task.1.demand.is_some() { Some(demand) } else { None },
+        tag: None,
+    }]);
+
+    let (pickups, deliveries) = if seed_task.0 { (tasks, None) } else { (None, tasks) };
+
+    Job { id: seed.id.clone(), pickups, deliveries, ..seed.clone() }
+}
+
+fn sum_demand(demand: &[i32], other: Option<&Vec<i32>>) -> Vec<i32> {
+    match other {
+        Some(other) => {
+            let size = demand.len().max(other.len());
+            (0..size).map(|idx| demand.get(idx).unwrap_or(&0) + other.get(idx).unwrap_or(&0)).collect()
+        }
+        None => demand.to_vec(),
+    }
+}
+
+fn get_max_capacity(problem: &Problem) -> Option<Vec<i32>> {
+    problem.fleet.vehicles.iter().map(|vehicle| &vehicle.capacity).fold(None, |acc, capacity| match acc {
+        Some(acc) => Some(get_max_values(&acc, capacity)),
+        None => Some(capacity.clone()),
+    })
+}
+
+fn get_max_values(first: &[i32], second: &[i32]) -> Vec<i32> {
+    let size = first.len().max(second.len());
+    (0..size).map(|idx| *first.get(idx).unwrap_or(&0).max(second.get(idx).unwrap_or(&0))).collect()
+}
+
+fn fits_capacity(capacity: &[i32], demand: &[i32]) -> bool {
+    demand.iter().enumerate().all(|(idx, value)| *value <= *capacity.get(idx).unwrap_or(&0))
+}
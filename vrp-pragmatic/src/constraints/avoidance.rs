@@ -0,0 +1,54 @@
+use crate::constraints::AVOIDANCE_COST_SOURCE;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{RouteContext, SolutionContext};
+use vrp_core::models::common::Cost;
+use vrp_core::models::problem::{AvoidanceTransportCost, Job};
+
+/// Keeps route cost in sync with extra cost of avoided legs. Insertion cost is already estimated by
+/// transport cost itself.
+pub struct AvoidanceModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+    transport: Arc<AvoidanceTransportCost>,
+}
+
+impl AvoidanceModule {
+    pub fn new(transport: Arc<AvoidanceTransportCost>) -> Self {
+        Self { constraints: vec![], keys: vec![], transport }
+    }
+}
+
+impl ConstraintModule for AvoidanceModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, route_ctx: &mut RouteContext, _job: &Job) {
+        self.accept_route_state(route_ctx);
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let actor = ctx.route.actor.as_ref();
+
+        let penalty = ctx
+            .route
+            .tour
+            .all_activities()
+            .zip(ctx.route.tour.all_activities().skip(1))
+            .map(|(from, to)| {
+                let profile = get_leg_profile(ctx, from);
+                self.transport.penalty(actor, profile, from.place.location, to.place.location, from.schedule.departure)
+            })
+            .sum::<Cost>();
+
+        set_extra_cost(ctx, AVOIDANCE_COST_SOURCE, penalty);
+    }
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
@@ -9,7 +9,7 @@ use std::sync::Arc;
 use vrp_core::construction::constraints::*;
 use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
 use vrp_core::models::common::{Cost, ValueDimension};
-use vrp_core::models::problem::{Job, Single};
+use vrp_core::models::problem::{Job, Single, TransportCost};
 use vrp_core::models::solution::Activity;
 
 /// Implements break functionality with variable location and time.
@@ -22,12 +22,17 @@ pub struct BreakModule {
 }
 
 impl BreakModule {
-    pub fn new(code: i32, extra_break_cost: Option<Cost>, demote_breaks_from_unassigned: bool) -> Self {
+    pub fn new(
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        code: i32,
+        extra_break_cost: Option<Cost>,
+        demote_breaks_from_unassigned: bool,
+    ) -> Self {
         Self {
             conditional: ConditionalJobModule::new(create_job_transition()),
             constraints: vec![
                 ConstraintVariant::HardRoute(Arc::new(BreakHardRouteConstraint { code })),
-                ConstraintVariant::HardActivity(Arc::new(BreakHardActivityConstraint { code })),
+                ConstraintVariant::HardActivity(Arc::new(BreakHardActivityConstraint { transport, code })),
                 ConstraintVariant::SoftRoute(Arc::new(BreakSoftRouteConstraint { extra_break_cost })),
             ],
             demote_breaks_from_unassigned,
@@ -67,6 +72,7 @@ impl ConstraintModule for BreakModule {
 }
 
 struct BreakHardActivityConstraint {
+    transport: Arc<dyn TransportCost + Send + Sync>,
     code: i32,
 }
 
@@ -99,16 +105,70 @@ impl BreakHardActivityConstraint {
     fn stop(&self) -> Option<ActivityConstraintViolation> {
         Some(ActivityConstraintViolation { code: self.code, stopped: false })
     }
+
+    /// Checks whether break starts after vehicle has worked required amount of time.
+    fn has_min_work_before(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> bool {
+        let min_work = match route_ctx.route.actor.vehicle.dimens.get_value::<f64>("min_work_before_break") {
+            Some(min_work) => *min_work,
+            None => return true,
+        };
+
+        let shift_start = route_ctx.route.tour.start().map_or(0., |start| start.schedule.departure);
+
+        self.get_break_start(route_ctx, activity_ctx) - shift_start >= min_work
+    }
+
+    /// Checks whether break does not exceed max amount of breaks and starts not closer than
+    /// required min spacing to other breaks in the tour.
+    fn has_break_limits(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> bool {
+        let dimens = &route_ctx.route.actor.vehicle.dimens;
+        let max_breaks = dimens.get_value::<usize>("max_breaks").cloned();
+        let min_spacing = dimens.get_value::<f64>("min_break_spacing").cloned();
+
+        if max_breaks.is_none() && min_spacing.is_none() {
+            return true;
+        }
+
+        let breaks = route_ctx.route.tour.all_activities().filter(|a| as_break_job(a).is_some()).collect::<Vec<_>>();
+
+        if max_breaks.map_or(false, |max_breaks| breaks.len() >= max_breaks) {
+            return false;
+        }
+
+        min_spacing.map_or(true, |min_spacing| {
+            let break_start = self.get_break_start(route_ctx, activity_ctx);
+            breaks.iter().all(|activity| (get_activity_start(activity) - break_start).abs() >= min_spacing)
+        })
+    }
+
+    /// Estimates start time of break inserted in given activity context.
+    fn get_break_start(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> f64 {
+        let route = route_ctx.route.as_ref();
+        let prev = activity_ctx.prev;
+        let target = activity_ctx.target;
+
+        let arrival = prev.schedule.departure
+            + self.transport.duration(
+                route.actor.vehicle.profile,
+                prev.place.location,
+                target.place.location,
+                prev.schedule.departure,
+            );
+
+        arrival.max(target.place.time.start)
+    }
 }
 
 impl HardActivityConstraint for BreakHardActivityConstraint {
     fn evaluate_activity(
         &self,
-        _: &RouteContext,
+        route_ctx: &RouteContext,
         activity_ctx: &ActivityContext,
     ) -> Option<ActivityConstraintViolation> {
         match as_break_job(&activity_ctx.target) {
             Some(_) if activity_ctx.prev.job.is_none() => self.stop(),
+            Some(_) if !self.has_min_work_before(route_ctx, activity_ctx) => self.stop(),
+            Some(_) if !self.has_break_limits(route_ctx, activity_ctx) => self.stop(),
             _ => None,
         }
     }
@@ -167,11 +227,15 @@ fn demote_unassigned_breaks(ctx: &mut SolutionContext) {
         return;
     }
 
-    // NOTE remove all breaks from list of unassigned jobs
+    // NOTE remove all breaks from list of unassigned jobs except mandatory ones
     let breaks_set: HashSet<_> = ctx
         .unassigned
         .iter()
-        .filter_map(|(job, _)| job.as_single().and_then(|single| get_vehicle_id_from_job(single).map(|_| job.clone())))
+        .filter_map(|(job, _)| {
+            job.as_single()
+                .filter(|single| !is_mandatory_break(single))
+                .and_then(|single| get_vehicle_id_from_job(single).map(|_| job.clone()))
+        })
         .collect();
 
     ctx.unassigned.retain(|job, _| breaks_set.get(job).is_none());
@@ -181,13 +245,20 @@ fn demote_unassigned_breaks(ctx: &mut SolutionContext) {
 /// Removes breaks which conditions are violated after ruin:
 /// * break without location served separately when original job is removed, but break is kept.
 /// * break is assigned right after departure
+/// * break starts before vehicle has worked required amount of time
+/// * break starts closer than required min spacing after previous break
 fn remove_orphan_breaks(ctx: &mut SolutionContext) {
     let breaks_set = ctx.routes.iter_mut().fold(HashSet::new(), |mut acc, rc: &mut RouteContext| {
+        let shift_start = rc.route.tour.start().map_or(0., |start| start.schedule.departure);
+        let min_work = rc.route.actor.vehicle.dimens.get_value::<f64>("min_work_before_break").cloned();
+        let min_spacing = rc.route.actor.vehicle.dimens.get_value::<f64>("min_break_spacing").cloned();
+
         // NOTE assume that first activity is never break (should be always departure)
-        let (_, breaks_set) = (0..).zip(rc.route.tour.all_activities()).fold(
-            (0, HashSet::new()),
-            |(prev, mut breaks), (idx, activity)| {
+        let (_, _, breaks_set) = (0..).zip(rc.route.tour.all_activities()).fold(
+            (0, None, HashSet::new()),
+            |(prev, last_break_start, mut breaks), (idx, activity)| {
                 let current = activity.place.location;
+                let mut last_break_start = last_break_start;
 
                 if let Some(break_job) = as_break_job(activity) {
                     // NOTE break should have location defined for all places or for none of them
@@ -196,14 +267,22 @@ fn remove_orphan_breaks(ctx: &mut SolutionContext) {
 
                     let is_orphan = prev != current && break_job.places.first().and_then(|p| p.location).is_none();
                     let is_dummy = idx == 1;
-
-                    if is_orphan || is_dummy {
+                    let break_start = get_activity_start(activity);
+                    let is_early = min_work.map_or(false, |min_work| break_start - shift_start < min_work);
+                    let is_close = match (min_spacing, last_break_start) {
+                        (Some(min_spacing), Some(last_break_start)) => break_start - last_break_start < min_spacing,
+                        _ => false,
+                    };
+
+                    if is_orphan || is_dummy || is_early || is_close {
                         // NOTE remove break with removed job location
                         breaks.insert(Job::Single(activity.job.as_ref().unwrap().clone()));
+                    } else {
+                        last_break_start = Some(break_start);
                     }
                 }
 
-                (current, breaks)
+                (current, last_break_start, breaks)
             },
         );
 
@@ -225,10 +304,18 @@ fn is_break_job(job: &Arc<Single>) -> bool {
     job.dimens.get_value::<String>("type").map_or(false, |t| t == "break")
 }
 
+fn is_mandatory_break(job: &Arc<Single>) -> bool {
+    is_break_job(job) && job.dimens.get_value::<bool>("required").cloned().unwrap_or(false)
+}
+
 fn as_break_job(activity: &Activity) -> Option<&Arc<Single>> {
     as_single_job(activity, |job| is_break_job(job))
 }
 
+fn get_activity_start(activity: &Activity) -> f64 {
+    activity.schedule.arrival.max(activity.place.time.start)
+}
+
 fn is_time(rc: &RouteContext, break_job: &Arc<Single>) -> bool {
     let departure = rc.route.tour.start().unwrap().schedule.departure;
     let arrival = rc.route.tour.end().map_or(0., |end| end.schedule.arrival);
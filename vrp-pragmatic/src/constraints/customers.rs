@@ -0,0 +1,131 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/customers_test.rs"]
+mod customers_test;
+
+use crate::constraints::CUSTOMER_COST_SOURCE;
+use std::collections::HashMap;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::{Cost, Location, ValueDimension};
+use vrp_core::models::problem::Job;
+use vrp_core::models::solution::{Activity, Route};
+
+/// A penalty for each extra visit of the same customer location within one tour.
+const SPLIT_VISIT_PENALTY: Cost = 1000.;
+
+/// Keeps jobs of the same customer at the same location contiguous in the tour, so they are served
+/// in one visit. Jobs are not forced to be assigned to the same tour. In hard mode, insertion which
+/// splits the visit is rejected, otherwise each extra visit is penalized.
+pub struct CustomerModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+    is_hard: bool,
+}
+
+impl CustomerModule {
+    pub fn new(code: i32, is_hard: bool) -> Self {
+        let constraint = if is_hard {
+            ConstraintVariant::HardActivity(Arc::new(CustomerHardActivityConstraint { code }))
+        } else {
+            ConstraintVariant::SoftActivity(Arc::new(CustomerSoftActivityConstraint {}))
+        };
+
+        Self { constraints: vec![constraint], keys: vec![], is_hard }
+    }
+}
+
+impl ConstraintModule for CustomerModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, route_ctx: &mut RouteContext, _job: &Job) {
+        self.accept_route_state(route_ctx);
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        if !self.is_hard {
+            let cost = get_customer_cost(&ctx.route);
+            set_extra_cost(ctx, CUSTOMER_COST_SOURCE, cost);
+        }
+    }
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct CustomerHardActivityConstraint {
+    code: i32,
+}
+
+impl HardActivityConstraint for CustomerHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        if get_new_split_visits(&route_ctx.route, activity_ctx) > 0 {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+}
+
+struct CustomerSoftActivityConstraint {}
+
+impl SoftActivityConstraint for CustomerSoftActivityConstraint {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        get_new_split_visits(&route_ctx.route, activity_ctx) as f64 * SPLIT_VISIT_PENALTY
+    }
+}
+
+/// Returns amount of customer visits which are split by inserting target activity.
+fn get_new_split_visits(route: &Route, activity_ctx: &ActivityContext) -> usize {
+    let prev_key = get_customer_key(activity_ctx.prev);
+    let next_key = activity_ctx.next.and_then(|next| get_customer_key(next));
+    let target_key = get_customer_key(activity_ctx.target);
+
+    let target_split = target_key.map_or(false, |target_key| {
+        prev_key != Some(target_key)
+            && next_key != Some(target_key)
+            && route.tour.all_activities().any(|activity| get_customer_key(activity) == Some(target_key))
+    });
+
+    let neighbours_split = prev_key.is_some() && prev_key == next_key && target_key != prev_key;
+
+    target_split as usize + neighbours_split as usize
+}
+
+/// Returns total amount of extra customer visits in the tour.
+pub(crate) fn get_customer_cost(route: &Route) -> Cost {
+    get_split_visits(route) as f64 * SPLIT_VISIT_PENALTY
+}
+
+fn get_split_visits(route: &Route) -> usize {
+    let (visits, _) = route.tour.all_activities().map(|activity| get_customer_key(activity)).fold(
+        (HashMap::<(&String, Location), usize>::new(), None),
+        |(mut visits, last_key), key| {
+            if let Some(key) = key.filter(|key| last_key != Some(*key)) {
+                *visits.entry(key).or_insert(0) += 1;
+            }
+
+            (visits, key)
+        },
+    );
+
+    visits.values().map(|count| count - 1).sum()
+}
+
+fn get_customer_key(activity: &Activity) -> Option<(&String, Location)> {
+    activity
+        .job
+        .as_ref()
+        .and_then(|single| single.dimens.get_value::<String>("customer"))
+        .map(|customer| (customer, activity.place.location))
+}
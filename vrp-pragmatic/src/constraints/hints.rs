@@ -0,0 +1,101 @@
+use crate::format::{CoordIndex, Location};
+use crate::utils::get_haversine_distance;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{RouteContext, SolutionContext};
+use vrp_core::models::common::{Cost, ValueDimension};
+use vrp_core::models::problem::{Job, TransportCost};
+use vrp_core::utils::compare_floats;
+
+/// A penalty for insertion into route which vehicle type differs from hinted one.
+const VEHICLE_TYPE_PENALTY: Cost = 1E6;
+/// A penalty per distance unit between hinted location and the closest location of the route.
+const LOCATION_PENALTY: Cost = 10.;
+
+/// Adds extra cost to insertions which do not follow job assignment hints, so recreate heuristics
+/// try hinted routes first. Hints never make insertion infeasible.
+pub struct HintModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl HintModule {
+    pub fn new(coord_index: Arc<CoordIndex>, transport: Arc<dyn TransportCost + Send + Sync>) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::SoftRoute(Arc::new(HintSoftRouteConstraint {
+                coord_index,
+                transport,
+            }))],
+            keys: vec![],
+        }
+    }
+}
+
+impl ConstraintModule for HintModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_ctx: &mut RouteContext, _job: &Job) {}
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct HintSoftRouteConstraint {
+    coord_index: Arc<CoordIndex>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl HintSoftRouteConstraint {
+    /// Returns distance to the closest route location: geo coordinates are compared using haversine
+    /// distance, location references use routing matrix distance.
+    fn get_distance_to_route(&self, ctx: &RouteContext, location: &Location) -> Option<f64> {
+        let activities = ctx.route.tour.all_activities();
+
+        match location {
+            Location::Coordinate { .. } => activities
+                .filter_map(|activity| self.coord_index.get_by_idx(&activity.place.location))
+                .filter(|route_location| route_location.to_lat_lng().is_some())
+                .map(|route_location| get_haversine_distance(&route_location, location))
+                .min_by(|a, b| compare_floats(*a, *b)),
+            Location::Reference { index } => {
+                let profile = ctx.route.actor.vehicle.profile;
+                activities
+                    .map(|activity| {
+                        let departure = activity.schedule.departure;
+                        self.transport.distance(profile, activity.place.location, *index, departure)
+                    })
+                    .filter(|distance| *distance >= 0.)
+                    .min_by(|a, b| compare_floats(*a, *b))
+            }
+        }
+    }
+}
+
+impl SoftRouteConstraint for HintSoftRouteConstraint {
+    fn estimate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Cost {
+        let dimens = job.dimens();
+
+        let vehicle_type_penalty = dimens.get_value::<String>("hint_vehicle_type").map_or(0., |vehicle_type| {
+            if ctx.route.actor.vehicle.dimens.get_value::<String>("type_id") == Some(vehicle_type) {
+                0.
+            } else {
+                VEHICLE_TYPE_PENALTY
+            }
+        });
+
+        let location_penalty = dimens
+            .get_value::<Location>("hint_location")
+            .and_then(|location| self.get_distance_to_route(ctx, location))
+            .map_or(0., |distance| distance * LOCATION_PENALTY);
+
+        vehicle_type_penalty + location_penalty
+    }
+}
@@ -0,0 +1,112 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/job_count_test.rs"]
+mod job_count_test;
+
+use crate::constraints::MIN_JOBS_COST_SOURCE;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{RouteContext, SolutionContext};
+use vrp_core::models::common::{Cost, ValueDimension};
+use vrp_core::models::problem::Job;
+use vrp_core::models::solution::Route;
+
+/// A penalty per job which is missing in used route to reach vehicle's minimum amount of jobs.
+const MISSING_JOB_PENALTY: Cost = 1000.;
+
+/// Limits amount of jobs served by vehicle: maximum is a hard limit, minimum is soft and applied
+/// as a penalty for used routes which serve less jobs. Breaks and reloads are not counted.
+pub struct JobCountModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl JobCountModule {
+    pub fn new(code: i32) -> Self {
+        Self {
+            constraints: vec![
+                ConstraintVariant::HardRoute(Arc::new(JobCountHardRouteConstraint { code })),
+                ConstraintVariant::SoftRoute(Arc::new(JobCountSoftRouteConstraint {})),
+            ],
+            keys: vec![],
+        }
+    }
+}
+
+impl ConstraintModule for JobCountModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, route_ctx: &mut RouteContext, _job: &Job) {
+        self.accept_route_state(route_ctx);
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let cost = get_min_jobs_cost(&ctx.route);
+        set_extra_cost(ctx, MIN_JOBS_COST_SOURCE, cost);
+    }
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct JobCountHardRouteConstraint {
+    code: i32,
+}
+
+impl HardRouteConstraint for JobCountHardRouteConstraint {
+    fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
+        let max_jobs = get_job_limit(&ctx.route, "max_jobs")?;
+
+        if is_counted_job(job) && get_job_count(&ctx.route) + 1 > max_jobs {
+            Some(RouteConstraintViolation { code: self.code })
+        } else {
+            None
+        }
+    }
+}
+
+struct JobCountSoftRouteConstraint {}
+
+impl SoftRouteConstraint for JobCountSoftRouteConstraint {
+    fn estimate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Cost {
+        let route = ctx.route.as_ref();
+
+        match get_job_limit(route, "min_jobs") {
+            Some(min_jobs) if is_counted_job(job) => {
+                let count = get_job_count(route);
+                let old_missing = if count == 0 { 0 } else { min_jobs.saturating_sub(count) };
+                let new_missing = min_jobs.saturating_sub(count + 1);
+
+                (new_missing as f64 - old_missing as f64) * MISSING_JOB_PENALTY
+            }
+            _ => 0.,
+        }
+    }
+}
+
+/// Returns penalty of given route caused by vehicle's minimum amount of jobs.
+pub(crate) fn get_min_jobs_cost(route: &Route) -> Cost {
+    get_job_limit(route, "min_jobs").map_or(0., |min_jobs| match get_job_count(route) {
+        0 => 0.,
+        count => min_jobs.saturating_sub(count) as f64 * MISSING_JOB_PENALTY,
+    })
+}
+
+fn get_job_limit(route: &Route, key: &str) -> Option<usize> {
+    route.actor.vehicle.dimens.get_value::<usize>(key).cloned()
+}
+
+fn get_job_count(route: &Route) -> usize {
+    route.tour.jobs().filter(is_counted_job).count()
+}
+
+fn is_counted_job(job: &Job) -> bool {
+    job.as_single()
+        .and_then(|single| single.dimens.get_value::<String>("type"))
+        .map_or(true, |job_type| job_type != "break" && job_type != "reload")
+}
@@ -0,0 +1,91 @@
+use crate::constraints::{estimate_route_end, get_route_end, MIN_DURATION_COST_SOURCE};
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::{Cost, Duration, Timestamp, ValueDimension};
+use vrp_core::models::problem::{Job, TransportCost};
+use vrp_core::models::solution::Route;
+
+/// Applies extra cost when used vehicle works less than its paid minimum duration: missing time
+/// is charged using vehicle's time cost.
+pub struct MinDurationModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl MinDurationModule {
+    pub fn new(transport: Arc<dyn TransportCost + Send + Sync>) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::SoftActivity(Arc::new(MinDurationSoftActivityConstraint {
+                transport,
+            }))],
+            keys: vec![],
+        }
+    }
+}
+
+impl ConstraintModule for MinDurationModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, route_ctx: &mut RouteContext, _job: &Job) {
+        self.accept_route_state(route_ctx);
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let cost = get_min_duration_cost(&ctx.route);
+        set_extra_cost(ctx, MIN_DURATION_COST_SOURCE, cost);
+    }
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct MinDurationSoftActivityConstraint {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl SoftActivityConstraint for MinDurationSoftActivityConstraint {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        let route = route_ctx.route.as_ref();
+        let min_duration = match get_min_duration(route) {
+            Some(min_duration) => min_duration,
+            None => return 0.,
+        };
+
+        let new_end = estimate_route_end(self.transport.as_ref(), route, activity_ctx);
+        let new_missing = (min_duration - (new_end - get_route_start(route))).max(0.);
+
+        (new_missing - get_missing_duration(route, min_duration)) * get_time_cost(route)
+    }
+}
+
+/// Returns extra cost of given route caused by paid minimum duration.
+pub(crate) fn get_min_duration_cost(route: &Route) -> Cost {
+    get_min_duration(route).map_or(0., |min_duration| get_missing_duration(route, min_duration) * get_time_cost(route))
+}
+
+fn get_missing_duration(route: &Route, min_duration: Duration) -> Duration {
+    if route.tour.job_count() == 0 {
+        0.
+    } else {
+        (min_duration - (get_route_end(route) - get_route_start(route))).max(0.)
+    }
+}
+
+fn get_min_duration(route: &Route) -> Option<Duration> {
+    route.actor.vehicle.dimens.get_value::<f64>("min_duration").cloned()
+}
+
+fn get_time_cost(route: &Route) -> Cost {
+    route.actor.vehicle.costs.per_driving_time + route.actor.driver.costs.per_driving_time
+}
+
+fn get_route_start(route: &Route) -> Timestamp {
+    route.tour.start().map_or(0., |start| start.schedule.departure)
+}
@@ -1,10 +1,31 @@
 //! Contains implementation of extra constraints.
 
 use std::sync::Arc;
-use vrp_core::models::common::{Dimensions, IdDimension, ValueDimension};
-use vrp_core::models::problem::Single;
+use vrp_core::construction::heuristics::ActivityContext;
+use vrp_core::models::common::{Dimensions, IdDimension, Timestamp, ValueDimension};
+use vrp_core::models::problem::{Single, TransportCost};
 use vrp_core::models::solution::{Activity, Route};
 
+/// An extra route cost source id used by overtime module.
+const OVERTIME_COST_SOURCE: i32 = 1;
+/// An extra route cost source id used by avoidance module.
+const AVOIDANCE_COST_SOURCE: i32 = 2;
+/// An extra route cost source id used by u-turn module.
+const UTURN_COST_SOURCE: i32 = 3;
+/// An extra route cost source id used by min duration module.
+const MIN_DURATION_COST_SOURCE: i32 = 4;
+/// An extra route cost source id used by job count module.
+const MIN_JOBS_COST_SOURCE: i32 = 5;
+/// An extra route cost source id used by customer module.
+const CUSTOMER_COST_SOURCE: i32 = 6;
+/// An extra route cost source id used by stop cost module.
+const STOP_COST_SOURCE: i32 = 7;
+
+/// A key which tracks amount of picked up jobs which are not delivered yet.
+const CURRENT_PICKUPS_KEY: i32 = 30;
+/// A key which tracks max amount of not delivered picked up jobs in the rest of the tour.
+const MAX_FUTURE_PICKUPS_KEY: i32 = 31;
+
 fn as_single_job<F>(activity: &Activity, condition: F) -> Option<&Arc<Single>>
 where
     F: Fn(&Arc<Single>) -> bool,
@@ -25,9 +46,67 @@ fn is_correct_vehicle(route: &Route, target_id: &String, target_shift: usize) ->
         && get_shift_index(&route.actor.vehicle.dimens) == target_shift
 }
 
+fn get_route_end(route: &Route) -> Timestamp {
+    route.tour.end().map_or(0., |end| end.schedule.departure)
+}
+
+/// Estimates route end time if target activity is inserted between prev and next ones.
+fn estimate_route_end(
+    transport: &(dyn TransportCost + Send + Sync),
+    route: &Route,
+    activity_ctx: &ActivityContext,
+) -> Timestamp {
+    let profile = route.actor.vehicle.profile;
+    let prev = activity_ctx.prev;
+    let target = activity_ctx.target;
+
+    let departure = prev.schedule.departure;
+    let arrival = departure + transport.duration(profile, prev.place.location, target.place.location, departure);
+    let departure = arrival.max(target.place.time.start) + target.place.duration;
+
+    match activity_ctx.next {
+        Some(next) => {
+            let arrival =
+                departure + transport.duration(profile, target.place.location, next.place.location, departure);
+
+            if next.job.is_none() {
+                arrival
+            } else {
+                get_route_end(route) + (arrival - next.schedule.arrival).max(0.)
+            }
+        }
+        None => departure,
+    }
+}
+
+mod avoidance;
+pub use self::avoidance::AvoidanceModule;
+
 mod breaks;
 pub use self::breaks::BreakModule;
 
+mod customers;
+pub(crate) use self::customers::get_customer_cost;
+pub use self::customers::CustomerModule;
+
+mod hints;
+pub use self::hints::HintModule;
+
+mod job_count;
+pub(crate) use self::job_count::get_min_jobs_cost;
+pub use self::job_count::JobCountModule;
+
+mod min_duration;
+pub(crate) use self::min_duration::get_min_duration_cost;
+pub use self::min_duration::MinDurationModule;
+
+mod overtime;
+pub(crate) use self::overtime::get_overtime_cost;
+pub use self::overtime::OvertimeModule;
+
+mod pickup_limit;
+pub use self::pickup_limit::PickupLimitModule;
+
 mod priorities;
 pub use self::priorities::PriorityModule;
 
@@ -37,5 +116,10 @@ pub use self::reloads::ReloadMultiTrip;
 mod reachable;
 pub use self::reachable::ReachableModule;
 
-mod skills;
-pub use self::skills::SkillsModule;
+mod stop_cost;
+pub(crate) use self::stop_cost::get_stop_cost;
+pub use self::stop_cost::StopCostModule;
+
+mod uturn;
+pub(crate) use self::uturn::get_u_turn_cost;
+pub use self::uturn::UTurnModule;
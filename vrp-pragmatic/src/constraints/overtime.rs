@@ -0,0 +1,86 @@
+use crate::constraints::{estimate_route_end, get_route_end, OVERTIME_COST_SOURCE};
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::{Cost, Timestamp, ValueDimension};
+use vrp_core::models::problem::{Job, TransportCost};
+use vrp_core::models::solution::Route;
+
+/// Applies extra cost for time spent by vehicle after its shift end.
+pub struct OvertimeModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl OvertimeModule {
+    pub fn new(transport: Arc<dyn TransportCost + Send + Sync>) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::SoftActivity(Arc::new(OvertimeSoftActivityConstraint { transport }))],
+            keys: vec![],
+        }
+    }
+}
+
+impl ConstraintModule for OvertimeModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, route_ctx: &mut RouteContext, _job: &Job) {
+        self.accept_route_state(route_ctx);
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let cost = get_overtime_cost(&ctx.route);
+        set_extra_cost(ctx, OVERTIME_COST_SOURCE, cost);
+    }
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct OvertimeSoftActivityConstraint {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl SoftActivityConstraint for OvertimeSoftActivityConstraint {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        let route = route_ctx.route.as_ref();
+        let (shift_end, overtime_cost) = match get_overtime_params(route) {
+            Some(params) => params,
+            None => return 0.,
+        };
+
+        let old_end = get_route_end(route);
+        let new_end = estimate_route_end(self.transport.as_ref(), route, activity_ctx);
+
+        ((new_end - shift_end).max(0.) - get_overtime(route, old_end, shift_end)) * overtime_cost
+    }
+}
+
+/// Returns overtime cost of given route.
+pub(crate) fn get_overtime_cost(route: &Route) -> Cost {
+    get_overtime_params(route)
+        .map_or(0., |(shift_end, overtime_cost)| get_overtime(route, get_route_end(route), shift_end) * overtime_cost)
+}
+
+fn get_overtime(route: &Route, route_end: Timestamp, shift_end: Timestamp) -> f64 {
+    if route.tour.job_count() == 0 {
+        0.
+    } else {
+        (route_end - shift_end).max(0.)
+    }
+}
+
+fn get_overtime_params(route: &Route) -> Option<(Timestamp, Cost)> {
+    let dimens = &route.actor.vehicle.dimens;
+
+    match (dimens.get_value::<f64>("shift_end"), dimens.get_value::<f64>("overtime_cost")) {
+        (Some(shift_end), Some(overtime_cost)) => Some((*shift_end, *overtime_cost)),
+        _ => None,
+    }
+}
@@ -0,0 +1,102 @@
+use crate::constraints::{CURRENT_PICKUPS_KEY, MAX_FUTURE_PICKUPS_KEY};
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::ValueDimension;
+use vrp_core::models::problem::{Job, Multi};
+use vrp_core::models::solution::Activity;
+
+/// Limits amount of picked up jobs which vehicle carries without delivering them. A pickup of
+/// pickup and delivery job is counted till its delivery, a standalone pickup - till the tour end.
+pub struct PickupLimitModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl PickupLimitModule {
+    pub fn new(code: i32) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(PickupLimitHardActivityConstraint { code }))],
+            keys: vec![CURRENT_PICKUPS_KEY, MAX_FUTURE_PICKUPS_KEY],
+        }
+    }
+}
+
+impl ConstraintModule for PickupLimitModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, route_ctx: &mut RouteContext, _job: &Job) {
+        self.accept_route_state(route_ctx);
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        if get_max_pickups(ctx).is_none() {
+            return;
+        }
+
+        let (route, state) = ctx.as_mut();
+
+        let current = route.tour.all_activities().fold(0, |current, activity| {
+            let current = (current + get_pickup_change(activity)).max(0);
+            state.put_activity_state(CURRENT_PICKUPS_KEY, activity, current);
+
+            current
+        });
+
+        route.tour.all_activities().rev().fold(current, |max, activity| {
+            let max = max.max(*state.get_activity_state::<i32>(CURRENT_PICKUPS_KEY, activity).unwrap());
+            state.put_activity_state(MAX_FUTURE_PICKUPS_KEY, activity, max);
+
+            max
+        });
+    }
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct PickupLimitHardActivityConstraint {
+    code: i32,
+}
+
+impl HardActivityConstraint for PickupLimitHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let max_pickups = get_max_pickups(route_ctx)?;
+
+        if get_pickup_change(activity_ctx.target) <= 0 {
+            return None;
+        }
+
+        let future =
+            *route_ctx.state.get_activity_state::<i32>(MAX_FUTURE_PICKUPS_KEY, activity_ctx.prev).unwrap_or(&0);
+
+        if future + 1 > max_pickups as i32 {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+}
+
+fn get_max_pickups(ctx: &RouteContext) -> Option<usize> {
+    ctx.route.actor.vehicle.dimens.get_value::<usize>("max_pickups").cloned()
+}
+
+/// Returns change of picked up jobs amount after given activity is served.
+fn get_pickup_change(activity: &Activity) -> i32 {
+    activity.job.as_ref().map_or(0, |single| match single.dimens.get_value::<String>("type").map(|t| t.as_str()) {
+        Some("pickup") => 1,
+        Some("delivery") if Multi::roots(single).is_some() => -1,
+        _ => 0,
+    })
+}
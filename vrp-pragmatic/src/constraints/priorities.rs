@@ -2,7 +2,7 @@ use std::slice::Iter;
 use std::sync::Arc;
 use vrp_core::construction::constraints::*;
 use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
-use vrp_core::models::common::ValueDimension;
+use vrp_core::models::common::PriorityDimension;
 use vrp_core::models::problem::Job;
 
 /** Adds some extra penalty to jobs with priority bigger than 1. */
@@ -85,9 +85,5 @@ impl HardActivityConstraint for PriorityHardActivityConstraint {
 }
 
 fn get_priority(job: &Job) -> Option<i32> {
-    match job {
-        Job::Single(job) => job.dimens.get_value::<i32>("priority"),
-        Job::Multi(job) => job.dimens.get_value::<i32>("priority"),
-    }
-    .cloned()
+    job.dimens().get_priority().cloned()
 }
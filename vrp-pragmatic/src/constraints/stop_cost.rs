@@ -0,0 +1,105 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/stop_cost_test.rs"]
+mod stop_cost_test;
+
+use crate::constraints::STOP_COST_SOURCE;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::{Cost, ValueDimension};
+use vrp_core::models::problem::Job;
+use vrp_core::models::solution::{Activity, Route};
+
+/// Applies extra cost for each stop made by vehicle with per stop cost. Jobs served one after
+/// another at the same location are considered as one stop.
+pub struct StopCostModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl StopCostModule {
+    pub fn new() -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::SoftActivity(Arc::new(StopCostSoftActivityConstraint {}))],
+            keys: vec![],
+        }
+    }
+}
+
+impl Default for StopCostModule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConstraintModule for StopCostModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, route_ctx: &mut RouteContext, _job: &Job) {
+        self.accept_route_state(route_ctx);
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let cost = get_stop_cost(&ctx.route);
+        set_extra_cost(ctx, STOP_COST_SOURCE, cost);
+    }
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct StopCostSoftActivityConstraint {}
+
+impl SoftActivityConstraint for StopCostSoftActivityConstraint {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        let per_stop = match get_per_stop_cost(&route_ctx.route) {
+            Some(per_stop) => per_stop,
+            None => return 0.,
+        };
+
+        let prev = activity_ctx.prev;
+        let target = activity_ctx.target;
+
+        let target_stops = if is_stop_activity(target) && is_new_stop(prev, target) { 1 } else { 0 };
+        let next_stops = match activity_ctx.next {
+            Some(next) if is_stop_activity(next) => {
+                (if is_new_stop(target, next) { 1 } else { 0 }) - (if is_new_stop(prev, next) { 1 } else { 0 })
+            }
+            _ => 0,
+        };
+
+        (target_stops + next_stops) as f64 * per_stop
+    }
+}
+
+/// Returns extra cost of given route caused by stops made.
+pub(crate) fn get_stop_cost(route: &Route) -> Cost {
+    get_per_stop_cost(route).map_or(0., |per_stop| get_stop_count(route) as f64 * per_stop)
+}
+
+fn get_stop_count(route: &Route) -> usize {
+    let activities = route.tour.all_activities().collect::<Vec<_>>();
+
+    activities.windows(2).filter(|pair| is_stop_activity(pair[1]) && is_new_stop(pair[0], pair[1])).count()
+}
+
+fn get_per_stop_cost(route: &Route) -> Option<Cost> {
+    route.actor.vehicle.dimens.get_value::<f64>("per_stop_cost").cloned()
+}
+
+/// Checks whether activity starts a new stop when it is served after given previous one.
+fn is_new_stop(prev: &Activity, activity: &Activity) -> bool {
+    !is_stop_activity(prev) || prev.place.location != activity.place.location
+}
+
+fn is_stop_activity(activity: &Activity) -> bool {
+    activity.job.as_ref().map_or(false, |single| {
+        single.dimens.get_value::<String>("type").map_or(true, |job_type| job_type != "break" && job_type != "reload")
+    })
+}
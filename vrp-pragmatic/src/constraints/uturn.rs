@@ -0,0 +1,130 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/uturn_test.rs"]
+mod uturn_test;
+
+use crate::constraints::UTURN_COST_SOURCE;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::{Cost, Distance, Profile, ValueDimension};
+use vrp_core::models::problem::{Job, TransportCost};
+use vrp_core::models::solution::{Activity, Route};
+
+/// Applies penalty for u-turns: when vehicle returns back to the location which it has visited
+/// right before the previous activity and does not progress further than vehicle's minimum distance.
+pub struct UTurnModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl UTurnModule {
+    pub fn new(transport: Arc<dyn TransportCost + Send + Sync>) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::SoftActivity(Arc::new(UTurnSoftActivityConstraint {
+                transport: transport.clone(),
+            }))],
+            keys: vec![],
+            transport,
+        }
+    }
+}
+
+impl ConstraintModule for UTurnModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, route_ctx: &mut RouteContext, _job: &Job) {
+        self.accept_route_state(route_ctx);
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let cost = get_u_turn_cost(&ctx.route, self.transport.as_ref());
+        set_extra_cost(ctx, UTURN_COST_SOURCE, cost);
+    }
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct UTurnSoftActivityConstraint {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl SoftActivityConstraint for UTurnSoftActivityConstraint {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        let route = route_ctx.route.as_ref();
+        let (min_progress, penalty) = match get_u_turn_params(route) {
+            Some(params) => params,
+            None => return 0.,
+        };
+
+        let profile = route.actor.vehicle.profile;
+        let count_u_turn = |first: Option<&Activity>, second: Option<&Activity>, third: Option<&Activity>| match (
+            first, second, third,
+        ) {
+            (Some(first), Some(second), Some(third)) => {
+                is_u_turn(self.transport.as_ref(), profile, min_progress, first, second, third)
+            }
+            _ => false,
+        }
+            as i32;
+
+        let index = activity_ctx.index;
+        let before_prev = if index > 0 { route.tour.get(index - 1).map(|a| a.as_ref()) } else { None };
+        let prev = Some(activity_ctx.prev.as_ref());
+        let target = Some(activity_ctx.target.as_ref());
+        let next = activity_ctx.next.map(|a| a.as_ref());
+        let after_next = next.and_then(|_| route.tour.get(index + 2)).map(|a| a.as_ref());
+
+        let old_turns = count_u_turn(before_prev, prev, next) + count_u_turn(prev, next, after_next);
+        let new_turns = count_u_turn(before_prev, prev, target)
+            + count_u_turn(prev, target, next)
+            + count_u_turn(target, next, after_next);
+
+        (new_turns - old_turns) as f64 * penalty
+    }
+}
+
+/// Returns u-turn cost of given route.
+pub(crate) fn get_u_turn_cost(route: &Route, transport: &(dyn TransportCost + Send + Sync)) -> Cost {
+    get_u_turn_params(route).map_or(0., |(min_progress, penalty)| {
+        let profile = route.actor.vehicle.profile;
+        let activities = route.tour.all_activities().collect::<Vec<_>>();
+
+        activities
+            .windows(3)
+            .filter(|items| match items {
+                [first, second, third] => is_u_turn(transport, profile, min_progress, first, second, third),
+                _ => false,
+            })
+            .count() as f64
+            * penalty
+    })
+}
+
+/// Checks whether vehicle moves away from the first activity and returns back to its location
+/// for the third one. Returning to the end of the tour is not considered as u-turn.
+fn is_u_turn(
+    transport: &(dyn TransportCost + Send + Sync),
+    profile: Profile,
+    min_progress: Distance,
+    first: &Activity,
+    second: &Activity,
+    third: &Activity,
+) -> bool {
+    second.job.is_some()
+        && third.job.is_some()
+        && first.place.location != second.place.location
+        && transport.distance(profile, first.place.location, third.place.location, first.schedule.departure)
+            < min_progress
+}
+
+fn get_u_turn_params(route: &Route) -> Option<(Distance, Cost)> {
+    route.actor.vehicle.dimens.get_value::<(f64, f64)>("u_turn").cloned()
+}
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+use vrp_core::models::common::{Cost, Distance, Duration, Location, Profile, Timestamp};
+use vrp_core::models::problem::{Actor, TransportCost};
+
+/// Treats floating start location as one which has zero distance and duration to any other location.
+pub struct FloatingStartTransportCost {
+    inner: Arc<dyn TransportCost + Send + Sync>,
+    location: Location,
+}
+
+impl FloatingStartTransportCost {
+    /// Creates a new instance of `FloatingStartTransportCost`.
+    pub fn new(inner: Arc<dyn TransportCost + Send + Sync>, location: Location) -> Self {
+        Self { inner, location }
+    }
+
+    fn is_floating(&self, from: Location, to: Location) -> bool {
+        from == self.location || to == self.location
+    }
+}
+
+impl TransportCost for FloatingStartTransportCost {
+    fn cost(&self, actor: &Actor, profile: Profile, from: Location, to: Location, departure: Timestamp) -> Cost {
+        if self.is_floating(from, to) {
+            0.
+        } else {
+            self.inner.cost(actor, profile, from, to, departure)
+        }
+    }
+
+    fn duration(&self, profile: Profile, from: Location, to: Location, departure: Timestamp) -> Duration {
+        if self.is_floating(from, to) {
+            0.
+        } else {
+            self.inner.duration(profile, from, to, departure)
+        }
+    }
+
+    fn distance(&self, profile: Profile, from: Location, to: Location, departure: Timestamp) -> Distance {
+        if self.is_floating(from, to) {
+            0.
+        } else {
+            self.inner.distance(profile, from, to, departure)
+        }
+    }
+
+    fn is_time_aware(&self) -> bool {
+        self.inner.is_time_aware()
+    }
+}
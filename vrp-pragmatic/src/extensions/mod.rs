@@ -1,5 +1,8 @@
 //! Contains some algorithm extensions.
 
+mod floating_start_transport_cost;
+pub use self::floating_start_transport_cost::FloatingStartTransportCost;
+
 mod multi_dim_capacity;
 pub use self::multi_dim_capacity::MultiDimensionalCapacity;
 
@@ -6,15 +6,18 @@ use std::cmp::Ordering::Less;
 use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
 
-/// A helper struct which keeps track of coordinate mapping.
+/// A helper struct which keeps track of coordinate mapping. Geo coordinates which are equal within
+/// configured precision are mapped to the same index, the first added one is used to represent it.
 pub struct CoordIndex {
     direct_index: HashMap<Location, usize>,
     reverse_index: HashMap<usize, Location>,
+    precision: Option<u32>,
 }
 
 impl CoordIndex {
     pub fn new(problem: &Problem) -> Self {
-        let mut index = Self { direct_index: Default::default(), reverse_index: Default::default() };
+        let precision = problem.config.as_ref().and_then(|config| config.location_precision);
+        let mut index = Self { direct_index: Default::default(), reverse_index: Default::default(), precision };
 
         // process plan
         problem.plan.jobs.iter().for_each(|job| {
@@ -55,41 +58,88 @@ impl CoordIndex {
         index
     }
 
+    /// Adds location to index. Coordinates get the next free index, while references keep the one
+    /// they point to in routing matrix.
     pub fn add(&mut self, location: &Location) {
-        if self.direct_index.get(location).is_none() {
-            let value = self.direct_index.len();
-            self.direct_index.insert(location.clone(), value);
+        let key = self.get_key(location);
+        if self.direct_index.get(&key).is_none() {
+            let value = match location {
+                Location::Coordinate { .. } => self.direct_index.len(),
+                Location::Reference { index } => *index,
+            };
+            self.direct_index.insert(key, value);
             self.reverse_index.insert(value, location.clone());
         }
     }
 
     pub fn get_by_loc(&self, location: &Location) -> Option<usize> {
-        self.direct_index.get(location).cloned()
+        self.direct_index.get(&self.get_key(location)).cloned()
     }
 
     pub fn get_by_idx(&self, index: &usize) -> Option<Location> {
         self.reverse_index.get(index).cloned()
     }
 
+    /// Returns index which is not used by any real location.
+    pub fn floating_index(&self) -> usize {
+        self.reverse_index.keys().max().map_or(0, |index| index + 1)
+    }
+
+    /// Returns true if there is at least one location represented by geo coordinate.
+    pub fn has_coordinates(&self) -> bool {
+        self.direct_index.keys().any(|location| matches!(location, Location::Coordinate { .. }))
+    }
+
+    /// Returns true if there is at least one location represented by reference in routing matrix.
+    pub fn has_references(&self) -> bool {
+        self.direct_index.keys().any(|location| matches!(location, Location::Reference { .. }))
+    }
+
     pub fn unique(&self) -> Vec<Location> {
         let mut sorted_pairs: Vec<_> = self.reverse_index.iter().collect();
         sorted_pairs.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(Less));
         sorted_pairs.iter().map(|pair| pair.1.clone()).collect()
     }
+
+    /// Returns location used as index key: geo coordinate is rounded to configured precision.
+    fn get_key(&self, location: &Location) -> Location {
+        match (location, self.precision) {
+            (Location::Coordinate { lat, lng }, Some(precision)) => {
+                let factor = 10_f64.powi(precision as i32);
+                Location::Coordinate { lat: (lat * factor).round() / factor, lng: (lng * factor).round() / factor }
+            }
+            _ => location.clone(),
+        }
+    }
 }
 
 impl Eq for Location {}
 
 impl PartialEq for Location {
     fn eq(&self, other: &Self) -> bool {
-        self.lat == other.lat && self.lng == other.lng
+        match (self, other) {
+            (Location::Coordinate { lat: l_lat, lng: l_lng }, Location::Coordinate { lat: r_lat, lng: r_lng }) => {
+                l_lat == r_lat && l_lng == r_lng
+            }
+            (Location::Reference { index: l_index }, Location::Reference { index: r_index }) => l_index == r_index,
+            _ => false,
+        }
     }
 }
 
 impl Hash for Location {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        write_hash(self.lat, state);
-        write_hash(self.lng, state);
+        match self {
+            Location::Coordinate { lat, lng } => {
+                state.write_u8(0);
+                write_hash(*lat, state);
+                write_hash(*lng, state);
+            }
+            Location::Reference { index } => {
+                state.write_u8(1);
+                state.write_usize(*index);
+            }
+        }
     }
 }
 
@@ -6,19 +6,42 @@ extern crate serde_json;
 use serde::{Deserialize, Serialize};
 use std::io::BufWriter;
 
-/// A location type represented by latitude and longitude.
+/// A location type represented by geo coordinate or by index in routing matrix.
 #[derive(Clone, Debug, Deserialize, Serialize)]
-pub struct Location {
-    /// Latitude.
-    pub lat: f64,
-    /// Longitude.
-    pub lng: f64,
+#[serde(untagged)]
+pub enum Location {
+    /// A location represented by geo coordinate with latitude and longitude.
+    Coordinate {
+        /// Latitude.
+        lat: f64,
+        /// Longitude.
+        lng: f64,
+    },
+    /// A location represented by index in routing matrix. Such location has no coordinate, so
+    /// features which require coordinates are not available.
+    Reference {
+        /// An index in routing matrix.
+        index: usize,
+    },
 }
 
 impl Location {
-    /// Creates new `[Location]`.
+    /// Creates new `[Location]` represented by geo coordinate.
     pub fn new(lat: f64, lng: f64) -> Self {
-        Self { lat, lng }
+        Self::Coordinate { lat, lng }
+    }
+
+    /// Creates new `[Location]` represented by index in routing matrix.
+    pub fn new_reference(index: usize) -> Self {
+        Self::Reference { index }
+    }
+
+    /// Returns latitude and longitude if location is represented by geo coordinate.
+    pub fn to_lat_lng(&self) -> Option<(f64, f64)> {
+        match self {
+            Self::Coordinate { lat, lng } => Some((*lat, *lng)),
+            Self::Reference { .. } => None,
+        }
     }
 }
 
@@ -77,6 +100,29 @@ const LOCKING_CONSTRAINT_CODE: i32 = 7;
 const REACHABLE_CONSTRAINT_CODE: i32 = 8;
 const PRIORITY_CONSTRAINT_CODE: i32 = 9;
 const AREA_CONSTRAINT_CODE: i32 = 10;
+const PICKUP_LIMIT_CONSTRAINT_CODE: i32 = 11;
+const JOB_COUNT_CONSTRAINT_CODE: i32 = 12;
+const CUSTOMER_CONSTRAINT_CODE: i32 = 13;
+
+/// Maps internal constraint code to public reason code and its description.
+pub(crate) fn map_code_reason(code: i32) -> (i32, &'static str) {
+    match code {
+        SKILLS_CONSTRAINT_CODE => (1, "cannot serve required skill"),
+        TIME_CONSTRAINT_CODE => (2, "cannot be visited within time window"),
+        CAPACITY_CONSTRAINT_CODE => (3, "does not fit into any vehicle due to capacity"),
+        REACHABLE_CONSTRAINT_CODE => (100, "location unreachable"),
+        DISTANCE_LIMIT_CONSTRAINT_CODE => (101, "cannot be assigned due to max distance constraint of vehicle"),
+        DURATION_LIMIT_CONSTRAINT_CODE => (102, "cannot be assigned due to shift time constraint of vehicle"),
+        BREAK_CONSTRAINT_CODE => (103, "break is not assignable"),
+        LOCKING_CONSTRAINT_CODE => (104, "cannot be served due to relation lock"),
+        PRIORITY_CONSTRAINT_CODE => (105, "cannot be served due to priority"),
+        AREA_CONSTRAINT_CODE => (106, "cannot be assigned due to area constraint"),
+        PICKUP_LIMIT_CONSTRAINT_CODE => (107, "cannot be assigned due to max pickups constraint of vehicle"),
+        JOB_COUNT_CONSTRAINT_CODE => (108, "cannot be assigned due to max jobs constraint of vehicle"),
+        CUSTOMER_CONSTRAINT_CODE => (109, "cannot be served in one visit with other jobs of the same customer"),
+        _ => (0, "unknown"),
+    }
+}
 
 mod coord_index;
 pub use self::coord_index::CoordIndex;
@@ -1,8 +1,10 @@
 use crate::extensions::{create_typed_actor_groups, MultiDimensionalCapacity};
 use crate::format::coord_index::CoordIndex;
+use crate::format::problem::reader::get_speed_model;
 use crate::format::problem::reader::{add_skills, ApiProblem, ProblemProperties};
-use crate::format::problem::Matrix;
+use crate::format::problem::{Matrix, MatrixFallback};
 use crate::parse_time;
+use crate::utils::get_haversine_distance;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use vrp_core::construction::constraints::CapacityDimension;
@@ -13,6 +15,7 @@ use vrp_core::models::problem::*;
 pub fn create_transport_costs(
     api_problem: &ApiProblem,
     matrices: &Vec<Matrix>,
+    coord_index: &CoordIndex,
 ) -> Result<Arc<dyn TransportCost + Sync + Send>, String> {
     let fleet_profiles = get_profile_map(api_problem);
 
@@ -21,12 +24,14 @@ pub fn create_transport_costs(
         .filter_map(|matrix| fleet_profiles.get(&matrix.profile).map(|profile| (profile, matrix)))
         .map(|(profile, matrix)| {
             let (durations, distances) = if let Some(error_codes) = &matrix.error_codes {
+                let size = (error_codes.len() as f64).sqrt() as usize;
                 let mut durations: Vec<Duration> = Default::default();
                 let mut distances: Vec<Distance> = Default::default();
                 for (i, error) in error_codes.iter().enumerate() {
                     if *error > 0 {
-                        durations.push(-1.);
-                        distances.push(-1.);
+                        let (duration, distance) = get_fallback_routing(api_problem, matrix, coord_index, i, size);
+                        durations.push(duration);
+                        distances.push(distance);
                     } else {
                         durations.push(*matrix.travel_times.get(i).unwrap() as f64);
                         distances.push(*matrix.distances.get(i).unwrap() as f64);
@@ -40,7 +45,10 @@ pub fn create_transport_costs(
                 )
             };
 
-            MatrixData::new(*profile, durations, distances)
+            MatrixData {
+                timestamp: matrix.timestamp.as_ref().map(parse_time),
+                ..MatrixData::new(*profile, durations, distances)
+            }
         })
         .collect::<Vec<_>>();
 
@@ -53,6 +61,58 @@ pub fn create_transport_costs(
     create_matrix_transport_cost(matrix_data)
 }
 
+/// Returns duration and distance for matrix item marked by error code.
+fn get_fallback_routing(
+    api_problem: &ApiProblem,
+    matrix: &Matrix,
+    coord_index: &CoordIndex,
+    index: usize,
+    size: usize,
+) -> (Duration, Distance) {
+    match &matrix.fallback {
+        Some(MatrixFallback::Approximation) => {
+            let get_location = |idx: usize| coord_index.get_by_idx(&idx).expect("cannot find matrix location");
+            let distance = get_haversine_distance(&get_location(index / size), &get_location(index % size)).round();
+            let speed =
+                get_speed_model(api_problem.fleet.profiles.iter().find(|profile| profile.name == matrix.profile))
+                    .get_speed(distance);
+
+            ((distance / speed).round(), distance)
+        }
+        Some(MatrixFallback::Penalty { distance, duration }) => (*duration, *distance),
+        Some(MatrixFallback::Unreachable) | None => (-1., -1.),
+    }
+}
+
+pub fn read_avoidance(api_problem: &ApiProblem, matrices: &Vec<Matrix>) -> Option<Vec<ProfileAvoidance>> {
+    let fleet_profiles = get_profile_map(api_problem);
+
+    let avoidance = matrices
+        .iter()
+        .filter_map(|matrix| {
+            let profile = fleet_profiles.get(&matrix.profile)?;
+            let avoidance = matrix.avoidance.as_ref()?;
+            let size = (avoidance.flags.len() as f64).sqrt() as usize;
+
+            let legs = avoidance
+                .flags
+                .iter()
+                .enumerate()
+                .filter(|(_, flag)| **flag != 0)
+                .map(|(idx, _)| (idx / size, idx % size))
+                .collect();
+
+            Some((*profile as Profile, avoidance.multiplier, legs))
+        })
+        .collect::<Vec<_>>();
+
+    if avoidance.is_empty() {
+        None
+    } else {
+        Some(avoidance)
+    }
+}
+
 pub fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, coord_index: &CoordIndex) -> Fleet {
     let profiles = get_profile_map(api_problem);
     let mut vehicles: Vec<Arc<Vehicle>> = Default::default();
@@ -62,18 +122,31 @@ pub fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, coord_ind
             fixed: vehicle.costs.fixed.unwrap_or(0.),
             per_distance: vehicle.costs.distance,
             per_driving_time: vehicle.costs.time,
-            per_waiting_time: vehicle.costs.time,
+            per_waiting_time: vehicle.costs.waiting.unwrap_or(vehicle.costs.time),
             per_service_time: vehicle.costs.time,
         };
 
         let profile = *profiles.get(&vehicle.profile).unwrap() as Profile;
+        let loaded_profile = vehicle.loaded_profile.as_ref().map(|name| *profiles.get(name).unwrap() as Profile);
         let areas = vehicle.limits.as_ref().and_then(|l| l.allowed_areas.as_ref()).map(|areas| {
-            areas.iter().map(|area| area.iter().map(|l| (l.lat, l.lng)).collect::<Vec<_>>()).collect::<Vec<_>>()
+            areas.iter().map(|area| area.iter().filter_map(|l| l.to_lat_lng()).collect::<Vec<_>>()).collect::<Vec<_>>()
         });
+        let max_distance = vehicle.limits.as_ref().and_then(|l| l.max_distance);
+        let shift_time = vehicle.limits.as_ref().and_then(|l| l.shift_time);
+        let max_pickups = vehicle.limits.as_ref().and_then(|l| l.max_pickups);
+        let min_work_before_break = vehicle.limits.as_ref().and_then(|l| l.min_work_before_break);
+        let max_breaks = vehicle.limits.as_ref().and_then(|l| l.max_breaks);
+        let min_break_spacing = vehicle.limits.as_ref().and_then(|l| l.min_break_spacing);
+        let min_jobs = vehicle.limits.as_ref().and_then(|l| l.min_jobs);
+        let max_jobs = vehicle.limits.as_ref().and_then(|l| l.max_jobs);
 
         for (shift_index, shift) in vehicle.shifts.iter().enumerate() {
             let start = {
-                let location = coord_index.get_by_loc(&shift.start.location).unwrap();
+                let location = if shift.floating_start == Some(true) {
+                    coord_index.floating_index()
+                } else {
+                    coord_index.get_by_loc(&shift.start.location).unwrap()
+                };
                 let time = parse_time(&shift.start.time);
                 (location, time)
             };
@@ -84,10 +157,12 @@ pub fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, coord_ind
                 Some((location, time))
             });
 
+            let max_overtime = shift.max_overtime.unwrap_or(0.);
+
             let details = vec![VehicleDetail {
                 start: Some(start.0),
                 end: end.map_or(None, |end| Some(end.0)),
-                time: Some(TimeWindow::new(start.1, end.map_or(std::f64::MAX, |end| end.1))),
+                time: Some(TimeWindow::new(start.1, end.map_or(std::f64::MAX, |end| end.1 + max_overtime))),
             }];
 
             vehicle.vehicle_ids.iter().for_each(|vehicle_id| {
@@ -100,6 +175,63 @@ pub fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, coord_ind
                     dimens.set_value("areas", areas);
                 }
 
+                if let Some(max_distance) = max_distance {
+                    dimens.set_value("max_distance", max_distance);
+                }
+
+                if let Some(shift_time) = shift_time {
+                    dimens.set_value("shift_time", shift_time);
+                }
+
+                if let Some(max_pickups) = max_pickups {
+                    dimens.set_value("max_pickups", max_pickups);
+                }
+
+                if let Some(min_work_before_break) = min_work_before_break {
+                    dimens.set_value("min_work_before_break", min_work_before_break);
+                }
+
+                if let Some(max_breaks) = max_breaks {
+                    dimens.set_value("max_breaks", max_breaks);
+                }
+
+                if let Some(min_break_spacing) = min_break_spacing {
+                    dimens.set_value("min_break_spacing", min_break_spacing);
+                }
+
+                if let Some(min_jobs) = min_jobs {
+                    dimens.set_value("min_jobs", min_jobs);
+                }
+
+                if let Some(max_jobs) = max_jobs {
+                    dimens.set_value("max_jobs", max_jobs);
+                }
+
+                if let (Some(end), Some(overtime_cost)) = (end, vehicle.costs.overtime) {
+                    dimens.set_value("shift_end", end.1);
+                    dimens.set_value("overtime_cost", overtime_cost);
+                }
+
+                if let Some(min_duration) = vehicle.costs.min_duration {
+                    dimens.set_value("min_duration", min_duration);
+                }
+
+                if let Some(per_stop) = vehicle.costs.per_stop {
+                    dimens.set_value("per_stop_cost", per_stop);
+                }
+
+                if let Some(loaded_profile) = loaded_profile {
+                    dimens.set_value("loaded_profile", loaded_profile);
+                }
+
+                if let Some(u_turn) = &vehicle.u_turn {
+                    dimens.set_value("u_turn", (u_turn.min_progress, u_turn.penalty));
+                }
+
+                if vehicle.must_use == Some(true) {
+                    dimens.set_value("must_use", true);
+                }
+
                 if props.has_multi_dimen_capacity {
                     dimens.set_capacity(MultiDimensionalCapacity::new(vehicle.capacity.clone()));
                 } else {
@@ -1,16 +1,23 @@
 use crate::extensions::MultiDimensionalCapacity;
 use crate::format::coord_index::CoordIndex;
 use crate::format::problem::reader::{add_skills, parse_time_window, ApiProblem, JobIndex, ProblemProperties};
-use crate::format::problem::{JobTask, RelationType, VehicleBreak, VehicleBreakTime, VehicleReload, VehicleType};
+use crate::format::problem::{
+    DurationPolicy, JobHint, JobPlace, JobTask, RelationType, VehicleBreak, VehicleBreakTime, VehicleReload,
+    VehicleType,
+};
 use crate::format::Location;
 use crate::utils::VariableJobPermutation;
 use std::collections::HashMap;
 use std::sync::Arc;
 use vrp_core::construction::constraints::{Demand, DemandDimension};
-use vrp_core::models::common::{Dimensions, Duration, IdDimension, TimeOffset, TimeSpan, TimeWindow, ValueDimension};
+use vrp_core::models::common::{
+    Dimensions, Duration, IdDimension, PriorityDimension, TimeOffset, TimeSpan, TimeWindow, ValueDimension,
+};
 use vrp_core::models::problem::{Actor, Fleet, Job, Jobs, Multi, Place, Single, TransportCost};
 use vrp_core::models::{Lock, LockDetail, LockOrder, LockPosition};
 
+type ApiJob = crate::format::problem::Job;
+
 // TODO configure sample size
 const MULTI_JOB_SAMPLE_SIZE: usize = 3;
 
@@ -59,27 +66,57 @@ pub fn read_locks(api_problem: &ApiProblem, job_index: &JobIndex) -> Vec<Arc<Loc
                 _ => LockPosition::Any,
             };
 
-            let (_, _, jobs) = rel
+            let get_tag = |idx: usize| rel.tags.as_ref().and_then(|tags| tags.get(idx)).and_then(|tag| tag.as_ref());
+            let tagged = rel
                 .jobs
                 .iter()
-                .filter(|job| job.as_str() != "departure" && job.as_str() != "arrival")
-                .fold((0_usize, 0_usize, vec![]), |(mut break_idx, mut reload_idx, mut jobs), job| {
-                    let job = match job.as_str() {
-                        "break" => {
-                            break_idx += 1;
-                            job_index.get(format!("{}_break_{}", vehicle_id, break_idx).as_str()).cloned().unwrap()
-                        }
-                        "reload" => {
-                            reload_idx += 1;
-                            job_index.get(format!("{}_reload_{}", vehicle_id, reload_idx).as_str()).cloned().unwrap()
-                        }
-                        _ => job_index.get(job).unwrap().clone(),
-                    };
-
-                    jobs.push(job);
+                .enumerate()
+                .filter_map(|(idx, job_id)| get_tag(idx).and_then(|tag| get_tagged_single(job_index.get(job_id)?, tag)))
+                .collect::<Vec<_>>();
 
-                    (break_idx, reload_idx, jobs)
-                });
+            let (_, _, _, jobs) = rel
+                .jobs
+                .iter()
+                .enumerate()
+                .filter(|(_, job)| job.as_str() != "departure" && job.as_str() != "arrival")
+                .fold(
+                    (0_usize, 0_usize, Vec::<Arc<Single>>::new(), vec![]),
+                    |(mut break_idx, mut reload_idx, mut used, mut jobs), (idx, job_id)| {
+                        let job = match job_id.as_str() {
+                            "break" => {
+                                break_idx += 1;
+                                job_index.get(format!("{}_break_{}", vehicle_id, break_idx).as_str()).cloned().unwrap()
+                            }
+                            "reload" => {
+                                reload_idx += 1;
+                                job_index
+                                    .get(format!("{}_reload_{}", vehicle_id, reload_idx).as_str())
+                                    .cloned()
+                                    .unwrap()
+                            }
+                            _ => match job_index.get(job_id).unwrap() {
+                                Job::Multi(multi) => {
+                                    let is_free = |single: &&Arc<Single>| {
+                                        !tagged.iter().chain(used.iter()).any(|other| Arc::ptr_eq(other, single))
+                                    };
+                                    let single = match get_tag(idx) {
+                                        Some(tag) => get_tagged_single(&Job::Multi(multi.clone()), tag),
+                                        None => multi.jobs.iter().find(is_free).cloned(),
+                                    }
+                                    .unwrap_or_else(|| panic!("cannot find task of job '{}' used in relation", job_id));
+
+                                    used.push(single.clone());
+                                    Job::Single(single)
+                                }
+                                job => job.clone(),
+                            },
+                        };
+
+                        jobs.push(job);
+
+                        (break_idx, reload_idx, used, jobs)
+                    },
+                );
 
             acc.push(LockDetail::new(order, position, jobs));
 
@@ -94,6 +131,18 @@ pub fn read_locks(api_problem: &ApiProblem, job_index: &JobIndex) -> Vec<Arc<Loc
     locks
 }
 
+/// Returns sub job of multi job which has given tag.
+fn get_tagged_single(job: &Job, tag: &str) -> Option<Arc<Single>> {
+    match job {
+        Job::Multi(multi) => multi
+            .jobs
+            .iter()
+            .find(|single| single.dimens.get_value::<String>("tag").map_or(false, |t| t == tag))
+            .cloned(),
+        Job::Single(_) => None,
+    }
+}
+
 fn read_required_jobs(
     api_problem: &ApiProblem,
     props: &ProblemProperties,
@@ -116,13 +165,25 @@ fn read_required_jobs(
             _ => panic!("Invalid activity type."),
         };
 
-        let places =
-            task.places.iter().map(|p| (Some(p.location.clone()), p.duration, parse_times(&p.times))).collect();
+        let places = task
+            .places
+            .iter()
+            .map(|p| (Some(p.location.clone()), get_place_duration(p), parse_times(&p.times)))
+            .collect();
 
         get_single_with_extras(places, demand, &task.tag, activity_type, has_multi_dimens, &coord_index)
     };
 
+    let hints = api_problem
+        .plan
+        .hints
+        .iter()
+        .flat_map(|hints| hints.iter())
+        .map(|hint| (hint.job_id.clone(), hint))
+        .collect::<HashMap<_, _>>();
+
     api_problem.plan.jobs.iter().for_each(|job| {
+        let hint = hints.get(&job.id).cloned();
         let pickups = job.pickups.as_ref().map_or(0, |p| p.len());
         let deliveries = job.deliveries.as_ref().map_or(0, |p| p.len());
         let is_static_demand = pickups == 0 || deliveries == 0;
@@ -149,9 +210,9 @@ fn read_required_jobs(
         assert!(singles.len() > 0);
 
         let problem_job = if singles.len() > 1 {
-            get_multi_job(&job.id, &job.priority, &job.skills, singles, job.pickups.as_ref().map_or(0, |p| p.len()))
+            get_multi_job(job, hint, singles, job.pickups.as_ref().map_or(0, |p| p.len()))
         } else {
-            get_single_job(&job.id, singles.into_iter().next().unwrap(), &job.priority, &job.skills)
+            get_single_job(job, hint, singles.into_iter().next().unwrap())
         };
 
         job_index.insert(job.id.clone(), problem_job.clone());
@@ -221,7 +282,9 @@ fn read_breaks(
                         vec![(None, place.duration, times)]
                     };
 
-                    let job = get_conditional_job(coord_index, vehicle_id.clone(), "break", shift_index, places, &None);
+                    let mut job =
+                        get_conditional_job(coord_index, vehicle_id.clone(), "break", shift_index, places, &None);
+                    add_required(&mut job.dimens, &place.mandatory);
 
                     (job_id, job)
                 })
@@ -329,29 +392,42 @@ fn get_single_with_extras(
     single
 }
 
-fn get_single_job(id: &String, single: Single, priority: &Option<i32>, skills: &Option<Vec<String>>) -> Job {
+fn get_single_job(job: &ApiJob, hint: Option<&JobHint>, single: Single) -> Job {
     let mut single = single;
-    single.dimens.set_id(id.as_str());
+    single.dimens.set_id(job.id.as_str());
 
-    add_priority(&mut single.dimens, priority);
-    add_skills(&mut single.dimens, skills);
+    add_priority(&mut single.dimens, &job.priority);
+    add_skills(&mut single.dimens, &job.skills);
+    add_required(&mut single.dimens, &job.required);
+    add_probability(&mut single.dimens, &job.probability);
+    add_unassigned_penalty(&mut single.dimens, &job.unassigned_penalty);
+    add_customer(&mut single.dimens, &job.customer);
+    add_metadata(&mut single.dimens, &job.metadata);
+    add_hint(&mut single.dimens, hint);
 
     Job::Single(Arc::new(single))
 }
 
-fn get_multi_job(
-    id: &String,
-    priority: &Option<i32>,
-    skills: &Option<Vec<String>>,
-    singles: Vec<Single>,
-    deliveries_start_index: usize,
-) -> Job {
+fn get_multi_job(job: &ApiJob, hint: Option<&JobHint>, singles: Vec<Single>, deliveries_start_index: usize) -> Job {
     let mut dimens: Dimensions = Default::default();
-    dimens.set_id(id.as_str());
-    add_priority(&mut dimens, priority);
-    add_skills(&mut dimens, skills);
-
-    let singles = singles.into_iter().map(Arc::new).collect::<Vec<_>>();
+    dimens.set_id(job.id.as_str());
+    add_priority(&mut dimens, &job.priority);
+    add_skills(&mut dimens, &job.skills);
+    add_required(&mut dimens, &job.required);
+    add_probability(&mut dimens, &job.probability);
+    add_unassigned_penalty(&mut dimens, &job.unassigned_penalty);
+    add_customer(&mut dimens, &job.customer);
+    add_metadata(&mut dimens, &job.metadata);
+    add_hint(&mut dimens, hint);
+
+    // NOTE customer is also kept on each sub job as it is checked on activity level
+    let singles = singles
+        .into_iter()
+        .map(|mut single| {
+            add_customer(&mut single.dimens, &job.customer);
+            Arc::new(single)
+        })
+        .collect::<Vec<_>>();
 
     let multi = if singles.len() == 2 && deliveries_start_index == 1 {
         Multi::new(singles, dimens)
@@ -382,10 +458,59 @@ fn add_tag(dimens: &mut Dimensions, tag: &Option<String>) {
 
 fn add_priority(dimens: &mut Dimensions, priority: &Option<i32>) {
     if let Some(priority) = priority {
-        dimens.set_value("priority", *priority);
+        dimens.set_priority(*priority);
+    }
+}
+
+fn add_required(dimens: &mut Dimensions, required: &Option<bool>) {
+    if let Some(true) = required {
+        dimens.set_value("required", true);
+    }
+}
+
+fn add_probability(dimens: &mut Dimensions, probability: &Option<f64>) {
+    if let Some(probability) = probability {
+        dimens.set_value("probability", *probability);
+    }
+}
+
+fn add_unassigned_penalty(dimens: &mut Dimensions, unassigned_penalty: &Option<f64>) {
+    if let Some(unassigned_penalty) = unassigned_penalty {
+        dimens.set_value("unassigned_penalty", *unassigned_penalty);
+    }
+}
+
+fn add_customer(dimens: &mut Dimensions, customer: &Option<String>) {
+    if let Some(customer) = customer {
+        dimens.set_value("customer", customer.clone());
+    }
+}
+
+fn add_metadata(dimens: &mut Dimensions, metadata: &Option<HashMap<String, String>>) {
+    if let Some(metadata) = metadata {
+        dimens.set_value("metadata", metadata.clone());
     }
 }
 
+fn add_hint(dimens: &mut Dimensions, hint: Option<&JobHint>) {
+    if let Some(hint) = hint {
+        if let Some(vehicle_type) = &hint.vehicle_type {
+            dimens.set_value("hint_vehicle_type", vehicle_type.clone());
+        }
+
+        if let Some(location) = &hint.location {
+            dimens.set_value("hint_location", location.clone());
+        }
+    }
+}
+
+fn get_place_duration(place: &JobPlace) -> Duration {
+    place.durations.as_ref().map_or(place.duration, |durations| match durations.policy {
+        Some(DurationPolicy::Max) => durations.delivery.max(durations.pickup),
+        _ => durations.delivery + durations.pickup,
+    })
+}
+
 fn empty() -> MultiDimensionalCapacity {
     MultiDimensionalCapacity::default()
 }
@@ -1,4 +1,4 @@
-//! Specifies logic to read problem and routing matrix from json input.
+//! Specifies logic to read problem and routing matrix from json input and to write problem back.
 //!
 
 mod model;
@@ -6,3 +6,6 @@ pub use self::model::*;
 
 mod reader;
 pub use self::reader::PragmaticProblem;
+
+mod writer;
+pub use self::writer::write_pragmatic_problem;
@@ -6,7 +6,8 @@ extern crate serde_json;
 
 use crate::format::{FormatError, Location};
 use serde::{Deserialize, Serialize};
-use serde_json::Error;
+use serde_json::{Error, Value};
+use std::collections::HashMap;
 use std::io::{BufReader, Read};
 use std::io::{BufWriter, Write};
 
@@ -38,6 +39,46 @@ pub struct Relation {
     /// Vehicle shift index.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub shift_index: Option<usize>,
+    /// List of job task tags, one per job id. When tag is specified, it targets specific task of
+    /// the job, otherwise the first unused one is taken.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<Option<String>>>,
+}
+
+/// A job assignment hint: a soft preference which is tried first by insertion heuristics.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobHint {
+    /// Job id.
+    pub job_id: String,
+    /// Preferred vehicle type id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vehicle_type: Option<String>,
+    /// Preferred location: routes which pass close to it are preferred.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+}
+
+/// Specifies how separate delivery and pickup durations are combined into service time.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DurationPolicy {
+    /// Service time is sum of both durations.
+    Sum,
+    /// Service time is the longest of durations.
+    Max,
+}
+
+/// Specifies separate delivery and pickup durations of replacement job place.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct JobPlaceDurations {
+    /// Time needed to unload delivered goods.
+    pub delivery: f64,
+    /// Time needed to load picked up goods.
+    pub pickup: f64,
+    /// A policy to combine durations. Default is sum.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<DurationPolicy>,
 }
 
 /// Specifies a place for sub job.
@@ -50,6 +91,10 @@ pub struct JobPlace {
     /// A list of job place time windows with time specified in RFC3339 format.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub times: Option<Vec<Vec<String>>>,
+    /// Separate delivery and pickup durations of replacement task. When specified, service time
+    /// is calculated from them instead of `duration`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub durations: Option<JobPlaceDurations>,
 }
 
 /// Specifies a job task.
@@ -70,6 +115,7 @@ pub struct JobTask {
 /// * all of them should be completed or none of them.
 /// * all pickups must be completed before any of deliveries.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Job {
     /// A job id.
     pub id: String,
@@ -97,6 +143,28 @@ pub struct Job {
     /// A set of skills required to serve a job.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub skills: Option<Vec<String>>,
+
+    /// Specifies whether job must be assigned. Omitting required job is penalized much more
+    /// than omitting optional one. Default is false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required: Option<bool>,
+
+    /// An occurrence probability of the job in range (0, 1]. Default is 1, i.e. the job is certain.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub probability: Option<f64>,
+
+    /// A penalty for leaving optional job unassigned. Bigger value - more important. Default is 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unassigned_penalty: Option<f64>,
+
+    /// A customer id. Jobs of the same customer at the same location are served in one visit,
+    /// without leaving the location in between, when they are assigned to the same tour.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<String>,
+
+    /// An arbitrary user metadata which is ignored by solver and returned back within job activities.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
 }
 
 /// A plan specifies work which has to be done.
@@ -107,6 +175,9 @@ pub struct Plan {
     /// List of relations between jobs and vehicles.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub relations: Option<Vec<Relation>>,
+    /// List of job assignment hints.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hints: Option<Vec<JobHint>>,
 }
 
 // endregion
@@ -115,6 +186,7 @@ pub struct Plan {
 
 /// Specifies vehicle costs.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct VehicleCosts {
     /// Fixed is cost of vehicle usage per tour.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -123,6 +195,20 @@ pub struct VehicleCosts {
     pub distance: f64,
     /// Cost per time unit.
     pub time: f64,
+    /// Cost per waiting time unit. When omitted, time cost is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub waiting: Option<f64>,
+    /// Extra cost per time unit spent after shift end.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overtime: Option<f64>,
+    /// A paid minimum duration of used vehicle's tour. When tour is shorter, the rest is charged
+    /// using time cost.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_duration: Option<f64>,
+    /// A flat fee charged per each stop made. Jobs served one after another at the same location
+    /// form a single stop.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_stop: Option<f64>,
 }
 
 /// Specifies vehicle place.
@@ -136,6 +222,7 @@ pub struct VehiclePlace {
 
 /// Specifies vehicle shift.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct VehicleShift {
     /// Vehicle start place.
     pub start: VehiclePlace,
@@ -144,6 +231,15 @@ pub struct VehicleShift {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub end: Option<VehiclePlace>,
 
+    /// Max time which vehicle is allowed to work after shift end.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_overtime: Option<f64>,
+
+    /// Specifies whether vehicle starts at its first job. If set to true, start location is ignored
+    /// and travel to the first job is free. Default is false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub floating_start: Option<bool>,
+
     /// Vehicle breaks.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub breaks: Option<Vec<VehicleBreak>>,
@@ -190,6 +286,50 @@ pub struct VehicleLimits {
     /// No area restrictions when omitted.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub allowed_areas: Option<Vec<Vec<Location>>>,
+
+    /// Max amount of picked up jobs which are not delivered yet.
+    /// No restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_pickups: Option<usize>,
+
+    /// Min time which vehicle should work since shift start before its break can be started.
+    /// No restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_work_before_break: Option<f64>,
+
+    /// Max amount of breaks which vehicle can have during shift.
+    /// No restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_breaks: Option<usize>,
+
+    /// Min time between starts of two breaks.
+    /// No restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_break_spacing: Option<f64>,
+
+    /// Min amount of jobs which used vehicle should serve. It is a soft limit: a penalty is
+    /// applied when vehicle serves less jobs. Breaks and reloads are not counted.
+    /// No restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_jobs: Option<usize>,
+
+    /// Max amount of jobs which vehicle can serve. Breaks and reloads are not counted.
+    /// No restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_jobs: Option<usize>,
+}
+
+/// Specifies how vehicle is penalized for u-turns: returning back to the location visited right
+/// before the previous one.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleUTurn {
+    /// A minimum distance between locations of activities separated by one activity. Smaller
+    /// distance is considered as u-turn.
+    pub min_progress: f64,
+
+    /// A penalty cost applied for each u-turn.
+    pub penalty: f64,
 }
 
 /// Vehicle break time variant.
@@ -214,6 +354,11 @@ pub struct VehicleBreak {
     /// Break locations.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub locations: Option<Vec<Location>>,
+
+    /// Specifies whether break must be scheduled. Solution without mandatory break is penalized
+    /// and considered as infeasible by checker.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mandatory: Option<bool>,
 }
 
 /// Specifies a vehicle type.
@@ -229,6 +374,11 @@ pub struct VehicleType {
     /// Vehicle profile name.
     pub profile: String,
 
+    /// Vehicle profile name used to travel when vehicle carries some load. If not specified,
+    /// `profile` is used for all trips.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loaded_profile: Option<String>,
+
     /// Vehicle costs.
     pub costs: VehicleCosts,
 
@@ -245,10 +395,27 @@ pub struct VehicleType {
     /// Vehicle limits.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limits: Option<VehicleLimits>,
+    /// Vehicle u-turn penalty.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub u_turn: Option<VehicleUTurn>,
+    /// Specifies whether vehicle should be used: leaving it idle is penalized.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub must_use: Option<bool>,
+}
+
+/// Specifies road class used by routing approximation.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoadClass {
+    /// Minimum leg distance (meters) for which the class is used.
+    pub min_distance: f64,
+    /// Class speed (meters per second).
+    pub speed: f64,
 }
 
 /// Specifies routing profile.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Profile {
     /// Profile name.
     pub name: String,
@@ -261,6 +428,12 @@ pub struct Profile {
     /// Default value is 10.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub speed: Option<f64>,
+
+    /// Road classes used to approximate leg speed from its distance: a class with the biggest minimum
+    /// distance which does not exceed leg distance overrides profile speed. Used only when routing
+    /// matrix is not specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub road_classes: Option<Vec<RoadClass>>,
 }
 
 /// Specifies fleet.
@@ -276,9 +449,24 @@ pub struct Fleet {
 
 // region Configuration
 
-/// Specifies extra configuration (reserved for future).
+/// Specifies extra configuration.
 #[derive(Clone, Deserialize, Debug, Serialize)]
-pub struct Config {}
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// Amount of decimal digits used to compare geo coordinates: coordinates which are equal
+    /// within this precision share the same location index in routing matrix. When omitted,
+    /// coordinates are compared exactly.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location_precision: Option<u32>,
+    /// A grace period after job time window end: arrival within it is treated as on-time.
+    /// Default is zero.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_window_grace: Option<f64>,
+    /// A global factor applied to all routing durations, e.g. to model traffic conditions.
+    /// Default is one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub traffic_factor: Option<f64>,
+}
 
 // endregion
 
@@ -294,6 +482,40 @@ pub struct Objectives {
     /// by the secondary objective if it improves the primary one.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub secondary: Option<Vec<Objective>>,
+    /// A list of weights for primary objectives. When specified, primary objectives are combined
+    /// into a weighted sum instead of being compared one by one. Secondary objectives cannot be
+    /// used together with weights.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weights: Option<Vec<f64>>,
+    /// A list of normalizations for weighted primary objectives which brings their values to
+    /// comparable scales. Objectives with null normalization use raw values.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub normalizations: Option<Vec<Option<ObjectiveNormalization>>>,
+}
+
+/// Specifies how objective value is normalized before weighting.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(tag = "type")]
+pub enum ObjectiveNormalization {
+    /// Maps objective value from [min, max] range into [0, 1].
+    #[serde(rename(deserialize = "min-max", serialize = "min-max"))]
+    MinMax {
+        /// Min expected value.
+        min: f64,
+        /// Max expected value.
+        max: f64,
+    },
+
+    /// Divides objective value by reference scale.
+    #[serde(rename(deserialize = "reference", serialize = "reference"))]
+    Reference {
+        /// A reference scale.
+        scale: f64,
+    },
+
+    /// Divides objective value by its value in the initial solution.
+    #[serde(rename(deserialize = "estimated", serialize = "estimated"))]
+    Estimated,
 }
 
 /// Specifies objective function types.
@@ -312,10 +534,28 @@ pub enum Objective {
     #[serde(rename(deserialize = "maximize-tours", serialize = "maximize-tours"))]
     MaximizeTours,
 
+    /// An objective to minimize expected total cost over sampled scenarios where jobs are present
+    /// according to their occurrence probability.
+    #[serde(rename(deserialize = "minimize-expected-cost", serialize = "minimize-expected-cost"))]
+    MinimizeExpectedCost {
+        /// An amount of sampled scenarios. Default is 32.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        samples: Option<usize>,
+    },
+
     /// An objective to minimize amount of unassigned jobs.
     #[serde(rename(deserialize = "minimize-unassigned", serialize = "minimize-unassigned"))]
     MinimizeUnassignedJobs,
 
+    /// An objective to minimize total cost where each next used tour has its own fleet cost
+    /// which is added to transport cost.
+    #[serde(rename(deserialize = "minimize-fleet-cost", serialize = "minimize-fleet-cost"))]
+    MinimizeFleetCost {
+        /// A cost of each next tour: the first value is used for the first tour, the second one
+        /// for the second tour and so on. The last value is used for all remaining tours.
+        costs: Vec<f64>,
+    },
+
     /// An objective to balance max load across all tours.
     #[serde(rename(deserialize = "balance-max-load", serialize = "balance-max-load"))]
     BalanceMaxLoad {
@@ -382,6 +622,11 @@ pub struct Problem {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub objectives: Option<Objectives>,
 
+    /// An overall planning horizon specified by start and end dates in RFC3339 format. All job
+    /// and vehicle times have to be within it. No restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub horizon: Option<Vec<String>>,
+
     /// Extra configuration.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub config: Option<Config>,
@@ -406,19 +651,68 @@ pub struct Matrix {
     /// Error codes to mark unreachable locations.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error_codes: Option<Vec<i64>>,
+
+    /// Specifies routing info used for location pairs marked by error codes.
+    /// Such pairs are considered as unreachable when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fallback: Option<MatrixFallback>,
+
+    /// Legs which should be avoided by vehicles of given profile.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avoidance: Option<MatrixAvoidance>,
+}
+
+/// Specifies legs of routing matrix which are more expensive for vehicles of given profile.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct MatrixAvoidance {
+    /// A cost multiplier applied to flagged legs.
+    pub multiplier: f64,
+
+    /// Flags to mark legs to be avoided: non-zero value means that leg is flagged.
+    pub flags: Vec<i64>,
+}
+
+/// Specifies routing info used for location pairs marked by error codes in routing matrix.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(tag = "type")]
+pub enum MatrixFallback {
+    /// Locations are considered as unreachable.
+    #[serde(rename(deserialize = "unreachable", serialize = "unreachable"))]
+    Unreachable,
+
+    /// Routing info is approximated using haversine distance and profile speed.
+    #[serde(rename(deserialize = "approximation", serialize = "approximation"))]
+    Approximation,
+
+    /// Routing info is set to fixed penalty values.
+    #[serde(rename(deserialize = "penalty", serialize = "penalty"))]
+    Penalty {
+        /// A distance used for location pair.
+        distance: f64,
+        /// A duration used for location pair.
+        duration: f64,
+    },
 }
 
 // endregion
 
 /// Deserializes problem in json format from [`BufReader`].
+/// Named capacity and demand maps, e.g. `{ "weight": 10, "volume": 5 }`, are converted to vectors
+/// where dimensions are ordered by name and missing dimensions are set to zero.
 pub fn deserialize_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, Vec<FormatError>> {
-    serde_json::from_reader(reader).map_err(|err| {
-        vec![FormatError::new(
-            "E0000".to_string(),
-            "cannot deserialize problem".to_string(),
-            format!("check input json: '{}'", err),
-        )]
-    })
+    serde_json::from_reader(reader)
+        .map_err(|err| err.to_string())
+        .and_then(|mut problem: Value| {
+            align_named_capacities(&mut problem)?;
+            serde_json::from_value(problem).map_err(|err| err.to_string())
+        })
+        .map_err(|err| {
+            vec![FormatError::new(
+                "E0000".to_string(),
+                "cannot deserialize problem".to_string(),
+                format!("check input json: '{}'", err),
+            )]
+        })
 }
 
 /// Deserializes routing matrix in json format from [`BufReader`].
@@ -436,3 +730,68 @@ pub fn deserialize_matrix<R: Read>(reader: BufReader<R>) -> Result<Matrix, Vec<F
 pub fn serialize_problem<W: Write>(writer: BufWriter<W>, problem: &Problem) -> Result<(), Error> {
     serde_json::to_writer_pretty(writer, problem)
 }
+
+/// Replaces named capacity and demand maps with vectors using the same dimension index.
+fn align_named_capacities(problem: &mut Value) -> Result<(), String> {
+    let mut values = get_capacity_values(problem);
+
+    if !values.iter().any(|value| value.is_object()) {
+        return Ok(());
+    }
+
+    if values.iter().any(|value| !value.is_object()) {
+        return Err("capacity and demand should be either all named or all positional".to_string());
+    }
+
+    let mut names =
+        values.iter().filter_map(|value| value.as_object()).flat_map(|value| value.keys().cloned()).collect::<Vec<_>>();
+    names.sort();
+    names.dedup();
+
+    values.iter_mut().for_each(|value| {
+        let dimensions =
+            names.iter().map(|name| value.get(name).cloned().unwrap_or_else(|| Value::from(0))).collect::<Vec<_>>();
+
+        **value = Value::Array(dimensions);
+    });
+
+    Ok(())
+}
+
+fn get_capacity_values(problem: &mut Value) -> Vec<&mut Value> {
+    problem
+        .as_object_mut()
+        .into_iter()
+        .flat_map(|problem| problem.iter_mut())
+        .flat_map(|(key, value)| match key.as_str() {
+            "plan" => get_demand_values(value),
+            "fleet" => get_vehicle_capacity_values(value),
+            _ => vec![],
+        })
+        .collect()
+}
+
+fn get_demand_values(plan: &mut Value) -> Vec<&mut Value> {
+    plan.get_mut("jobs")
+        .and_then(|jobs| jobs.as_array_mut())
+        .into_iter()
+        .flat_map(|jobs| jobs.iter_mut())
+        .filter_map(|job| job.as_object_mut())
+        .flat_map(|job| job.iter_mut())
+        .filter(|(key, _)| ["pickups", "deliveries", "replacements", "services"].contains(&key.as_str()))
+        .filter_map(|(_, tasks)| tasks.as_array_mut())
+        .flat_map(|tasks| tasks.iter_mut())
+        .filter_map(|task| task.get_mut("demand"))
+        .filter(|demand| !demand.is_null())
+        .collect()
+}
+
+fn get_vehicle_capacity_values(fleet: &mut Value) -> Vec<&mut Value> {
+    fleet
+        .get_mut("vehicles")
+        .and_then(|vehicles| vehicles.as_array_mut())
+        .into_iter()
+        .flat_map(|vehicles| vehicles.iter_mut())
+        .filter_map(|vehicle| vehicle.get_mut("capacity"))
+        .collect()
+}
@@ -1,18 +1,21 @@
 use crate::extensions::MultiDimensionalCapacity;
 use crate::format::problem::reader::{ApiProblem, ProblemProperties};
-use crate::format::problem::BalanceOptions;
 use crate::format::problem::Objective::*;
+use crate::format::problem::{BalanceOptions, ObjectiveNormalization};
 use std::sync::Arc;
 use vrp_core::construction::constraints::{ConstraintPipeline, FleetUsageConstraintModule};
-use vrp_core::models::problem::{ObjectiveCost, TargetConstraint, TargetObjective};
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::models::common::MultiObjective;
+use vrp_core::models::problem::ObjectiveNormalization as CoreNormalization;
+use vrp_core::models::problem::{ObjectiveCost, TargetConstraint, TargetObjective, WeightedObjectiveCost};
 use vrp_core::solver::objectives::*;
 
 pub fn create_objective(
     api_problem: &ApiProblem,
     constraint: &mut ConstraintPipeline,
     props: &ProblemProperties,
-) -> Arc<ObjectiveCost> {
-    Arc::new(if let Some(objectives) = &api_problem.objectives {
+) -> Arc<dyn MultiObjective<Solution = InsertionContext> + Send + Sync> {
+    if let Some(objectives) = &api_problem.objectives {
         let mut map_objectives = |objectives: &Vec<_>| {
             let mut core_objectives: Vec<TargetObjective> = vec![];
             objectives.iter().for_each(|objective| match objective {
@@ -25,7 +28,14 @@ pub fn create_objective(
                     constraint.add_module(Box::new(FleetUsageConstraintModule::new_maximized()));
                     core_objectives.push(Box::new(TotalRoutes::new_maximized()))
                 }
+                MinimizeExpectedCost { samples } => core_objectives
+                    .push(Box::new(samples.map_or_else(ExpectedTransportCost::default, ExpectedTransportCost::new))),
                 MinimizeUnassignedJobs => core_objectives.push(Box::new(TotalUnassignedJobs::default())),
+                MinimizeFleetCost { costs } => {
+                    let (module, objective) = TotalFleetCost::new_with_curve(get_fleet_cost_curve(costs));
+                    constraint.add_module(module);
+                    core_objectives.push(objective);
+                }
                 BalanceMaxLoad { options } => {
                     let (module, objective) = get_load_balance(props, options);
                     constraint.add_module(module);
@@ -54,19 +64,46 @@ pub fn create_objective(
         };
 
         let primary_objectives = map_objectives(&objectives.primary);
+
+        if let Some(weights) = &objectives.weights {
+            let objective =
+                WeightedObjectiveCost::new(primary_objectives.into_iter().zip(weights.iter().cloned()).collect());
+
+            return Arc::new(match &objectives.normalizations {
+                Some(normalizations) => objective.with_normalizations(
+                    normalizations
+                        .iter()
+                        .map(|normalization| {
+                            normalization.as_ref().map(|normalization| match normalization {
+                                ObjectiveNormalization::MinMax { min, max } => CoreNormalization::MinMax(*min, *max),
+                                ObjectiveNormalization::Reference { scale } => CoreNormalization::Reference(*scale),
+                                ObjectiveNormalization::Estimated => CoreNormalization::Estimated,
+                            })
+                        })
+                        .collect(),
+                ),
+                None => objective,
+            });
+        }
+
         let secondary_objectives = map_objectives(&objectives.secondary.clone().unwrap_or_else(|| vec![]));
 
-        ObjectiveCost::new(primary_objectives, secondary_objectives)
+        Arc::new(ObjectiveCost::new(primary_objectives, secondary_objectives))
     } else {
         constraint.add_module(Box::new(FleetUsageConstraintModule::new_minimized()));
-        ObjectiveCost::default()
-    })
+        Arc::new(ObjectiveCost::default())
+    }
 }
 
 fn unwrap_options(options: &Option<BalanceOptions>) -> (Option<f64>, Option<f64>) {
     (options.as_ref().and_then(|o| o.threshold), options.as_ref().and_then(|o| o.tolerance))
 }
 
+fn get_fleet_cost_curve(costs: &[f64]) -> FleetCostCurve {
+    let costs = costs.to_vec();
+    Arc::new(move |routes| (0..routes).map(|idx| costs.get(idx).or_else(|| costs.last()).cloned().unwrap_or(0.)).sum())
+}
+
 fn get_load_balance(
     props: &ProblemProperties,
     options: &Option<BalanceOptions>,
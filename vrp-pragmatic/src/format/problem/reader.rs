@@ -11,27 +11,26 @@ mod fleet_reader;
 #[path = "./objective_reader.rs"]
 mod objective_reader;
 
-use self::fleet_reader::{create_transport_costs, read_fleet, read_limits};
+use self::fleet_reader::{create_transport_costs, read_avoidance, read_fleet, read_limits};
 use self::job_reader::{read_jobs_with_extra_locks, read_locks};
 use self::objective_reader::create_objective;
 use crate::constraints::*;
-use crate::extensions::{MultiDimensionalCapacity, OnlyVehicleActivityCost};
+use crate::extensions::{FloatingStartTransportCost, MultiDimensionalCapacity, OnlyVehicleActivityCost};
 use crate::format::coord_index::CoordIndex;
-use crate::format::problem::{deserialize_matrix, deserialize_problem, Matrix};
+use crate::format::problem::{deserialize_matrix, deserialize_problem, Matrix, MatrixFallback, Profile};
 use crate::format::*;
-use crate::utils::get_approx_transportation;
+use crate::utils::{get_approx_transportation, SpeedModel};
 use crate::validation::ValidationContext;
 use crate::{get_unique_locations, parse_time};
-use std::cmp::Ordering::Equal;
 use std::collections::{HashMap, HashSet};
 use std::io::{BufReader, Read};
 use std::iter::FromIterator;
+use std::ops::{Add, Sub};
 use std::sync::Arc;
 use vrp_core::construction::constraints::*;
-use vrp_core::models::common::{Dimensions, TimeWindow, ValueDimension};
-use vrp_core::models::problem::{ActivityCost, Fleet, Job, TransportCost};
+use vrp_core::models::common::{Dimensions, Duration, TimeWindow, ValueDimension};
+use vrp_core::models::problem::{ActivityCost, AvoidanceTransportCost, Fleet, Job, ScaledTransportCost, TransportCost};
 use vrp_core::models::{Extras, Lock, Problem};
-use vrp_core::utils::compare_floats;
 
 pub type ApiProblem = crate::format::problem::Problem;
 pub type JobIndex = HashMap<String, Job>;
@@ -103,21 +102,50 @@ pub struct ProblemProperties {
     has_reload: bool,
     has_priorities: bool,
     has_area_limits: bool,
+    has_overtime: bool,
+    has_min_durations: bool,
+    has_stop_costs: bool,
+    has_floating_start: bool,
+    has_u_turns: bool,
+    has_loaded_profiles: bool,
+    has_pickup_limits: bool,
+    has_job_limits: bool,
+    has_hints: bool,
+    has_customers: bool,
+}
+
+/// A default profile speed used for routing approximation.
+const DEFAULT_SPEED: f64 = 10.;
+
+/// Returns speed model of the profile used for routing approximation.
+pub(crate) fn get_speed_model(profile: Option<&Profile>) -> SpeedModel {
+    let speed = profile.and_then(|profile| profile.speed).unwrap_or(DEFAULT_SPEED);
+    let road_classes = profile
+        .and_then(|profile| profile.road_classes.as_ref())
+        .map(|classes| classes.iter().map(|class| (class.min_distance, class.speed)).collect::<Vec<_>>());
+
+    road_classes.map_or_else(|| SpeedModel::new(speed), |classes| SpeedModel::new_with_road_classes(speed, classes))
 }
 
 fn create_approx_matrices(problem: &ApiProblem) -> Vec<Matrix> {
-    const DEFAULT_SPEED: f64 = 10.;
-    // get each speed value once
-    let speeds = problem
-        .fleet
-        .profiles
-        .iter()
-        .map(|profile| profile.speed.unwrap_or(DEFAULT_SPEED))
-        .map(|speed| unsafe { std::mem::transmute(speed) })
-        .collect::<HashSet<i64>>();
-    let speeds = speeds.into_iter().map(|speed| unsafe { std::mem::transmute(speed) }).collect::<Vec<_>>();
+    // get each speed model once
+    let speeds = problem.fleet.profiles.iter().map(|profile| get_speed_model(Some(profile))).fold(
+        Vec::<SpeedModel>::new(),
+        |mut acc, model| {
+            if !acc.contains(&model) {
+                acc.push(model);
+            }
+            acc
+        },
+    );
 
     let locations = get_unique_locations(&problem);
+
+    // NOTE routing cannot be approximated without geo coordinates, such problem is rejected by validation
+    if locations.iter().any(|location| location.to_lat_lng().is_none()) {
+        return vec![];
+    }
+
     let approx_data = get_approx_transportation(&locations, speeds.as_slice());
 
     problem
@@ -125,9 +153,8 @@ fn create_approx_matrices(problem: &ApiProblem) -> Vec<Matrix> {
         .profiles
         .iter()
         .map(move |profile| {
-            let speed = profile.speed.clone().unwrap_or(DEFAULT_SPEED);
-            let idx =
-                speeds.iter().position(|s| compare_floats(*s, speed) == Equal).expect("Cannot find profile speed");
+            let speed = get_speed_model(Some(profile));
+            let idx = speeds.iter().position(|s| *s == speed).expect("Cannot find profile speed");
 
             Matrix {
                 profile: profile.name.clone(),
@@ -135,6 +162,8 @@ fn create_approx_matrices(problem: &ApiProblem) -> Vec<Matrix> {
                 travel_times: approx_data[idx].0.clone(),
                 distances: approx_data[idx].1.clone(),
                 error_codes: None,
+                fallback: None,
+                avoidance: None,
             }
         })
         .collect()
@@ -151,13 +180,25 @@ fn map_to_problem(api_problem: ApiProblem, matrices: Vec<Matrix>) -> Result<Prob
     let problem_props = get_problem_properties(&api_problem, &matrices);
 
     let coord_index = Arc::new(CoordIndex::new(&api_problem));
-    let transport = create_transport_costs(&api_problem, &matrices).map_err(|err| {
+    let transport = create_transport_costs(&api_problem, &matrices, &coord_index).map_err(|err| {
         vec![FormatError::new(
             "E0002".to_string(),
             "cannot create transport costs".to_string(),
             format!("Check matrix routing data: '{}'", err),
         )]
     })?;
+    let transport = match api_problem.config.as_ref().and_then(|config| config.traffic_factor) {
+        Some(factor) => Arc::new(ScaledTransportCost::new(transport, factor, None)),
+        None => transport,
+    };
+    let avoidance = read_avoidance(&api_problem, &matrices)
+        .map(|avoidance| Arc::new(AvoidanceTransportCost::new(transport.clone(), avoidance)));
+    let transport = avoidance.clone().map_or(transport, |avoidance| avoidance);
+    let transport: Arc<dyn TransportCost + Send + Sync> = if problem_props.has_floating_start {
+        Arc::new(FloatingStartTransportCost::new(transport, coord_index.floating_index()))
+    } else {
+        transport
+    };
     let activity = Arc::new(OnlyVehicleActivityCost::default());
     let fleet = read_fleet(&api_problem, &problem_props, &coord_index);
 
@@ -166,7 +207,7 @@ fn map_to_problem(api_problem: ApiProblem, matrices: Vec<Matrix>) -> Result<Prob
         read_jobs_with_extra_locks(&api_problem, &problem_props, &coord_index, &fleet, &transport, &mut job_index);
     let locks = locks.into_iter().chain(read_locks(&api_problem, &job_index).into_iter()).collect();
     let limits = read_limits(&api_problem).unwrap_or_else(|| Arc::new(|_| (None, None)));
-    let extras = Arc::new(create_extras(&problem_props, coord_index.clone()));
+    let extras = Arc::new(create_extras(&api_problem, &problem_props, coord_index.clone()));
     let mut constraint = create_constraint_pipeline(
         coord_index,
         &fleet,
@@ -175,6 +216,8 @@ fn map_to_problem(api_problem: ApiProblem, matrices: Vec<Matrix>) -> Result<Prob
         &problem_props,
         &locks,
         limits,
+        avoidance,
+        api_problem.config.as_ref().and_then(|config| config.time_window_grace),
     );
 
     let objective = create_objective(&api_problem, &mut constraint, &problem_props);
@@ -199,25 +242,40 @@ fn create_constraint_pipeline(
     props: &ProblemProperties,
     locks: &Vec<Arc<Lock>>,
     limits: TravelLimitFunc,
+    avoidance: Option<Arc<AvoidanceTransportCost>>,
+    time_window_grace: Option<Duration>,
 ) -> ConstraintPipeline {
     let mut constraint = ConstraintPipeline::default();
-    constraint.add_module(Box::new(TransportConstraintModule::new(
-        activity.clone(),
-        transport.clone(),
-        limits,
-        1,
-        2,
-        3,
-    )));
 
-    add_capacity_module(&mut constraint, &props);
+    let (capacity_module, load_state) = create_capacity_module(props);
+
+    let transport_module = match time_window_grace {
+        Some(grace) => TransportConstraintModule::new_with_time_window_grace(
+            activity.clone(),
+            transport.clone(),
+            limits,
+            1,
+            2,
+            3,
+            grace,
+        ),
+        None => TransportConstraintModule::new(activity.clone(), transport.clone(), limits, 1, 2, 3),
+    };
+
+    constraint.add_module(Box::new(if props.has_loaded_profiles {
+        transport_module.with_load_state(load_state)
+    } else {
+        transport_module
+    }));
+
+    constraint.add_module(capacity_module);
 
     if props.has_breaks {
-        constraint.add_module(Box::new(BreakModule::new(BREAK_CONSTRAINT_CODE, Some(-100.), false)));
+        constraint.add_module(Box::new(BreakModule::new(transport.clone(), BREAK_CONSTRAINT_CODE, Some(-100.), false)));
     }
 
     if props.has_skills {
-        constraint.add_module(Box::new(SkillsModule::new(SKILLS_CONSTRAINT_CODE)));
+        constraint.add_module(Box::new(SkillConstraintModule::new(SKILLS_CONSTRAINT_CODE)));
     }
 
     if props.has_priorities {
@@ -233,33 +291,80 @@ fn create_constraint_pipeline(
     }
 
     if props.has_area_limits {
-        add_area_module(&mut constraint, coord_index);
+        add_area_module(&mut constraint, coord_index.clone());
+    }
+
+    if props.has_pickup_limits {
+        constraint.add_module(Box::new(PickupLimitModule::new(PICKUP_LIMIT_CONSTRAINT_CODE)));
+    }
+
+    if props.has_job_limits {
+        constraint.add_module(Box::new(JobCountModule::new(JOB_COUNT_CONSTRAINT_CODE)));
+    }
+
+    if props.has_customers {
+        constraint.add_module(Box::new(CustomerModule::new(CUSTOMER_CONSTRAINT_CODE, true)));
+    }
+
+    if props.has_hints {
+        constraint.add_module(Box::new(HintModule::new(coord_index, transport.clone())));
+    }
+
+    if props.has_overtime {
+        constraint.add_module(Box::new(OvertimeModule::new(transport.clone())));
+    }
+
+    if props.has_min_durations {
+        constraint.add_module(Box::new(MinDurationModule::new(transport.clone())));
+    }
+
+    if props.has_stop_costs {
+        constraint.add_module(Box::new(StopCostModule::new()));
+    }
+
+    if let Some(avoidance) = avoidance {
+        constraint.add_module(Box::new(AvoidanceModule::new(avoidance)));
+    }
+
+    if props.has_u_turns {
+        constraint.add_module(Box::new(UTurnModule::new(transport.clone())));
     }
 
     constraint
 }
 
-fn add_capacity_module(constraint: &mut ConstraintPipeline, props: &ProblemProperties) {
-    constraint.add_module(if props.has_reload {
+/// Creates capacity module and a function which returns load state of the vehicle.
+fn create_capacity_module(props: &ProblemProperties) -> (Box<dyn ConstraintModule + Send + Sync>, LoadStateFunc) {
+    fn with_load_state<Capacity>(
+        module: CapacityConstraintModule<Capacity>,
+    ) -> (Box<dyn ConstraintModule + Send + Sync>, LoadStateFunc)
+    where
+        Capacity: Add<Output = Capacity> + Sub<Output = Capacity> + Ord + Copy + Default + Send + Sync + 'static,
+    {
+        let load_state = module.get_load_state();
+        (Box::new(module), load_state)
+    }
+
+    if props.has_reload {
         let threshold = 0.9;
         if props.has_multi_dimen_capacity {
-            Box::new(CapacityConstraintModule::<MultiDimensionalCapacity>::new_with_multi_trip(
+            with_load_state(CapacityConstraintModule::<MultiDimensionalCapacity>::new_with_multi_trip(
                 CAPACITY_CONSTRAINT_CODE,
                 Arc::new(ReloadMultiTrip::new(Box::new(|capacity| *capacity * 0.9))),
             ))
         } else {
-            Box::new(CapacityConstraintModule::<i32>::new_with_multi_trip(
+            with_load_state(CapacityConstraintModule::<i32>::new_with_multi_trip(
                 CAPACITY_CONSTRAINT_CODE,
                 Arc::new(ReloadMultiTrip::new(Box::new(move |capacity| (*capacity as f64 * threshold).round() as i32))),
             ))
         }
     } else {
         if props.has_multi_dimen_capacity {
-            Box::new(CapacityConstraintModule::<MultiDimensionalCapacity>::new(CAPACITY_CONSTRAINT_CODE))
+            with_load_state(CapacityConstraintModule::<MultiDimensionalCapacity>::new(CAPACITY_CONSTRAINT_CODE))
         } else {
-            Box::new(CapacityConstraintModule::<i32>::new(CAPACITY_CONSTRAINT_CODE))
+            with_load_state(CapacityConstraintModule::<i32>::new(CAPACITY_CONSTRAINT_CODE))
         }
-    });
+    }
 }
 
 fn add_area_module(constraint: &mut ConstraintPipeline, coord_index: Arc<CoordIndex>) {
@@ -268,19 +373,21 @@ fn add_area_module(constraint: &mut ConstraintPipeline, coord_index: Arc<CoordIn
         Arc::new(move |location| {
             coord_index
                 .get_by_idx(&location)
-                .map_or_else(|| panic!("Cannot find location!"), |location| (location.lat, location.lng))
+                .and_then(|location| location.to_lat_lng())
+                .unwrap_or_else(|| panic!("Cannot find location coordinate!"))
         }),
         AREA_CONSTRAINT_CODE,
     )));
 }
 
-fn create_extras(props: &ProblemProperties, coord_index: Arc<CoordIndex>) -> Extras {
+fn create_extras(api_problem: &ApiProblem, props: &ProblemProperties, coord_index: Arc<CoordIndex>) -> Extras {
     let mut extras = Extras::default();
     extras.insert(
         "capacity_type".to_string(),
         Arc::new((if props.has_multi_dimen_capacity { "multi" } else { "single" }).to_string()),
     );
     extras.insert("coord_index".to_owned(), coord_index);
+    extras.insert("profiles".to_owned(), Arc::new(api_problem.fleet.profiles.clone()));
 
     extras
 }
@@ -291,7 +398,9 @@ fn parse_time_window(tw: &Vec<String>) -> TimeWindow {
 }
 
 fn get_problem_properties(api_problem: &ApiProblem, matrices: &Vec<Matrix>) -> ProblemProperties {
-    let has_unreachable_locations = matrices.iter().any(|m| m.error_codes.is_some());
+    let has_unreachable_locations = matrices
+        .iter()
+        .any(|m| m.error_codes.is_some() && matches!(m.fallback, None | Some(MatrixFallback::Unreachable)));
     let has_multi_dimen_capacity = api_problem.fleet.vehicles.iter().any(|t| t.capacity.len() > 1)
         || api_problem.plan.jobs.iter().any(|job| {
             job.pickups
@@ -321,6 +430,36 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &Vec<Matrix>) -> P
         .iter()
         .any(|v| v.limits.as_ref().and_then(|l| l.allowed_areas.as_ref()).map_or(false, |a| !a.is_empty()));
 
+    let has_overtime = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .any(|t| t.costs.overtime.is_some() && t.shifts.iter().any(|s| s.max_overtime.is_some()));
+
+    let has_min_durations = api_problem.fleet.vehicles.iter().any(|t| t.costs.min_duration.is_some());
+
+    let has_stop_costs = api_problem.fleet.vehicles.iter().any(|t| t.costs.per_stop.is_some());
+
+    let has_floating_start =
+        api_problem.fleet.vehicles.iter().flat_map(|t| t.shifts.iter()).any(|s| s.floating_start == Some(true));
+
+    let has_u_turns = api_problem.fleet.vehicles.iter().any(|t| t.u_turn.is_some());
+
+    let has_loaded_profiles = api_problem.fleet.vehicles.iter().any(|t| t.loaded_profile.is_some());
+
+    let has_pickup_limits =
+        api_problem.fleet.vehicles.iter().any(|t| t.limits.as_ref().map_or(false, |l| l.max_pickups.is_some()));
+
+    let has_job_limits = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .any(|t| t.limits.as_ref().map_or(false, |l| l.min_jobs.is_some() || l.max_jobs.is_some()));
+
+    let has_hints = api_problem.plan.hints.as_ref().map_or(false, |hints| !hints.is_empty());
+
+    let has_customers = api_problem.plan.jobs.iter().any(|job| job.customer.is_some());
+
     ProblemProperties {
         has_multi_dimen_capacity,
         has_breaks,
@@ -329,6 +468,16 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &Vec<Matrix>) -> P
         has_reload,
         has_priorities,
         has_area_limits,
+        has_overtime,
+        has_min_durations,
+        has_stop_costs,
+        has_floating_start,
+        has_u_turns,
+        has_loaded_profiles,
+        has_pickup_limits,
+        has_job_limits,
+        has_hints,
+        has_customers,
     }
 }
 
@@ -0,0 +1,455 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/writer_test.rs"]
+mod writer_test;
+
+use crate::extensions::MultiDimensionalCapacity;
+use crate::format::coord_index::CoordIndex;
+use crate::format::problem::*;
+use crate::format::Location;
+use crate::format_time;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use vrp_core::construction::constraints::{CapacityDimension, Demand, DemandDimension};
+use vrp_core::models::common::{IdDimension, PriorityDimension, TimeSpan, TimeWindow, ValueDimension};
+use vrp_core::models::problem::{Job, Multi, Single, Vehicle};
+use vrp_core::models::{LockOrder, LockPosition};
+
+type ApiProblem = crate::format::problem::Problem;
+type ApiJob = crate::format::problem::Job;
+type ApiFleet = crate::format::problem::Fleet;
+type CoreProblem = vrp_core::models::Problem;
+
+/// Keeps data required to map core model back to pragmatic format.
+struct WriteContext<'a> {
+    coord_index: &'a CoordIndex,
+    profiles: &'a Vec<Profile>,
+    is_multi_dimen: bool,
+}
+
+/// Creates pragmatic problem from core problem which was read from pragmatic format.
+/// NOTE objectives, planning horizon and original start location of vehicle with floating start
+/// are not kept in core model, so they are not restored.
+pub fn write_pragmatic_problem(problem: &CoreProblem) -> ApiProblem {
+    let coord_index = problem
+        .extras
+        .get("coord_index")
+        .and_then(|s| s.downcast_ref::<CoordIndex>())
+        .unwrap_or_else(|| panic!("Cannot get coord index!"));
+    let profiles = problem
+        .extras
+        .get("profiles")
+        .and_then(|s| s.downcast_ref::<Vec<Profile>>())
+        .unwrap_or_else(|| panic!("Cannot get profiles!"));
+    let is_multi_dimen = problem
+        .extras
+        .get("capacity_type")
+        .and_then(|s| s.downcast_ref::<String>())
+        .map_or(false, |capacity_type| capacity_type == "multi");
+
+    let ctx = WriteContext { coord_index, profiles, is_multi_dimen };
+
+    let jobs = problem.jobs.all().filter(|job| !is_conditional_job(job)).collect::<Vec<_>>();
+    let hints = jobs.iter().filter_map(write_hint).collect::<Vec<_>>();
+
+    ApiProblem {
+        plan: Plan {
+            jobs: jobs.iter().map(|job| write_job(&ctx, job)).collect(),
+            relations: write_relations(problem),
+            hints: if hints.is_empty() { None } else { Some(hints) },
+        },
+        fleet: write_fleet(&ctx, problem),
+        objectives: None,
+        horizon: None,
+        config: None,
+    }
+}
+
+fn write_job(ctx: &WriteContext, job: &Job) -> ApiJob {
+    let (dimens, singles) = match job {
+        Job::Single(single) => (&single.dimens, vec![single.clone()]),
+        Job::Multi(multi) => (&multi.dimens, multi.jobs.clone()),
+    };
+
+    let mut api_job = ApiJob {
+        id: dimens.get_id().cloned().expect("job without id"),
+        pickups: None,
+        deliveries: None,
+        replacements: None,
+        services: None,
+        priority: dimens.get_priority().cloned(),
+        skills: write_skills(dimens.get_value::<HashSet<String>>("skills")),
+        required: dimens.get_value::<bool>("required").cloned(),
+        probability: dimens.get_value::<f64>("probability").cloned(),
+        unassigned_penalty: dimens.get_value::<f64>("unassigned_penalty").cloned(),
+        customer: dimens.get_value::<String>("customer").cloned(),
+        metadata: dimens.get_value::<HashMap<String, String>>("metadata").cloned(),
+    };
+
+    singles.iter().for_each(|single| {
+        let activity_type = get_job_type(single).expect("job without type");
+        let tasks = match activity_type.as_str() {
+            "pickup" => &mut api_job.pickups,
+            "delivery" => &mut api_job.deliveries,
+            "replacement" => &mut api_job.replacements,
+            "service" => &mut api_job.services,
+            _ => panic!("Unexpected job type: '{}'", activity_type),
+        };
+
+        tasks.get_or_insert_with(Vec::new).push(JobTask {
+            places: single
+                .places
+                .iter()
+                .map(|place| JobPlace {
+                    location: get_location(ctx, place.location.expect("job place without location")),
+                    duration: place.duration,
+                    times: write_times(&place.times),
+                    durations: None,
+                })
+                .collect(),
+            demand: write_demand(ctx, single, activity_type.as_str()),
+            tag: single.dimens.get_value::<String>("tag").cloned(),
+        });
+    });
+
+    api_job
+}
+
+fn write_hint(job: &Job) -> Option<JobHint> {
+    let dimens = job.dimens();
+    let vehicle_type = dimens.get_value::<String>("hint_vehicle_type").cloned();
+    let location = dimens.get_value::<Location>("hint_location").cloned();
+
+    if vehicle_type.is_none() && location.is_none() {
+        None
+    } else {
+        Some(JobHint { job_id: dimens.get_id().cloned().expect("job without id"), vehicle_type, location })
+    }
+}
+
+fn write_demand(ctx: &WriteContext, single: &Single, activity_type: &str) -> Option<Vec<i32>> {
+    let is_delivery = activity_type == "delivery";
+
+    if ctx.is_multi_dimen {
+        let demand: &Demand<MultiDimensionalCapacity> = single.dimens.get_demand()?;
+        let value = if is_delivery { demand.delivery.0 + demand.delivery.1 } else { demand.pickup.0 + demand.pickup.1 };
+
+        if value.size == 0 {
+            None
+        } else {
+            Some(value.as_vec())
+        }
+    } else {
+        let demand: &Demand<i32> = single.dimens.get_demand()?;
+        let value = if is_delivery { demand.delivery.0 + demand.delivery.1 } else { demand.pickup.0 + demand.pickup.1 };
+
+        if value == 0 {
+            None
+        } else {
+            Some(vec![value])
+        }
+    }
+}
+
+fn write_relations(problem: &CoreProblem) -> Option<Vec<Relation>> {
+    let mut relations = problem
+        .locks
+        .iter()
+        .filter_map(|lock| {
+            let actor = problem.fleet.actors.iter().find(|actor| (lock.condition)(actor.as_ref()))?;
+            let vehicle_id = actor.vehicle.dimens.get_id().cloned()?;
+            let shift_index = get_shift_index(&actor.vehicle);
+
+            Some(lock.details.iter().map(move |detail| {
+                let type_field = match detail.order {
+                    LockOrder::Any => RelationType::Any,
+                    LockOrder::Sequence => RelationType::Sequence,
+                    LockOrder::Strict => RelationType::Strict,
+                };
+
+                let (has_departure, has_arrival) = match detail.position {
+                    LockPosition::Any => (false, false),
+                    LockPosition::Departure => (true, false),
+                    LockPosition::Arrival => (false, true),
+                    LockPosition::Fixed => (true, true),
+                };
+
+                let jobs = has_departure
+                    .then(|| ("departure".to_string(), None))
+                    .into_iter()
+                    .chain(detail.jobs.iter().map(write_relation_job))
+                    .chain(has_arrival.then(|| ("arrival".to_string(), None)))
+                    .collect::<Vec<_>>();
+
+                let tags = jobs.iter().map(|(_, tag)| tag.clone()).collect::<Vec<_>>();
+                let tags = if tags.iter().any(|tag| tag.is_some()) { Some(tags) } else { None };
+
+                Relation {
+                    type_field,
+                    jobs: jobs.into_iter().map(|(job_id, _)| job_id).collect(),
+                    vehicle_id: vehicle_id.clone(),
+                    shift_index: Some(shift_index),
+                    tags,
+                }
+            }))
+        })
+        .flatten()
+        .collect::<Vec<_>>();
+
+    // NOTE locks are not kept in the original order, so sort relations to have stable output
+    relations.sort_by(|a, b| (&a.vehicle_id, a.shift_index).cmp(&(&b.vehicle_id, b.shift_index)));
+
+    if relations.is_empty() {
+        None
+    } else {
+        Some(relations)
+    }
+}
+
+/// Returns job id used in relation and tag of the sub job if job is a part of multi job.
+fn write_relation_job(job: &Job) -> (String, Option<String>) {
+    match job {
+        Job::Single(single) => match (get_job_type(single), Multi::roots(single)) {
+            (Some(job_type), _) if job_type == "break" || job_type == "reload" => (job_type.clone(), None),
+            (_, Some(multi)) => (
+                multi.dimens.get_id().cloned().expect("job without id"),
+                single.dimens.get_value::<String>("tag").cloned(),
+            ),
+            _ => (single.dimens.get_id().cloned().expect("job without id"), None),
+        },
+        Job::Multi(multi) => (multi.dimens.get_id().cloned().expect("job without id"), None),
+    }
+}
+
+fn write_fleet(ctx: &WriteContext, problem: &CoreProblem) -> ApiFleet {
+    let vehicles = &problem.fleet.vehicles;
+    let type_ids = vehicles.iter().map(|vehicle| get_type_id(vehicle)).fold(Vec::<&String>::new(), |mut acc, id| {
+        if !acc.contains(&id) {
+            acc.push(id);
+        }
+        acc
+    });
+
+    let conditional_jobs =
+        problem.jobs.all().filter(is_conditional_job).map(|job| job.to_single().clone()).collect::<Vec<_>>();
+
+    ApiFleet {
+        vehicles: type_ids
+            .into_iter()
+            .map(|type_id| {
+                let type_vehicles =
+                    vehicles.iter().filter(|vehicle| get_type_id(vehicle) == type_id).collect::<Vec<_>>();
+                write_vehicle_type(ctx, type_vehicles.as_slice(), conditional_jobs.as_slice())
+            })
+            .collect(),
+        profiles: ctx.profiles.clone(),
+    }
+}
+
+fn write_vehicle_type(ctx: &WriteContext, vehicles: &[&Arc<Vehicle>], conditional_jobs: &[Arc<Single>]) -> VehicleType {
+    let vehicle = vehicles.first().expect("vehicle type without vehicles");
+    let dimens = &vehicle.dimens;
+
+    let vehicle_ids = vehicles
+        .iter()
+        .filter(|vehicle| get_shift_index(vehicle) == 0)
+        .filter_map(|vehicle| vehicle.dimens.get_id().cloned())
+        .collect();
+
+    let shift_count = vehicles.iter().map(|vehicle| get_shift_index(vehicle)).max().map_or(0, |max| max + 1);
+    let shifts = (0..shift_count)
+        .filter_map(|shift_index| vehicles.iter().find(|vehicle| get_shift_index(vehicle) == shift_index))
+        .map(|vehicle| write_shift(ctx, vehicle, conditional_jobs))
+        .collect();
+
+    let capacity = if ctx.is_multi_dimen {
+        dimens.get_capacity().map(|capacity: &MultiDimensionalCapacity| capacity.as_vec())
+    } else {
+        dimens.get_capacity().map(|capacity: &i32| vec![*capacity])
+    }
+    .expect("vehicle without capacity");
+
+    VehicleType {
+        type_id: get_type_id(vehicle).clone(),
+        vehicle_ids,
+        profile: get_profile_name(ctx, vehicle.profile),
+        loaded_profile: dimens.get_value::<i32>("loaded_profile").map(|profile| get_profile_name(ctx, *profile)),
+        costs: VehicleCosts {
+            fixed: Some(vehicle.costs.fixed),
+            distance: vehicle.costs.per_distance,
+            time: vehicle.costs.per_driving_time,
+            waiting: if vehicle.costs.per_waiting_time == vehicle.costs.per_driving_time {
+                None
+            } else {
+                Some(vehicle.costs.per_waiting_time)
+            },
+            overtime: dimens.get_value::<f64>("overtime_cost").cloned(),
+            min_duration: dimens.get_value::<f64>("min_duration").cloned(),
+            per_stop: dimens.get_value::<f64>("per_stop_cost").cloned(),
+        },
+        shifts,
+        capacity,
+        skills: write_skills(dimens.get_value::<HashSet<String>>("skills")),
+        limits: write_limits(vehicle),
+        u_turn: dimens
+            .get_value::<(f64, f64)>("u_turn")
+            .map(|(min_progress, penalty)| VehicleUTurn { min_progress: *min_progress, penalty: *penalty }),
+        must_use: dimens.get_value::<bool>("must_use").cloned(),
+    }
+}
+
+fn write_shift(ctx: &WriteContext, vehicle: &Vehicle, conditional_jobs: &[Arc<Single>]) -> VehicleShift {
+    let detail = vehicle.details.first().expect("vehicle without details");
+    let time = detail.time.clone().unwrap_or_else(TimeWindow::max);
+    let start = detail.start.expect("vehicle without start location");
+
+    let floating_start = ctx.coord_index.get_by_idx(&start).is_none();
+    let start_location = if floating_start {
+        ctx.coord_index.get_by_idx(&0).expect("cannot find any location")
+    } else {
+        get_location(ctx, start)
+    };
+
+    let shift_end = vehicle.dimens.get_value::<f64>("shift_end").cloned();
+    let end = detail
+        .end
+        .map(|end| VehiclePlace { time: format_time(shift_end.unwrap_or(time.end)), location: get_location(ctx, end) });
+    let max_overtime = shift_end.map(|shift_end| time.end - shift_end).filter(|overtime| *overtime > 0.);
+
+    let vehicle_id = vehicle.dimens.get_id().expect("vehicle without id");
+    let shift_index = get_shift_index(vehicle);
+    let get_conditional_jobs = |job_type: &str| {
+        conditional_jobs
+            .iter()
+            .filter(|single| get_job_type(single).map_or(false, |t| t == job_type))
+            .filter(|single| single.dimens.get_value::<String>("vehicle_id") == Some(vehicle_id))
+            .filter(|single| single.dimens.get_value::<usize>("shift_index") == Some(&shift_index))
+            .collect::<Vec<_>>()
+    };
+
+    let breaks = get_conditional_jobs("break")
+        .into_iter()
+        .map(|single| {
+            let place = single.places.first().expect("break without place");
+            VehicleBreak {
+                time: match place.times.first().expect("break without time") {
+                    TimeSpan::Window(tw) => {
+                        VehicleBreakTime::TimeWindow(vec![format_time(tw.start), format_time(tw.end)])
+                    }
+                    TimeSpan::Offset(offset) => VehicleBreakTime::TimeOffset(vec![offset.start, offset.end]),
+                },
+                duration: place.duration,
+                locations: if place.location.is_some() {
+                    Some(
+                        single.places.iter().filter_map(|place| place.location).map(|l| get_location(ctx, l)).collect(),
+                    )
+                } else {
+                    None
+                },
+                mandatory: single.dimens.get_value::<bool>("required").cloned(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let reloads = get_conditional_jobs("reload")
+        .into_iter()
+        .map(|single| {
+            let place = single.places.first().expect("reload without place");
+            VehicleReload {
+                location: get_location(ctx, place.location.expect("reload without location")),
+                duration: place.duration,
+                times: write_times(&place.times),
+                tag: single.dimens.get_value::<String>("tag").cloned(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    VehicleShift {
+        start: VehiclePlace { time: format_time(time.start), location: start_location },
+        end,
+        max_overtime,
+        floating_start: if floating_start { Some(true) } else { None },
+        breaks: if breaks.is_empty() { None } else { Some(breaks) },
+        reloads: if reloads.is_empty() { None } else { Some(reloads) },
+    }
+}
+
+fn write_limits(vehicle: &Vehicle) -> Option<VehicleLimits> {
+    let dimens = &vehicle.dimens;
+    let limits = VehicleLimits {
+        max_distance: dimens.get_value::<f64>("max_distance").cloned(),
+        shift_time: dimens.get_value::<f64>("shift_time").cloned(),
+        allowed_areas: dimens.get_value::<Vec<Vec<(f64, f64)>>>("areas").map(|areas| {
+            areas.iter().map(|area| area.iter().map(|(lat, lng)| Location::new(*lat, *lng)).collect()).collect()
+        }),
+        max_pickups: dimens.get_value::<usize>("max_pickups").cloned(),
+        min_work_before_break: dimens.get_value::<f64>("min_work_before_break").cloned(),
+        max_breaks: dimens.get_value::<usize>("max_breaks").cloned(),
+        min_break_spacing: dimens.get_value::<f64>("min_break_spacing").cloned(),
+        min_jobs: dimens.get_value::<usize>("min_jobs").cloned(),
+        max_jobs: dimens.get_value::<usize>("max_jobs").cloned(),
+    };
+
+    let has_limits = limits.max_distance.is_some()
+        || limits.shift_time.is_some()
+        || limits.allowed_areas.is_some()
+        || limits.max_pickups.is_some()
+        || limits.min_work_before_break.is_some()
+        || limits.max_breaks.is_some()
+        || limits.min_break_spacing.is_some()
+        || limits.min_jobs.is_some()
+        || limits.max_jobs.is_some();
+
+    if has_limits {
+        Some(limits)
+    } else {
+        None
+    }
+}
+
+fn write_times(times: &[TimeSpan]) -> Option<Vec<Vec<String>>> {
+    let windows = times
+        .iter()
+        .filter_map(|time| match time {
+            TimeSpan::Window(tw) => Some(tw),
+            TimeSpan::Offset(_) => None,
+        })
+        .collect::<Vec<_>>();
+
+    match windows.as_slice() {
+        [] => None,
+        [tw] if **tw == TimeWindow::max() => None,
+        _ => Some(windows.iter().map(|tw| vec![format_time(tw.start), format_time(tw.end)]).collect()),
+    }
+}
+
+fn write_skills(skills: Option<&HashSet<String>>) -> Option<Vec<String>> {
+    skills.map(|skills| {
+        let mut skills = skills.iter().cloned().collect::<Vec<_>>();
+        skills.sort();
+        skills
+    })
+}
+
+fn get_location(ctx: &WriteContext, index: usize) -> Location {
+    ctx.coord_index.get_by_idx(&index).unwrap_or_else(|| panic!("Cannot find location with index: {}", index))
+}
+
+fn get_profile_name(ctx: &WriteContext, profile: i32) -> String {
+    ctx.profiles.get(profile as usize).map(|profile| profile.name.clone()).expect("cannot find profile")
+}
+
+fn get_job_type(single: &Single) -> Option<&String> {
+    single.dimens.get_value::<String>("type")
+}
+
+fn is_conditional_job(job: &Job) -> bool {
+    job.as_single().and_then(|single| get_job_type(single)).map_or(false, |t| t == "break" || t == "reload")
+}
+
+fn get_type_id(vehicle: &Vehicle) -> &String {
+    vehicle.dimens.get_value::<String>("type_id").expect("vehicle without type id")
+}
+
+fn get_shift_index(vehicle: &Vehicle) -> usize {
+    *vehicle.dimens.get_value::<usize>("shift_index").expect("vehicle without shift index")
+}
@@ -1,5 +1,11 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/geo_serializer_test.rs"]
+mod geo_serializer_test;
+
 use super::Solution;
 use crate::format::solution::{Stop, Tour};
+use crate::format::Location;
+use serde::ser::Error as _;
 use serde::Serialize;
 use serde_json::Error;
 use std::collections::HashMap;
@@ -45,22 +51,49 @@ fn get_marker_symbol(stop: &Stop) -> String {
     .to_string()
 }
 
-fn get_stop_point(tour_idx: usize, stop_idx: usize, stop: &Stop, color: &str) -> Feature {
-    Feature {
-        properties: slice_to_map(&[
-            ("marker-color", color),
-            ("marker-size", "medium"),
-            ("marker-symbol", get_marker_symbol(&stop).as_str()),
-            ("tour_idx", tour_idx.to_string().as_str()),
-            ("stop_idx", stop_idx.to_string().as_str()),
-            ("jobs_ids", stop.activities.iter().map(|a| a.job_id.clone()).collect::<Vec<_>>().join(",").as_str()),
-        ]),
-        geometry: Geometry::Point { coordinates: (stop.location.lng, stop.location.lat) },
-    }
+/// Returns metadata of stop activities. Values of the same key are joined by comma.
+fn get_stop_metadata(stop: &Stop) -> HashMap<String, String> {
+    stop.activities.iter().filter_map(|activity| activity.metadata.as_ref()).flat_map(|metadata| metadata.iter()).fold(
+        HashMap::new(),
+        |mut acc, (key, value)| {
+            acc.entry(key.clone())
+                .and_modify(|values: &mut String| {
+                    values.push(',');
+                    values.push_str(value)
+                })
+                .or_insert_with(|| value.clone());
+            acc
+        },
+    )
 }
 
-fn get_tour_line(tour_idx: usize, tour: &Tour, color: &str) -> Feature {
-    Feature {
+/// Returns geo json coordinate of the location or an error if location has no geo coordinate.
+fn get_coordinate(location: &Location) -> Result<(f64, f64), Error> {
+    location
+        .to_lat_lng()
+        .map(|(lat, lng)| (lng, lat))
+        .ok_or_else(|| Error::custom("geo json requires geo coordinates, but location reference is used"))
+}
+
+fn get_stop_point(tour_idx: usize, stop_idx: usize, stop: &Stop, color: &str) -> Result<Feature, Error> {
+    let mut properties = get_stop_metadata(stop);
+    properties.extend(slice_to_map(&[
+        ("marker-color", color),
+        ("marker-size", "medium"),
+        ("marker-symbol", get_marker_symbol(stop).as_str()),
+        ("tour_idx", tour_idx.to_string().as_str()),
+        ("stop_idx", stop_idx.to_string().as_str()),
+        ("jobs_ids", stop.activities.iter().map(|a| a.job_id.clone()).collect::<Vec<_>>().join(",").as_str()),
+    ]));
+
+    Ok(Feature { properties, geometry: Geometry::Point { coordinates: get_coordinate(&stop.location)? } })
+}
+
+fn get_tour_line(tour_idx: usize, tour: &Tour, color: &str, tolerance: Option<f64>) -> Result<Feature, Error> {
+    let coordinates = tour.stops.iter().map(|stop| get_coordinate(&stop.location)).collect::<Result<Vec<_>, _>>()?;
+    let coordinates = tolerance.map_or_else(|| coordinates.clone(), |tolerance| simplify_line(&coordinates, tolerance));
+
+    Ok(Feature {
         properties: slice_to_map(&[
             ("vehicle_id", tour.vehicle_id.as_str()),
             ("tour_idx", tour_idx.to_string().as_str()),
@@ -70,14 +103,73 @@ fn get_tour_line(tour_idx: usize, tour: &Tour, color: &str) -> Feature {
             ("stroke-width", "4"),
             ("stroke", color),
         ]),
-        geometry: Geometry::LineString {
-            coordinates: tour.stops.iter().map(|stop| (stop.location.lng, stop.location.lat)).collect(),
+        geometry: Geometry::LineString { coordinates },
+    })
+}
+
+/// Simplifies line using Douglas-Peucker algorithm: points which are closer than tolerance
+/// to the segment formed by kept points are removed. First and last points are always kept.
+fn simplify_line(points: &[(f64, f64)], tolerance: f64) -> Vec<(f64, f64)> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+
+    let first = points.first().unwrap();
+    let last = points.last().unwrap();
+
+    let (max_idx, max_distance) = points.iter().enumerate().skip(1).take(points.len() - 2).fold(
+        (0, 0.),
+        |(max_idx, max_distance), (idx, point)| {
+            let distance = get_perpendicular_distance(point, first, last);
+            if distance > max_distance {
+                (idx, distance)
+            } else {
+                (max_idx, max_distance)
+            }
         },
+    );
+
+    if max_distance > tolerance {
+        let mut left = simplify_line(&points[..=max_idx], tolerance);
+        let right = simplify_line(&points[max_idx..], tolerance);
+
+        left.pop();
+        left.extend(right.into_iter());
+
+        left
+    } else {
+        vec![*first, *last]
+    }
+}
+
+/// Returns distance from point to the line defined by start and end points.
+fn get_perpendicular_distance(point: &(f64, f64), start: &(f64, f64), end: &(f64, f64)) -> f64 {
+    let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+    let length = (dx * dx + dy * dy).sqrt();
+
+    if length == 0. {
+        ((point.0 - start.0).powi(2) + (point.1 - start.1).powi(2)).sqrt()
+    } else {
+        (dy * point.0 - dx * point.1 + end.0 * start.1 - end.1 * start.0).abs() / length
     }
 }
 
 /// Serializes solution into geo json format.
 pub fn serialize_solution_as_geojson<W: Write>(writer: BufWriter<W>, solution: &Solution) -> Result<(), Error> {
+    serialize_geojson(writer, solution, None)
+}
+
+/// Serializes solution into geo json format simplifying tour lines with given tolerance.
+/// Stop points are kept as is, only tour lines are affected.
+pub fn serialize_simplified_solution_as_geojson<W: Write>(
+    writer: BufWriter<W>,
+    solution: &Solution,
+    tolerance: f64,
+) -> Result<(), Error> {
+    serialize_geojson(writer, solution, Some(tolerance))
+}
+
+fn serialize_geojson<W: Write>(writer: BufWriter<W>, solution: &Solution, tolerance: Option<f64>) -> Result<(), Error> {
     let stop_markers = solution.tours.iter().enumerate().flat_map(|(tour_idx, tour)| {
         tour.stops.iter().enumerate().map(move |(stop_idx, stop)| {
             get_stop_point(tour_idx, stop_idx, &stop, get_color_inverse(tour_idx).as_str())
@@ -88,12 +180,11 @@ pub fn serialize_solution_as_geojson<W: Write>(writer: BufWriter<W>, solution: &
         .tours
         .iter()
         .enumerate()
-        .map(|(tour_idx, tour)| get_tour_line(tour_idx, tour, get_color(tour_idx).as_str()));
+        .map(|(tour_idx, tour)| get_tour_line(tour_idx, tour, get_color(tour_idx).as_str(), tolerance));
 
-    serde_json::to_writer_pretty(
-        writer,
-        &FeatureCollection { features: stop_markers.into_iter().chain(stop_lines.into_iter()).collect() },
-    )
+    let features = stop_markers.into_iter().chain(stop_lines.into_iter()).collect::<Result<Vec<_>, _>>()?;
+
+    serde_json::to_writer_pretty(writer, &FeatureCollection { features })
 }
 
 fn get_color(idx: usize) -> String {
@@ -0,0 +1,172 @@
+use crate::format::coord_index::CoordIndex;
+use crate::format::solution::{deserialize_solution, Activity as ApiActivity, Stop, Tour};
+use crate::parse_time;
+use std::io::{BufReader, Read};
+use std::sync::Arc;
+use vrp_core::construction::heuristics::{create_end_activity, create_start_activity};
+use vrp_core::models::common::{IdDimension, Schedule, TimeWindow, ValueDimension};
+use vrp_core::models::problem::{Actor, Job, Single};
+use vrp_core::models::solution::{Activity, Place, Registry, Route, Tour as CoreTour};
+use vrp_core::models::{Problem, Solution};
+
+type ApiSolution = crate::format::solution::model::Solution;
+
+/// Reads solution in pragmatic format and maps it to the core solution of given problem.
+pub fn read_init_solution<R: Read>(solution: BufReader<R>, problem: Arc<Problem>) -> Result<Solution, String> {
+    let solution = deserialize_solution(solution).map_err(|err| format!("cannot deserialize solution: {}", err))?;
+
+    map_to_solution(&solution, problem)
+}
+
+/// Maps solution in pragmatic format to the core solution of given problem.
+pub fn map_to_solution(solution: &ApiSolution, problem: Arc<Problem>) -> Result<Solution, String> {
+    let coord_index = problem
+        .extras
+        .get("coord_index")
+        .and_then(|s| s.downcast_ref::<CoordIndex>())
+        .ok_or_else(|| "cannot get coord index".to_string())?;
+
+    let mut registry = Registry::new(&problem.fleet);
+
+    let routes = solution
+        .tours
+        .iter()
+        .map(|tour| {
+            let actor = get_actor(problem.as_ref(), tour)?;
+            registry.use_actor(&actor);
+
+            let mut core_tour = CoreTour::default();
+            core_tour.set_start(create_start_activity(&actor));
+            create_end_activity(&actor).map(|end| core_tour.set_end(end));
+
+            let mut used: Vec<Arc<Single>> = vec![];
+
+            for stop in tour.stops.iter() {
+                for activity in stop.activities.iter().filter(|activity| !is_terminal(activity)) {
+                    let single = get_single(problem.as_ref(), tour, activity, &used)?;
+                    let location = activity.location.as_ref().unwrap_or(&stop.location);
+                    let location = coord_index
+                        .get_by_loc(location)
+                        .ok_or_else(|| format!("unknown location of job '{}'", activity.job_id))?;
+
+                    let schedule = get_schedule(stop, activity);
+                    let start_time = core_tour.start().unwrap().schedule.departure;
+                    let (duration, time) = get_place_details(&single, location, &schedule, start_time);
+
+                    used.push(single.clone());
+                    core_tour.insert_last(Box::new(Activity {
+                        place: Place { location, duration, time },
+                        schedule,
+                        job: Some(single),
+                    }));
+                }
+            }
+
+            Ok(Route { actor, tour: core_tour })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let job_ids = solution.unassigned.iter().map(|job| job.job_id.clone()).collect::<Vec<_>>();
+    let unassigned = problem
+        .jobs
+        .all()
+        .filter(|job| get_job_id(job).map_or(false, |id| job_ids.contains(id)))
+        .map(|job| (job, 0))
+        .collect();
+
+    Ok(Solution { registry, routes, unassigned, extras: problem.extras.clone() })
+}
+
+fn get_actor(problem: &Problem, tour: &Tour) -> Result<Arc<Actor>, String> {
+    problem
+        .fleet
+        .actors
+        .iter()
+        .find(|actor| {
+            actor.vehicle.dimens.get_id().map_or(false, |id| *id == tour.vehicle_id)
+                && actor.vehicle.dimens.get_value::<usize>("shift_index").map_or(false, |idx| *idx == tour.shift_index)
+        })
+        .cloned()
+        .ok_or_else(|| format!("cannot find vehicle '{}' with shift {}", tour.vehicle_id, tour.shift_index))
+}
+
+fn get_single(
+    problem: &Problem,
+    tour: &Tour,
+    activity: &ApiActivity,
+    used: &[Arc<Single>],
+) -> Result<Arc<Single>, String> {
+    let is_same_type = |single: &Arc<Single>| {
+        single.dimens.get_value::<String>("type").map_or(false, |t| *t == activity.activity_type)
+            && (activity.job_tag.is_none() || single.dimens.get_value::<String>("tag") == activity.job_tag.as_ref())
+            && !used.iter().any(|other| Arc::ptr_eq(other, single))
+    };
+
+    let is_conditional = activity.activity_type == "break" || activity.activity_type == "reload";
+
+    problem
+        .jobs
+        .all()
+        .filter_map(|job| match job {
+            Job::Single(single) if is_conditional => {
+                let is_vehicle_job = single.dimens.get_value::<String>("vehicle_id") == Some(&tour.vehicle_id)
+                    && single.dimens.get_value::<usize>("shift_index") == Some(&tour.shift_index);
+
+                if is_vehicle_job && is_same_type(&single) {
+                    Some(single)
+                } else {
+                    None
+                }
+            }
+            Job::Single(single) if !is_conditional && single.dimens.get_id() == Some(&activity.job_id) => {
+                Some(single).filter(|single| is_same_type(single))
+            }
+            Job::Multi(multi) if multi.dimens.get_id() == Some(&activity.job_id) => {
+                multi.jobs.iter().find(|single| is_same_type(single)).cloned()
+            }
+            _ => None,
+        })
+        .next()
+        .ok_or_else(|| {
+            format!("cannot match activity '{}' of job '{}' to problem", activity.activity_type, activity.job_id)
+        })
+}
+
+fn get_place_details(single: &Single, location: usize, schedule: &Schedule, start_time: f64) -> (f64, TimeWindow) {
+    let place = single
+        .places
+        .iter()
+        .find(|place| place.location.map_or(true, |l| l == location))
+        .unwrap_or_else(|| single.places.first().unwrap());
+
+    let times = place.times.iter().map(|time| time.to_time_window(start_time)).collect::<Vec<_>>();
+    let service = TimeWindow::new(schedule.arrival, schedule.departure);
+    let time = times
+        .iter()
+        .find(|time| time.intersects(&service))
+        .or_else(|| times.first())
+        .cloned()
+        .unwrap_or_else(|| TimeWindow::max());
+
+    (place.duration, time)
+}
+
+fn get_schedule(stop: &Stop, activity: &ApiActivity) -> Schedule {
+    let (arrival, departure) = activity
+        .time
+        .as_ref()
+        .map_or_else(|| (&stop.time.arrival, &stop.time.departure), |interval| (&interval.start, &interval.end));
+
+    Schedule::new(parse_time(arrival), parse_time(departure))
+}
+
+fn get_job_id(job: &Job) -> Option<&String> {
+    match job {
+        Job::Single(single) => single.dimens.get_id(),
+        Job::Multi(multi) => multi.dimens.get_id(),
+    }
+}
+
+fn is_terminal(activity: &ApiActivity) -> bool {
+    activity.activity_type == "departure" || activity.activity_type == "arrival"
+}
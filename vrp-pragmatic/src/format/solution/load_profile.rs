@@ -0,0 +1,113 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/load_profile_test.rs"]
+mod load_profile_test;
+
+use crate::extensions::MultiDimensionalCapacity;
+use vrp_core::construction::constraints::{route_intervals, Demand, DemandDimension};
+use vrp_core::models::common::{Dimensions, ValueDimension};
+use vrp_core::models::solution::{Route, TourActivity};
+use vrp_core::models::Problem;
+
+type DomainExtras = vrp_core::models::Extras;
+
+/// Keeps vehicle load after each activity of the route, including departure and arrival.
+pub struct RouteLoadProfile {
+    loads: Vec<MultiDimensionalCapacity>,
+}
+
+impl RouteLoadProfile {
+    /// Calculates load profile of the route from job demands. Load is recalculated at each reload:
+    /// deliveries of the next trip are loaded and pickups of the previous one are unloaded.
+    pub fn new(problem: &Problem, route: &Route) -> Self {
+        let is_multi_dimen = has_multi_dimensional_capacity(problem.extras.as_ref());
+        let intervals = route_intervals(route, Box::new(|a| get_activity_type(a).map_or(false, |t| t == "reload")));
+        let last_idx = route.tour.total() - 1;
+
+        let mut loads = Vec::with_capacity(route.tour.total());
+
+        intervals.into_iter().fold(MultiDimensionalCapacity::default(), |leftover, (start_idx, end_idx)| {
+            let (start_delivery, end_pickup) = route.tour.activities_slice(start_idx, end_idx).iter().fold(
+                (leftover, MultiDimensionalCapacity::default()),
+                |acc, activity| {
+                    let (delivery, pickup) = get_activity_demand(activity, is_multi_dimen)
+                        .map(|demand| (demand.delivery.0, demand.pickup.0))
+                        .unwrap_or_default();
+                    (acc.0 + delivery, acc.1 + pickup)
+                },
+            );
+
+            let load = (start_idx..=end_idx).fold(start_delivery, |current, idx| {
+                let activity = route.tour.get(idx).unwrap();
+                let load = match get_activity_demand(activity, is_multi_dimen) {
+                    Some(demand) => current - demand.delivery.0 - demand.delivery.1 + demand.pickup.0 + demand.pickup.1,
+                    // NOTE arrival must have zero load
+                    None if idx == last_idx && activity.job.is_none() && idx != 0 => {
+                        MultiDimensionalCapacity::new(vec![0; current.size])
+                    }
+                    None => current,
+                };
+
+                loads.push(load);
+
+                load
+            });
+
+            load - end_pickup
+        });
+
+        Self { loads }
+    }
+
+    /// Returns vehicle load after activity with given index in the tour.
+    pub fn get(&self, activity_idx: usize) -> Option<&MultiDimensionalCapacity> {
+        self.loads.get(activity_idx)
+    }
+
+    /// Returns true if vehicle carries some load after activity with given index in the tour.
+    pub fn is_loaded(&self, activity_idx: usize) -> bool {
+        self.get(activity_idx).map_or(false, |load| *load != MultiDimensionalCapacity::default())
+    }
+
+    /// Returns vehicle loads after each activity in the tour.
+    pub fn loads(&self) -> &[MultiDimensionalCapacity] {
+        self.loads.as_slice()
+    }
+}
+
+pub(crate) fn get_activity_type(activity: &TourActivity) -> Option<&String> {
+    activity.job.as_ref().and_then(|single| single.dimens.get_value::<String>("type"))
+}
+
+fn get_activity_demand(activity: &TourActivity, is_multi_dimen: bool) -> Option<Demand<MultiDimensionalCapacity>> {
+    activity.job.as_ref().and_then(|job| get_capacity(&job.dimens, is_multi_dimen))
+}
+
+fn get_capacity(dimens: &Dimensions, is_multi_dimen: bool) -> Option<Demand<MultiDimensionalCapacity>> {
+    if is_multi_dimen {
+        dimens.get_demand().cloned()
+    } else {
+        let create_capacity = |value: i32| {
+            if value == 0 {
+                MultiDimensionalCapacity::default()
+            } else {
+                MultiDimensionalCapacity::new(vec![value])
+            }
+        };
+        dimens.get_demand().map(|demand: &Demand<i32>| Demand {
+            pickup: (create_capacity(demand.pickup.0), create_capacity(demand.pickup.1)),
+            delivery: (create_capacity(demand.delivery.0), create_capacity(demand.delivery.1)),
+        })
+    }
+}
+
+fn has_multi_dimensional_capacity(extras: &DomainExtras) -> bool {
+    let capacity_type = extras
+        .get("capacity_type")
+        .and_then(|s| s.downcast_ref::<String>())
+        .unwrap_or_else(|| panic!("Cannot get capacity type!"));
+    match capacity_type.as_str() {
+        "multi" => true,
+        "single" => false,
+        _ => panic!("Unknown capacity type: '{}'", capacity_type),
+    }
+}
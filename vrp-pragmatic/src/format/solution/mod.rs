@@ -4,10 +4,18 @@ mod model;
 pub use self::model::*;
 
 mod geo_serializer;
-pub use self::geo_serializer::serialize_solution_as_geojson;
+pub use self::geo_serializer::{serialize_simplified_solution_as_geojson, serialize_solution_as_geojson};
 
 mod extensions;
 
+mod load_profile;
+pub use self::load_profile::RouteLoadProfile;
+
+mod initial_reader;
+pub use self::initial_reader::{map_to_solution, read_init_solution};
+
 mod writer;
+pub use self::writer::create_route_sequences;
 pub use self::writer::create_solution;
+pub use self::writer::create_solution_with_suggestions;
 pub use self::writer::PragmaticSolution;
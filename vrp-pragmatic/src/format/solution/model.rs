@@ -1,6 +1,7 @@
 use crate::format::Location;
 use serde::{Deserialize, Serialize};
 use serde_json::Error;
+use std::collections::HashMap;
 use std::io::{BufReader, BufWriter, Read, Write};
 
 /// Timing statistic.
@@ -63,10 +64,16 @@ pub struct Activity {
     /// Active time.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub time: Option<Interval>,
+    /// Vehicle load after this activity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub load: Option<Vec<i32>>,
     /// Job tag.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename(serialize = "tag", deserialize = "tag"))]
     pub job_tag: Option<String>,
+    /// Job metadata.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
 }
 
 /// A stop is a place where vehicle is supposed to be parked.
@@ -109,6 +116,18 @@ pub struct UnassignedJobReason {
     pub description: String,
 }
 
+/// A closest feasible assignment of unassigned job when its blocking constraint is relaxed.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UnassignedJobSuggestion {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Vehicle shift index.
+    pub shift_index: usize,
+    /// Index of tour activity after which job would be inserted, departure has zero index.
+    pub position: usize,
+}
+
 /// Unassigned job.
 #[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -117,6 +136,9 @@ pub struct UnassignedJob {
     pub job_id: String,
     /// Possible reasons.
     pub reasons: Vec<UnassignedJobReason>,
+    /// A closest feasible assignment when the constraint from the first reason is relaxed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<UnassignedJobSuggestion>,
 }
 
 /// Defines iteration model.
@@ -156,6 +178,24 @@ pub struct Solution {
     pub extras: Option<Extras>,
 }
 
+/// An ordered sequence of location indices visited by vehicle, including its start and end.
+/// Indices refer to the routing matrix, schedules and costs are omitted.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RouteSequence {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Shift index.
+    pub shift_index: usize,
+    /// Location indices in visiting order.
+    pub locations: Vec<usize>,
+}
+
+/// Serializes route sequences into json format.
+pub fn serialize_route_sequences<W: Write>(writer: BufWriter<W>, sequences: &[RouteSequence]) -> Result<(), Error> {
+    serde_json::to_writer_pretty(writer, sequences)
+}
+
 /// Serializes solution into json format.
 pub fn serialize_solution<W: Write>(writer: BufWriter<W>, solution: &Solution) -> Result<(), Error> {
     serde_json::to_writer_pretty(writer, solution)
@@ -2,34 +2,47 @@
 #[path = "../../../tests/unit/format/solution/writer_test.rs"]
 mod writer_test;
 
-use crate::extensions::MultiDimensionalCapacity;
+use crate::constraints::{
+    get_customer_cost, get_min_duration_cost, get_min_jobs_cost, get_overtime_cost, get_stop_cost, get_u_turn_cost,
+};
 use crate::format::coord_index::CoordIndex;
+use crate::format::solution::load_profile::get_activity_type;
 use crate::format::solution::model::Timing;
 use crate::format::solution::{
-    serialize_solution, serialize_solution_as_geojson, Activity, Extras, Interval, Statistic, Stop, Tour,
-    UnassignedJob, UnassignedJobReason,
+    serialize_route_sequences, serialize_solution, serialize_solution_as_geojson, Activity, Extras, Interval,
+    RouteLoadProfile, RouteSequence, Statistic, Stop, Tour, UnassignedJob, UnassignedJobReason,
+    UnassignedJobSuggestion,
 };
 use crate::format::*;
 use crate::format_time;
+use std::collections::HashMap;
 use std::io::{BufWriter, Write};
-use vrp_core::construction::constraints::{route_intervals, Demand, DemandDimension};
+use std::sync::Arc;
+use vrp_core::construction::constraints::{route_intervals, ConstraintPipeline};
+use vrp_core::construction::heuristics::{best_positions, InsertionContext};
 use vrp_core::models::common::*;
 use vrp_core::models::problem::{Job, Multi};
-use vrp_core::models::solution::{Route, TourActivity};
+use vrp_core::models::solution::Route;
 use vrp_core::models::{Problem, Solution};
+use vrp_core::utils::DefaultRandom;
 
 type ApiSolution = crate::format::solution::model::Solution;
 type ApiSchedule = crate::format::solution::model::Schedule;
 type DomainLocation = vrp_core::models::common::Location;
-type DomainExtras = vrp_core::models::Extras;
 
 /// A trait to serialize solution in pragmatic format.
 pub trait PragmaticSolution<W: Write> {
     /// Serializes solution in pragmatic json format.
     fn write_pragmatic_json(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String>;
 
+    /// Serializes solution in pragmatic json format with suggestions for unassigned jobs.
+    fn write_pragmatic_json_with_suggestions(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String>;
+
     /// Serializes solution in pragmatic geo json format.
     fn write_geo_json(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String>;
+
+    /// Serializes ordered location indices of each route in json format.
+    fn write_route_sequences(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String>;
 }
 
 impl<W: Write> PragmaticSolution<W> for Solution {
@@ -39,35 +52,52 @@ impl<W: Write> PragmaticSolution<W> for Solution {
         Ok(())
     }
 
+    fn write_pragmatic_json_with_suggestions(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String> {
+        let solution = create_solution_with_suggestions(problem, self);
+        serialize_solution(writer, &solution).map_err(|err| err.to_string())?;
+        Ok(())
+    }
+
     fn write_geo_json(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String> {
         let solution = create_solution(problem, &self);
         serialize_solution_as_geojson(writer, &solution).map_err(|err| err.to_string())?;
         Ok(())
     }
+
+    fn write_route_sequences(&self, _: &Problem, writer: BufWriter<W>) -> Result<(), String> {
+        let sequences = create_route_sequences(&self);
+        serialize_route_sequences(writer, sequences.as_slice()).map_err(|err| err.to_string())?;
+        Ok(())
+    }
 }
 
 struct Leg {
     pub last_detail: Option<(DomainLocation, Timestamp)>,
-    pub load: Option<MultiDimensionalCapacity>,
     pub statistic: Statistic,
 }
 
 impl Leg {
-    fn new(
-        last_detail: Option<(DomainLocation, Timestamp)>,
-        load: Option<MultiDimensionalCapacity>,
-        statistic: Statistic,
-    ) -> Self {
-        Self { last_detail, load, statistic }
+    fn new(last_detail: Option<(DomainLocation, Timestamp)>, statistic: Statistic) -> Self {
+        Self { last_detail, statistic }
     }
 
     fn empty() -> Self {
-        Self { last_detail: None, load: None, statistic: Statistic::default() }
+        Self { last_detail: None, statistic: Statistic::default() }
     }
 }
 
 /// Creates solution.
 pub fn create_solution(problem: &Problem, solution: &Solution) -> ApiSolution {
+    create_api_solution(problem, solution, false)
+}
+
+/// Creates solution where each unassigned job has a suggestion of its closest feasible assignment.
+/// Please note, that it is expensive as insertion is evaluated with each blocking constraint relaxed.
+pub fn create_solution_with_suggestions(problem: &Problem, solution: &Solution) -> ApiSolution {
+    create_api_solution(problem, solution, true)
+}
+
+fn create_api_solution(problem: &Problem, solution: &Solution, with_suggestions: bool) -> ApiSolution {
     let coord_index = solution
         .extras
         .get("coord_index")
@@ -78,16 +108,31 @@ pub fn create_solution(problem: &Problem, solution: &Solution) -> ApiSolution {
 
     let statistic = tours.iter().fold(Statistic::default(), |acc, tour| acc + tour.statistic.clone());
 
-    let unassigned = create_unassigned(solution);
+    let unassigned = create_unassigned(problem, solution, with_suggestions);
 
     let extras = create_extras(solution);
 
     ApiSolution { statistic, tours, unassigned, extras }
 }
 
-fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> Tour {
-    let is_multi_dimen = has_multi_dimensional_capacity(problem.extras.as_ref());
+/// Creates ordered sequences of location indices for each route, including start and end.
+pub fn create_route_sequences(solution: &Solution) -> Vec<RouteSequence> {
+    solution
+        .routes
+        .iter()
+        .map(|route| {
+            let vehicle = route.actor.vehicle.as_ref();
+
+            RouteSequence {
+                vehicle_id: vehicle.dimens.get_id().unwrap().clone(),
+                shift_index: *vehicle.dimens.get_value::<usize>("shift_index").unwrap(),
+                locations: route.tour.all_activities().map(|activity| activity.place.location).collect(),
+            }
+        })
+        .collect()
+}
 
+fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> Tour {
     let actor = route.actor.as_ref();
     let vehicle = actor.vehicle.as_ref();
 
@@ -100,35 +145,24 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
     };
 
     let intervals = route_intervals(route, Box::new(|a| get_activity_type(a).map_or(false, |t| t == "reload")));
+    let load_profile = RouteLoadProfile::new(problem, route);
 
     let mut leg = intervals.into_iter().fold(Leg::empty(), |leg, (start_idx, end_idx)| {
-        let (start_delivery, end_pickup) = route.tour.activities_slice(start_idx, end_idx).iter().fold(
-            (leg.load.unwrap_or_else(|| MultiDimensionalCapacity::default()), MultiDimensionalCapacity::default()),
-            |acc, activity| {
-                let (delivery, pickup) = activity
-                    .job
-                    .as_ref()
-                    .and_then(|job| {
-                        get_capacity(&job.dimens, is_multi_dimen).and_then(|d| Some((d.delivery.0, d.pickup.0)))
-                    })
-                    .unwrap_or((MultiDimensionalCapacity::default(), MultiDimensionalCapacity::default()));
-                (acc.0 + delivery, acc.1 + pickup)
-            },
-        );
-
         let (start_idx, start) = if start_idx == 0 {
             let start = route.tour.start().unwrap();
             tour.stops.push(Stop {
-                location: coord_index.get_by_idx(&start.place.location).unwrap(),
+                location: get_start_location(route, coord_index),
                 time: format_schedule(&start.schedule),
-                load: start_delivery.as_vec(),
+                load: load_profile.get(0).unwrap().as_vec(),
                 distance: 0,
                 activities: vec![Activity {
                     job_id: "departure".to_string(),
                     activity_type: "departure".to_string(),
                     location: None,
                     time: None,
+                    load: None,
                     job_tag: None,
+                    metadata: None,
                 }],
             });
             (start_idx + 1, start)
@@ -136,23 +170,23 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
             (start_idx, route.tour.get(start_idx - 1).unwrap())
         };
 
-        let mut leg = route.tour.activities_slice(start_idx, end_idx).iter().fold(
-            Leg::new(Some((start.place.location, start.schedule.departure)), Some(start_delivery), leg.statistic),
-            |leg, act| {
+        route.tour.activities_slice(start_idx, end_idx).iter().zip(start_idx..).fold(
+            Leg::new(Some((start.place.location, start.schedule.departure)), leg.statistic),
+            |leg, (act, act_idx)| {
                 let activity_type = get_activity_type(act).cloned();
                 let (prev_location, prev_departure) = leg.last_detail.unwrap();
-                let prev_load = if activity_type.is_some() {
-                    leg.load.unwrap()
-                } else {
-                    // NOTE arrival must have zero load
-                    let dimen_size = leg.load.unwrap().size;
-                    MultiDimensionalCapacity::new(vec![0; dimen_size])
-                };
+                let load = *load_profile.get(act_idx).unwrap();
 
                 let activity_type = activity_type.unwrap_or_else(|| "arrival".to_string());
                 let is_break = activity_type == "break";
 
                 let job_tag = act.job.as_ref().and_then(|job| job.dimens.get_value::<String>("tag").cloned());
+                let metadata = act.job.as_ref().and_then(|single| {
+                    single.dimens.get_value::<HashMap<String, String>>("metadata").cloned().or_else(|| {
+                        Multi::roots(single)
+                            .and_then(|multi| multi.dimens.get_value::<HashMap<String, String>>("metadata").cloned())
+                    })
+                });
                 let job_id = match activity_type.as_str() {
                     "pickup" | "delivery" | "replacement" | "service" => {
                         let single = act.job.as_ref().unwrap();
@@ -162,8 +196,12 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
                     _ => activity_type.clone(),
                 };
 
-                let driving =
-                    problem.transport.duration(vehicle.profile, prev_location, act.place.location, prev_departure);
+                let profile = match vehicle.dimens.get_value::<Profile>("loaded_profile") {
+                    Some(profile) if load_profile.is_loaded(act_idx - 1) => *profile,
+                    _ => vehicle.profile,
+                };
+
+                let driving = problem.transport.duration(profile, prev_location, act.place.location, prev_departure);
                 let arrival = prev_departure + driving;
                 let start = act.schedule.arrival.max(act.place.time.start);
                 let waiting = start - act.schedule.arrival;
@@ -173,23 +211,20 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
                 // total cost and distance
                 let cost = leg.statistic.cost
                     + problem.activity.cost(actor, act, act.schedule.arrival)
-                    + problem.transport.cost(actor, prev_location, act.place.location, prev_departure);
+                    + problem.transport.cost(actor, profile, prev_location, act.place.location, prev_departure);
                 let distance = leg.statistic.distance
-                    + problem.transport.distance(vehicle.profile, prev_location, act.place.location, prev_departure)
-                        as i32;
+                    + problem.transport.distance(profile, prev_location, act.place.location, prev_departure) as i32;
 
                 if prev_location != act.place.location {
                     tour.stops.push(Stop {
                         location: coord_index.get_by_idx(&act.place.location).unwrap(),
                         time: format_as_schedule(&(arrival, departure)),
-                        load: prev_load.as_vec(),
+                        load: load.as_vec(),
                         distance,
                         activities: vec![],
                     });
                 }
 
-                let load = calculate_load(prev_load, act, is_multi_dimen);
-
                 let last = tour.stops.len() - 1;
                 let mut last = tour.stops.get_mut(last).unwrap();
 
@@ -200,7 +235,9 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
                     activity_type,
                     location: Some(coord_index.get_by_idx(&act.place.location).unwrap()),
                     time: Some(Interval { start: format_time(arrival), end: format_time(departure) }),
+                    load: Some(load.as_vec()),
                     job_tag,
+                    metadata,
                 });
 
                 Leg {
@@ -216,14 +253,9 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
                             break_time: leg.statistic.times.break_time + (if is_break { serving as i32 } else { 0 }),
                         },
                     },
-                    load: Some(load),
                 }
             },
-        );
-
-        leg.load = Some(leg.load.unwrap() - end_pickup);
-
-        leg
+        )
     });
 
     // NOTE remove redundant info
@@ -234,9 +266,16 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
         .for_each(|activity| {
             activity.location = None;
             activity.time = None;
+            activity.load = None;
         });
 
-    leg.statistic.cost += vehicle.costs.fixed;
+    leg.statistic.cost += vehicle.costs.fixed
+        + get_overtime_cost(route)
+        + get_min_duration_cost(route)
+        + get_stop_cost(route)
+        + get_min_jobs_cost(route)
+        + get_customer_cost(route)
+        + get_u_turn_cost(route, problem.transport.as_ref());
 
     tour.vehicle_id = vehicle.dimens.get_id().unwrap().clone();
     tour.type_id = vehicle.dimens.get_value::<String>("type_id").unwrap().clone();
@@ -245,6 +284,11 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
     tour
 }
 
+/// Returns start location of the route. Floating start is reported at the first activity location.
+fn get_start_location(route: &Route, coord_index: &CoordIndex) -> crate::format::Location {
+    route.tour.all_activities().filter_map(|activity| coord_index.get_by_idx(&activity.place.location)).next().unwrap()
+}
+
 fn format_schedule(schedule: &Schedule) -> ApiSchedule {
     ApiSchedule { arrival: format_time(schedule.arrival), departure: format_time(schedule.departure) }
 }
@@ -253,33 +297,11 @@ fn format_as_schedule(schedule: &(f64, f64)) -> ApiSchedule {
     format_schedule(&Schedule::new(schedule.0, schedule.1))
 }
 
-fn calculate_load(
-    current: MultiDimensionalCapacity,
-    act: &TourActivity,
-    is_multi_dimen: bool,
-) -> MultiDimensionalCapacity {
-    let job = act.job.as_ref();
-    let demand = job
-        .and_then(|job| get_capacity(&job.dimens, is_multi_dimen))
-        .unwrap_or(Demand::<MultiDimensionalCapacity>::default());
-    current - demand.delivery.0 - demand.delivery.1 + demand.pickup.0 + demand.pickup.1
-}
+fn create_unassigned(problem: &Problem, solution: &Solution, with_suggestions: bool) -> Vec<UnassignedJob> {
+    let relaxed_contexts = if with_suggestions { create_relaxed_contexts(problem, solution) } else { HashMap::new() };
 
-fn create_unassigned(solution: &Solution) -> Vec<UnassignedJob> {
     solution.unassigned.iter().fold(vec![], |mut acc, unassigned| {
-        let reason = match *unassigned.1 {
-            SKILLS_CONSTRAINT_CODE => (1, "cannot serve required skill"),
-            TIME_CONSTRAINT_CODE => (2, "cannot be visited within time window"),
-            CAPACITY_CONSTRAINT_CODE => (3, "does not fit into any vehicle due to capacity"),
-            REACHABLE_CONSTRAINT_CODE => (100, "location unreachable"),
-            DISTANCE_LIMIT_CONSTRAINT_CODE => (101, "cannot be assigned due to max distance constraint of vehicle"),
-            DURATION_LIMIT_CONSTRAINT_CODE => (102, "cannot be assigned due to shift time constraint of vehicle"),
-            BREAK_CONSTRAINT_CODE => (103, "break is not assignable"),
-            LOCKING_CONSTRAINT_CODE => (104, "cannot be served due to relation lock"),
-            PRIORITY_CONSTRAINT_CODE => (105, "cannot be served due to priority"),
-            AREA_CONSTRAINT_CODE => (106, "cannot be assigned due to area constraint"),
-            _ => (0, "unknown"),
-        };
+        let reason = map_code_reason(*unassigned.1);
         let dimens = match unassigned.0 {
             Job::Single(job) => &job.dimens,
             Job::Multi(job) => &job.dimens,
@@ -290,43 +312,79 @@ fn create_unassigned(solution: &Solution) -> Vec<UnassignedJob> {
                 .map(|vehicle_id| format!("{}_break", vehicle_id))
                 .unwrap_or_else(|| dimens.get_id().unwrap().clone()),
             reasons: vec![UnassignedJobReason { code: reason.0, description: reason.1.to_string() }],
+            suggestion: relaxed_contexts.get(unassigned.1).and_then(|ctx| create_suggestion(ctx, unassigned.0)),
         });
 
         acc
     })
 }
 
-fn get_activity_type(activity: &TourActivity) -> Option<&String> {
-    activity.job.as_ref().and_then(|single| single.dimens.get_value::<String>("type"))
+/// Creates insertion context for each distinct code of unassigned jobs where constraint with that code is ignored.
+fn create_relaxed_contexts(problem: &Problem, solution: &Solution) -> HashMap<i32, InsertionContext> {
+    if solution.unassigned.is_empty() {
+        return HashMap::new();
+    }
+
+    let insertion_ctx = create_insertion_ctx(problem, solution);
+    let problem = &insertion_ctx.problem;
+
+    solution.unassigned.values().fold(HashMap::new(), |mut acc, &code| {
+        acc.entry(code).or_insert_with(|| InsertionContext {
+            problem: Arc::new(copy_problem(problem, Arc::new(problem.constraint.relaxed(code)))),
+            solution: insertion_ctx.solution.deep_copy(),
+            random: insertion_ctx.random.clone(),
+            cache: None,
+        });
+
+        acc
+    })
 }
 
-fn get_capacity(dimens: &Dimensions, is_multi_dimen: bool) -> Option<Demand<MultiDimensionalCapacity>> {
-    if is_multi_dimen {
-        dimens.get_demand().cloned()
-    } else {
-        let create_capacity = |value: i32| {
-            if value == 0 {
-                MultiDimensionalCapacity::default()
-            } else {
-                MultiDimensionalCapacity::new(vec![value])
-            }
-        };
-        dimens.get_demand().map(|demand: &Demand<i32>| Demand {
-            pickup: (create_capacity(demand.pickup.0), create_capacity(demand.pickup.1)),
-            delivery: (create_capacity(demand.delivery.0), create_capacity(demand.delivery.1)),
-        })
-    }
+fn create_insertion_ctx(problem: &Problem, solution: &Solution) -> InsertionContext {
+    let problem = Arc::new(copy_problem(problem, problem.constraint.clone()));
+    let solution = Arc::new(Solution {
+        registry: solution.registry.deep_copy(),
+        routes: solution.routes.iter().map(|route| route.deep_copy()).collect(),
+        unassigned: solution.unassigned.clone(),
+        extras: solution.extras.clone(),
+    });
+
+    let mut insertion_ctx =
+        InsertionContext::new_from_solution(problem, (solution, None), Arc::new(DefaultRandom::default()));
+    insertion_ctx.restore();
+
+    insertion_ctx
+}
+
+/// Finds the cheapest insertion of unassigned job within context with relaxed constraint.
+fn create_suggestion(insertion_ctx: &InsertionContext, job: &Job) -> Option<UnassignedJobSuggestion> {
+    let (route_index, position, _) = best_positions(insertion_ctx, job, 1).into_iter().next()?;
+
+    let actor = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .map(|route_ctx| route_ctx.route.actor.clone())
+        .chain(insertion_ctx.solution.registry.next())
+        .nth(route_index)?;
+
+    Some(UnassignedJobSuggestion {
+        vehicle_id: actor.vehicle.dimens.get_id().unwrap().clone(),
+        shift_index: *actor.vehicle.dimens.get_value::<usize>("shift_index").unwrap(),
+        position,
+    })
 }
 
-fn has_multi_dimensional_capacity(extras: &DomainExtras) -> bool {
-    let capacity_type = extras
-        .get("capacity_type")
-        .and_then(|s| s.downcast_ref::<String>())
-        .unwrap_or_else(|| panic!("Cannot get capacity type!"));
-    match capacity_type.as_str() {
-        "multi" => true,
-        "single" => false,
-        _ => panic!("Unknown capacity type: '{}'", capacity_type),
+fn copy_problem(problem: &Problem, constraint: Arc<ConstraintPipeline>) -> Problem {
+    Problem {
+        fleet: problem.fleet.clone(),
+        jobs: problem.jobs.clone(),
+        locks: problem.locks.clone(),
+        constraint,
+        activity: problem.activity.clone(),
+        transport: problem.transport.clone(),
+        objective: problem.objective.clone(),
+        extras: problem.extras.clone(),
     }
 }
 
@@ -25,6 +25,7 @@ mod utils;
 mod validation;
 
 pub mod checker;
+pub mod clustering;
 pub mod format;
 
 use crate::format::problem::Problem;
@@ -1,7 +1,7 @@
 //! Contains utility logic.
 
 mod approx_transporation;
-pub use self::approx_transporation::get_approx_transportation;
+pub use self::approx_transporation::{get_approx_transportation, get_haversine_distance, SpeedModel};
 
 mod permutations;
 pub use self::permutations::VariableJobPermutation;
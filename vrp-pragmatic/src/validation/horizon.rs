@@ -0,0 +1,89 @@
+#[cfg(test)]
+#[path = "../../tests/unit/validation/horizon_test.rs"]
+mod horizon_test;
+
+use super::*;
+use crate::parse_time_safe;
+use crate::validation::common::get_time_window_from_vec;
+use vrp_core::models::common::TimeWindow;
+
+/// Checks that planning horizon is defined by valid start and end.
+fn check_e1400_invalid_horizon(ctx: &ValidationContext) -> Result<(), FormatError> {
+    match (&ctx.problem.horizon, get_horizon(ctx)) {
+        (Some(_), None) => Err(FormatError::new(
+            "E1400".to_string(),
+            "invalid planning horizon".to_string(),
+            "specify horizon as a list of start and end dates in RFC3339 format, start should not be after end"
+                .to_string(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Checks that job and vehicle times are within planning horizon.
+fn check_e1401_times_outside_horizon(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let horizon = match get_horizon(ctx) {
+        Some(horizon) => horizon,
+        None => return Ok(()),
+    };
+
+    let is_outside =
+        |time: &String| parse_time_safe(time).map_or(false, |time| time < horizon.start || time > horizon.end);
+    let is_tw_outside = |tw: &Vec<String>| tw.iter().any(&is_outside);
+
+    let job_issues = ctx
+        .jobs()
+        .filter(|job| {
+            ctx.tasks(job)
+                .iter()
+                .flat_map(|task| task.places.iter())
+                .filter_map(|place| place.times.as_ref())
+                .flat_map(|tws| tws.iter())
+                .any(&is_tw_outside)
+        })
+        .map(|job| format!("job '{}'", job.id));
+
+    let vehicle_issues = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.shifts.iter().any(|shift| {
+                is_outside(&shift.start.time)
+                    || shift.end.as_ref().map_or(false, |end| is_outside(&end.time))
+                    || shift.breaks.iter().flat_map(|breaks| breaks.iter()).any(|vehicle_break| {
+                        match &vehicle_break.time {
+                            VehicleBreakTime::TimeWindow(tw) => is_tw_outside(tw),
+                            VehicleBreakTime::TimeOffset(_) => false,
+                        }
+                    })
+                    || shift
+                        .reloads
+                        .iter()
+                        .flat_map(|reloads| reloads.iter())
+                        .filter_map(|reload| reload.times.as_ref())
+                        .flat_map(|tws| tws.iter())
+                        .any(&is_tw_outside)
+            })
+        })
+        .map(|vehicle| format!("vehicle type '{}'", vehicle.type_id));
+
+    let issues = job_issues.chain(vehicle_issues).collect::<Vec<_>>();
+
+    if issues.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1401".to_string(),
+            "time outside of planning horizon".to_string(),
+            format!("ensure that all times are within planning horizon: {}", issues.join(", ")),
+        ))
+    }
+}
+
+fn get_horizon(ctx: &ValidationContext) -> Option<TimeWindow> {
+    ctx.problem.horizon.as_ref().and_then(get_time_window_from_vec).filter(|horizon| horizon.start <= horizon.end)
+}
+
+/// Validates planning horizon of the problem.
+pub fn validate_horizon(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
+    combine_error_results(&[check_e1400_invalid_horizon(ctx), check_e1401_times_outside_horizon(ctx)])
+}
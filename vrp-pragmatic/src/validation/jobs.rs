@@ -4,6 +4,8 @@ mod jobs_test;
 
 use super::*;
 use crate::extensions::MultiDimensionalCapacity;
+use std::collections::HashSet;
+use std::iter::once;
 
 /// Checks that plan has no jobs with duplicate ids.
 fn check_e1100_no_jobs_with_duplicate_ids(ctx: &ValidationContext) -> Result<(), FormatError> {
@@ -146,7 +148,15 @@ fn check_e1106_negative_duration(ctx: &ValidationContext) -> Result<(), FormatEr
         .filter(|job| {
             ctx.tasks(job)
                 .iter()
-                .flat_map(|task| task.places.iter().map(|place| place.duration))
+                .flat_map(|task| task.places.iter())
+                .flat_map(|place| {
+                    once(place.duration).chain(
+                        place
+                            .durations
+                            .iter()
+                            .flat_map(|durations| once(durations.delivery).chain(once(durations.pickup))),
+                    )
+                })
                 .any(|duration| duration.is_sign_negative())
         })
         .map(|job| job.id.clone())
@@ -186,6 +196,100 @@ fn check_e1107_negative_demand(ctx: &ValidationContext) -> Result<(), FormatErro
     }
 }
 
+/// Checks that separate delivery and pickup durations are used only within replacement tasks.
+fn check_e1108_durations_in_replacement_only(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let has_durations = |tasks: &Option<Vec<JobTask>>| {
+        tasks
+            .iter()
+            .flat_map(|tasks| tasks.iter())
+            .flat_map(|task| task.places.iter())
+            .any(|place| place.durations.is_some())
+    };
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| has_durations(&job.pickups) || has_durations(&job.deliveries) || has_durations(&job.services))
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1108".to_string(),
+            "job has durations in non replacement task".to_string(),
+            format!("use durations only within replacement tasks, job ids: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that job hints refer to existing jobs and vehicle types.
+fn check_e1109_invalid_hints(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let job_ids = ctx.jobs().map(|job| &job.id).collect::<HashSet<_>>();
+    let type_ids = ctx.vehicles().map(|vehicle| &vehicle.type_id).collect::<HashSet<_>>();
+
+    let ids = ctx
+        .problem
+        .plan
+        .hints
+        .iter()
+        .flat_map(|hints| hints.iter())
+        .filter(|hint| {
+            !job_ids.contains(&hint.job_id)
+                || hint.vehicle_type.as_ref().map_or(false, |vehicle_type| !type_ids.contains(vehicle_type))
+        })
+        .map(|hint| hint.job_id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1109".to_string(),
+            "invalid job hint".to_string(),
+            format!("ensure that hinted jobs and vehicle types exist, job ids: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that job occurrence probability is in (0, 1] range.
+fn check_e1110_invalid_probability(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| job.probability.map_or(false, |probability| !(probability > 0. && probability <= 1.)))
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1110".to_string(),
+            "invalid job probability".to_string(),
+            format!("ensure that probability is in (0, 1] range, job ids: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that job unassigned penalty is positive.
+fn check_e1111_invalid_unassigned_penalty(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| matches!(job.unassigned_penalty, Some(penalty) if penalty.is_nan() || penalty <= 0.))
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1111".to_string(),
+            "invalid job unassigned penalty".to_string(),
+            format!("ensure that unassigned penalty is positive, job ids: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
 /// Validates jobs from the plan.
 pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
     combine_error_results(&[
@@ -197,5 +301,9 @@ pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
         check_e1105_empty_jobs(ctx),
         check_e1106_negative_duration(ctx),
         check_e1107_negative_demand(ctx),
+        check_e1108_durations_in_replacement_only(ctx),
+        check_e1109_invalid_hints(ctx),
+        check_e1110_invalid_probability(ctx),
+        check_e1111_invalid_unassigned_penalty(ctx),
     ])
 }
@@ -28,6 +28,9 @@ use self::relations::validate_relations;
 mod routing;
 use self::routing::validate_profiles;
 
+mod horizon;
+use self::horizon::validate_horizon;
+
 impl<'a> ValidationContext<'a> {
     /// Creates an instance of `ValidationContext`.
     pub fn new(problem: &'a Problem, matrices: Option<&'a Vec<Matrix>>) -> Self {
@@ -47,6 +50,7 @@ impl<'a> ValidationContext<'a> {
             .chain(validate_objectives(&self).err().into_iter())
             .chain(validate_profiles(&self).err().into_iter())
             .chain(validate_relations(&self).err().into_iter())
+            .chain(validate_horizon(&self).err().into_iter())
             .flatten()
             .collect::<Vec<_>>();
 
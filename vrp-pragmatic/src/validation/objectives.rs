@@ -28,7 +28,9 @@ fn check_e1601_duplicate_objectives(objectives: &Vec<&Objective>) -> Result<(),
                 MinimizeCost => acc.entry("minimize-cost"),
                 MinimizeTours => acc.entry("minimize-tours"),
                 MaximizeTours => acc.entry("maximize-tours"),
+                MinimizeExpectedCost { samples: _ } => acc.entry("minimize-expected-cost"),
                 MinimizeUnassignedJobs => acc.entry("minimize-unassigned"),
+                MinimizeFleetCost { costs: _ } => acc.entry("minimize-fleet-cost"),
                 BalanceMaxLoad { options: _ } => acc.entry("balance-max-load"),
                 BalanceActivities { options: _ } => acc.entry("balance-activities"),
                 BalanceDistance { options: _ } => acc.entry("balance-distance"),
@@ -62,6 +64,8 @@ fn check_e1602_no_cost_value_objective(objectives: &Vec<&Objective>) -> Result<(
         .iter()
         .filter(|objective| match objective {
             MinimizeCost => true,
+            MinimizeFleetCost { costs: _ } => true,
+            MinimizeExpectedCost { samples: _ } => true,
             _ => false,
         })
         .count();
@@ -70,7 +74,73 @@ fn check_e1602_no_cost_value_objective(objectives: &Vec<&Objective>) -> Result<(
         Err(FormatError::new(
             "E1602".to_string(),
             "missing cost objective".to_string(),
-            "specify 'minimize-cost' objective".to_string(),
+            "specify 'minimize-cost', 'minimize-fleet-cost' or 'minimize-expected-cost' objective".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that fleet cost objective has non empty list of non negative costs.
+fn check_e1603_invalid_fleet_cost(objectives: &Vec<&Objective>) -> Result<(), FormatError> {
+    let has_invalid = objectives.iter().any(|objective| match objective {
+        MinimizeFleetCost { costs } => costs.is_empty() || costs.iter().any(|cost| cost.is_sign_negative()),
+        _ => false,
+    });
+
+    if has_invalid {
+        Err(FormatError::new(
+            "E1603".to_string(),
+            "invalid fleet cost objective".to_string(),
+            "specify at least one tour cost and make sure that all costs are non negative".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that objective weights are specified for each primary objective only.
+fn check_e1604_invalid_objective_weights(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let has_invalid = ctx.problem.objectives.as_ref().map_or(false, |objectives| {
+        objectives.weights.as_ref().map_or(false, |weights| {
+            weights.len() != objectives.primary.len()
+                || weights.iter().any(|weight| weight.is_sign_negative())
+                || objectives.secondary.is_some()
+        })
+    });
+
+    if has_invalid {
+        Err(FormatError::new(
+            "E1604".to_string(),
+            "invalid objective weights".to_string(),
+            "specify non negative weight for each primary objective and remove secondary objectives".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that objective normalizations are specified for each weighted primary objective and valid.
+fn check_e1605_invalid_objective_normalizations(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let has_invalid = ctx.problem.objectives.as_ref().map_or(false, |objectives| {
+        objectives.normalizations.as_ref().map_or(false, |normalizations| {
+            objectives.weights.is_none()
+                || normalizations.len() != objectives.primary.len()
+                || normalizations.iter().any(|normalization| match normalization {
+                    Some(ObjectiveNormalization::MinMax { min, max }) => max <= min,
+                    Some(ObjectiveNormalization::Reference { scale }) => *scale <= 0.,
+                    _ => false,
+                })
+        })
+    });
+
+    if has_invalid {
+        Err(FormatError::new(
+            "E1605".to_string(),
+            "invalid objective normalizations".to_string(),
+            "specify weights and normalization for each primary objective, use max greater than min and positive \
+             reference scale"
+                .to_string(),
         ))
     } else {
         Ok(())
@@ -93,6 +163,9 @@ pub fn validate_objectives(ctx: &ValidationContext) -> Result<(), Vec<FormatErro
             check_e1600_empty_objective(&objectives),
             check_e1601_duplicate_objectives(&objectives),
             check_e1602_no_cost_value_objective(&objectives),
+            check_e1603_invalid_fleet_cost(&objectives),
+            check_e1604_invalid_objective_weights(ctx),
+            check_e1605_invalid_objective_normalizations(ctx),
         ])
     } else {
         Ok(())
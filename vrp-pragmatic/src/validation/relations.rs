@@ -139,6 +139,48 @@ fn check_e1204_job_assigned_to_multiple_vehicles(relations: &Vec<Relation>) -> R
     }
 }
 
+/// Checks that relation tags are consistent with job ids and refer existing job tasks.
+fn check_e1205_unknown_job_tags(ctx: &ValidationContext, relations: &Vec<Relation>) -> Result<(), FormatError> {
+    let mut job_ids = relations
+        .iter()
+        .filter_map(|relation| relation.tags.as_ref().map(|tags| (relation, tags)))
+        .flat_map(|(relation, tags)| {
+            let has_same_size = tags.len() == relation.jobs.len();
+
+            relation
+                .jobs
+                .iter()
+                .zip(tags.iter().chain(std::iter::repeat(&None)))
+                .filter(move |(job_id, tag)| match (ctx.job_index.get(job_id.as_str()), tag) {
+                    _ if !has_same_size => true,
+                    (Some(job), Some(tag)) => {
+                        !ctx.tasks(job).into_iter().any(|task| task.tag.as_ref().map_or(false, |t| t == tag))
+                    }
+                    (None, Some(_)) => true,
+                    _ => false,
+                })
+                .map(|(job_id, _)| job_id.clone())
+        })
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    job_ids.sort();
+
+    if job_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1205".to_string(),
+            "relation has unknown job tag".to_string(),
+            format!(
+                "specify one tag per job id and use only tags defined on job tasks, job ids: '{}'",
+                job_ids.join(", ")
+            ),
+        ))
+    }
+}
+
 /// Validates relations in the plan.
 pub fn validate_relations(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
     let vehicle_map = ctx
@@ -154,6 +196,7 @@ pub fn validate_relations(ctx: &ValidationContext) -> Result<(), Vec<FormatError
             check_e1202_empty_job_list(relations),
             check_e1203_no_multiple_places_times(ctx, relations),
             check_e1204_job_assigned_to_multiple_vehicles(relations),
+            check_e1205_unknown_job_tags(ctx, relations),
         ])
     } else {
         Ok(())
@@ -3,6 +3,9 @@
 mod routing_test;
 
 use super::*;
+use crate::format::CoordIndex;
+use crate::{format_time, parse_time_safe};
+use std::collections::{BTreeMap, BTreeSet};
 
 /// Checks that no duplicated profile names specified.
 fn check_e1500_duplicated_profiles(ctx: &ValidationContext) -> Result<(), FormatError> {
@@ -28,7 +31,210 @@ fn check_e1501_empty_profiles(ctx: &ValidationContext) -> Result<(), FormatError
     }
 }
 
+/// Checks that matrix avoidance has valid multiplier and flags size.
+fn check_e1502_invalid_matrix_avoidance(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let profiles = ctx
+        .matrices
+        .iter()
+        .flat_map(|matrices| matrices.iter())
+        .filter(|matrix| {
+            matrix.avoidance.as_ref().map_or(false, |avoidance| {
+                avoidance.multiplier <= 0. || avoidance.flags.len() != matrix.travel_times.len()
+            })
+        })
+        .map(|matrix| matrix.profile.clone())
+        .collect::<Vec<_>>();
+
+    if profiles.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1502".to_string(),
+            "invalid matrix avoidance".to_string(),
+            format!(
+                "use positive multiplier and flags of the same size as matrix for profiles: '{}'",
+                profiles.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that time aware matrices have valid timestamps, at least two per profile and the same
+/// set of timestamps for every profile.
+fn check_e1503_inconsistent_matrix_timestamps(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let matrices = match ctx.matrices {
+        Some(matrices) if matrices.iter().any(|matrix| matrix.timestamp.is_some()) => matrices,
+        _ => return Ok(()),
+    };
+
+    let (timestamps, invalid) = matrices.iter().fold(
+        (BTreeMap::<&String, BTreeSet<i64>>::new(), BTreeSet::new()),
+        |(mut timestamps, mut invalid), matrix| {
+            let entry = timestamps.entry(&matrix.profile).or_default();
+            match matrix.timestamp.as_ref().and_then(|timestamp| parse_time_safe(timestamp).ok()) {
+                Some(timestamp) => {
+                    entry.insert(timestamp as i64);
+                }
+                None => {
+                    invalid.insert(&matrix.profile);
+                }
+            }
+
+            (timestamps, invalid)
+        },
+    );
+
+    let all_timestamps = timestamps.values().flatten().cloned().collect::<BTreeSet<_>>();
+
+    let issues = timestamps
+        .iter()
+        .filter_map(|(profile, profile_timestamps)| {
+            let missing = all_timestamps
+                .difference(profile_timestamps)
+                .map(|timestamp| format_time(*timestamp as f64))
+                .collect::<Vec<_>>();
+
+            if invalid.contains(profile) {
+                Some(format!("'{}' has matrix without valid timestamp", profile))
+            } else if profile_timestamps.len() < 2 {
+                Some(format!("'{}' has less than two timestamps", profile))
+            } else if !missing.is_empty() {
+                Some(format!("'{}' misses timestamps: {}", profile, missing.join(", ")))
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if issues.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1503".to_string(),
+            "inconsistent time aware matrices".to_string(),
+            format!(
+                "specify valid timestamp for each matrix and the same set of at least two timestamps for every profile: {}",
+                issues.join("; ")
+            ),
+        ))
+    }
+}
+
+/// Checks that matrix fallback penalty has non-negative values.
+fn check_e1504_invalid_matrix_fallback(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let profiles = ctx
+        .matrices
+        .iter()
+        .flat_map(|matrices| matrices.iter())
+        .filter(|matrix| match matrix.fallback {
+            Some(MatrixFallback::Penalty { distance, duration }) => distance < 0. || duration < 0.,
+            _ => false,
+        })
+        .map(|matrix| matrix.profile.clone())
+        .collect::<Vec<_>>();
+
+    if profiles.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1504".to_string(),
+            "invalid matrix fallback".to_string(),
+            format!("use non-negative penalty distance and duration for profiles: '{}'", profiles.join(", ")),
+        ))
+    }
+}
+
+/// Checks that profile road classes have positive speed and non-negative min distance.
+fn check_e1505_invalid_road_classes(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let profiles = ctx
+        .problem
+        .fleet
+        .profiles
+        .iter()
+        .filter(|profile| {
+            profile
+                .road_classes
+                .iter()
+                .flat_map(|classes| classes.iter())
+                .any(|class| class.speed <= 0. || class.min_distance < 0.)
+        })
+        .map(|profile| profile.name.clone())
+        .collect::<Vec<_>>();
+
+    if profiles.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1505".to_string(),
+            "invalid profile road classes".to_string(),
+            format!("use positive speed and non-negative min distance for profiles: '{}'", profiles.join(", ")),
+        ))
+    }
+}
+
+/// Checks that location references are not mixed with geo coordinates, point to routing matrix
+/// entries and are not used with features which require geo coordinates.
+fn check_e1506_invalid_location_references(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let coord_index = CoordIndex::new(ctx.problem);
+    if !coord_index.has_references() {
+        return Ok(());
+    }
+
+    let required_size = coord_index.floating_index();
+    let matrices = ctx.matrices.map_or(&[][..], |matrices| matrices.as_slice());
+    let has_areas = ctx.vehicles().any(|vehicle| vehicle.limits.as_ref().map_or(false, |l| l.allowed_areas.is_some()));
+
+    let issues = vec![
+        (coord_index.has_coordinates(), "location references are mixed with geo coordinates"),
+        (matrices.is_empty(), "no routing matrix is specified"),
+        (
+            matrices.iter().any(|matrix| ((matrix.travel_times.len() as f64).sqrt() as usize) < required_size),
+            "location reference index is out of routing matrix",
+        ),
+        (
+            matrices.iter().any(|matrix| matches!(matrix.fallback, Some(MatrixFallback::Approximation))),
+            "matrix approximation fallback requires geo coordinates",
+        ),
+        (has_areas, "vehicle allowed areas require geo coordinates"),
+    ]
+    .into_iter()
+    .filter(|(is_issue, _)| *is_issue)
+    .map(|(_, issue)| issue)
+    .collect::<Vec<_>>();
+
+    if issues.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1506".to_string(),
+            "invalid location references".to_string(),
+            format!("use only location references which point to routing matrix entries: {}", issues.join("; ")),
+        ))
+    }
+}
+
+/// Checks that traffic factor is positive.
+fn check_e1507_invalid_traffic_factor(ctx: &ValidationContext) -> Result<(), FormatError> {
+    match ctx.problem.config.as_ref().and_then(|config| config.traffic_factor) {
+        Some(factor) if factor <= 0. => Err(FormatError::new(
+            "E1507".to_string(),
+            "invalid traffic factor".to_string(),
+            format!("use positive traffic factor instead of '{}'", factor),
+        )),
+        _ => Ok(()),
+    }
+}
+
 /// Validates profiles from the fleet.
 pub fn validate_profiles(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
-    combine_error_results(&[check_e1500_duplicated_profiles(ctx), check_e1501_empty_profiles(ctx)])
+    combine_error_results(&[
+        check_e1500_duplicated_profiles(ctx),
+        check_e1501_empty_profiles(ctx),
+        check_e1502_invalid_matrix_avoidance(ctx),
+        check_e1503_inconsistent_matrix_timestamps(ctx),
+        check_e1504_invalid_matrix_fallback(ctx),
+        check_e1505_invalid_road_classes(ctx),
+        check_e1506_invalid_location_references(ctx),
+        check_e1507_invalid_traffic_factor(ctx),
+    ])
 }
@@ -162,6 +162,146 @@ fn check_e1305_vehicle_limit_area_is_correct(ctx: &ValidationContext) -> Result<
     }
 }
 
+/// Checks that vehicle loaded profile is defined in fleet profiles.
+fn check_e1306_vehicle_loaded_profile_exists(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle
+                .loaded_profile
+                .as_ref()
+                .map_or(false, |name| !ctx.problem.fleet.profiles.iter().any(|profile| profile.name == *name))
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1306".to_string(),
+            "vehicle loaded profile is not defined in profiles".to_string(),
+            format!("add profile to fleet profiles or remove it from vehicle types: '{}'", type_ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that vehicle min duration is not negative.
+fn check_e1307_vehicle_min_duration_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| vehicle.costs.min_duration.map_or(false, |min_duration| min_duration < 0.))
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1307".to_string(),
+            "vehicle min duration is invalid".to_string(),
+            format!("use non-negative min duration for vehicle types: '{}'", type_ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that vehicle min work before break is not negative.
+fn check_e1308_vehicle_min_work_before_break_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle
+                .limits
+                .as_ref()
+                .and_then(|limits| limits.min_work_before_break)
+                .map_or(false, |min_work| min_work < 0.)
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1308".to_string(),
+            "vehicle min work before break is invalid".to_string(),
+            format!("use non-negative min work before break for vehicle types: '{}'", type_ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that vehicle max jobs is positive and not less than min jobs.
+fn check_e1309_vehicle_job_limits_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.limits.as_ref().map_or(false, |limits| match (limits.min_jobs, limits.max_jobs) {
+                (_, Some(0)) => true,
+                (Some(min_jobs), Some(max_jobs)) => min_jobs > max_jobs,
+                _ => false,
+            })
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1309".to_string(),
+            "vehicle job limits are invalid".to_string(),
+            format!(
+                "use positive max jobs which is not less than min jobs for vehicle types: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle per stop cost is not negative.
+fn check_e1310_vehicle_per_stop_cost_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| vehicle.costs.per_stop.map_or(false, |per_stop| per_stop < 0.))
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1310".to_string(),
+            "vehicle per stop cost is invalid".to_string(),
+            format!("use non-negative per stop cost for vehicle types: '{}'", type_ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that vehicle min break spacing is not negative.
+fn check_e1311_vehicle_min_break_spacing_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle
+                .limits
+                .as_ref()
+                .and_then(|limits| limits.min_break_spacing)
+                .map_or(false, |min_spacing| min_spacing < 0.)
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1311".to_string(),
+            "vehicle min break spacing is invalid".to_string(),
+            format!("use non-negative min break spacing for vehicle types: '{}'", type_ids.join(", ")),
+        ))
+    }
+}
+
 fn get_invalid_type_ids(
     ctx: &ValidationContext,
     check_shift: Box<dyn Fn(&VehicleShift, Option<TimeWindow>) -> bool>,
@@ -208,5 +348,11 @@ pub fn validate_vehicles(ctx: &ValidationContext) -> Result<(), Vec<FormatError>
         check_e1303_vehicle_breaks_time_is_correct(ctx),
         check_e1304_vehicle_reload_time_is_correct(ctx),
         check_e1305_vehicle_limit_area_is_correct(ctx),
+        check_e1306_vehicle_loaded_profile_exists(ctx),
+        check_e1307_vehicle_min_duration_is_correct(ctx),
+        check_e1308_vehicle_min_work_before_break_is_correct(ctx),
+        check_e1309_vehicle_job_limits_are_correct(ctx),
+        check_e1310_vehicle_per_stop_cost_is_correct(ctx),
+        check_e1311_vehicle_min_break_spacing_is_correct(ctx),
     ])
 }
@@ -9,15 +9,19 @@ fn can_assign_break_between_jobs() {
         plan: Plan {
             jobs: vec![create_delivery_job("job1", vec![5., 0.]), create_delivery_job("job2", vec![10., 0.])],
             relations: Option::None,
+            hints: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 costs: create_default_vehicle_costs(),
                 shifts: vec![VehicleShift {
+                    max_overtime: None,
+                    floating_start: None,
                     breaks: Some(vec![VehicleBreak {
                         time: VehicleBreakTime::TimeWindow(vec![format_time(5.), format_time(10.)]),
                         duration: 2.0,
                         locations: Some(vec![vec![6., 0.].to_loc()]),
+                        mandatory: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
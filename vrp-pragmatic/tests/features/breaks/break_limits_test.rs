@@ -0,0 +1,92 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+use crate::{format_time, parse_time};
+
+fn create_limits(max_breaks: Option<usize>, min_break_spacing: Option<f64>) -> VehicleLimits {
+    VehicleLimits {
+        max_distance: None,
+        shift_time: None,
+        allowed_areas: None,
+        max_pickups: None,
+        min_work_before_break: None,
+        max_breaks,
+        min_break_spacing,
+        min_jobs: None,
+        max_jobs: None,
+    }
+}
+
+fn create_break(start: f64, end: f64) -> VehicleBreak {
+    VehicleBreak {
+        time: VehicleBreakTime::TimeWindow(vec![format_time(start), format_time(end)]),
+        duration: 2.0,
+        locations: None,
+        mandatory: None,
+    }
+}
+
+fn create_problem(jobs: Vec<Job>, breaks: Vec<VehicleBreak>, limits: VehicleLimits) -> Problem {
+    Problem {
+        plan: Plan { jobs, relations: None, hints: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift { breaks: Some(breaks), ..create_default_vehicle_shift() }],
+                limits: Some(limits),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_break_starts(solution: &Solution) -> Vec<f64> {
+    solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities.iter().map(move |activity| (stop, activity)))
+        .filter(|(_, activity)| activity.activity_type == "break")
+        .map(|(stop, activity)| {
+            activity.time.as_ref().map_or_else(|| parse_time(&stop.time.arrival), |time| parse_time(&time.start))
+        })
+        .collect()
+}
+
+#[test]
+fn can_limit_max_amount_of_breaks() {
+    let problem = create_problem(
+        vec![create_delivery_job("job1", vec![5., 0.]), create_delivery_job("job2", vec![99., 0.])],
+        vec![create_break(5., 10.), create_break(100., 120.)],
+        create_limits(Some(1), None),
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_break_starts(&solution).len(), 1);
+    assert!(solution.unassigned.iter().all(|job| job.job_id != "job1" && job.job_id != "job2"));
+}
+
+#[test]
+fn can_keep_min_spacing_between_breaks() {
+    let problem = create_problem(
+        vec![
+            create_delivery_job("job1", vec![5., 0.]),
+            create_delivery_job("job2", vec![99., 0.]),
+            create_delivery_job("job3", vec![150., 0.]),
+        ],
+        vec![create_break(5., 30.), create_break(100., 200.)],
+        create_limits(None, Some(120.)),
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.tours.len(), 1);
+    let break_starts = get_break_starts(&solution);
+    assert_eq!(break_starts.len(), 2);
+    assert!((break_starts[1] - break_starts[0]).abs() >= 120.);
+}
@@ -9,16 +9,20 @@ fn can_assign_break_using_second_location() {
         plan: Plan {
             jobs: vec![create_delivery_job("job1", vec![10., 0.]), create_delivery_job("job2", vec![20., 0.])],
             relations: Option::None,
+            hints: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 costs: create_default_vehicle_costs(),
                 shifts: vec![VehicleShift {
                     end: Some(VehiclePlace { time: format_time(1000.).to_string(), location: vec![30., 0.].to_loc() }),
+                    max_overtime: None,
+                    floating_start: None,
                     breaks: Some(vec![VehicleBreak {
                         time: VehicleBreakTime::TimeWindow(vec![format_time(10.), format_time(30.)]),
                         duration: 2.0,
                         locations: Some(vec![vec![1., 0.].to_loc(), vec![11., 0.].to_loc()]),
+                        mandatory: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
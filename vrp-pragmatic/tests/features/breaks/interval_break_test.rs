@@ -9,14 +9,18 @@ fn can_assign_interval_break_between_jobs() {
         plan: Plan {
             jobs: vec![create_delivery_job("job1", vec![5., 0.]), create_delivery_job("job2", vec![15., 0.])],
             relations: Option::None,
+            hints: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
+                    max_overtime: None,
+                    floating_start: None,
                     breaks: Some(vec![VehicleBreak {
                         time: VehicleBreakTime::TimeOffset(vec![5., 10.]),
                         duration: 2.0,
                         locations: None,
+                        mandatory: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -69,7 +73,9 @@ fn can_assign_interval_break_between_jobs() {
                                     start: "1970-01-01T00:00:05Z".to_string(),
                                     end: "1970-01-01T00:00:06Z".to_string(),
                                 }),
+                                load: Some(vec![1]),
                                 job_tag: None,
+                                metadata: None,
                             },
                             Activity {
                                 job_id: "break".to_string(),
@@ -79,7 +85,9 @@ fn can_assign_interval_break_between_jobs() {
                                     start: "1970-01-01T00:00:06Z".to_string(),
                                     end: "1970-01-01T00:00:08Z".to_string(),
                                 }),
+                                load: Some(vec![1]),
                                 job_tag: None,
+                                metadata: None,
                             }
                         ],
                     },
@@ -124,16 +132,20 @@ fn can_assign_interval_break_with_reload() {
                 create_delivery_job("job4", vec![25., 0.]),
             ],
             relations: Option::None,
+            hints: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
                     start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc() },
                     end: Some(VehiclePlace { time: format_time(1000.).to_string(), location: vec![30., 0.].to_loc() }),
+                    max_overtime: None,
+                    floating_start: None,
                     breaks: Some(vec![VehicleBreak {
                         time: VehicleBreakTime::TimeOffset(vec![8., 12.]),
                         duration: 2.0,
                         locations: None,
+                        mandatory: None,
                     }]),
                     reloads: Some(vec![VehicleReload {
                         times: Some(vec![vec![format_time(0.), format_time(1000.)]]),
@@ -192,7 +204,9 @@ fn can_assign_interval_break_with_reload() {
                                     start: "1970-01-01T00:00:10Z".to_string(),
                                     end: "1970-01-01T00:00:11Z".to_string(),
                                 }),
+                                load: Some(vec![1]),
                                 job_tag: None,
+                                metadata: None,
                             },
                             Activity {
                                 job_id: "break".to_string(),
@@ -202,7 +216,9 @@ fn can_assign_interval_break_with_reload() {
                                     start: "1970-01-01T00:00:11Z".to_string(),
                                     end: "1970-01-01T00:00:13Z".to_string(),
                                 }),
+                                load: Some(vec![1]),
                                 job_tag: None,
+                                metadata: None,
                             }
                         ],
                     },
@@ -0,0 +1,58 @@
+use crate::checker::CheckerContext;
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_problem_with_mandatory_break(shift_end: f64) -> Problem {
+    Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![5., 0.])], relations: Option::None, hints: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc() },
+                    end: Some(VehiclePlace { time: format_time(shift_end), location: vec![0., 0.].to_loc() }),
+                    max_overtime: None,
+                    floating_start: None,
+                    breaks: Some(vec![VehicleBreak {
+                        time: VehicleBreakTime::TimeWindow(vec![format_time(5.), format_time(8.)]),
+                        duration: 5.0,
+                        locations: None,
+                        mandatory: Some(true),
+                    }]),
+                    reloads: None,
+                }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_assign_mandatory_break() {
+    let problem = create_problem_with_mandatory_break(100.);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.statistic.times.break_time, 5);
+}
+
+#[test]
+fn can_flag_solution_without_mandatory_break() {
+    let problem = create_problem_with_mandatory_break(12.);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution =
+        solve_with_metaheuristic_and_iterations_without_check(problem.clone(), Some(vec![matrix.clone()]), 100);
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.unassigned.len(), 1);
+    assert_eq!(solution.unassigned.first().unwrap().job_id, "my_vehicle_1_break");
+    assert_eq!(
+        CheckerContext::new(problem, Some(vec![matrix]), solution).check(),
+        Err("Mandatory break is not assigned for tour 'my_vehicle_1', expected: '1', got '0'".to_string())
+    );
+}
@@ -0,0 +1,63 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+use crate::{format_time, parse_time};
+
+#[test]
+fn can_schedule_break_only_after_min_work_time() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job("job2", vec![2., 0.]),
+                create_delivery_job("job3", vec![3., 0.]),
+                create_delivery_job("job4", vec![4., 0.]),
+                create_delivery_job("job5", vec![5., 0.]),
+            ],
+            relations: None,
+            hints: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    breaks: Some(vec![VehicleBreak {
+                        time: VehicleBreakTime::TimeWindow(vec![format_time(0.), format_time(10.)]),
+                        duration: 2.0,
+                        locations: Some(vec![vec![0., 0.].to_loc()]),
+                        mandatory: None,
+                    }]),
+                    ..create_default_open_vehicle_shift()
+                }],
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    allowed_areas: None,
+                    max_pickups: None,
+                    min_work_before_break: Some(6.),
+                    max_breaks: None,
+                    min_break_spacing: None,
+                    min_jobs: None,
+                    max_jobs: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.tours.len(), 1);
+    let break_start = solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities.iter().map(move |activity| (stop, activity)))
+        .find(|(_, activity)| activity.activity_type == "break")
+        .map(|(stop, activity)| {
+            activity.time.as_ref().map_or_else(|| parse_time(&stop.time.arrival), |time| parse_time(&time.start))
+        })
+        .expect("break should be assigned");
+    assert!(break_start >= 6.);
+}
@@ -1,6 +1,9 @@
 mod basic_break_test;
+mod break_limits_test;
 mod break_with_multiple_locations;
 mod interval_break_test;
+mod mandatory_break_test;
+mod min_work_break_test;
 mod multi_break_test;
 mod relation_break_test;
 mod skip_break_test;
@@ -9,20 +9,25 @@ fn can_use_two_breaks() {
         plan: Plan {
             jobs: vec![create_delivery_job("job1", vec![5., 0.]), create_delivery_job("job2", vec![99., 0.])],
             relations: Option::None,
+            hints: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
+                    max_overtime: None,
+                    floating_start: None,
                     breaks: Some(vec![
                         VehicleBreak {
                             time: VehicleBreakTime::TimeWindow(vec![format_time(5.), format_time(10.)]),
                             duration: 2.0,
                             locations: Some(vec![vec![6., 0.].to_loc()]),
+                            mandatory: None,
                         },
                         VehicleBreak {
                             time: VehicleBreakTime::TimeWindow(vec![format_time(100.), format_time(120.)]),
                             duration: 2.0,
                             locations: None,
+                            mandatory: None,
                         },
                     ]),
                     ..create_default_vehicle_shift()
@@ -92,7 +97,9 @@ fn can_use_two_breaks() {
                                     start: "1970-01-01T00:01:42Z".to_string(),
                                     end: "1970-01-01T00:01:43Z".to_string(),
                                 }),
+                                load: Some(vec![0]),
                                 job_tag: None,
+                                metadata: None,
                             },
                             Activity {
                                 job_id: "break".to_string(),
@@ -102,7 +109,9 @@ fn can_use_two_breaks() {
                                     start: "1970-01-01T00:01:43Z".to_string(),
                                     end: "1970-01-01T00:01:45Z".to_string(),
                                 }),
+                                load: Some(vec![0]),
                                 job_tag: None,
+                                metadata: None,
                             }
                         ],
                     },
@@ -12,15 +12,20 @@ fn get_solution(relation_type: RelationType, jobs: Vec<String>) -> Solution {
                 jobs,
                 vehicle_id: "my_vehicle_1".to_string(),
                 shift_index: None,
+                tags: None,
             }]),
+            hints: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
+                    max_overtime: None,
+                    floating_start: None,
                     breaks: Some(vec![VehicleBreak {
                         time: VehicleBreakTime::TimeWindow(vec![format_time(0.), format_time(1000.)]),
                         duration: 2.0,
                         locations: Some(vec![vec![3., 0.].to_loc()]),
+                        mandatory: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
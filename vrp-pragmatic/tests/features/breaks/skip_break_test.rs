@@ -9,6 +9,7 @@ fn can_skip_break_when_vehicle_not_used() {
         plan: Plan {
             jobs: vec![create_delivery_job("job1", vec![5., 0.]), create_delivery_job("job2", vec![10., 0.])],
             relations: Option::None,
+            hints: None,
         },
         fleet: Fleet {
             vehicles: vec![
@@ -19,10 +20,13 @@ fn can_skip_break_when_vehicle_not_used() {
                             time: format_time(1000.).to_string(),
                             location: vec![100., 0.].to_loc(),
                         }),
+                        max_overtime: None,
+                        floating_start: None,
                         breaks: Some(vec![VehicleBreak {
                             time: VehicleBreakTime::TimeWindow(vec![format_time(5.), format_time(8.)]),
                             duration: 2.0,
                             locations: Some(vec![vec![6., 0.].to_loc()]),
+                            mandatory: None,
                         }]),
                         reloads: None,
                     }],
@@ -104,14 +108,18 @@ fn can_skip_break_when_jobs_completed() {
         plan: Plan {
             jobs: vec![create_delivery_job_with_duration("job1", vec![1., 0.], 10.)],
             relations: Option::None,
+            hints: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
+                    max_overtime: None,
+                    floating_start: None,
                     breaks: Some(vec![VehicleBreak {
                         time: VehicleBreakTime::TimeWindow(vec![format_time(5.), format_time(8.)]),
                         duration: 2.0,
                         locations: Some(vec![vec![6., 0.].to_loc()]),
+                        mandatory: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -177,6 +185,7 @@ fn can_skip_break_when_jobs_completed() {
                     code: 2,
                     description: "cannot be visited within time window".to_string(),
                 }],
+                suggestion: None,
             }],
             extras: None,
         }
@@ -189,20 +198,25 @@ fn can_skip_second_break_when_jobs_completed() {
         plan: Plan {
             jobs: vec![create_delivery_job("job1", vec![5., 0.]), create_delivery_job("job2", vec![10., 0.])],
             relations: Option::None,
+            hints: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
+                    max_overtime: None,
+                    floating_start: None,
                     breaks: Some(vec![
                         VehicleBreak {
                             time: VehicleBreakTime::TimeWindow(vec![format_time(5.), format_time(10.)]),
                             duration: 2.0,
                             locations: Some(vec![vec![6., 0.].to_loc()]),
+                            mandatory: None,
                         },
                         VehicleBreak {
                             time: VehicleBreakTime::TimeWindow(vec![format_time(100.), format_time(120.)]),
                             duration: 2.0,
                             locations: None,
+                            mandatory: None,
                         },
                     ]),
                     ..create_default_vehicle_shift()
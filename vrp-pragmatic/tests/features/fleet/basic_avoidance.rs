@@ -0,0 +1,64 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_test_problem(profile: &str) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![1., 0.]), create_delivery_job("job2", vec![2., 0.])],
+            relations: None,
+            hints: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { profile: profile.to_string(), ..create_default_vehicle("my_vehicle") }],
+            profiles: vec![
+                Profile { name: "van".to_string(), profile_type: "car".to_string(), speed: None, road_classes: None },
+                Profile {
+                    name: "truck".to_string(),
+                    profile_type: "truck".to_string(),
+                    speed: None,
+                    road_classes: None,
+                },
+            ],
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn create_test_matrix(profile: &str, avoidance: Option<MatrixAvoidance>) -> Matrix {
+    // NOTE job1 -> job2 is cheap, job2 -> job1 is expensive
+    let data = vec![0, 1, 1, 5, 0, 1, 1, 1, 0];
+
+    Matrix {
+        profile: profile.to_string(),
+        timestamp: None,
+        travel_times: data.clone(),
+        distances: data,
+        error_codes: None,
+        fallback: None,
+        avoidance,
+    }
+}
+
+fn get_job_order(profile: &str) -> Vec<String> {
+    let problem = create_test_problem(profile);
+    let matrices = vec![
+        create_test_matrix("van", None),
+        create_test_matrix("truck", Some(MatrixAvoidance { multiplier: 10., flags: vec![0, 1, 0, 0, 0, 0, 0, 0, 0] })),
+    ];
+
+    let solution = solve_with_metaheuristic(problem, Some(matrices));
+
+    assert!(solution.unassigned.is_empty());
+    solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities.iter())
+        .map(|activity| activity.job_id.clone())
+        .collect()
+}
+
+#[test]
+fn can_avoid_multiplied_leg_only_for_specific_profile() {
+    assert_eq!(get_job_order("van"), vec!["departure", "job1", "job2", "arrival"]);
+    assert_eq!(get_job_order("truck"), vec!["departure", "job2", "job1", "arrival"]);
+}
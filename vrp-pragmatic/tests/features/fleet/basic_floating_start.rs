@@ -0,0 +1,68 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+#[test]
+fn can_start_at_first_job_without_travel_cost() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_times("job1", vec![10., 0.], vec![(20, 30)], 1.)],
+            relations: Option::None,
+            hints: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift { floating_start: Some(true), ..create_default_open_vehicle_shift() }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution,
+        Solution {
+            statistic: Statistic {
+                cost: 11.,
+                distance: 0,
+                duration: 1,
+                times: Timing { driving: 0, serving: 1, waiting: 0, break_time: 0 },
+            },
+            tours: vec![Tour {
+                vehicle_id: "my_vehicle_1".to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                stops: vec![
+                    create_stop_with_activity(
+                        "departure",
+                        "departure",
+                        (10., 0.),
+                        1,
+                        ("1970-01-01T00:00:00Z", "1970-01-01T00:00:20Z"),
+                        0
+                    ),
+                    create_stop_with_activity(
+                        "job1",
+                        "delivery",
+                        (10., 0.),
+                        0,
+                        ("1970-01-01T00:00:20Z", "1970-01-01T00:00:21Z"),
+                        0
+                    ),
+                ],
+                statistic: Statistic {
+                    cost: 11.,
+                    distance: 0,
+                    duration: 1,
+                    times: Timing { driving: 0, serving: 1, waiting: 0, break_time: 0 },
+                },
+            }],
+            unassigned: vec![],
+            extras: None,
+        }
+    );
+}
@@ -0,0 +1,82 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+#[test]
+fn can_use_loaded_profile_for_legs_with_load() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![10., 0.])], relations: Option::None, hints: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { loaded_profile: Some("truck".to_string()), ..create_default_vehicle_type() }],
+            profiles: vec![
+                Profile { name: "car".to_string(), profile_type: "car".to_string(), speed: None, road_classes: None },
+                Profile {
+                    name: "truck".to_string(),
+                    profile_type: "truck".to_string(),
+                    speed: None,
+                    road_classes: None,
+                },
+            ],
+        },
+        ..create_empty_problem()
+    };
+    let empty_matrix = create_matrix_from_problem(&problem);
+    let loaded_matrix = Matrix {
+        profile: "truck".to_string(),
+        travel_times: empty_matrix.travel_times.iter().map(|duration| duration * 2).collect(),
+        ..empty_matrix.clone()
+    };
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![empty_matrix, loaded_matrix]));
+
+    assert_eq!(
+        solution,
+        Solution {
+            statistic: Statistic {
+                cost: 61.,
+                distance: 20,
+                duration: 31,
+                times: Timing { driving: 30, serving: 1, waiting: 0, break_time: 0 },
+            },
+            tours: vec![Tour {
+                vehicle_id: "my_vehicle_1".to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                stops: vec![
+                    create_stop_with_activity(
+                        "departure",
+                        "departure",
+                        (0., 0.),
+                        1,
+                        ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                        0
+                    ),
+                    create_stop_with_activity(
+                        "job1",
+                        "delivery",
+                        (10., 0.),
+                        0,
+                        ("1970-01-01T00:00:20Z", "1970-01-01T00:00:21Z"),
+                        10
+                    ),
+                    create_stop_with_activity(
+                        "arrival",
+                        "arrival",
+                        (0., 0.),
+                        0,
+                        ("1970-01-01T00:00:31Z", "1970-01-01T00:00:31Z"),
+                        20
+                    )
+                ],
+                statistic: Statistic {
+                    cost: 61.,
+                    distance: 20,
+                    duration: 31,
+                    times: Timing { driving: 30, serving: 1, waiting: 0, break_time: 0 },
+                },
+            }],
+            unassigned: vec![],
+            extras: None,
+        }
+    );
+}
@@ -0,0 +1,66 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_vehicle_with_min_duration(id: &str, fixed: f64, min_duration: Option<f64>) -> VehicleType {
+    VehicleType {
+        type_id: id.to_string(),
+        vehicle_ids: vec![format!("{}_1", id)],
+        costs: VehicleCosts { fixed: Some(fixed), min_duration, ..create_default_vehicle_costs() },
+        shifts: vec![VehicleShift {
+            end: Some(VehiclePlace { time: format_time(10000.), location: vec![0., 0.].to_loc() }),
+            ..create_default_vehicle_shift()
+        }],
+        ..create_default_vehicle_type()
+    }
+}
+
+#[test]
+fn can_charge_paid_min_duration_of_short_tour() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_duration("job1", vec![900., 0.], 0.)],
+            relations: None,
+            hints: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_min_duration("my_vehicle", 10., Some(7200.))],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.statistic.duration, 1800);
+    // fixed + distance + 30 minutes of driving + 90 minutes charged up to the paid minimum
+    assert_eq!(solution.statistic.cost, 10. + 1800. + 1800. + 5400.);
+}
+
+#[test]
+fn can_prefer_vehicle_without_paid_min_duration() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_duration("job1", vec![900., 0.], 0.)],
+            relations: None,
+            hints: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                create_vehicle_with_min_duration("paid_min", 10., Some(7200.)),
+                create_vehicle_with_min_duration("expensive", 1000., None),
+            ],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours.first().unwrap().type_id, "expensive");
+    assert_eq!(solution.statistic.cost, 1000. + 1800. + 1800.);
+}
@@ -0,0 +1,78 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+#[test]
+fn can_use_overtime_allowance_with_extra_cost() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![15., 0.])], relations: Option::None, hints: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: VehicleCosts { overtime: Some(2.), ..create_default_vehicle_costs() },
+                shifts: vec![VehicleShift {
+                    end: Some(VehiclePlace { time: format_time(11.), location: vec![0., 0.].to_loc() }),
+                    max_overtime: Some(30.),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution,
+        Solution {
+            statistic: Statistic {
+                cost: 111.,
+                distance: 30,
+                duration: 31,
+                times: Timing { driving: 30, serving: 1, waiting: 0, break_time: 0 },
+            },
+            tours: vec![Tour {
+                vehicle_id: "my_vehicle_1".to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                stops: vec![
+                    create_stop_with_activity(
+                        "departure",
+                        "departure",
+                        (0., 0.),
+                        1,
+                        ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                        0
+                    ),
+                    create_stop_with_activity(
+                        "job1",
+                        "delivery",
+                        (15., 0.),
+                        0,
+                        ("1970-01-01T00:00:15Z", "1970-01-01T00:00:16Z"),
+                        15
+                    ),
+                    create_stop_with_activity(
+                        "arrival",
+                        "arrival",
+                        (0., 0.),
+                        0,
+                        ("1970-01-01T00:00:31Z", "1970-01-01T00:00:31Z"),
+                        30
+                    )
+                ],
+                statistic: Statistic {
+                    cost: 111.,
+                    distance: 30,
+                    duration: 31,
+                    times: Timing { driving: 30, serving: 1, waiting: 0, break_time: 0 },
+                },
+            }],
+            unassigned: vec![],
+            extras: None,
+        }
+    );
+}
@@ -0,0 +1,52 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_vehicle_with_stop_cost(per_stop: Option<f64>) -> VehicleType {
+    VehicleType { costs: VehicleCosts { per_stop, ..create_default_vehicle_costs() }, ..create_default_vehicle_type() }
+}
+
+fn create_test_problem(per_stop: Option<f64>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_duration("job1", vec![5., 0.], 0.),
+                create_delivery_job_with_duration("job2", vec![10., 0.], 0.),
+                create_delivery_job_with_duration("job3", vec![5., 0.], 0.),
+            ],
+            relations: None,
+            hints: None,
+        },
+        fleet: Fleet { vehicles: vec![create_vehicle_with_stop_cost(per_stop)], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_charge_per_stop_cost() {
+    let problem = create_test_problem(Some(100.));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.statistic.distance, 20);
+    // fixed + distance + time + two stops: jobs at the same location are served in one stop
+    assert_eq!(solution.statistic.cost, 10. + 20. + 20. + 200.);
+    assert_eq!(solution.tours[0].stops.len(), 4);
+}
+
+#[test]
+fn can_prefer_consolidated_stops_of_equal_distance() {
+    let problem = create_test_problem(Some(100.));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let job_stops = solution.tours[0]
+        .stops
+        .iter()
+        .filter(|stop| stop.activities.iter().any(|activity| activity.activity_type == "delivery"))
+        .collect::<Vec<_>>();
+    assert_eq!(job_stops.len(), 2);
+    assert!(job_stops.iter().any(|stop| stop.location == vec![5., 0.].to_loc() && stop.activities.len() == 2));
+}
@@ -0,0 +1,34 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+#[test]
+fn can_charge_u_turn_penalty_in_tour_cost() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", vec![10., 0.], vec![(0, 20)], 0.),
+                create_delivery_job_with_times("job2", vec![1., 0.], vec![(19, 100)], 0.),
+            ],
+            relations: None,
+            hints: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                u_turn: Some(VehicleUTurn { min_progress: 5., penalty: 100. }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.statistic.distance, 20);
+    assert_eq!(solution.statistic.duration, 20);
+    // fixed + distance + time + u-turn made when going back to job2 right after job1
+    assert_eq!(solution.statistic.cost, 10. + 20. + 20. + 100.);
+    assert_eq!(solution.tours[0].statistic.cost, solution.statistic.cost);
+}
@@ -0,0 +1,48 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_avoid_costly_vehicle_unless_necessary, (last_job_times, expected_tours), {
+    can_avoid_costly_vehicle_unless_necessary_impl(last_job_times, expected_tours);
+}}
+
+can_avoid_costly_vehicle_unless_necessary! {
+    case01_not_necessary: ((50, 100), 3),
+    case02_necessary: ((1, 2), 4),
+}
+
+fn can_avoid_costly_vehicle_unless_necessary_impl(last_job_times: (i32, i32), expected_tours: usize) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", vec![1., 0.], vec![(1, 2)], 1.),
+                create_delivery_job_with_times("job2", vec![-1., 0.], vec![(1, 2)], 1.),
+                create_delivery_job_with_times("job3", vec![0., 1.], vec![(1, 2)], 1.),
+                create_delivery_job_with_times("job4", vec![0., -1.], vec![last_job_times], 1.),
+            ],
+            relations: None,
+            hints: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: (1..=4).map(|idx| format!("my_vehicle_{}", idx)).collect(),
+                costs: VehicleCosts { fixed: None, ..create_default_vehicle_costs() },
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        objectives: Some(Objectives {
+            primary: vec![MinimizeUnassignedJobs],
+            secondary: Some(vec![MinimizeFleetCost { costs: vec![1., 1., 1., 1000.] }]),
+            weights: None,
+            normalizations: None,
+        }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.tours.len(), expected_tours);
+}
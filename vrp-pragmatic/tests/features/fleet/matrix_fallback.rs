@@ -0,0 +1,42 @@
+use crate::format::problem::*;
+use crate::format::Location;
+use crate::helpers::*;
+use crate::utils::get_haversine_distance;
+
+fn get_approximated_distance() -> i32 {
+    get_haversine_distance(&Location::new(0., 0.), &Location::new(0.001, 0.)).round() as i32
+}
+
+parameterized_test! {can_use_matrix_fallback_for_errored_pairs, (fallback, expected), {
+    can_use_matrix_fallback_for_errored_pairs_impl(fallback, expected);
+}}
+
+can_use_matrix_fallback_for_errored_pairs! {
+    case01: (MatrixFallback::Penalty { distance: 100., duration: 50. }, (200, 100)),
+    case02: (MatrixFallback::Approximation, (get_approximated_distance() * 2,
+                                             ((get_approximated_distance() as f64) / 10.).round() as i32 * 2)),
+}
+
+fn can_use_matrix_fallback_for_errored_pairs_impl(fallback: MatrixFallback, expected: (i32, i32)) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![0.001, 0.])], relations: None, hints: None },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = Matrix {
+        profile: "car".to_owned(),
+        timestamp: None,
+        travel_times: vec![0, 1, 1, 0],
+        distances: vec![0, 1, 1, 0],
+        error_codes: Some(vec![0, 1, 1, 0]),
+        fallback: Some(fallback),
+        avoidance: None,
+    };
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.statistic.distance, expected.0);
+    assert_eq!(solution.statistic.times.driving, expected.1);
+}
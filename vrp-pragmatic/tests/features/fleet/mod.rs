@@ -1,4 +1,15 @@
+mod basic_avoidance;
+mod basic_floating_start;
+mod basic_loaded_profile;
+mod basic_min_duration;
 mod basic_multi_shift;
 mod basic_open_end;
+mod basic_overtime;
+mod basic_stop_cost;
+mod basic_u_turn;
+mod fleet_cost_curve;
+mod matrix_fallback;
 mod multi_dimens;
+mod must_use_vehicles;
 mod unreachable_jobs;
+mod weighted_objectives;
@@ -0,0 +1,50 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_problem(must_use: Option<bool>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job("job2", vec![2., 0.]),
+                create_delivery_job("job3", vec![3., 0.]),
+                create_delivery_job("job4", vec![4., 0.]),
+            ],
+            relations: None,
+            hints: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                must_use,
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_use_all_must_use_vehicles() {
+    let problem = create_problem(Some(true));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_empty());
+    let mut vehicle_ids = solution.tours.iter().map(|tour| tour.vehicle_id.clone()).collect::<Vec<_>>();
+    vehicle_ids.sort();
+    assert_eq!(vehicle_ids, vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()]);
+}
+
+#[test]
+fn can_use_one_vehicle_without_must_use() {
+    let problem = create_problem(None);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.tours.len(), 1);
+}
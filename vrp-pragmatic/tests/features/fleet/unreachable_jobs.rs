@@ -5,7 +5,7 @@ use crate::helpers::*;
 #[test]
 fn can_use_vehicle_with_open_end() {
     let problem = Problem {
-        plan: Plan { jobs: vec![create_delivery_job("job1", vec![1., 0.])], relations: Option::None },
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![1., 0.])], relations: Option::None, hints: None },
         fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
         ..create_empty_problem()
     };
@@ -15,6 +15,8 @@ fn can_use_vehicle_with_open_end() {
         travel_times: vec![0, 1, 1, 0],
         distances: vec![0, 1, 1, 0],
         error_codes: Some(vec![0, 1, 1, 1]),
+        fallback: None,
+        avoidance: None,
     };
 
     let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
@@ -31,7 +33,8 @@ fn can_use_vehicle_with_open_end() {
             tours: vec![],
             unassigned: vec![UnassignedJob {
                 job_id: "job1".to_string(),
-                reasons: vec![UnassignedJobReason { code: 100, description: "location unreachable".to_string() }]
+                reasons: vec![UnassignedJobReason { code: 100, description: "location unreachable".to_string() }],
+                suggestion: None
             }],
             extras: None,
         }
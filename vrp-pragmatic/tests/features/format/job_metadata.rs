@@ -0,0 +1,41 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+use std::io::BufWriter;
+
+#[test]
+fn can_pass_job_metadata_to_solution() {
+    let metadata = vec![("group".to_string(), "A".to_string())].into_iter().collect();
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { metadata: Some(metadata), ..create_delivery_job("job1", vec![1., 0.]) },
+                create_delivery_job("job2", vec![2., 0.]),
+            ],
+            relations: Option::None,
+            hints: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let get_stop = |job_id: &str| {
+        solution
+            .tours
+            .iter()
+            .flat_map(|tour| tour.stops.iter())
+            .find(|stop| stop.activities.iter().any(|activity| activity.job_id == job_id))
+            .unwrap()
+    };
+    let group = get_stop("job1").activities.first().and_then(|activity| activity.metadata.as_ref());
+    assert_eq!(group.and_then(|metadata| metadata.get("group")).cloned(), Some("A".to_string()));
+    assert!(get_stop("job2").activities.first().unwrap().metadata.is_none());
+
+    let mut buffer = vec![];
+    serialize_solution(BufWriter::new(&mut buffer), &solution).unwrap();
+    let serialized = String::from_utf8(buffer).unwrap();
+    assert!(serialized.contains("\"group\": \"A\""));
+}
@@ -0,0 +1,57 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format::Location;
+use crate::helpers::*;
+use std::io::BufWriter;
+
+fn create_reference_problem() -> Problem {
+    let shift = VehicleShift {
+        start: VehiclePlace { location: Location::new_reference(0), ..create_default_vehicle_shift().start },
+        end: Some(VehiclePlace { location: Location::new_reference(0), ..create_default_vehicle_shift().end.unwrap() }),
+        ..create_default_vehicle_shift()
+    };
+
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_reference("job1", 1),
+                create_delivery_job_with_reference("job2", 2),
+                create_delivery_job_with_reference("job3", 3),
+            ],
+            relations: None,
+            hints: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { shifts: vec![shift], ..create_default_vehicle_type() }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn create_reference_matrix() -> Matrix {
+    create_matrix(vec![0, 1, 2, 3, 1, 0, 1, 2, 2, 1, 0, 1, 3, 2, 1, 0])
+}
+
+#[test]
+fn can_solve_problem_with_location_references_only() {
+    let solution = solve_with_metaheuristic(create_reference_problem(), Some(vec![create_reference_matrix()]));
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.statistic.distance, 6);
+    assert!(solution.tours.iter().flat_map(|tour| tour.stops.iter()).all(|stop| stop.location.to_lat_lng().is_none()));
+
+    let result = serialize_solution_as_geojson(BufWriter::new(vec![]), &solution);
+    assert!(result.err().map_or(false, |err| err.to_string().contains("geo coordinates")));
+}
+
+#[test]
+fn can_reject_location_references_without_matrix() {
+    let result = create_reference_problem().read_pragmatic();
+
+    assert_eq!(
+        result.err().map(|errors| errors.iter().map(|err| err.code.as_str()).collect::<Vec<_>>().join(",")),
+        Some("E1506".to_string())
+    );
+}
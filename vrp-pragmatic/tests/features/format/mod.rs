@@ -0,0 +1,3 @@
+mod job_metadata;
+mod location_reference;
+mod unassigned_suggestion;
@@ -0,0 +1,46 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+#[test]
+fn can_suggest_route_for_job_unassigned_due_to_capacity() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job("job2", vec![2., 0.]),
+                create_delivery_job("job3", vec![10., 0.]),
+            ],
+            relations: None,
+            hints: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift { end: None, ..create_default_vehicle_shift() }],
+                ..create_vehicle_with_capacity("my_vehicle", vec![2])
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic_and_suggestions(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        solution.unassigned,
+        vec![UnassignedJob {
+            job_id: "job3".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: 3,
+                description: "does not fit into any vehicle due to capacity".to_string()
+            }],
+            suggestion: Some(UnassignedJobSuggestion {
+                vehicle_id: "my_vehicle_1".to_string(),
+                shift_index: 0,
+                position: 2
+            }),
+        }]
+    );
+}
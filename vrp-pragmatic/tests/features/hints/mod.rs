@@ -0,0 +1 @@
+mod vehicle_type_hint;
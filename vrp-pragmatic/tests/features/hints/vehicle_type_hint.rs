@@ -0,0 +1,52 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_vehicle(id: &str, fixed: f64, capacity: Vec<i32>) -> VehicleType {
+    VehicleType {
+        costs: VehicleCosts { fixed: Some(fixed), ..create_default_vehicle_costs() },
+        ..create_vehicle_with_capacity(id, capacity)
+    }
+}
+
+fn create_problem_with_hint(demand: Vec<i32>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_demand("job1", vec![1., 0.], demand)],
+            relations: None,
+            hints: Some(vec![JobHint {
+                job_id: "job1".to_string(),
+                vehicle_type: Some("hinted".to_string()),
+                location: None,
+            }]),
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle("cheap", 10., vec![10]), create_vehicle("hinted", 100., vec![1])],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_assign_job_to_hinted_vehicle_type() {
+    let problem = create_problem_with_hint(vec![1]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours.first().unwrap().type_id, "hinted");
+}
+
+#[test]
+fn can_fallback_when_hinted_vehicle_type_is_not_feasible() {
+    let problem = create_problem_with_hint(vec![2]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours.first().unwrap().type_id, "cheap");
+}
@@ -6,7 +6,7 @@ use crate::helpers::*;
 #[test]
 fn can_limit_by_area() {
     let problem = Problem {
-        plan: Plan { jobs: vec![create_delivery_job("job1", vec![10., 0.])], relations: Option::None },
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![10., 0.])], relations: Option::None, hints: None },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 limits: Some(VehicleLimits {
@@ -18,6 +18,12 @@ fn can_limit_by_area() {
                         Location::new(5., 5.),
                         Location::new(-5., 5.),
                     ]]),
+                    max_pickups: None,
+                    min_work_before_break: None,
+                    max_breaks: None,
+                    min_break_spacing: None,
+                    min_jobs: None,
+                    max_jobs: None,
                 }),
                 ..create_default_vehicle_type()
             }],
@@ -44,7 +50,8 @@ fn can_limit_by_area() {
                 reasons: vec![UnassignedJobReason {
                     code: 106,
                     description: "cannot be assigned due to area constraint".to_string()
-                }]
+                }],
+                suggestion: None
             }],
             extras: None,
         }
@@ -0,0 +1,102 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_vehicle_type_with_job_limits(min_jobs: Option<usize>, max_jobs: Option<usize>) -> VehicleType {
+    VehicleType {
+        limits: Some(VehicleLimits {
+            max_distance: None,
+            shift_time: None,
+            allowed_areas: None,
+            max_pickups: None,
+            min_work_before_break: None,
+            max_breaks: None,
+            min_break_spacing: None,
+            min_jobs,
+            max_jobs,
+        }),
+        ..create_default_vehicle_type()
+    }
+}
+
+#[test]
+fn can_limit_by_max_jobs() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job("job2", vec![2., 0.]),
+                create_delivery_job("job3", vec![3., 0.]),
+            ],
+            relations: Option::None,
+            hints: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_type_with_job_limits(None, Some(2))],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.unassigned.len(), 1);
+    assert_eq!(solution.unassigned[0].reasons[0].code, 108);
+    assert_eq!(
+        solution.unassigned[0].reasons[0].description,
+        "cannot be assigned due to max jobs constraint of vehicle".to_string()
+    );
+}
+
+#[test]
+fn can_assign_jobs_when_min_jobs_is_not_reachable() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job("job2", vec![2., 0.]),
+                create_delivery_job("job3", vec![3., 0.]),
+                create_delivery_job("job4", vec![4., 0.]),
+            ],
+            relations: Option::None,
+            hints: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                capacity: vec![2],
+                ..create_vehicle_type_with_job_limits(Some(3), None)
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.tours.len(), 2);
+}
+
+#[test]
+fn can_charge_missing_min_jobs_in_tour_cost() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![1., 0.])], relations: Option::None, hints: None },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_type_with_job_limits(Some(3), None)],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.tours.len(), 1);
+    // fixed + distance + time + penalty for two missing jobs
+    assert_eq!(solution.statistic.cost, 10. + 2. + 3. + 2000.);
+    assert_eq!(solution.tours[0].statistic.cost, solution.statistic.cost);
+}
@@ -5,10 +5,20 @@ use crate::helpers::*;
 #[test]
 fn can_limit_by_max_distance() {
     let problem = Problem {
-        plan: Plan { jobs: vec![create_delivery_job("job1", vec![100., 0.])], relations: Option::None },
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![100., 0.])], relations: Option::None, hints: None },
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                limits: Some(VehicleLimits { max_distance: Some(99.), shift_time: None, allowed_areas: None }),
+                limits: Some(VehicleLimits {
+                    max_distance: Some(99.),
+                    shift_time: None,
+                    allowed_areas: None,
+                    max_pickups: None,
+                    min_work_before_break: None,
+                    max_breaks: None,
+                    min_break_spacing: None,
+                    min_jobs: None,
+                    max_jobs: None,
+                }),
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
@@ -21,6 +31,8 @@ fn can_limit_by_max_distance() {
         travel_times: vec![1, 1, 1, 1],
         distances: vec![1, 100, 100, 1],
         error_codes: Option::None,
+        fallback: None,
+        avoidance: None,
     };
 
     let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
@@ -40,7 +52,8 @@ fn can_limit_by_max_distance() {
                 reasons: vec![UnassignedJobReason {
                     code: 101,
                     description: "cannot be assigned due to max distance constraint of vehicle".to_string()
-                }]
+                }],
+                suggestion: None
             }],
             extras: None,
         }
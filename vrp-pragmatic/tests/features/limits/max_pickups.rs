@@ -0,0 +1,90 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_vehicle_type_with_max_pickups(max_pickups: usize) -> VehicleType {
+    VehicleType {
+        limits: Some(VehicleLimits {
+            max_distance: None,
+            shift_time: None,
+            allowed_areas: None,
+            max_pickups: Some(max_pickups),
+            min_work_before_break: None,
+            max_breaks: None,
+            min_break_spacing: None,
+            min_jobs: None,
+            max_jobs: None,
+        }),
+        ..create_default_vehicle_type()
+    }
+}
+
+#[test]
+fn can_limit_by_max_pickups() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_pickup_job("job1", vec![1., 0.]),
+                create_pickup_job("job2", vec![2., 0.]),
+                create_pickup_job("job3", vec![3., 0.]),
+            ],
+            relations: Option::None,
+            hints: None,
+        },
+        fleet: Fleet { vehicles: vec![create_vehicle_type_with_max_pickups(2)], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        solution.unassigned,
+        vec![UnassignedJob {
+            job_id: "job3".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: 107,
+                description: "cannot be assigned due to max pickups constraint of vehicle".to_string()
+            }],
+            suggestion: None
+        }]
+    );
+}
+
+#[test]
+fn can_deliver_before_next_pickup_when_max_pickups_reached() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_pickup_delivery_job("job1", vec![1., 0.], vec![4., 0.]),
+                create_pickup_delivery_job("job2", vec![2., 0.], vec![5., 0.]),
+                create_pickup_delivery_job("job3", vec![3., 0.], vec![6., 0.]),
+            ],
+            relations: Option::None,
+            hints: None,
+        },
+        fleet: Fleet { vehicles: vec![create_vehicle_type_with_max_pickups(2)], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.tours.len(), 1);
+    let max_open = solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities.iter())
+        .fold((0, 0), |(open, max), activity| {
+            let open = match activity.activity_type.as_str() {
+                "pickup" => open + 1,
+                "delivery" => open - 1,
+                _ => open,
+            };
+            (open, max.max(open))
+        })
+        .1;
+    assert_eq!(max_open, 2);
+}
@@ -1,3 +1,5 @@
 mod area_allowance;
+mod job_count;
 mod max_distance;
+mod max_pickups;
 mod shift_time;
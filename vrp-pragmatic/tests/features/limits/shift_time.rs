@@ -5,10 +5,20 @@ use crate::helpers::*;
 #[test]
 fn can_limit_one_job_by_shift_time() {
     let problem = Problem {
-        plan: Plan { jobs: vec![create_delivery_job("job1", vec![100., 0.])], relations: Option::None },
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![100., 0.])], relations: Option::None, hints: None },
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                limits: Some(VehicleLimits { max_distance: None, shift_time: Some(99.), allowed_areas: None }),
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: Some(99.),
+                    allowed_areas: None,
+                    max_pickups: None,
+                    min_work_before_break: None,
+                    max_breaks: None,
+                    min_break_spacing: None,
+                    min_jobs: None,
+                    max_jobs: None,
+                }),
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
@@ -21,6 +31,8 @@ fn can_limit_one_job_by_shift_time() {
         travel_times: vec![1, 100, 100, 1],
         distances: vec![1, 1, 1, 1],
         error_codes: Option::None,
+        fallback: None,
+        avoidance: None,
     };
 
     let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
@@ -40,7 +52,8 @@ fn can_limit_one_job_by_shift_time() {
                 reasons: vec![UnassignedJobReason {
                     code: 102,
                     description: "cannot be assigned due to shift time constraint of vehicle".to_string()
-                }]
+                }],
+                suggestion: None
             }],
             extras: None,
         }
@@ -59,10 +72,21 @@ fn can_skip_job_from_multiple_because_of_shift_time() {
                 create_delivery_job_with_duration("job5", vec![5., 0.], 10.),
             ],
             relations: Option::None,
+            hints: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                limits: Some(VehicleLimits { max_distance: None, shift_time: Some(40.), allowed_areas: None }),
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: Some(40.),
+                    allowed_areas: None,
+                    max_pickups: None,
+                    min_work_before_break: None,
+                    max_breaks: None,
+                    min_break_spacing: None,
+                    min_jobs: None,
+                    max_jobs: None,
+                }),
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
@@ -141,14 +165,16 @@ fn can_skip_job_from_multiple_because_of_shift_time() {
                     reasons: vec![UnassignedJobReason {
                         code: 102,
                         description: "cannot be assigned due to shift time constraint of vehicle".to_string()
-                    }]
+                    }],
+                    suggestion: None
                 },
                 UnassignedJob {
                     job_id: "job5".to_string(),
                     reasons: vec![UnassignedJobReason {
                         code: 102,
                         description: "cannot be assigned due to shift time constraint of vehicle".to_string()
-                    }]
+                    }],
+                    suggestion: None
                 }
             ],
             extras: None,
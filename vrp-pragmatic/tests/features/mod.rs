@@ -2,6 +2,8 @@
 
 mod breaks;
 mod fleet;
+mod format;
+mod hints;
 mod limits;
 mod multjob;
 mod pickdev;
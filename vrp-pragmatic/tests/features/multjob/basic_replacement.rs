@@ -13,6 +13,7 @@ fn can_assign_replacement_job() {
                 create_pickup_job("job3", vec![3., 0.]),
             ],
             relations: Option::None,
+            hints: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
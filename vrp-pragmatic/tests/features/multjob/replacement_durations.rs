@@ -0,0 +1,84 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+parameterized_test! {can_use_replacement_durations, (policy, service, cost), {
+    can_use_replacement_durations_impl(policy, service, cost);
+}}
+
+can_use_replacement_durations! {
+    case01: (Some(DurationPolicy::Sum), 80, 94.),
+    case02: (None, 80, 94.),
+    case03: (Some(DurationPolicy::Max), 50, 64.),
+}
+
+fn can_use_replacement_durations_impl(policy: Option<DurationPolicy>, service: i32, cost: f64) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_replacement_job_with_durations(
+                "job1",
+                vec![1., 0.],
+                JobPlaceDurations { delivery: 50., pickup: 30., policy },
+            )],
+            relations: None,
+            hints: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution,
+        Solution {
+            statistic: Statistic {
+                cost,
+                distance: 2,
+                duration: service + 2,
+                times: Timing { driving: 2, serving: service, waiting: 0, break_time: 0 },
+            },
+            tours: vec![Tour {
+                vehicle_id: "my_vehicle_1".to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                stops: vec![
+                    create_stop_with_activity(
+                        "departure",
+                        "departure",
+                        (0., 0.),
+                        1,
+                        ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                        0
+                    ),
+                    create_stop_with_activity(
+                        "job1",
+                        "replacement",
+                        (1., 0.),
+                        1,
+                        ("1970-01-01T00:00:01Z", format_time(1. + service as f64).as_str()),
+                        1
+                    ),
+                    create_stop_with_activity(
+                        "arrival",
+                        "arrival",
+                        (0., 0.),
+                        0,
+                        (format_time(2. + service as f64).as_str(), format_time(2. + service as f64).as_str()),
+                        2
+                    )
+                ],
+                statistic: Statistic {
+                    cost,
+                    distance: 2,
+                    duration: service + 2,
+                    times: Timing { driving: 2, serving: service, waiting: 0, break_time: 0 },
+                },
+            }],
+            unassigned: vec![],
+            extras: None,
+        }
+    );
+}
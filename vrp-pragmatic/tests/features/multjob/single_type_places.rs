@@ -9,6 +9,7 @@ fn can_use_only_deliveries_as_static_demand() {
         plan: Plan {
             jobs: vec![create_multi_job("job1", vec![], vec![((8., 0.), 2., vec![1]), ((2., 0.), 1., vec![1])])],
             relations: None,
+            hints: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -83,6 +84,7 @@ fn can_use_only_pickups_as_static_demand() {
         plan: Plan {
             jobs: vec![create_multi_job("job1", vec![((8., 0.), 2., vec![1]), ((2., 0.), 1., vec![1])], vec![])],
             relations: None,
+            hints: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
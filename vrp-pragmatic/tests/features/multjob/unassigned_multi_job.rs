@@ -12,6 +12,7 @@ fn can_unassign_multi_job_due_to_capacity() {
                 vec![((6., 0.), 1., vec![3])],
             )],
             relations: Option::None,
+            hints: None,
         },
         fleet: Fleet {
             vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])],
@@ -38,7 +39,8 @@ fn can_unassign_multi_job_due_to_capacity() {
                 reasons: vec![UnassignedJobReason {
                     code: 3,
                     description: "does not fit into any vehicle due to capacity".to_string()
-                }]
+                }],
+                suggestion: None
             }],
             extras: None,
         }
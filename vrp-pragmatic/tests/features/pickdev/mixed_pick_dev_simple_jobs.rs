@@ -12,6 +12,7 @@ fn can_use_one_pickup_delivery_and_two_deliveries_with_one_vehicle() {
                 create_delivery_job("job3", vec![4., 0.]),
             ],
             relations: None,
+            hints: None,
         },
         fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
         ..create_empty_problem()
@@ -15,7 +15,9 @@ fn can_use_two_pickup_delivery_jobs_and_relation_with_one_vehicle() {
                 jobs: to_strings(vec!["job1", "job2", "job1", "job2"]),
                 vehicle_id: "my_vehicle_1".to_string(),
                 shift_index: None,
+                tags: None,
             }]),
+            hints: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
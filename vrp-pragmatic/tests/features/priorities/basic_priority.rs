@@ -12,6 +12,7 @@ fn can_follow_priorities() {
                 create_delivery_job("job3", vec![7., 0.]),
             ],
             relations: Option::None,
+            hints: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -0,0 +1,55 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_problem(is_far_required: bool) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { required: Some(is_far_required), ..create_delivery_job("job1", vec![10., 0.]) },
+                create_delivery_job("job2", vec![1., 0.]),
+            ],
+            relations: Option::None,
+            hints: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![1])],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_served_and_unassigned(solution: &Solution) -> (Vec<String>, Vec<String>) {
+    let served = solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities.iter())
+        .filter(|activity| activity.activity_type == "delivery")
+        .map(|activity| activity.job_id.clone())
+        .collect();
+    let unassigned = solution.unassigned.iter().map(|job| job.job_id.clone()).collect();
+
+    (served, unassigned)
+}
+
+#[test]
+fn can_serve_required_job_instead_of_cheaper_optional() {
+    let problem = create_problem(true);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(get_served_and_unassigned(&solution), (vec!["job1".to_string()], vec!["job2".to_string()]));
+}
+
+#[test]
+fn can_drop_expensive_optional_job() {
+    let problem = create_problem(false);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(get_served_and_unassigned(&solution), (vec!["job2".to_string()], vec!["job1".to_string()]));
+}
@@ -1 +1,4 @@
 mod basic_priority;
+mod basic_required;
+mod probable_jobs;
+mod unassigned_penalty;
@@ -0,0 +1,73 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_problem(capacity: i32) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![10., 0.]),
+                create_delivery_job("job2", vec![9., 0.]),
+                Job { probability: Some(0.5), ..create_delivery_job("job3", vec![1., 0.]) },
+                Job { probability: Some(0.5), ..create_delivery_job("job4", vec![2., 0.]) },
+            ],
+            relations: None,
+            hints: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![capacity])],
+            profiles: create_default_profiles(),
+        },
+        objectives: Some(Objectives {
+            primary: vec![MinimizeUnassignedJobs],
+            secondary: Some(vec![MinimizeExpectedCost { samples: Some(16) }]),
+            weights: None,
+            normalizations: None,
+        }),
+        ..create_empty_problem()
+    }
+}
+
+fn get_served_and_unassigned(solution: &Solution) -> (Vec<String>, Vec<String>) {
+    let mut served = solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities.iter())
+        .filter(|activity| activity.activity_type == "delivery")
+        .map(|activity| activity.job_id.clone())
+        .collect::<Vec<_>>();
+    let mut unassigned = solution.unassigned.iter().map(|job| job.job_id.clone()).collect::<Vec<_>>();
+
+    served.sort();
+    unassigned.sort();
+
+    (served, unassigned)
+}
+
+#[test]
+fn can_prefer_certain_jobs_over_probable_ones() {
+    let problem = create_problem(2);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        get_served_and_unassigned(&solution),
+        (to_strings(vec!["job1", "job2"]), to_strings(vec!["job3", "job4"]))
+    );
+}
+
+#[test]
+fn can_serve_probable_jobs_when_capacity_allows() {
+    let problem = create_problem(4);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        get_served_and_unassigned(&solution),
+        (to_strings(vec!["job1", "job2", "job3", "job4"]), Vec::<String>::new())
+    );
+}
@@ -0,0 +1,39 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+#[test]
+fn can_prefer_dropping_jobs_with_lower_unassigned_penalty() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { unassigned_penalty: Some(3.), ..create_delivery_job_with_demand("job1", vec![10., 0.], vec![2]) },
+                create_delivery_job("job2", vec![1., 0.]),
+                create_delivery_job("job3", vec![2., 0.]),
+            ],
+            relations: None,
+            hints: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let served = solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities.iter())
+        .filter(|activity| activity.activity_type == "delivery")
+        .map(|activity| activity.job_id.clone())
+        .collect::<Vec<_>>();
+    let mut unassigned = solution.unassigned.iter().map(|job| job.job_id.clone()).collect::<Vec<_>>();
+    unassigned.sort();
+
+    assert_eq!(served, to_strings(vec!["job1"]));
+    assert_eq!(unassigned, to_strings(vec!["job2", "job3"]));
+}
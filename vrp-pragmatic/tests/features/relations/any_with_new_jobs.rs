@@ -16,7 +16,9 @@ fn can_use_any_relation_with_new_job_for_one_vehicle_with_open_end() {
                 jobs: to_strings(vec!["job1", "job3"]),
                 vehicle_id: "my_vehicle_1".to_string(),
                 shift_index: None,
+                tags: None,
             }]),
+            hints: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -21,14 +21,17 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
                     jobs: to_strings(vec!["departure", "job4", "job2", "job6"]),
                     vehicle_id: "my_vehicle_1".to_string(),
                     shift_index: None,
+                    tags: None,
                 },
                 Relation {
                     type_field: RelationType::Any,
                     jobs: to_strings(vec!["job1", "job3"]),
                     vehicle_id: "my_vehicle_1".to_string(),
                     shift_index: None,
+                    tags: None,
                 },
             ]),
+            hints: None,
         },
         fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
         ..create_empty_problem()
@@ -1,5 +1,8 @@
 mod any_with_new_jobs;
 mod mixed_strict_any;
 mod mixed_strict_sequence;
+mod same_customer_visit;
+mod sequence_with_new_jobs;
 mod strict_with_new_jobs;
 mod strict_with_old_jobs;
+mod strict_with_tags;
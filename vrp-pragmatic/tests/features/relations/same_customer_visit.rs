@@ -0,0 +1,52 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_problem() -> Problem {
+    let customer = Some("customer1".to_string());
+
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                Job {
+                    customer: customer.clone(),
+                    ..create_delivery_job_with_times("job1", vec![5., 0.], vec![(0, 10)], 1.)
+                },
+                Job { customer, ..create_delivery_job_with_times("job2", vec![5., 0.], vec![(10, 200)], 1.) },
+                create_delivery_job_with_times("job3", vec![6., 0.], vec![(10, 20)], 1.),
+            ],
+            relations: None,
+            hints: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![10])],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_job_ids(solution: &Solution) -> (Vec<String>, Vec<String>) {
+    let served = solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities.iter())
+        .filter(|activity| activity.activity_type == "delivery")
+        .map(|activity| activity.job_id.clone())
+        .collect();
+    let unassigned = solution.unassigned.iter().map(|job| job.job_id.clone()).collect();
+
+    (served, unassigned)
+}
+
+#[test]
+fn can_serve_jobs_of_same_customer_in_one_visit() {
+    // NOTE without customer, it is cheaper to serve job3 between job1 and job2
+    let problem = create_problem();
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(get_job_ids(&solution), (to_strings(vec!["job1", "job2", "job3"]), vec![]));
+}
@@ -0,0 +1,56 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+#[test]
+fn can_keep_sequence_skeleton_order_while_inserting_new_jobs() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job("job2", vec![2., 0.]),
+                create_delivery_job("job3", vec![3., 0.]),
+                create_delivery_job("job4", vec![4., 0.]),
+                create_delivery_job("job5", vec![5., 0.]),
+                create_delivery_job("job6", vec![6., 0.]),
+                create_delivery_job("job7", vec![7., 0.]),
+                create_delivery_job("job8", vec![8., 0.]),
+            ],
+            relations: Some(vec![Relation {
+                type_field: RelationType::Sequence,
+                jobs: to_strings(vec!["job6", "job2", "job8"]),
+                vehicle_id: "my_vehicle_1".to_string(),
+                shift_index: None,
+                tags: None,
+            }]),
+            hints: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string()],
+                capacity: vec![10],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.tours.len(), 1);
+    let job_ids = solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities.iter())
+        .map(|activity| activity.job_id.as_str())
+        .filter(|job_id| *job_id != "departure" && *job_id != "arrival")
+        .collect::<Vec<_>>();
+    assert_eq!(job_ids.len(), 8);
+    let skeleton =
+        job_ids.iter().filter(|job_id| ["job6", "job2", "job8"].contains(job_id)).cloned().collect::<Vec<_>>();
+    assert_eq!(skeleton, vec!["job6", "job2", "job8"]);
+    let position = |id: &str| job_ids.iter().position(|job_id| *job_id == id).unwrap();
+    assert!(position("job8") - position("job6") > 2, "no free jobs are inserted between skeleton jobs");
+}
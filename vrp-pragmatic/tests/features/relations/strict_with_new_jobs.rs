@@ -24,14 +24,17 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                     jobs: to_strings(vec!["departure", "job1", "job6", "job4", "job8"]),
                     vehicle_id: "my_vehicle_1".to_string(),
                     shift_index: None,
+                    tags: None,
                 },
                 Relation {
                     type_field: RelationType::Strict,
                     jobs: to_strings(vec!["departure", "job2", "job3", "job5", "job7"]),
                     vehicle_id: "my_vehicle_2".to_string(),
                     shift_index: None,
+                    tags: None,
                 },
             ]),
+            hints: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
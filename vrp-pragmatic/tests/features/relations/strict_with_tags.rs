@@ -0,0 +1,50 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_tagged_task(location: Vec<f64>, tag: &str) -> JobTask {
+    JobTask { tag: Some(tag.to_string()), ..create_task(location) }
+}
+
+#[test]
+fn can_use_strict_relation_with_tagged_multi_job_tasks() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job {
+                    deliveries: Some(vec![
+                        create_tagged_task(vec![2., 0.], "near"),
+                        create_tagged_task(vec![10., 0.], "far"),
+                    ]),
+                    ..create_job("multi")
+                },
+                create_delivery_job("job1", vec![5., 0.]),
+            ],
+            relations: Some(vec![Relation {
+                type_field: RelationType::Strict,
+                jobs: to_strings(vec!["departure", "multi", "job1", "multi"]),
+                vehicle_id: "my_vehicle_1".to_string(),
+                shift_index: None,
+                tags: Some(vec![None, Some("far".to_string()), None, Some("near".to_string())]),
+            }]),
+            hints: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.tours.len(), 1);
+    let activities = solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities.iter())
+        .map(|activity| (activity.job_id.as_str(), activity.job_tag.as_ref().map(|tag| tag.as_str())))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        activities,
+        vec![("departure", None), ("multi", Some("far")), ("job1", None), ("multi", Some("near")), ("arrival", None)]
+    );
+}
@@ -16,12 +16,15 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload() {
                 ),
             ],
             relations: Option::None,
+            hints: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
                     start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc() },
                     end: Some(VehiclePlace { time: format_time(100.).to_string(), location: vec![0., 0.].to_loc() }),
+                    max_overtime: None,
+                    floating_start: None,
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
                         times: None,
@@ -23,19 +23,22 @@ can_use_vehicle_with_two_tours_and_two_jobs! {
                     reasons: vec![UnassignedJobReason {
                         code: 3,
                         description: "does not fit into any vehicle due to capacity".to_string()
-                    }]
+                    }],
+                    suggestion: None
                 }
              ]),
 }
 
 fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned: Vec<UnassignedJob>) {
     let problem = Problem {
-        plan: Plan { jobs, relations: Option::None },
+        plan: Plan { jobs, relations: Option::None, hints: None },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
                     start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc() },
                     end: Some(VehiclePlace { time: format_time(100.).to_string(), location: vec![0., 0.].to_loc() }),
+                    max_overtime: None,
+                    floating_start: None,
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
                         times: None,
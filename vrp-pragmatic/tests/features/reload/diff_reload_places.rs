@@ -15,12 +15,15 @@ fn can_use_reloads_with_different_locations() {
                 create_delivery_job("job5", vec![30., 0.]),
             ],
             relations: None,
+            hints: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
                     start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc() },
                     end: Some(VehiclePlace { time: format_time(1000.), location: vec![32., 0.].to_loc() }),
+                    max_overtime: None,
+                    floating_start: None,
                     breaks: None,
                     reloads: Some(vec![
                         VehicleReload {
@@ -12,12 +12,15 @@ fn can_use_multi_dim_capacity() {
                 create_delivery_job_with_demand("job2", vec![2., 0.], vec![1, 1]),
             ],
             relations: None,
+            hints: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
                     start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc() },
                     end: Some(VehiclePlace { time: format_time(100.).to_string(), location: vec![0., 0.].to_loc() }),
+                    max_overtime: None,
+                    floating_start: None,
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
                         times: None,
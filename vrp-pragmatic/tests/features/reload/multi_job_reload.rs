@@ -19,12 +19,15 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                 ),
             ],
             relations: Option::None,
+            hints: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
                     start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc() },
                     end: Some(VehiclePlace { time: format_time(100.).to_string(), location: vec![10., 0.].to_loc() }),
+                    max_overtime: None,
+                    floating_start: None,
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
                         times: None,
@@ -161,21 +164,30 @@ fn can_properly_handle_load_without_capacity_violation() {
                 create_pickup_job_with_demand("job2", vec![67., 0.], vec![2]),
             ],
             relations: None,
+            hints: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                costs: VehicleCosts { fixed: Some(20.0), distance: 0.002, time: 0.003 },
+                costs: VehicleCosts {
+                    fixed: Some(20.0),
+                    distance: 0.002,
+                    time: 0.003,
+                    waiting: None,
+                    overtime: None,
+                    min_duration: None,
+                    per_stop: None,
+                },
                 shifts: vec![VehicleShift {
                     reloads: Some(vec![
                         VehicleReload {
                             times: None,
-                            location: Location { lat: 0.0, lng: 0.0 },
+                            location: Location::Coordinate { lat: 0.0, lng: 0.0 },
                             duration: 2620.0,
                             tag: None,
                         },
                         VehicleReload {
                             times: None,
-                            location: Location { lat: 0.0, lng: 0.0 },
+                            location: Location::Coordinate { lat: 0.0, lng: 0.0 },
                             duration: 2874.0,
                             tag: None,
                         },
@@ -15,12 +15,15 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                 create_pickup_job("p2", vec![5., 0.]),
             ],
             relations: None,
+            hints: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
                     start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc() },
                     end: Some(VehiclePlace { time: format_time(100.).to_string(), location: vec![6., 0.].to_loc() }),
+                    max_overtime: None,
+                    floating_start: None,
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
                         times: None,
@@ -124,6 +127,7 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                     code: 3,
                     description: "does not fit into any vehicle due to capacity".to_string()
                 }],
+                suggestion: None,
             }],
             extras: None,
         }
@@ -8,6 +8,7 @@ fn can_wait_for_job_start() {
         plan: Plan {
             jobs: vec![create_delivery_job_with_skills("job1", vec![1., 0.], vec!["unique_skill".to_string()])],
             relations: Option::None,
+            hints: None,
         },
         fleet: Fleet {
             vehicles: vec![
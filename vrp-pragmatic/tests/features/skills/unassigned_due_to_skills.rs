@@ -8,6 +8,7 @@ fn can_have_unassigned_due_to_missing_vehicle_skill() {
         plan: Plan {
             jobs: vec![create_delivery_job_with_skills("job1", vec![1., 0.], vec!["unique_skill".to_string()])],
             relations: Option::None,
+            hints: None,
         },
         fleet: Fleet {
             vehicles: vec![create_default_vehicle("vehicle_without_skill")],
@@ -31,7 +32,8 @@ fn can_have_unassigned_due_to_missing_vehicle_skill() {
             tours: vec![],
             unassigned: vec![UnassignedJob {
                 job_id: "job1".to_string(),
-                reasons: vec![UnassignedJobReason { code: 1, description: "cannot serve required skill".to_string() }]
+                reasons: vec![UnassignedJobReason { code: 1, description: "cannot serve required skill".to_string() }],
+                suggestion: None
             }],
             extras: None,
         }
@@ -14,6 +14,7 @@ fn can_use_multiple_times() {
                 create_delivery_job_with_times("job5", vec![50., 0.], vec![(40, 50)], 0.),
             ],
             relations: Option::None,
+            hints: None,
         },
         fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
         ..create_empty_problem()
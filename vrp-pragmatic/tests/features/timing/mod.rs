@@ -2,3 +2,5 @@ mod basic_multiple_times;
 mod basic_waiting_time;
 mod strict_leads_to_unassigned;
 mod strict_split_into_two_tours;
+mod time_window_grace;
+mod traffic_factor;
@@ -14,6 +14,7 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
                 create_delivery_job_with_times("job5", vec![50., 0.], vec![(0, 10)], 0.),
             ],
             relations: Option::None,
+            hints: None,
         },
         fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
         ..create_empty_problem()
@@ -97,7 +98,8 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
                 reasons: vec![UnassignedJobReason {
                     code: 2,
                     description: "cannot be visited within time window".to_string()
-                }]
+                }],
+                suggestion: None
             }],
             extras: None,
         },
@@ -14,6 +14,7 @@ fn can_split_into_two_tours_because_of_strict_times() {
                 create_delivery_job_with_times("job5", vec![50., 0.], vec![(50, 60)], 10.),
             ],
             relations: Option::None,
+            hints: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
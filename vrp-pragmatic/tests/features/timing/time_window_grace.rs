@@ -0,0 +1,31 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_use_time_window_grace, (grace, expected_unassigned), {
+    can_use_time_window_grace_impl(grace, expected_unassigned);
+}}
+
+can_use_time_window_grace! {
+    case01_no_grace: (None, 1),
+    case02_zero_grace: (Some(0.), 1),
+    case03_enough_grace: (Some(5.), 0),
+}
+
+fn can_use_time_window_grace_impl(grace: Option<f64>, expected_unassigned: usize) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_times("job1", vec![10., 0.], vec![(0, 9)], 0.)],
+            relations: None,
+            hints: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        config: Some(Config { location_precision: None, time_window_grace: grace, traffic_factor: None }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.unassigned.len(), expected_unassigned);
+    assert_eq!(solution.tours.len(), 1 - expected_unassigned);
+}
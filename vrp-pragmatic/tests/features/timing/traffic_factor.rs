@@ -0,0 +1,31 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_use_traffic_factor, (factor, expected_unassigned), {
+    can_use_traffic_factor_impl(factor, expected_unassigned);
+}}
+
+can_use_traffic_factor! {
+    case01_no_factor: (None, 0),
+    case02_light_traffic: (Some(0.5), 0),
+    case03_heavy_traffic: (Some(2.), 1),
+}
+
+fn can_use_traffic_factor_impl(factor: Option<f64>, expected_unassigned: usize) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_times("job1", vec![10., 0.], vec![(0, 15)], 0.)],
+            relations: None,
+            hints: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        config: Some(Config { location_precision: None, time_window_grace: None, traffic_factor: factor }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.unassigned.len(), expected_unassigned);
+    assert_eq!(solution.tours.len(), 1 - expected_unassigned);
+}
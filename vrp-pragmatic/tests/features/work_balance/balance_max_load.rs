@@ -15,6 +15,7 @@ fn can_balance_max_load() {
                 create_delivery_job("job6", vec![6., 0.]),
             ],
             relations: None,
+            hints: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -28,6 +29,8 @@ fn can_balance_max_load() {
         objectives: Some(Objectives {
             primary: vec![BalanceMaxLoad { options: None }],
             secondary: Some(vec![MinimizeCost]),
+            weights: None,
+            normalizations: None,
         }),
         ..create_empty_problem()
     };
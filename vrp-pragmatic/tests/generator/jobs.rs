@@ -63,6 +63,11 @@ prop_compose! {
             services: None,
             priority,
             skills,
+            required: None,
+            probability: None,
+            unassigned_penalty: None,
+            customer: None,
+            metadata: None,
         }
     }
 }
@@ -74,7 +79,7 @@ pub fn generate_jobs(job_proto: impl Strategy<Value = Job>, range: Range<usize>)
 
 /// Generates job plan.
 pub fn generate_plan(jobs_proto: impl Strategy<Value = Vec<Job>>) -> impl Strategy<Value = Plan> {
-    jobs_proto.prop_map(|jobs| Plan { jobs, relations: None })
+    jobs_proto.prop_map(|jobs| Plan { jobs, relations: None, hints: None })
 }
 
 prop_compose! {
@@ -102,6 +107,11 @@ prop_compose! {
             services,
             priority,
             skills,
+            required: None,
+            probability: None,
+            unassigned_penalty: None,
+            customer: None,
+            metadata: None,
         }
     }
 }
@@ -132,7 +142,7 @@ prop_compose! {
      duration in durations,
      times in time_windows
     ) -> JobPlace {
-      JobPlace { times, location, duration}
+      JobPlace { times, location, duration, durations: None }
     }
 }
 
@@ -33,6 +33,9 @@ prop_compose! {
             capacity,
             skills,
             limits,
+            loaded_profile: None,
+            u_turn: None,
+            must_use: None,
         }
     }
 }
@@ -74,6 +77,7 @@ prop_compose! {
             time,
             duration,
             locations,
+            mandatory: None,
         }
     }
 }
@@ -100,6 +104,8 @@ prop_compose! {
         VehicleShift {
           start: places.0,
           end: places.1,
+          max_overtime: None,
+          floating_start: None,
           breaks,
           reloads
         }
@@ -1,10 +1,10 @@
 use crate::format::problem::*;
-use crate::format::CoordIndex;
+use crate::format::{CoordIndex, Location};
 use crate::format_time;
 use crate::helpers::ToLocation;
 
 pub fn create_job_place(location: Vec<f64>) -> JobPlace {
-    JobPlace { times: None, location: location.to_loc(), duration: 1. }
+    JobPlace { times: None, location: location.to_loc(), duration: 1., durations: None }
 }
 
 pub fn create_task(location: Vec<f64>) -> JobTask {
@@ -20,6 +20,11 @@ pub fn create_job(id: &str) -> Job {
         services: None,
         priority: None,
         skills: None,
+        required: None,
+        probability: None,
+        unassigned_penalty: None,
+        customer: None,
+        metadata: None,
     }
 }
 
@@ -27,6 +32,11 @@ pub fn create_delivery_job(id: &str, location: Vec<f64>) -> Job {
     Job { deliveries: Some(vec![create_task(location.clone())]), ..create_job(id) }
 }
 
+pub fn create_delivery_job_with_reference(id: &str, index: usize) -> Job {
+    let place = JobPlace { location: Location::new_reference(index), ..create_job_place(vec![0., 0.]) };
+    Job { deliveries: Some(vec![JobTask { places: vec![place], ..create_task(vec![0., 0.]) }]), ..create_job(id) }
+}
+
 pub fn create_delivery_job_with_priority(id: &str, location: Vec<f64>, priority: i32) -> Job {
     Job { priority: Some(priority), ..create_delivery_job(id, location) }
 }
@@ -73,6 +83,16 @@ pub fn create_replacement_job(id: &str, location: Vec<f64>) -> Job {
     Job { replacements: Some(vec![create_task(location.clone())]), ..create_job(id) }
 }
 
+pub fn create_replacement_job_with_durations(id: &str, location: Vec<f64>, durations: JobPlaceDurations) -> Job {
+    Job {
+        replacements: Some(vec![JobTask {
+            places: vec![JobPlace { durations: Some(durations), ..create_job_place(location.clone()) }],
+            ..create_task(location)
+        }]),
+        ..create_job(id)
+    }
+}
+
 pub fn create_service_job(id: &str, location: Vec<f64>) -> Job {
     Job { services: Some(vec![JobTask { demand: None, ..create_task(location.clone()) }]), ..create_job(id) }
 }
@@ -149,6 +169,8 @@ pub fn create_default_open_vehicle_shift() -> VehicleShift {
     VehicleShift {
         start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc() },
         end: None,
+        max_overtime: None,
+        floating_start: None,
         breaks: None,
         reloads: None,
     }
@@ -158,13 +180,23 @@ pub fn create_default_vehicle_shift_with_locations(start: (f64, f64), end: (f64,
     VehicleShift {
         start: VehiclePlace { time: format_time(0.), location: vec![start.0, start.1].to_loc() },
         end: Some(VehiclePlace { time: format_time(1000.).to_string(), location: vec![end.0, end.1].to_loc() }),
+        max_overtime: None,
+        floating_start: None,
         breaks: None,
         reloads: None,
     }
 }
 
 pub fn create_default_vehicle_costs() -> VehicleCosts {
-    VehicleCosts { fixed: Some(10.), distance: 1., time: 1. }
+    VehicleCosts {
+        fixed: Some(10.),
+        distance: 1.,
+        time: 1.,
+        waiting: None,
+        overtime: None,
+        min_duration: None,
+        per_stop: None,
+    }
 }
 
 pub fn create_default_vehicle_type() -> VehicleType {
@@ -185,18 +217,22 @@ pub fn create_vehicle_with_capacity(id: &str, capacity: Vec<i32>) -> VehicleType
         capacity,
         skills: None,
         limits: None,
+        loaded_profile: None,
+        u_turn: None,
+        must_use: None,
     }
 }
 
 pub fn create_default_profiles() -> Vec<Profile> {
-    vec![Profile { name: "car".to_string(), profile_type: "car".to_string(), speed: None }]
+    vec![Profile { name: "car".to_string(), profile_type: "car".to_string(), speed: None, road_classes: None }]
 }
 
 pub fn create_empty_problem() -> Problem {
     Problem {
-        plan: Plan { jobs: vec![], relations: None },
+        plan: Plan { jobs: vec![], relations: None, hints: None },
         fleet: Fleet { vehicles: vec![], profiles: vec![] },
         objectives: None,
+        horizon: None,
         config: None,
     }
 }
@@ -212,6 +248,8 @@ pub fn create_matrix(data: Vec<i64>) -> Matrix {
         travel_times: data.clone(),
         distances: data.clone(),
         error_codes: None,
+        fallback: None,
+        avoidance: None,
     }
 }
 
@@ -221,8 +259,12 @@ pub fn create_matrix_from_problem(problem: &Problem) -> Matrix {
     let data: Vec<i64> = unique
         .iter()
         .cloned()
-        .flat_map(|a| {
-            unique.iter().map(move |b| ((a.lat - b.lat).powf(2.) + (a.lng - b.lng).powf(2.)).sqrt().round() as i64)
+        .filter_map(|location| location.to_lat_lng())
+        .flat_map(|(a_lat, a_lng)| {
+            unique
+                .iter()
+                .filter_map(|location| location.to_lat_lng())
+                .map(move |(b_lat, b_lng)| ((a_lat - b_lat).powf(2.) + (a_lng - b_lng).powf(2.)).sqrt().round() as i64)
         })
         .collect();
 
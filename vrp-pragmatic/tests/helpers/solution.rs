@@ -56,7 +56,9 @@ fn create_stop_with_activity_impl(
             activity_type: activity_type.to_string(),
             location: None,
             time: None,
+            load: None,
             job_tag,
+            metadata: None,
         }],
     }
 }
@@ -1,10 +1,11 @@
 use crate::checker::CheckerContext;
 use crate::format::problem::{Matrix, PragmaticProblem, Problem};
-use crate::format::solution::{create_solution, Solution};
+use crate::format::solution::{create_solution, create_solution_with_suggestions, Solution};
 use std::cmp::Ordering::Less;
 use std::sync::Arc;
 use vrp_core::construction::heuristics::InsertionContext;
 use vrp_core::models::Problem as CoreProblem;
+use vrp_core::models::Solution as CoreSolution;
 use vrp_core::solver::mutation::{Recreate, RecreateWithCheapest};
 use vrp_core::solver::RefinementContext;
 use vrp_core::solver::{Builder, DominancePopulation};
@@ -46,6 +47,33 @@ pub fn solve_with_metaheuristic_and_iterations(
     let problem_copy = problem.clone();
     let matrices_copy = matrices.clone();
 
+    let solution = solve_with_metaheuristic_and_iterations_without_check(problem, matrices, generations);
+
+    assert_eq!(CheckerContext::new(problem_copy, matrices_copy, solution.clone()).check().err(), None);
+
+    solution
+}
+
+/// Runs solver with default metaheuristic and specified amount of generations without checking solution.
+pub fn solve_with_metaheuristic_and_iterations_without_check(
+    problem: Problem,
+    matrices: Option<Vec<Matrix>>,
+    generations: usize,
+) -> Solution {
+    solve_with_metaheuristic_and_writer(problem, matrices, generations, create_solution)
+}
+
+/// Runs solver with default metaheuristic and writes solution with suggestions for unassigned jobs.
+pub fn solve_with_metaheuristic_and_suggestions(problem: Problem, matrices: Option<Vec<Matrix>>) -> Solution {
+    solve_with_metaheuristic_and_writer(problem, matrices, 100, create_solution_with_suggestions)
+}
+
+fn solve_with_metaheuristic_and_writer(
+    problem: Problem,
+    matrices: Option<Vec<Matrix>>,
+    generations: usize,
+    writer: fn(&CoreProblem, &CoreSolution) -> Solution,
+) -> Solution {
     let problem = get_core_problem(problem, matrices);
 
     let (solution, _) = Builder::default()
@@ -58,11 +86,7 @@ pub fn solve_with_metaheuristic_and_iterations(
         .solve()
         .unwrap_or_else(|err| panic!("cannot solver problem: {}", err));
 
-    let solution = sort_all_data(create_solution(problem.as_ref(), &solution));
-
-    assert_eq!(CheckerContext::new(problem_copy, matrices_copy, solution.clone()).check().err(), None);
-
-    solution
+    sort_all_data(writer(problem.as_ref(), &solution))
 }
 
 fn get_core_problem(problem: Problem, matrices: Option<Vec<Matrix>>) -> Arc<CoreProblem> {
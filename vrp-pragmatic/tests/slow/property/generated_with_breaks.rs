@@ -83,6 +83,7 @@ prop_compose! {
             plan,
             fleet,
             objectives: None,
+            horizon: None,
             config: None,
         }
     }
@@ -50,10 +50,10 @@ prop_compose! {
         Problem {
             plan: Plan {
                 relations: Some(relations),
-                ..plan
-            },
+                ..plan },
             fleet,
             objectives: None,
+            horizon: None,
             config: None
         }
     }
@@ -43,6 +43,7 @@ prop_compose! {
             plan,
             fleet,
             objectives: None,
+            horizon: None,
             config: None
         }
     }
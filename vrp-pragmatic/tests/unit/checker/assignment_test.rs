@@ -138,9 +138,15 @@ fn check_jobs_impl(
                     services: Some(create_tasks("service", &tasks)),
                     priority: None,
                     skills: None,
+                    required: None,
+                    probability: None,
+                    unassigned_penalty: None,
+                    customer: None,
+                    metadata: None,
                 })
                 .collect(),
             relations: None,
+            hints: None,
         },
         fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: vec![] },
         ..create_empty_problem()
@@ -159,7 +165,7 @@ fn check_jobs_impl(
             .collect(),
         unassigned: unassigned
             .into_iter()
-            .map(|job| UnassignedJob { job_id: job.to_string(), reasons: vec![] })
+            .map(|job| UnassignedJob { job_id: job.to_string(), reasons: vec![], suggestion: None })
             .collect(),
         extras: None,
     };
@@ -25,13 +25,21 @@ fn can_check_breaks_impl(break_times: VehicleBreakTime, expected_result: Result<
         plan: Plan {
             jobs: vec![create_delivery_job("job1", vec![1., 0.]), create_delivery_job("job2", vec![2., 0.])],
             relations: None,
+            hints: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
                     start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc() },
                     end: Some(VehiclePlace { time: format_time(1000.).to_string(), location: vec![0., 0.].to_loc() }),
-                    breaks: Some(vec![VehicleBreak { time: break_times, duration: 0.0, locations: None }]),
+                    max_overtime: None,
+                    floating_start: None,
+                    breaks: Some(vec![VehicleBreak {
+                        time: break_times,
+                        duration: 0.0,
+                        locations: None,
+                        mandatory: None,
+                    }]),
                     reloads: None,
                 }],
                 capacity: vec![5],
@@ -83,14 +91,18 @@ fn can_check_breaks_impl(break_times: VehicleBreakTime, expected_result: Result<
                             activity_type: "delivery".to_string(),
                             location: None,
                             time: None,
+                            load: None,
                             job_tag: None,
+                            metadata: None,
                         },
                         Activity {
                             job_id: "break".to_string(),
                             activity_type: "break".to_string(),
                             location: None,
                             time: None,
+                            load: None,
                             job_tag: None,
+                            metadata: None,
                         },
                     ],
                 },
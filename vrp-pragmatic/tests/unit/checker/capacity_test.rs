@@ -31,12 +31,15 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                 create_pickup_delivery_job("job5", vec![1., 0.], vec![5., 0.]),
             ],
             relations: None,
+            hints: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
                     start: VehiclePlace { time: format_time(0.), location: vec![0., 0.].to_loc() },
                     end: Some(VehiclePlace { time: format_time(1000.).to_string(), location: vec![0., 0.].to_loc() }),
+                    max_overtime: None,
+                    floating_start: None,
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
                         times: None,
@@ -86,14 +89,18 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                             activity_type: "delivery".to_string(),
                             location: None,
                             time: None,
+                            load: None,
                             job_tag: None,
+                            metadata: None,
                         },
                         Activity {
                             job_id: "job5".to_string(),
                             activity_type: "pickup".to_string(),
                             location: None,
                             time: None,
+                            load: None,
                             job_tag: None,
+                            metadata: None,
                         },
                     ],
                 },
@@ -110,7 +117,9 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                         activity_type: "reload".to_string(),
                         location: None,
                         time: None,
+                        load: None,
                         job_tag: None,
+                        metadata: None,
                     }],
                 },
                 Stop {
@@ -130,7 +139,9 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                                 start: "1970-01-01T00:00:08Z".to_string(),
                                 end: "1970-01-01T00:00:09Z".to_string(),
                             }),
+                            load: None,
                             job_tag: None,
+                            metadata: None,
                         },
                         Activity {
                             job_id: "job3".to_string(),
@@ -140,7 +151,9 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                                 start: "1970-01-01T00:00:09Z".to_string(),
                                 end: "1970-01-01T00:00:10Z".to_string(),
                             }),
+                            load: None,
                             job_tag: None,
+                            metadata: None,
                         },
                     ],
                 },
@@ -0,0 +1,56 @@
+use crate::checker::check_constraints;
+use crate::format::problem::*;
+use crate::helpers::*;
+use std::sync::Arc;
+use vrp_core::models::Problem as CoreProblem;
+
+fn create_test_problem(capacity: i32) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![1., 0.]), create_delivery_job("job2", vec![2., 0.])],
+            relations: None,
+            hints: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![capacity])],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_core_problem(problem: Problem) -> Arc<CoreProblem> {
+    let matrix = create_matrix_from_problem(&problem);
+    Arc::new((problem, vec![matrix]).read_pragmatic().ok().unwrap())
+}
+
+#[test]
+fn can_accept_feasible_solution() {
+    let problem = create_test_problem(2);
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = solve_with_cheapest_insertion(problem.clone(), Some(vec![matrix]));
+
+    let result = check_constraints(get_core_problem(problem), &solution);
+
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn can_report_capacity_violation_with_route_and_job() {
+    let problem = create_test_problem(2);
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+    assert_eq!(solution.tours.len(), 1);
+
+    let result = check_constraints(get_core_problem(create_test_problem(1)), &solution);
+
+    let job_id = &solution.tours.first().unwrap().stops.get(2).unwrap().activities.first().unwrap().job_id;
+    assert_eq!(
+        result,
+        Err(vec![format!(
+            "tour 'my_vehicle_1' (shift 0), activity 2 'delivery' of job '{}': constraint violation code 3 \
+             (does not fit into any vehicle due to capacity)",
+            job_id
+        )])
+    );
+}
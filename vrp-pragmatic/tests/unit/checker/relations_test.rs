@@ -13,6 +13,7 @@ mod single {
             jobs: job_ids.iter().map(|id| id.to_string()).collect(),
             vehicle_id: "my_vehicle_1".to_string(),
             shift_index: None,
+            tags: None,
         }
     }
 
@@ -22,6 +23,7 @@ mod single {
             jobs: vec!["job1".to_string()],
             vehicle_id: vehicle_id.to_string(),
             shift_index: None,
+            tags: None,
         }
     }
 
@@ -31,6 +33,7 @@ mod single {
             jobs: vec!["job1".to_string()],
             vehicle_id: "my_vehicle_1".to_string(),
             shift_index: Some(1),
+            tags: None,
         }
     }
 
@@ -77,6 +80,7 @@ mod single {
                     create_pickup_job("job5", vec![5., 0.]),
                 ],
                 relations,
+                hints: None,
             },
             fleet: Fleet {
                 vehicles: vec![VehicleType {
@@ -90,10 +94,13 @@ mod single {
                             time: format_time(1000.).to_string(),
                             location: vec![0., 0.].to_loc(),
                         }),
+                        max_overtime: None,
+                        floating_start: None,
                         breaks: Some(vec![VehicleBreak {
                             time: VehicleBreakTime::TimeWindow(vec![format_time(0.), format_time(1000.)]),
                             duration: 2.0,
                             locations: None,
+                            mandatory: None,
                         }]),
                         reloads: Some(vec![VehicleReload {
                             times: None,
@@ -105,6 +112,9 @@ mod single {
                     capacity: vec![5],
                     skills: None,
                     limits: None,
+                    loaded_profile: None,
+                    u_turn: None,
+                    must_use: None,
                 }],
                 profiles: create_default_profiles(),
             },
@@ -153,14 +163,18 @@ mod single {
                                     activity_type: "delivery".to_string(),
                                     location: None,
                                     time: None,
+                                    load: None,
                                     job_tag: None,
+                                    metadata: None,
                                 },
                                 Activity {
                                     job_id: "break".to_string(),
                                     activity_type: "break".to_string(),
                                     location: None,
                                     time: None,
+                                    load: None,
                                     job_tag: None,
+                                    metadata: None,
                                 },
                             ],
                         },
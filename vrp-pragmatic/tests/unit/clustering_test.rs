@@ -0,0 +1,127 @@
+use super::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job("job2", vec![1., 0.]),
+                create_delivery_job("job3", vec![1., 0.]),
+                create_delivery_job("job4", vec![2., 0.]),
+            ],
+            relations: None,
+            hints: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_aggregate_co_located_jobs() {
+    let (problem, clusters) = aggregate_jobs(&create_test_problem(), 10.);
+
+    assert_eq!(clusters.size(), 1);
+    assert_eq!(
+        clusters.get(&"job1".to_string()).unwrap().iter().map(|job| job.id.as_str()).collect::<Vec<_>>(),
+        vec!["job1", "job2", "job3"]
+    );
+    assert_eq!(problem.plan.jobs.len(), 2);
+
+    let super_job = problem.plan.jobs.first().unwrap();
+    let task = super_job.deliveries.as_ref().unwrap().first().unwrap();
+    assert_eq!(super_job.id, "job1");
+    assert_eq!(task.demand, Some(vec![3]));
+    assert_eq!(task.places.first().unwrap().duration, 3.);
+}
+
+#[test]
+fn can_solve_aggregated_problem_and_expand_solution() {
+    let (problem, clusters) = aggregate_jobs(&create_test_problem(), 10.);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = expand_solution(&solve_with_metaheuristic(problem, Some(vec![matrix])), &clusters);
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.tours.len(), 1);
+    let stop = solution.tours[0].stops.iter().find(|stop| stop.activities.len() > 1).unwrap();
+    assert_eq!(
+        stop.activities.iter().map(|activity| activity.job_id.as_str()).collect::<Vec<_>>(),
+        vec!["job1", "job2", "job3"]
+    );
+    let arrival = parse_time(&stop.time.arrival);
+    assert_eq!(
+        stop.activities.iter().map(|activity| activity.time.clone().unwrap().start).collect::<Vec<_>>(),
+        vec![format_time(arrival), format_time(arrival + 1.), format_time(arrival + 2.)]
+    );
+    assert_eq!(solution.tours[0].stops.iter().flat_map(|stop| stop.activities.iter()).count(), 6);
+}
+
+#[test]
+fn can_aggregate_jobs_with_location_references_using_matrix() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_reference("job1", 0),
+                create_delivery_job_with_reference("job2", 1),
+                create_delivery_job_with_reference("job3", 2),
+            ],
+            relations: None,
+            hints: None,
+        },
+        ..create_test_problem()
+    };
+    let matrix = create_matrix(vec![0, 5, 50, 5, 0, 50, 50, 50, 0]);
+
+    let (_, clusters) = aggregate_jobs(&problem, 10.);
+    assert_eq!(clusters.size(), 0);
+
+    let (problem, clusters) = aggregate_jobs_with_matrix(&problem, &matrix, 10.);
+    assert_eq!(clusters.size(), 1);
+    assert_eq!(clusters.get(&"job1".to_string()).unwrap().len(), 2);
+    assert_eq!(problem.plan.jobs.len(), 2);
+}
+
+parameterized_test! {can_skip_jobs_with_different_properties, job, {
+    can_skip_jobs_with_different_properties_impl(job);
+}}
+
+can_skip_jobs_with_different_properties! {
+    case01_probability: Job { probability: Some(0.5), ..create_delivery_job("job2", vec![1., 0.]) },
+    case02_unassigned_penalty: Job { unassigned_penalty: Some(100.), ..create_delivery_job("job2", vec![1., 0.]) },
+    case03_customer: Job { customer: Some("customer1".to_string()), ..create_delivery_job("job2", vec![1., 0.]) },
+}
+
+fn can_skip_jobs_with_different_properties_impl(job: Job) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![1., 0.]), job], relations: None, hints: None },
+        ..create_test_problem()
+    };
+
+    let (problem, clusters) = aggregate_jobs(&problem, 10.);
+
+    assert_eq!(clusters.size(), 0);
+    assert_eq!(problem.plan.jobs.len(), 2);
+}
+
+#[test]
+fn can_skip_jobs_with_hints() {
+    let problem = Problem {
+        plan: Plan {
+            hints: Some(vec![JobHint { job_id: "job2".to_string(), vehicle_type: None, location: None }]),
+            ..create_test_problem().plan
+        },
+        ..create_test_problem()
+    };
+
+    let (problem, clusters) = aggregate_jobs(&problem, 10.);
+
+    assert_eq!(clusters.size(), 1);
+    assert_eq!(
+        clusters.get(&"job1".to_string()).unwrap().iter().map(|job| job.id.as_str()).collect::<Vec<_>>(),
+        vec!["job1", "job3"]
+    );
+    assert_eq!(problem.plan.jobs.len(), 3);
+}
@@ -5,9 +5,16 @@ use std::sync::Arc;
 use vrp_core::construction::constraints::ConstraintPipeline;
 use vrp_core::construction::heuristics::{RouteContext, RouteState, SolutionContext};
 use vrp_core::models::common::{IdDimension, Location, ValueDimension};
-use vrp_core::models::problem::{Fleet, Single};
+use vrp_core::models::problem::{create_matrix_transport_cost, Fleet, MatrixData, Single, TransportCost};
 use vrp_core::models::solution::Registry;
 
+fn create_transport() -> Arc<dyn TransportCost + Send + Sync> {
+    let size = 4_i32;
+    let data = (0..size).flat_map(|from| (0..size).map(move |to| (from - to).abs() as f64)).collect::<Vec<_>>();
+
+    create_matrix_transport_cost(vec![MatrixData::new(0, data.clone(), data)]).unwrap()
+}
+
 fn create_single(id: &str) -> Arc<Single> {
     let mut single = create_single_with_location(Some(DEFAULT_JOB_LOCATION));
     single.dimens.set_id(id);
@@ -62,7 +69,7 @@ fn can_remove_orphan_break_impl(break_job_loc: Option<Location>, break_activity_
     };
 
     ConstraintPipeline::default()
-        .add_module(Box::new(BreakModule::new(0, None, false)))
+        .add_module(Box::new(BreakModule::new(create_transport(), 0, None, false)))
         .accept_solution_state(&mut solution_ctx);
 
     if break_removed {
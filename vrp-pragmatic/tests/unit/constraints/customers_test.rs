@@ -0,0 +1,98 @@
+use super::*;
+use crate::extensions::create_typed_actor_groups;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::problem::{Fleet, Single};
+use vrp_core::models::solution::TourActivity;
+
+const CODE: i32 = 1;
+
+fn create_fleet() -> Fleet {
+    Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(test_vehicle("v1"))],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    )
+}
+
+fn create_activity(customer: Option<&str>, location: Location) -> TourActivity {
+    let mut single = create_single_with_location(Some(location));
+    if let Some(customer) = customer {
+        single.dimens.set_value("customer", customer.to_string());
+    }
+
+    create_activity_with_job_at_location(Arc::new(single), location)
+}
+
+fn create_route_ctx(fleet: &Fleet, activities: Vec<(Option<&str>, Location)>) -> RouteContext {
+    let activities = activities.into_iter().map(|(customer, location)| create_activity(customer, location)).collect();
+
+    RouteContext {
+        route: Arc::new(create_route_with_activities(fleet, "v1", activities)),
+        state: Arc::new(RouteState::default()),
+    }
+}
+
+parameterized_test! {can_penalize_split_visits, (activities, expected), {
+    can_penalize_split_visits_impl(activities, expected);
+}}
+
+can_penalize_split_visits! {
+    case01_back_to_back: (vec![(Some("a"), 1), (Some("a"), 1), (Some("b"), 2)], 0.),
+    case02_split: (vec![(Some("a"), 1), (Some("b"), 2), (Some("a"), 1)], SPLIT_VISIT_PENALTY),
+    case03_different_locations: (vec![(Some("a"), 1), (Some("a"), 2), (Some("a"), 1)], SPLIT_VISIT_PENALTY),
+    case04_split_twice: (vec![(Some("a"), 1), (None, 3), (Some("a"), 1), (None, 4), (Some("a"), 1)], 2. * SPLIT_VISIT_PENALTY),
+    case05_no_customers: (vec![(None, 1), (None, 2), (None, 1)], 0.),
+}
+
+fn can_penalize_split_visits_impl(activities: Vec<(Option<&str>, Location)>, expected: Cost) {
+    let fleet = create_fleet();
+    let mut route_ctx = create_route_ctx(&fleet, activities);
+
+    CustomerModule::new(CODE, false).accept_route_state(&mut route_ctx);
+
+    assert_eq!(get_extra_cost(&route_ctx), expected);
+    assert_eq!(get_customer_cost(&route_ctx.route), expected);
+}
+
+parameterized_test! {can_evaluate_activity_insertion, (index, target, expected), {
+    can_evaluate_activity_insertion_impl(index, target, expected);
+}}
+
+can_evaluate_activity_insertion! {
+    case01_before_same_customer: (0, (Some("a"), 1), 0),
+    case02_between_same_customer: (1, (Some("a"), 1), 0),
+    case03_after_same_customer: (2, (Some("a"), 1), 0),
+    case04_away_from_same_customer: (3, (Some("a"), 1), 1),
+    case05_other_between_same_customer: (1, (None, 5), 1),
+    case06_other_next_to_customer: (2, (None, 5), 0),
+    case07_new_customer: (3, (Some("c"), 3), 0),
+    case08_same_customer_other_location: (3, (Some("b"), 3), 0),
+    case09_between_and_away: (1, (Some("b"), 2), 2),
+}
+
+fn can_evaluate_activity_insertion_impl(index: usize, target: (Option<&str>, Location), expected: usize) {
+    let fleet = create_fleet();
+    let route_ctx = create_route_ctx(&fleet, vec![(Some("a"), 1), (Some("a"), 1), (Some("b"), 2)]);
+    let target = create_activity(target.0, target.1);
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route.tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(index + 1),
+    };
+
+    let hard_result = CustomerHardActivityConstraint { code: CODE }.evaluate_activity(&route_ctx, &activity_ctx);
+    let soft_result = CustomerSoftActivityConstraint {}.estimate_activity(&route_ctx, &activity_ctx);
+
+    assert_eq!(hard_result.map(|violation| violation.code), if expected > 0 { Some(CODE) } else { None });
+    assert_eq!(soft_result, expected as f64 * SPLIT_VISIT_PENALTY);
+}
+
+#[test]
+fn can_ignore_jobs_without_customer() {
+    let single: Single = create_single_with_location(Some(1));
+    let activity = create_activity_with_job_at_location(Arc::new(single), 1);
+
+    assert_eq!(get_customer_key(&activity), None);
+}
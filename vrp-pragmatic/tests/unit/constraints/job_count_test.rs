@@ -0,0 +1,132 @@
+use super::*;
+use crate::extensions::create_typed_actor_groups;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::common::IdDimension;
+use vrp_core::models::problem::{Fleet, Single};
+use vrp_core::models::solution::Registry;
+
+const CODE: i32 = 1;
+
+fn create_fleet(min_jobs: Option<usize>, max_jobs: Option<usize>) -> Fleet {
+    let mut vehicle = test_vehicle("v1");
+    if let Some(min_jobs) = min_jobs {
+        vehicle.dimens.set_value("min_jobs", min_jobs);
+    }
+    if let Some(max_jobs) = max_jobs {
+        vehicle.dimens.set_value("max_jobs", max_jobs);
+    }
+
+    Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(vehicle)],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    )
+}
+
+fn create_single(id: &str, job_type: Option<&str>) -> Arc<Single> {
+    let mut single = create_single_with_location(Some(DEFAULT_JOB_LOCATION));
+    single.dimens.set_id(id);
+    if let Some(job_type) = job_type {
+        single.dimens.set_value("type", job_type.to_string());
+    }
+
+    Arc::new(single)
+}
+
+fn create_route_ctx(fleet: &Fleet, jobs: usize) -> RouteContext {
+    let activities = (0..jobs)
+        .map(|idx| create_activity_with_job_at_location(create_single(&format!("job{}", idx), None), idx + 1))
+        .chain(std::iter::once(create_activity_with_job_at_location(create_single("break", Some("break")), 1)))
+        .collect();
+
+    let mut route_ctx = RouteContext {
+        route: Arc::new(create_route_with_activities(fleet, "v1", activities)),
+        state: Arc::new(RouteState::default()),
+    };
+    JobCountModule::new(CODE).accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+fn create_solution_ctx(fleet: &Fleet) -> SolutionContext {
+    SolutionContext {
+        required: vec![],
+        ignored: vec![],
+        unassigned: Default::default(),
+        locked: Default::default(),
+        state: Default::default(),
+        routes: vec![],
+        registry: Registry::new(fleet),
+    }
+}
+
+parameterized_test! {can_apply_min_jobs_penalty, (min_jobs, jobs, expected), {
+    can_apply_min_jobs_penalty_impl(min_jobs, jobs, expected);
+}}
+
+can_apply_min_jobs_penalty! {
+    case01: (Some(3), 1, 2. * MISSING_JOB_PENALTY),
+    case02: (Some(3), 2, MISSING_JOB_PENALTY),
+    case03: (Some(3), 3, 0.),
+    case04: (Some(3), 4, 0.),
+    case05: (Some(3), 0, 0.),
+    case06: (None, 1, 0.),
+}
+
+fn can_apply_min_jobs_penalty_impl(min_jobs: Option<usize>, jobs: usize, expected: Cost) {
+    let route_ctx = create_route_ctx(&create_fleet(min_jobs, None), jobs);
+
+    assert_eq!(get_extra_cost(&route_ctx), expected);
+}
+
+parameterized_test! {can_estimate_job_insertion_with_min_jobs, (jobs, job_type, expected), {
+    can_estimate_job_insertion_with_min_jobs_impl(jobs, job_type, expected);
+}}
+
+can_estimate_job_insertion_with_min_jobs! {
+    case01: (0, None, 2. * MISSING_JOB_PENALTY),
+    case02: (1, None, -MISSING_JOB_PENALTY),
+    case03: (2, None, -MISSING_JOB_PENALTY),
+    case04: (3, None, 0.),
+    case05: (1, Some("break"), 0.),
+}
+
+fn can_estimate_job_insertion_with_min_jobs_impl(jobs: usize, job_type: Option<&str>, expected: Cost) {
+    let fleet = create_fleet(Some(3), None);
+    let route_ctx = create_route_ctx(&fleet, jobs);
+    let job = Job::Single(create_single("new", job_type));
+
+    let cost = JobCountSoftRouteConstraint {}.estimate_job(&create_solution_ctx(&fleet), &route_ctx, &job);
+
+    assert_eq!(cost, expected);
+}
+
+parameterized_test! {can_reject_job_with_max_jobs, (max_jobs, jobs, job_type, expected), {
+    can_reject_job_with_max_jobs_impl(max_jobs, jobs, job_type, expected);
+}}
+
+can_reject_job_with_max_jobs! {
+    case01: (Some(2), 1, None, None),
+    case02: (Some(2), 2, None, Some(CODE)),
+    case03: (Some(2), 3, None, Some(CODE)),
+    case04: (Some(2), 2, Some("break"), None),
+    case05: (Some(2), 2, Some("reload"), None),
+    case06: (None, 2, None, None),
+}
+
+fn can_reject_job_with_max_jobs_impl(
+    max_jobs: Option<usize>,
+    jobs: usize,
+    job_type: Option<&str>,
+    expected: Option<i32>,
+) {
+    let fleet = create_fleet(None, max_jobs);
+    let route_ctx = create_route_ctx(&fleet, jobs);
+    let job = Job::Single(create_single("new", job_type));
+
+    let result =
+        JobCountHardRouteConstraint { code: CODE }.evaluate_job(&create_solution_ctx(&fleet), &route_ctx, &job);
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
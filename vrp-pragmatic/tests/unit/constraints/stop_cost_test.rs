@@ -0,0 +1,86 @@
+use super::*;
+use crate::extensions::create_typed_actor_groups;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::common::Location;
+use vrp_core::models::problem::{Fleet, Vehicle};
+use vrp_core::models::solution::TourActivity;
+
+const PER_STOP: Cost = 10.;
+
+fn create_fleet() -> Fleet {
+    let mut vehicle: Vehicle = test_vehicle("v1");
+    vehicle.dimens.set_value("per_stop_cost", PER_STOP);
+
+    Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(vehicle)],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    )
+}
+
+fn create_activity(location: Location) -> TourActivity {
+    create_activity_with_job_at_location(Arc::new(create_single_with_location(Some(location))), location)
+}
+
+fn create_route_ctx(fleet: &Fleet, locations: Vec<Location>) -> RouteContext {
+    RouteContext {
+        route: Arc::new(create_route_with_activities(
+            fleet,
+            "v1",
+            locations.into_iter().map(create_activity).collect(),
+        )),
+        state: Arc::new(RouteState::default()),
+    }
+}
+
+parameterized_test! {can_calculate_stop_cost, (locations, expected), {
+    can_calculate_stop_cost_impl(locations, expected);
+}}
+
+can_calculate_stop_cost! {
+    case01_empty: (vec![], 0.),
+    case02_different_locations: (vec![1, 2, 3], 3. * PER_STOP),
+    case03_same_location: (vec![1, 1, 1], PER_STOP),
+    case04_mixed: (vec![1, 1, 2, 1], 3. * PER_STOP),
+    case05_depot_location: (vec![0, 0], PER_STOP),
+}
+
+fn can_calculate_stop_cost_impl(locations: Vec<Location>, expected: Cost) {
+    let fleet = create_fleet();
+    let mut route_ctx = create_route_ctx(&fleet, locations);
+
+    StopCostModule::new().accept_route_state(&mut route_ctx);
+
+    assert_eq!(get_extra_cost(&route_ctx), expected);
+}
+
+parameterized_test! {can_estimate_activity_insertion, (index, location, expected), {
+    can_estimate_activity_insertion_impl(index, location, expected);
+}}
+
+can_estimate_activity_insertion! {
+    case01_new_location: (1, 5, PER_STOP),
+    case02_same_as_prev: (1, 1, 0.),
+    case03_same_as_next: (1, 2, 0.),
+    case04_split_stop: (2, 5, 2. * PER_STOP),
+    case05_inside_stop: (2, 2, 0.),
+    case06_after_last: (3, 2, 0.),
+    case07_after_last_new: (3, 4, PER_STOP),
+}
+
+fn can_estimate_activity_insertion_impl(index: usize, location: Location, expected: Cost) {
+    let fleet = create_fleet();
+    let route_ctx = create_route_ctx(&fleet, vec![1, 2, 2]);
+    let target = create_activity(location);
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route.tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(index + 1),
+    };
+
+    let result = StopCostSoftActivityConstraint {}.estimate_activity(&route_ctx, &activity_ctx);
+
+    assert_eq!(result, expected);
+}
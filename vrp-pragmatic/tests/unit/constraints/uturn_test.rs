@@ -0,0 +1,104 @@
+use super::*;
+use crate::extensions::create_typed_actor_groups;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::common::Location;
+use vrp_core::models::problem::{create_matrix_transport_cost, Fleet, MatrixData};
+
+const MIN_PROGRESS: f64 = 1.;
+const PENALTY: f64 = 10.;
+
+fn create_transport() -> Arc<dyn TransportCost + Send + Sync> {
+    let size = 4_i32;
+    let data = (0..size).flat_map(|from| (0..size).map(move |to| (from - to).abs() as f64)).collect::<Vec<_>>();
+
+    create_matrix_transport_cost(vec![MatrixData::new(0, data.clone(), data)]).unwrap()
+}
+
+fn create_fleet() -> Fleet {
+    let mut vehicle = test_vehicle("v1");
+    vehicle.dimens.set_value("u_turn", (MIN_PROGRESS, PENALTY));
+
+    Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(vehicle)],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    )
+}
+
+fn create_route(fleet: &Fleet, locations: Vec<Location>) -> Route {
+    create_route_with_activities(
+        fleet,
+        "v1",
+        locations
+            .into_iter()
+            .map(|location| {
+                create_activity_with_job_at_location(Arc::new(create_single_with_location(Some(location))), location)
+            })
+            .collect(),
+    )
+}
+
+parameterized_test! {can_calculate_u_turn_cost, (locations, expected), {
+    can_calculate_u_turn_cost_impl(locations, expected);
+}}
+
+can_calculate_u_turn_cost! {
+    case01: (vec![1, 2, 1, 3], PENALTY),
+    case02: (vec![1, 2, 3], 0.),
+    case03: (vec![1, 1, 2, 3], 0.),
+    case04: (vec![1, 2, 1, 2], 2. * PENALTY),
+}
+
+fn can_calculate_u_turn_cost_impl(locations: Vec<Location>, expected: Cost) {
+    let route = create_route(&create_fleet(), locations);
+
+    let cost = get_u_turn_cost(&route, create_transport().as_ref());
+
+    assert_eq!(cost, expected);
+}
+
+#[test]
+fn can_prefer_forward_progressing_route() {
+    let fleet = create_fleet();
+    let transport = create_transport();
+
+    let with_u_turn = get_u_turn_cost(&create_route(&fleet, vec![1, 2, 1, 3]), transport.as_ref());
+    let without_u_turn = get_u_turn_cost(&create_route(&fleet, vec![1, 2, 3]), transport.as_ref());
+
+    assert!(with_u_turn > without_u_turn);
+}
+
+parameterized_test! {can_estimate_activity_insertion, (index, target_location, expected), {
+    can_estimate_activity_insertion_impl(index, target_location, expected);
+}}
+
+can_estimate_activity_insertion! {
+    case01: (2, 1, PENALTY),
+    case02: (3, 2, PENALTY),
+    case03: (1, 3, PENALTY),
+    case04: (3, 3, 0.),
+    case05: (0, 1, 0.),
+}
+
+fn can_estimate_activity_insertion_impl(index: usize, target_location: Location, expected: Cost) {
+    let route_ctx = RouteContext {
+        route: Arc::new(create_route(&create_fleet(), vec![1, 2, 3])),
+        state: Arc::new(RouteState::default()),
+    };
+    let target = create_activity_with_job_at_location(
+        Arc::new(create_single_with_location(Some(target_location))),
+        target_location,
+    );
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route.tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(index + 1),
+    };
+
+    let cost =
+        UTurnSoftActivityConstraint { transport: create_transport() }.estimate_activity(&route_ctx, &activity_ctx);
+
+    assert_eq!(cost, expected);
+}
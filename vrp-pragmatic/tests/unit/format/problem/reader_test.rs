@@ -1,13 +1,16 @@
 use super::create_approx_matrices;
 use crate::extensions::MultiDimensionalCapacity;
 use crate::format::problem::*;
+use crate::get_unique_locations;
 use crate::helpers::*;
 use std::collections::HashSet;
+use std::io::BufReader;
 use std::iter::FromIterator;
 use std::sync::Arc;
-use vrp_core::construction::constraints::{Demand, DemandDimension};
+use vrp_core::construction::constraints::{CapacityDimension, Demand, DemandDimension};
 use vrp_core::models::common::{Dimensions, IdDimension, TimeSpan, TimeWindow};
 use vrp_core::models::problem::{Jobs, Multi, Place, Single};
+use vrp_core::models::Problem as CoreProblem;
 
 fn get_job(index: usize, jobs: &Jobs) -> vrp_core::models::problem::Job {
     jobs.all().collect::<Vec<_>>().get(index).unwrap().clone()
@@ -70,6 +73,7 @@ fn can_read_complex_problem() {
                             ]),
                             location: vec![52.48325, 13.4436].to_loc(),
                             duration: 100.0,
+                            durations: None,
                         }],
                         demand: Some(vec![0, 1]),
                         tag: Some("my_delivery".to_string()),
@@ -78,6 +82,11 @@ fn can_read_complex_problem() {
                     services: None,
                     priority: None,
                     skills: Some(vec!["unique".to_string()]),
+                    required: None,
+                    probability: None,
+                    unassigned_penalty: None,
+                    customer: None,
+                    metadata: None,
                 },
                 Job {
                     id: "pickup_delivery_job".to_string(),
@@ -89,6 +98,7 @@ fn can_read_complex_problem() {
                             ]]),
                             location: vec![52.48300, 13.4420].to_loc(),
                             duration: 110.0,
+                            durations: None,
                         }],
                         demand: Some(vec![2]),
                         tag: None,
@@ -101,6 +111,7 @@ fn can_read_complex_problem() {
                             ]]),
                             location: vec![52.48325, 13.4436].to_loc(),
                             duration: 120.0,
+                            durations: None,
                         }],
                         demand: Some(vec![2]),
                         tag: None,
@@ -109,6 +120,11 @@ fn can_read_complex_problem() {
                     services: None,
                     priority: None,
                     skills: None,
+                    required: None,
+                    probability: None,
+                    unassigned_penalty: None,
+                    customer: None,
+                    metadata: None,
                 },
                 Job {
                     id: "pickup_job".to_string(),
@@ -121,6 +137,7 @@ fn can_read_complex_problem() {
                             ]]),
                             location: vec![52.48321, 13.4438].to_loc(),
                             duration: 90.0,
+                            durations: None,
                         }],
                         demand: Some(vec![3]),
                         tag: None,
@@ -130,16 +147,30 @@ fn can_read_complex_problem() {
                     services: None,
                     priority: None,
                     skills: Some(vec!["unique2".to_string()]),
+                    required: None,
+                    probability: None,
+                    unassigned_penalty: None,
+                    customer: None,
+                    metadata: None,
                 },
             ],
             relations: Option::None,
+            hints: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 type_id: "my_vehicle".to_string(),
                 vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
                 profile: "car".to_string(),
-                costs: VehicleCosts { fixed: Some(100.), distance: 1., time: 2. },
+                costs: VehicleCosts {
+                    fixed: Some(100.),
+                    distance: 1.,
+                    time: 2.,
+                    waiting: None,
+                    overtime: None,
+                    min_duration: None,
+                    per_stop: None,
+                },
                 shifts: vec![VehicleShift {
                     start: VehiclePlace {
                         time: "1970-01-01T00:00:00Z".to_string(),
@@ -149,6 +180,8 @@ fn can_read_complex_problem() {
                         time: "1970-01-01T00:01:40Z".to_string(),
                         location: vec![52.4862, 13.45148].to_loc(),
                     }),
+                    max_overtime: None,
+                    floating_start: None,
                     breaks: Some(vec![VehicleBreak {
                         time: VehicleBreakTime::TimeWindow(vec![
                             "1970-01-01T00:00:10Z".to_string(),
@@ -156,16 +189,31 @@ fn can_read_complex_problem() {
                         ]),
                         duration: 100.0,
                         locations: Some(vec![vec![52.48315, 13.4330].to_loc()]),
+                        mandatory: None,
                     }]),
                     reloads: None,
                 }],
                 capacity: vec![10, 1],
                 skills: Some(vec!["unique1".to_string(), "unique2".to_string()]),
-                limits: Some(VehicleLimits { max_distance: Some(123.1), shift_time: Some(100.), allowed_areas: None }),
+                limits: Some(VehicleLimits {
+                    max_distance: Some(123.1),
+                    shift_time: Some(100.),
+                    allowed_areas: None,
+                    max_pickups: None,
+                    min_work_before_break: None,
+                    max_breaks: None,
+                    min_break_spacing: None,
+                    min_jobs: None,
+                    max_jobs: None,
+                }),
+                loaded_profile: None,
+                u_turn: None,
+                must_use: None,
             }],
             profiles: create_default_profiles(),
         },
         objectives: None,
+        horizon: None,
         config: None,
     };
     let matrix = Matrix {
@@ -174,6 +222,8 @@ fn can_read_complex_problem() {
         travel_times: vec![1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1],
         distances: vec![2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2],
         error_codes: Option::None,
+        fallback: None,
+        avoidance: None,
     };
 
     let problem = (problem, vec![matrix]).read_pragmatic().ok().unwrap();
@@ -272,14 +322,30 @@ fn can_create_approximation_matrices() {
                 create_delivery_job("job2", vec![52.5165, 13.3808]),
             ],
             relations: None,
+            hints: None,
         },
         fleet: Fleet {
             vehicles: vec![],
             profiles: vec![
-                Profile { name: "car1".to_string(), profile_type: "car".to_string(), speed: Some(8.) },
-                Profile { name: "car2".to_string(), profile_type: "car".to_string(), speed: Some(10.) },
-                Profile { name: "car3".to_string(), profile_type: "car".to_string(), speed: Some(5.) },
-                Profile { name: "car4".to_string(), profile_type: "car".to_string(), speed: None },
+                Profile {
+                    name: "car1".to_string(),
+                    profile_type: "car".to_string(),
+                    speed: Some(8.),
+                    road_classes: None,
+                },
+                Profile {
+                    name: "car2".to_string(),
+                    profile_type: "car".to_string(),
+                    speed: Some(10.),
+                    road_classes: None,
+                },
+                Profile {
+                    name: "car3".to_string(),
+                    profile_type: "car".to_string(),
+                    speed: Some(5.),
+                    road_classes: None,
+                },
+                Profile { name: "car4".to_string(), profile_type: "car".to_string(), speed: None, road_classes: None },
             ],
         },
         ..create_empty_problem()
@@ -298,3 +364,143 @@ fn can_create_approximation_matrices() {
         assert_eq!(matrix.travel_times, &[0, duration, duration, 0]);
     }
 }
+
+#[test]
+fn can_read_waiting_cost_distinct_from_time_cost() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![1., 0.])], relations: None, hints: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: VehicleCosts { time: 2., waiting: Some(0.), ..create_default_vehicle_costs() },
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let problem = (problem, vec![matrix]).read_pragmatic().ok().unwrap();
+
+    let vehicle = problem.fleet.vehicles.first().unwrap();
+    assert_eq!(vehicle.costs.per_driving_time, 2.);
+    assert_eq!(vehicle.costs.per_waiting_time, 0.);
+    assert_eq!(vehicle.costs.per_service_time, 2.);
+}
+
+fn create_problem_with_close_jobs(location_precision: Option<u32>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job("job2", vec![1., 0.]),
+                create_delivery_job("job3", vec![1., 0.]),
+                create_delivery_job("job4", vec![1.00001, 0.]),
+            ],
+            relations: None,
+            hints: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        config: Some(Config { location_precision, time_window_grace: None, traffic_factor: None }),
+        ..create_empty_problem()
+    }
+}
+
+fn get_job_locations(problem: &CoreProblem) -> Vec<Option<usize>> {
+    let mut jobs = problem.jobs.all().map(|job| job.to_single().clone()).collect::<Vec<_>>();
+    jobs.sort_by(|a, b| a.dimens.get_id().cmp(&b.dimens.get_id()));
+
+    jobs.iter().map(|job| get_single_place(job).location).collect()
+}
+
+#[test]
+fn can_deduplicate_identical_job_locations() {
+    let problem = create_problem_with_close_jobs(None);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let problem = (problem, vec![matrix]).read_pragmatic().ok().unwrap();
+
+    assert_eq!(problem.jobs.size(), 4);
+    let locations = get_job_locations(&problem);
+    assert_eq!(locations[0], locations[1]);
+    assert_eq!(locations[0], locations[2]);
+    assert_ne!(locations[0], locations[3]);
+}
+
+#[test]
+fn can_deduplicate_job_locations_with_precision() {
+    let problem = create_problem_with_close_jobs(Some(3));
+    assert_eq!(get_unique_locations(&problem).len(), 2);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let problem = (problem, vec![matrix]).read_pragmatic().ok().unwrap();
+
+    assert_eq!(problem.jobs.size(), 4);
+    let locations = get_job_locations(&problem);
+    assert!(locations.iter().all(|location| *location == locations[0]));
+}
+
+#[test]
+fn can_read_named_capacity_and_demand() {
+    let problem = r#"
+    {
+      "plan": {
+        "jobs": [
+          {
+            "id": "job1",
+            "deliveries": [
+              {
+                "places": [{ "location": { "lat": 1.0, "lng": 0.0 }, "duration": 1.0 }],
+                "demand": { "volume": 5 }
+              }
+            ]
+          }
+        ]
+      },
+      "fleet": {
+        "vehicles": [
+          {
+            "typeId": "my_vehicle",
+            "vehicleIds": ["my_vehicle_1"],
+            "profile": "car",
+            "costs": { "fixed": 10.0, "distance": 1.0, "time": 1.0 },
+            "shifts": [{ "start": { "time": "1970-01-01T00:00:00Z", "location": { "lat": 0.0, "lng": 0.0 } } }],
+            "capacity": { "weight": 10, "volume": 20 }
+          }
+        ],
+        "profiles": [{ "name": "car", "type": "car" }]
+      }
+    }
+    "#;
+
+    let api_problem = deserialize_problem(BufReader::new(problem.as_bytes())).ok().unwrap();
+    assert_eq!(api_problem.plan.jobs[0].deliveries.as_ref().unwrap()[0].demand, Some(vec![5, 0]));
+    assert_eq!(api_problem.fleet.vehicles[0].capacity, vec![20, 10]);
+
+    let problem = problem.to_string().read_pragmatic().ok().unwrap();
+    let vehicle = problem.fleet.vehicles.first().unwrap();
+    assert_eq!(
+        vehicle.dimens.get_capacity().map(|capacity: &MultiDimensionalCapacity| capacity.as_vec()),
+        Some(vec![20, 10])
+    );
+    let job = get_single_job(0, problem.jobs.as_ref());
+    assert_eq!(
+        job.dimens.get_demand().map(|demand: &Demand<MultiDimensionalCapacity>| demand.delivery.0.as_vec()),
+        Some(vec![5, 0])
+    );
+}
+
+#[test]
+fn can_reject_mixed_named_and_positional_capacity() {
+    let problem = SIMPLE_PROBLEM.replacen(
+        r#""capacity": [
+          10
+        ]"#,
+        r#""capacity": { "weight": 10 }"#,
+        1,
+    );
+
+    let result = deserialize_problem(BufReader::new(problem.as_bytes()));
+
+    assert_eq!(result.err().map(|errors| errors.first().unwrap().code.clone()), Some("E0000".to_string()));
+}
@@ -0,0 +1,284 @@
+use super::*;
+use crate::format::problem::PragmaticProblem;
+use crate::helpers::*;
+use vrp_core::models::common::Dimensions;
+use vrp_core::models::problem::Place;
+
+fn create_test_problem() -> ApiProblem {
+    ApiProblem {
+        plan: Plan {
+            jobs: vec![
+                ApiJob {
+                    skills: Some(vec!["skill1".to_string()]),
+                    priority: Some(2),
+                    probability: Some(0.5),
+                    unassigned_penalty: Some(3.),
+                    metadata: Some(vec![("key".to_string(), "value".to_string())].into_iter().collect()),
+                    ..create_delivery_job_with_times("job1", vec![1., 0.], vec![(0, 100), (200, 300)], 10.)
+                },
+                create_pickup_delivery_job_with_params(
+                    "job2",
+                    vec![2],
+                    (vec![2., 0.], 5., vec![(10, 50)]),
+                    (vec![3., 0.], 7., vec![(60, 100)]),
+                ),
+                create_multi_job(
+                    "job3",
+                    vec![((4., 0.), 1., vec![1]), ((5., 0.), 1., vec![1])],
+                    vec![((6., 0.), 1., vec![2])],
+                ),
+                ApiJob { required: Some(true), ..create_replacement_job("job4", vec![7., 0.]) },
+                create_service_job("job5", vec![8., 0.]),
+                create_pickup_job_with_demand("job6", vec![9., 0.], vec![3]),
+            ],
+            relations: Some(vec![
+                Relation {
+                    type_field: RelationType::Strict,
+                    jobs: to_strings(vec!["departure", "job3", "job3", "break"]),
+                    vehicle_id: "type1_1".to_string(),
+                    shift_index: Some(0),
+                    tags: Some(vec![None, Some("2".to_string()), Some("1".to_string()), None]),
+                },
+                Relation {
+                    type_field: RelationType::Any,
+                    jobs: to_strings(vec!["job5", "arrival"]),
+                    vehicle_id: "type2_1".to_string(),
+                    shift_index: None,
+                    tags: None,
+                },
+            ]),
+            hints: Some(vec![JobHint {
+                job_id: "job6".to_string(),
+                vehicle_type: Some("type2".to_string()),
+                location: Some(vec![9., 1.].to_loc()),
+            }]),
+        },
+        fleet: ApiFleet {
+            vehicles: vec![
+                VehicleType {
+                    vehicle_ids: vec!["type1_1".to_string(), "type1_2".to_string()],
+                    costs: VehicleCosts { waiting: Some(0.5), overtime: Some(2.), ..create_default_vehicle_costs() },
+                    shifts: vec![
+                        VehicleShift {
+                            max_overtime: Some(100.),
+                            breaks: Some(vec![
+                                VehicleBreak {
+                                    time: VehicleBreakTime::TimeWindow(vec![format_time(100.), format_time(200.)]),
+                                    duration: 10.,
+                                    locations: Some(vec![vec![1., 1.].to_loc(), vec![2., 1.].to_loc()]),
+                                    mandatory: Some(true),
+                                },
+                                VehicleBreak {
+                                    time: VehicleBreakTime::TimeOffset(vec![300., 400.]),
+                                    duration: 20.,
+                                    locations: None,
+                                    mandatory: None,
+                                },
+                            ]),
+                            ..create_default_vehicle_shift()
+                        },
+                        VehicleShift {
+                            start: VehiclePlace { time: format_time(2000.), location: vec![3., 1.].to_loc() },
+                            end: None,
+                            reloads: Some(vec![VehicleReload {
+                                location: vec![0., 0.].to_loc(),
+                                duration: 30.,
+                                times: Some(vec![vec![format_time(2100.), format_time(2200.)]]),
+                                tag: Some("reload1".to_string()),
+                            }]),
+                            ..create_default_vehicle_shift()
+                        },
+                    ],
+                    skills: Some(vec!["skill1".to_string(), "skill2".to_string()]),
+                    limits: Some(VehicleLimits {
+                        max_distance: Some(1000.),
+                        shift_time: Some(500.),
+                        allowed_areas: None,
+                        max_pickups: Some(2),
+                        min_work_before_break: None,
+                        max_breaks: None,
+                        min_break_spacing: None,
+                        min_jobs: None,
+                        max_jobs: Some(5),
+                    }),
+                    u_turn: Some(VehicleUTurn { min_progress: 1., penalty: 10. }),
+                    ..create_vehicle_with_capacity("type1", vec![10, 5])
+                },
+                VehicleType {
+                    profile: "truck".to_string(),
+                    loaded_profile: Some("car".to_string()),
+                    costs: VehicleCosts { min_duration: Some(100.), ..create_default_vehicle_costs() },
+                    must_use: Some(true),
+                    ..create_vehicle_with_capacity("type2", vec![20, 10])
+                },
+            ],
+            profiles: vec![
+                Profile {
+                    name: "car".to_string(),
+                    profile_type: "car".to_string(),
+                    speed: Some(5.),
+                    road_classes: None,
+                },
+                Profile {
+                    name: "truck".to_string(),
+                    profile_type: "truck".to_string(),
+                    speed: None,
+                    road_classes: None,
+                },
+            ],
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_times(place: &Place) -> Vec<(f64, f64)> {
+    place
+        .times
+        .iter()
+        .map(|time| match time {
+            TimeSpan::Window(tw) => (tw.start, tw.end),
+            TimeSpan::Offset(offset) => (offset.start, offset.end),
+        })
+        .collect()
+}
+
+fn get_string_dimens(dimens: &Dimensions) -> Vec<Option<String>> {
+    vec!["id", "type", "tag", "vehicle_id", "type_id"]
+        .into_iter()
+        .map(|key| dimens.get_value::<String>(key).cloned())
+        .collect()
+}
+
+fn assert_same_singles(original: &Single, restored: &Single) {
+    assert_eq!(get_string_dimens(&original.dimens), get_string_dimens(&restored.dimens));
+    assert_eq!(original.places.len(), restored.places.len());
+    original.places.iter().zip(restored.places.iter()).for_each(|(original, restored)| {
+        assert_eq!(original.location, restored.location);
+        assert_eq!(original.duration, restored.duration);
+        assert_eq!(get_times(original), get_times(restored));
+    });
+
+    let get_demand = |single: &Single| {
+        single.dimens.get_demand().map(|demand: &Demand<MultiDimensionalCapacity>| {
+            vec![demand.pickup.0, demand.pickup.1, demand.delivery.0, demand.delivery.1]
+                .into_iter()
+                .map(|capacity| capacity.as_vec())
+                .collect::<Vec<_>>()
+        })
+    };
+    assert_eq!(get_demand(original), get_demand(restored));
+}
+
+fn assert_same_jobs(original: &Job, restored: &Job) {
+    assert_eq!(get_string_dimens(original.dimens()), get_string_dimens(restored.dimens()));
+    assert_eq!(original.dimens().get_value::<i32>("priority"), restored.dimens().get_value::<i32>("priority"));
+    assert_eq!(
+        original.dimens().get_value::<HashSet<String>>("skills"),
+        restored.dimens().get_value::<HashSet<String>>("skills")
+    );
+
+    match (original, restored) {
+        (Job::Single(original), Job::Single(restored)) => assert_same_singles(original, restored),
+        (Job::Multi(original), Job::Multi(restored)) => {
+            assert_eq!(original.jobs.len(), restored.jobs.len());
+            original.jobs.iter().zip(restored.jobs.iter()).for_each(|(o, r)| assert_same_singles(o, r));
+        }
+        _ => unreachable!("job types are different"),
+    }
+}
+
+fn assert_same_vehicles(original: &Vehicle, restored: &Vehicle) {
+    assert_eq!(get_string_dimens(&original.dimens), get_string_dimens(&restored.dimens));
+    assert_eq!(original.profile, restored.profile);
+    assert_eq!(original.dimens.get_value::<usize>("shift_index"), restored.dimens.get_value::<usize>("shift_index"));
+    assert_eq!(
+        original.dimens.get_capacity().map(|c: &MultiDimensionalCapacity| c.as_vec()),
+        restored.dimens.get_capacity().map(|c: &MultiDimensionalCapacity| c.as_vec())
+    );
+
+    let get_costs = |vehicle: &Vehicle| {
+        let costs = &vehicle.costs;
+        vec![costs.fixed, costs.per_distance, costs.per_driving_time, costs.per_waiting_time, costs.per_service_time]
+    };
+    assert_eq!(get_costs(original), get_costs(restored));
+
+    assert_eq!(original.details.len(), restored.details.len());
+    original.details.iter().zip(restored.details.iter()).for_each(|(original, restored)| {
+        assert_eq!(original.start, restored.start);
+        assert_eq!(original.end, restored.end);
+        assert_eq!(original.time, restored.time);
+    });
+}
+
+#[test]
+fn can_write_problem_which_is_read_back_to_the_same_problem() {
+    let original = create_test_problem().read_pragmatic().unwrap();
+
+    let written = write_pragmatic_problem(&original);
+    let restored = written.clone().read_pragmatic().unwrap();
+
+    assert_eq!(original.jobs.size(), restored.jobs.size());
+    original
+        .jobs
+        .all()
+        .zip(restored.jobs.all())
+        .for_each(|(original, restored)| assert_same_jobs(&original, &restored));
+
+    assert_eq!(original.fleet.vehicles.len(), restored.fleet.vehicles.len());
+    original
+        .fleet
+        .vehicles
+        .iter()
+        .zip(restored.fleet.vehicles.iter())
+        .for_each(|(original, restored)| assert_same_vehicles(original, restored));
+
+    let get_lock_jobs = |problem: &CoreProblem| {
+        let mut jobs = problem
+            .locks
+            .iter()
+            .map(|lock| lock.details.iter().map(|detail| detail.jobs.len()).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        jobs.sort();
+        jobs
+    };
+    assert_eq!(get_lock_jobs(&original), get_lock_jobs(&restored));
+
+    assert_eq!(
+        serde_json::to_string(&written).unwrap(),
+        serde_json::to_string(&write_pragmatic_problem(&restored)).unwrap()
+    );
+}
+
+#[test]
+fn can_write_problem_properties() {
+    let problem = write_pragmatic_problem(&create_test_problem().read_pragmatic().unwrap());
+
+    assert_eq!(problem.plan.jobs.len(), 6);
+    assert_eq!(problem.plan.jobs[2].pickups.as_ref().map(|tasks| tasks.len()), Some(2));
+    assert_eq!(problem.plan.jobs[0].probability, Some(0.5));
+    assert_eq!(problem.plan.jobs[0].unassigned_penalty, Some(3.));
+    assert_eq!(problem.plan.jobs[3].required, Some(true));
+    assert_eq!(problem.plan.hints.as_ref().map(|hints| hints.len()), Some(1));
+
+    let relations = problem.plan.relations.unwrap();
+    assert_eq!(relations.len(), 2);
+    assert_eq!(relations[0].jobs, to_strings(vec!["departure", "job3", "job3", "break"]));
+    assert_eq!(relations[0].tags, Some(vec![None, Some("2".to_string()), Some("1".to_string()), None]));
+    assert_eq!(relations[1].jobs, to_strings(vec!["job5", "arrival"]));
+
+    let vehicle = &problem.fleet.vehicles[0];
+    assert_eq!(vehicle.vehicle_ids, to_strings(vec!["type1_1", "type1_2"]));
+    assert_eq!(vehicle.shifts.len(), 2);
+    assert_eq!(vehicle.shifts[0].max_overtime, Some(100.));
+    assert_eq!(vehicle.shifts[0].breaks.as_ref().map(|breaks| breaks.len()), Some(2));
+    assert_eq!(vehicle.shifts[1].reloads.as_ref().map(|reloads| reloads.len()), Some(1));
+    assert_eq!(vehicle.capacity, vec![10, 5]);
+    assert_eq!(vehicle.limits.as_ref().and_then(|limits| limits.max_distance), Some(1000.));
+    assert_eq!(vehicle.costs.waiting, Some(0.5));
+
+    let vehicle = &problem.fleet.vehicles[1];
+    assert_eq!(vehicle.profile, "truck");
+    assert_eq!(vehicle.loaded_profile, Some("car".to_string()));
+    assert_eq!(vehicle.must_use, Some(true));
+    assert_eq!(problem.fleet.profiles.len(), 2);
+}
@@ -0,0 +1,41 @@
+use super::*;
+use crate::format::solution::Activity;
+use crate::helpers::create_stop_with_activity;
+
+#[test]
+fn can_simplify_collinear_points_to_endpoints() {
+    let points = vec![(0., 0.), (1., 1.), (2., 2.), (3., 3.), (4., 4.)];
+
+    let result = simplify_line(&points, 0.001);
+
+    assert_eq!(result, vec![(0., 0.), (4., 4.)]);
+}
+
+#[test]
+fn can_keep_points_outside_tolerance() {
+    let points = vec![(0., 0.), (1., 0.), (2., 1.), (3., 0.), (4., 0.)];
+
+    assert_eq!(simplify_line(&points, 0.5), vec![(0., 0.), (2., 1.), (4., 0.)]);
+    assert_eq!(simplify_line(&points, 2.), vec![(0., 0.), (4., 0.)]);
+}
+
+#[test]
+fn can_add_activity_metadata_to_stop_point() {
+    let create_activity = |job_id: &str, group: &str| Activity {
+        job_id: job_id.to_string(),
+        activity_type: "delivery".to_string(),
+        location: None,
+        time: None,
+        load: None,
+        job_tag: None,
+        metadata: Some(vec![("group".to_string(), group.to_string())].into_iter().collect()),
+    };
+    let mut stop =
+        create_stop_with_activity("job1", "delivery", (1., 0.), 0, ("1970-01-01T00:00:00Z", "1970-01-01T00:00:01Z"), 1);
+    stop.activities = vec![create_activity("job1", "A"), create_activity("job2", "B")];
+
+    let feature = get_stop_point(0, 1, &stop, "red").unwrap();
+
+    assert_eq!(feature.properties.get("group").cloned(), Some("A,B".to_string()));
+    assert_eq!(feature.properties.get("jobs_ids").cloned(), Some("job1,job2".to_string()));
+}
@@ -0,0 +1,56 @@
+use crate::format::problem::*;
+use crate::format::solution::RouteLoadProfile;
+use crate::helpers::*;
+use std::sync::Arc;
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::utils::DefaultRandom;
+
+#[test]
+fn can_calculate_load_profile_with_reload() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_demand("job1", vec![1., 0.], vec![2]),
+                create_pickup_job_with_demand("job2", vec![2., 0.], vec![1]),
+                create_delivery_job_with_demand("job3", vec![3., 0.], vec![3]),
+                create_pickup_job_with_demand("job4", vec![4., 0.], vec![2]),
+            ],
+            relations: Some(vec![Relation {
+                type_field: RelationType::Strict,
+                jobs: to_strings(vec!["departure", "job1", "job2", "reload", "job3", "job4", "arrival"]),
+                vehicle_id: "my_vehicle_1".to_string(),
+                shift_index: None,
+                tags: None,
+            }]),
+            hints: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    reloads: Some(vec![VehicleReload {
+                        times: None,
+                        location: vec![0., 0.].to_loc(),
+                        duration: 2.0,
+                        tag: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                capacity: vec![5],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let problem = Arc::new((problem, vec![matrix]).read_pragmatic().ok().unwrap());
+    let insertion_ctx = InsertionContext::new(problem.clone(), Arc::new(DefaultRandom::default()));
+    let route = insertion_ctx.solution.routes.first().unwrap().route.as_ref();
+
+    let profile = RouteLoadProfile::new(problem.as_ref(), route);
+
+    assert_eq!(
+        profile.loads().iter().map(|load| load.as_vec()).collect::<Vec<_>>(),
+        vec![vec![2], vec![0], vec![1], vec![3], vec![0], vec![2], vec![0]]
+    );
+}
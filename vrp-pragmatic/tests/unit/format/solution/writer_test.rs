@@ -1,6 +1,13 @@
+use crate::format::coord_index::CoordIndex;
 use crate::format::problem::*;
 use crate::format::solution::*;
 use crate::helpers::*;
+use std::io::BufWriter;
+use std::sync::Arc;
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::solver::mutation::{Recreate, RecreateWithCheapest};
+use vrp_core::solver::{DominancePopulation, RefinementContext};
+use vrp_core::utils::DefaultRandom;
 
 #[test]
 fn can_create_solution() {
@@ -8,6 +15,7 @@ fn can_create_solution() {
         plan: Plan {
             jobs: vec![create_delivery_job("job1", vec![5., 0.]), create_delivery_job("job2", vec![10., 0.])],
             relations: Option::None,
+            hints: None,
         },
         fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
         ..create_empty_problem()
@@ -82,6 +90,7 @@ fn can_merge_activities_in_one_stop() {
         plan: Plan {
             jobs: vec![create_delivery_job("job1", vec![5., 0.]), create_delivery_job("job2", vec![5., 0.])],
             relations: Option::None,
+            hints: None,
         },
         fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
         ..create_empty_problem()
@@ -129,7 +138,9 @@ fn can_merge_activities_in_one_stop() {
                                     start: "1970-01-01T00:00:05Z".to_string(),
                                     end: "1970-01-01T00:00:06Z".to_string()
                                 }),
-                                job_tag: None
+                                load: Some(vec![1]),
+                                job_tag: None,
+                                metadata: None
                             },
                             Activity {
                                 job_id: "job1".to_string(),
@@ -139,7 +150,9 @@ fn can_merge_activities_in_one_stop() {
                                     start: "1970-01-01T00:00:06Z".to_string(),
                                     end: "1970-01-01T00:00:07Z".to_string()
                                 }),
-                                job_tag: None
+                                load: Some(vec![0]),
+                                job_tag: None,
+                                metadata: None
                             }
                         ]
                     },
@@ -164,3 +177,40 @@ fn can_merge_activities_in_one_stop() {
         }
     );
 }
+
+#[test]
+fn can_write_route_sequences() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![5., 0.]), create_delivery_job("job2", vec![10., 0.])],
+            relations: Option::None,
+            hints: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let coord_index = CoordIndex::new(&problem);
+    let core_problem = Arc::new((problem, vec![matrix]).read_pragmatic().ok().unwrap());
+    let random = Arc::new(DefaultRandom::default());
+    let population = Box::new(DominancePopulation::new(core_problem.clone(), random.clone(), 8, 4, 2));
+    let mut refinement_ctx = RefinementContext::new(core_problem.clone(), population, None);
+    let solution = RecreateWithCheapest::default()
+        .run(&mut refinement_ctx, InsertionContext::new(core_problem.clone(), random))
+        .solution
+        .to_solution(core_problem.extras.clone());
+    let mut buffer = Vec::new();
+
+    solution.write_route_sequences(core_problem.as_ref(), BufWriter::new(&mut buffer)).unwrap();
+
+    let sequences: Vec<RouteSequence> = serde_json::from_slice(buffer.as_slice()).unwrap();
+    let get_index = |x: f64| coord_index.get_by_loc(&vec![x, 0.].to_loc()).unwrap();
+    assert_eq!(
+        sequences,
+        vec![RouteSequence {
+            vehicle_id: "my_vehicle_1".to_string(),
+            shift_index: 0,
+            locations: vec![get_index(0.), get_index(10.), get_index(5.), get_index(0.)],
+        }]
+    );
+}
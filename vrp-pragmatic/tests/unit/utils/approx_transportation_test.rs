@@ -4,18 +4,18 @@ use vrp_core::models::problem::{create_matrix_transport_cost, MatrixData};
 
 fn get_test_locations() -> Vec<Location> {
     vec![
-        Location { lat: 52.52599, lng: 13.45413 },
-        Location { lat: 52.5225, lng: 13.4095 },
-        Location { lat: 52.5165, lng: 13.3808 },
+        Location::Coordinate { lat: 52.52599, lng: 13.45413 },
+        Location::Coordinate { lat: 52.5225, lng: 13.4095 },
+        Location::Coordinate { lat: 52.5165, lng: 13.3808 },
     ]
 }
 
 #[test]
 fn can_calculate_distance_between_two_locations() {
-    let l1 = Location { lat: 52.52599, lng: 13.45413 };
-    let l2 = Location { lat: 52.5165, lng: 13.3808 };
+    let l1 = Location::Coordinate { lat: 52.52599, lng: 13.45413 };
+    let l2 = Location::Coordinate { lat: 52.5165, lng: 13.3808 };
 
-    let distance = get_distance(&l1, &l2);
+    let distance = get_haversine_distance(&l1, &l2);
 
     assert_eq!(distance.round(), 5078.);
 }
@@ -24,7 +24,7 @@ fn can_calculate_distance_between_two_locations() {
 fn can_use_approximated_with_matrix_costs() {
     let locations = get_test_locations();
     let speed = 10.;
-    let approx_data = get_approx_transportation(&locations, &[speed]);
+    let approx_data = get_approx_transportation(&locations, &[SpeedModel::new(speed)]);
     assert_eq!(approx_data.len(), 1);
 
     let (durations, distances) = approx_data.first().unwrap();
@@ -42,3 +42,33 @@ fn can_use_approximated_with_matrix_costs() {
         assert_eq!(duration.round(), (distance / speed).round());
     });
 }
+
+#[test]
+fn can_use_higher_speed_for_long_legs_with_road_classes() {
+    let locations = vec![
+        Location::Coordinate { lat: 52.52599, lng: 13.45413 },
+        Location::Coordinate { lat: 52.52599, lng: 13.45613 },
+        Location::Coordinate { lat: 52.52599, lng: 13.65413 },
+    ];
+    let uniform = SpeedModel::new(10.);
+    let classes = SpeedModel::new_with_road_classes(10., vec![(1000., 15.), (10000., 25.)]);
+
+    let approx_data = get_approx_transportation(&locations, &[uniform, classes]);
+    assert_eq!(approx_data.len(), 2);
+
+    let (uniform_durations, distances) = approx_data.first().unwrap();
+    let (class_durations, _) = approx_data.last().unwrap();
+    let get_speed = |durations: &Vec<i64>, index: usize| distances[index] as f64 / durations[index] as f64;
+
+    // short leg is below any road class: the same duration as for uniform speed
+    let short_leg = 1;
+    assert!(distances[short_leg] < 1000);
+    assert_eq!(class_durations[short_leg], uniform_durations[short_leg]);
+
+    // long leg uses the fastest road class
+    let long_leg = 2;
+    assert!(distances[long_leg] > 10000);
+    assert!(class_durations[long_leg] < uniform_durations[long_leg]);
+    assert!(get_speed(class_durations, long_leg) > get_speed(class_durations, short_leg));
+    assert_eq!(get_speed(class_durations, long_leg).round(), 25.);
+}
@@ -0,0 +1,59 @@
+use super::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_problem_with_horizon(horizon: Option<Vec<String>>, job_times: Vec<(i32, i32)>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_times("job1", vec![1., 0.], job_times, 1.)],
+            relations: None,
+            hints: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        horizon,
+        ..create_empty_problem()
+    }
+}
+
+parameterized_test! {can_detect_invalid_horizon, (horizon, expected), {
+    can_detect_invalid_horizon_impl(horizon, expected);
+}}
+
+can_detect_invalid_horizon! {
+    case01: (None, None),
+    case02: (Some(vec![format_time(0.), format_time(1000.)]), None),
+    case03: (Some(vec![format_time(1000.), format_time(0.)]), Some(())),
+    case04: (Some(vec![format_time(0.)]), Some(())),
+    case05: (Some(vec!["2020-01-01".to_string(), format_time(1000.)]), Some(())),
+}
+
+fn can_detect_invalid_horizon_impl(horizon: Option<Vec<String>>, expected: Option<()>) {
+    let problem = create_problem_with_horizon(horizon, vec![(0, 100)]);
+
+    let result = check_e1400_invalid_horizon(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1400".to_string()));
+}
+
+parameterized_test! {can_detect_times_outside_horizon, (job_times, shift_end, expected), {
+    can_detect_times_outside_horizon_impl(job_times, shift_end, expected);
+}}
+
+can_detect_times_outside_horizon! {
+    case01: (vec![(0, 100)], 1000., None),
+    case02: (vec![(0, 100), (500, 2000)], 1000., Some("job 'job1'")),
+    case03: (vec![(0, 100)], 2000., Some("vehicle type 'my_vehicle'")),
+    case04: (vec![(1500, 1600)], 2000., Some("job 'job1', vehicle type 'my_vehicle'")),
+}
+
+fn can_detect_times_outside_horizon_impl(job_times: Vec<(i32, i32)>, shift_end: f64, expected: Option<&str>) {
+    let mut problem = create_problem_with_horizon(Some(vec![format_time(0.), format_time(1000.)]), job_times);
+    problem.fleet.vehicles[0].shifts[0].end.as_mut().unwrap().time = format_time(shift_end);
+
+    let result = check_e1401_times_outside_horizon(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.clone().err().map(|err| err.code), expected.map(|_| "E1401".to_string()));
+    if let Some(expected) = expected {
+        assert!(result.err().unwrap().action.ends_with(expected));
+    }
+}
@@ -20,7 +20,7 @@ can_detect_reserved_ids! {
 
 fn can_detect_reserved_ids_impl(job_id: String, expected: Option<&str>) {
     let problem = Problem {
-        plan: Plan { jobs: vec![create_delivery_job(job_id.as_str(), vec![1., 0.])], relations: None },
+        plan: Plan { jobs: vec![create_delivery_job(job_id.as_str(), vec![1., 0.])], relations: None, hints: None },
         fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], profiles: vec![] },
         ..create_empty_problem()
     };
@@ -46,8 +46,14 @@ fn can_detect_empty_job() {
                 services: None,
                 priority: None,
                 skills: None,
+                required: None,
+                probability: None,
+                unassigned_penalty: None,
+                customer: None,
+                metadata: None,
             }],
             relations: None,
+            hints: None,
         },
         ..create_empty_problem()
     };
@@ -60,7 +66,11 @@ fn can_detect_empty_job() {
 #[test]
 fn can_detect_negative_duration() {
     let problem = Problem {
-        plan: Plan { jobs: vec![create_delivery_job_with_duration("job1", vec![1., 0.], -10.)], relations: None },
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_duration("job1", vec![1., 0.], -10.)],
+            relations: None,
+            hints: None,
+        },
         ..create_empty_problem()
     };
 
@@ -72,7 +82,11 @@ fn can_detect_negative_duration() {
 #[test]
 fn can_detect_negative_demand() {
     let problem = Problem {
-        plan: Plan { jobs: vec![create_delivery_job_with_demand("job1", vec![1., 0.], vec![0, -1])], relations: None },
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_demand("job1", vec![1., 0.], vec![0, -1])],
+            relations: None,
+            hints: None,
+        },
         ..create_empty_problem()
     };
 
@@ -80,3 +94,125 @@ fn can_detect_negative_demand() {
 
     assert_result("E1107", "job1", result);
 }
+
+parameterized_test! {can_detect_durations_in_non_replacement_task, (is_replacement, expected), {
+    can_detect_durations_in_non_replacement_task_impl(is_replacement, expected);
+}}
+
+can_detect_durations_in_non_replacement_task! {
+    case01: (true, None),
+    case02: (false, Some("job1")),
+}
+
+fn can_detect_durations_in_non_replacement_task_impl(is_replacement: bool, expected: Option<&str>) {
+    let durations = JobPlaceDurations { delivery: 5., pickup: 3., policy: None };
+    let job = create_replacement_job_with_durations("job1", vec![1., 0.], durations);
+    let job = if is_replacement { job } else { Job { deliveries: job.replacements, replacements: None, ..job } };
+    let problem = Problem { plan: Plan { jobs: vec![job], relations: None, hints: None }, ..create_empty_problem() };
+
+    let result = check_e1108_durations_in_replacement_only(&ValidationContext::new(&problem, None)).err();
+
+    if let Some(action) = expected {
+        assert_result("E1108", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_hints, (job_id, vehicle_type, expected), {
+    can_detect_invalid_hints_impl(job_id, vehicle_type, expected);
+}}
+
+can_detect_invalid_hints! {
+    case01: ("job1", Some("my_vehicle"), None),
+    case02: ("job1", None, None),
+    case03: ("job2", None, Some("job2")),
+    case04: ("job1", Some("vehicle2"), Some("job1")),
+}
+
+fn can_detect_invalid_hints_impl(job_id: &str, vehicle_type: Option<&str>, expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![1., 0.])],
+            relations: None,
+            hints: Some(vec![JobHint {
+                job_id: job_id.to_string(),
+                vehicle_type: vehicle_type.map(|vehicle_type| vehicle_type.to_string()),
+                location: None,
+            }]),
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: vec![] },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1109_invalid_hints(&ValidationContext::new(&problem, None)).err();
+
+    if let Some(action) = expected {
+        assert_result("E1109", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_probability, (probability, expected), {
+    can_detect_invalid_probability_impl(probability, expected);
+}}
+
+can_detect_invalid_probability! {
+    case01: (None, None),
+    case02: (Some(1.), None),
+    case03: (Some(0.5), None),
+    case04: (Some(0.), Some("job1")),
+    case05: (Some(1.5), Some("job1")),
+    case06: (Some(-0.5), Some("job1")),
+}
+
+fn can_detect_invalid_probability_impl(probability: Option<f64>, expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { probability, ..create_delivery_job("job1", vec![1., 0.]) }],
+            relations: None,
+            hints: None,
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1110_invalid_probability(&ValidationContext::new(&problem, None)).err();
+
+    if let Some(action) = expected {
+        assert_result("E1110", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_unassigned_penalty, (penalty, expected), {
+    can_detect_invalid_unassigned_penalty_impl(penalty, expected);
+}}
+
+can_detect_invalid_unassigned_penalty! {
+    case01: (None, None),
+    case02: (Some(1.), None),
+    case03: (Some(10.5), None),
+    case04: (Some(0.), Some("job1")),
+    case05: (Some(-1.), Some("job1")),
+}
+
+fn can_detect_invalid_unassigned_penalty_impl(unassigned_penalty: Option<f64>, expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { unassigned_penalty, ..create_delivery_job("job1", vec![1., 0.]) }],
+            relations: None,
+            hints: None,
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1111_invalid_unassigned_penalty(&ValidationContext::new(&problem, None)).err();
+
+    if let Some(action) = expected {
+        assert_result("E1111", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
@@ -25,10 +25,10 @@ parameterized_test! {can_detect_empty_objective, (objectives, expected), {
 }}
 
 can_detect_empty_objective! {
-    case01: (Some(Objectives { primary: vec![], secondary: None }), Some(())),
-    case02: (Some(Objectives { primary: vec![min_cost() ], secondary: None}), None),
-    case03: (Some(Objectives { primary: vec![], secondary: Some(vec![]) }), Some(())),
-    case04: (Some(Objectives { primary: vec![], secondary: Some(vec![min_cost() ]) }), None),
+    case01: (Some(Objectives { primary: vec![], secondary: None, weights: None, normalizations: None }), Some(())),
+    case02: (Some(Objectives { primary: vec![min_cost() ], secondary: None, weights: None, normalizations: None }), None),
+    case03: (Some(Objectives { primary: vec![], secondary: Some(vec![]), weights: None, normalizations: None }), Some(())),
+    case04: (Some(Objectives { primary: vec![], secondary: Some(vec![min_cost() ]), weights: None, normalizations: None }), None),
 }
 
 fn can_detect_empty_objective_impl(objectives: Option<Objectives>, expected: Option<()>) {
@@ -46,16 +46,16 @@ parameterized_test! {can_detect_duplicates, (objectives, expected), {
 }}
 
 can_detect_duplicates! {
-    case01: (Some(Objectives { primary: vec![min_cost() ], secondary: None}), None),
-    case02: (Some(Objectives { primary: vec![], secondary: Some(vec![min_cost() ]) }), None),
-    case03: (Some(Objectives { primary: vec![min_cost()], secondary: Some(vec![min_cost() ]) }), Some("minimize-cost".to_owned())),
+    case01: (Some(Objectives { primary: vec![min_cost() ], secondary: None, weights: None, normalizations: None }), None),
+    case02: (Some(Objectives { primary: vec![], secondary: Some(vec![min_cost() ]), weights: None, normalizations: None }), None),
+    case03: (Some(Objectives { primary: vec![min_cost()], secondary: Some(vec![min_cost() ]), weights: None, normalizations: None }), Some("minimize-cost".to_owned())),
     case04: (Some(Objectives {
             primary: vec![min_cost()],
-            secondary: Some(vec![min_cost() ]) }),
+            secondary: Some(vec![min_cost() ]), weights: None, normalizations: None }),
         Some("minimize-cost".to_owned())),
     case05: (Some(Objectives {
             primary: vec![min_cost(), balance_dist(), balance_dist()],
-            secondary: Some(vec![min_cost() ]) }),
+            secondary: Some(vec![min_cost() ]), weights: None, normalizations: None }),
         Some("balance-distance,minimize-cost".to_owned())),
 }
 
@@ -74,10 +74,12 @@ parameterized_test! {can_detect_missing_cost_objective, (objectives, expected),
 }}
 
 can_detect_missing_cost_objective! {
-    case01: (Some(Objectives { primary: vec![min_cost() ], secondary: None}), None),
-    case02: (Some(Objectives { primary: vec![], secondary: Some(vec![min_cost() ]) }), None),
-    case03: (Some(Objectives { primary: vec![balance_dist()], secondary: None }), Some(())),
-    case04: (Some(Objectives { primary: vec![], secondary: Some(vec![balance_dist() ]) }), Some(())),
+    case01: (Some(Objectives { primary: vec![min_cost() ], secondary: None, weights: None, normalizations: None }), None),
+    case02: (Some(Objectives { primary: vec![], secondary: Some(vec![min_cost() ]), weights: None, normalizations: None }), None),
+    case03: (Some(Objectives { primary: vec![balance_dist()], secondary: None, weights: None, normalizations: None }), Some(())),
+    case04: (Some(Objectives { primary: vec![], secondary: Some(vec![balance_dist() ]), weights: None, normalizations: None }), Some(())),
+    case05: (Some(Objectives { primary: vec![MinimizeFleetCost { costs: vec![1.] }], secondary: None, weights: None, normalizations: None }), None),
+    case06: (Some(Objectives { primary: vec![MinimizeExpectedCost { samples: None }], secondary: None, weights: None, normalizations: None }), None),
 }
 
 fn can_detect_missing_cost_objective_impl(objectives: Option<Objectives>, expected: Option<()>) {
@@ -89,3 +91,100 @@ fn can_detect_missing_cost_objective_impl(objectives: Option<Objectives>, expect
 
     assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1602".to_string()));
 }
+
+parameterized_test! {can_detect_invalid_fleet_cost, (costs, expected), {
+    can_detect_invalid_fleet_cost_impl(costs, expected);
+}}
+
+can_detect_invalid_fleet_cost! {
+    case01: (vec![10., 10., 100.], None),
+    case02: (vec![0.], None),
+    case03: (vec![], Some(())),
+    case04: (vec![10., -1.], Some(())),
+}
+
+fn can_detect_invalid_fleet_cost_impl(costs: Vec<f64>, expected: Option<()>) {
+    let problem = Problem {
+        objectives: Some(Objectives {
+            primary: vec![MinimizeFleetCost { costs }, min_cost()],
+            secondary: None,
+            weights: None,
+            normalizations: None,
+        }),
+        ..create_empty_problem()
+    };
+    let ctx = ValidationContext::new(&problem, None);
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1603_invalid_fleet_cost(&objectives);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1603".to_string()));
+}
+
+parameterized_test! {can_detect_invalid_objective_weights, (weights, secondary, expected), {
+    can_detect_invalid_objective_weights_impl(weights, secondary, expected);
+}}
+
+can_detect_invalid_objective_weights! {
+    case01: (None, None, None),
+    case02: (Some(vec![3., 1.]), None, None),
+    case03: (Some(vec![1., 0.]), None, None),
+    case04: (Some(vec![1.]), None, Some(())),
+    case05: (Some(vec![1., -1.]), None, Some(())),
+    case06: (Some(vec![3., 1.]), Some(vec![balance_dist()]), Some(())),
+}
+
+fn can_detect_invalid_objective_weights_impl(
+    weights: Option<Vec<f64>>,
+    secondary: Option<Vec<Objective>>,
+    expected: Option<()>,
+) {
+    let problem = Problem {
+        objectives: Some(Objectives {
+            primary: vec![min_cost(), MinimizeTours],
+            secondary,
+            weights,
+            normalizations: None,
+        }),
+        ..create_empty_problem()
+    };
+
+    let result = check_e1604_invalid_objective_weights(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1604".to_string()));
+}
+
+parameterized_test! {can_detect_invalid_objective_normalizations, (weights, normalizations, expected), {
+    can_detect_invalid_objective_normalizations_impl(weights, normalizations, expected);
+}}
+
+can_detect_invalid_objective_normalizations! {
+    case01: (Some(vec![1., 1.]), None, None),
+    case02: (Some(vec![1., 1.]), Some(vec![None, Some(ObjectiveNormalization::Estimated)]), None),
+    case03: (Some(vec![1., 1.]), Some(vec![Some(ObjectiveNormalization::MinMax { min: 0., max: 10. }),
+                                            Some(ObjectiveNormalization::Reference { scale: 100. })]), None),
+    case04: (None, Some(vec![None, Some(ObjectiveNormalization::Estimated)]), Some(())),
+    case05: (Some(vec![1., 1.]), Some(vec![Some(ObjectiveNormalization::Estimated)]), Some(())),
+    case06: (Some(vec![1., 1.]), Some(vec![None, Some(ObjectiveNormalization::MinMax { min: 10., max: 10. })]), Some(())),
+    case07: (Some(vec![1., 1.]), Some(vec![None, Some(ObjectiveNormalization::Reference { scale: 0. })]), Some(())),
+}
+
+fn can_detect_invalid_objective_normalizations_impl(
+    weights: Option<Vec<f64>>,
+    normalizations: Option<Vec<Option<ObjectiveNormalization>>>,
+    expected: Option<()>,
+) {
+    let problem = Problem {
+        objectives: Some(Objectives {
+            primary: vec![min_cost(), MinimizeTours],
+            secondary: None,
+            weights,
+            normalizations,
+        }),
+        ..create_empty_problem()
+    };
+
+    let result = check_e1605_invalid_objective_normalizations(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1605".to_string()));
+}
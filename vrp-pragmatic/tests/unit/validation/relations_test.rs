@@ -34,7 +34,9 @@ fn can_detect_relation_errors_impl(job_ids: Vec<String>, vehicle_id: String, exp
                 jobs: job_ids,
                 vehicle_id,
                 shift_index: None,
+                tags: None,
             }]),
+            hints: None,
         },
         fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], profiles: vec![] },
         ..create_empty_problem()
@@ -79,7 +81,9 @@ fn can_detect_multi_place_time_window_jobs_impl(relation_type: RelationType, exp
                 jobs: vec!["job1".to_string(), "job2".to_string(), "job3".to_string()],
                 vehicle_id: "vehicle_1".to_string(),
                 shift_index: None,
+                tags: None,
             }]),
+            hints: None,
         },
         fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], profiles: vec![] },
         ..create_empty_problem()
@@ -120,9 +124,11 @@ fn can_detect_multi_vehicle_assignment_impl(relations: Vec<(&str, &str)>, expect
                         jobs: vec![job_id.to_string()],
                         vehicle_id: vehicle_id.to_string(),
                         shift_index: None,
+                        tags: None,
                     })
                     .collect(),
             ),
+            hints: None,
         },
         fleet: Fleet {
             vehicles: vec![create_default_vehicle("car"), create_default_vehicle("truck")],
@@ -142,3 +148,54 @@ fn can_detect_multi_vehicle_assignment_impl(relations: Vec<(&str, &str)>, expect
         _ => panic!(format!("{:?} vs {}", result, expected.is_some())),
     }
 }
+
+parameterized_test! {can_detect_unknown_job_tags, (tags, expected), {
+    can_detect_unknown_job_tags_impl(tags, expected);
+}}
+
+can_detect_unknown_job_tags! {
+    case01: (vec![Some("d1"), None], None),
+    case02: (vec![Some("d1"), Some("d2")], Some("job2")),
+    case03: (vec![Some("d3"), None], Some("job1")),
+    case04: (vec![Some("d1")], Some("job1, job2")),
+}
+
+fn can_detect_unknown_job_tags_impl(tags: Vec<Option<&str>>, expected: Option<&str>) {
+    let create_tagged_task =
+        |location: Vec<f64>, tag: &str| JobTask { tag: Some(tag.to_string()), ..create_task(location) };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job {
+                    deliveries: Some(vec![
+                        create_tagged_task(vec![1., 0.], "d1"),
+                        create_tagged_task(vec![2., 0.], "d2"),
+                    ]),
+                    ..create_job("job1")
+                },
+                create_delivery_job("job2", vec![3., 0.]),
+            ],
+            relations: Some(vec![Relation {
+                type_field: RelationType::Sequence,
+                jobs: vec!["job1".to_string(), "job2".to_string()],
+                vehicle_id: "vehicle_1".to_string(),
+                shift_index: None,
+                tags: Some(tags.into_iter().map(|tag| tag.map(|tag| tag.to_string())).collect()),
+            }]),
+            hints: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], profiles: vec![] },
+        ..create_empty_problem()
+    };
+
+    let result = validate_result(&ValidationContext::new(&problem, None));
+
+    match (&result, &expected) {
+        (Some(error), Some(jobs)) => {
+            assert_eq!(error.code, "E1205");
+            assert!(error.action.contains(jobs))
+        }
+        (None, None) => {}
+        _ => panic!(format!("{:?} vs {}", result, expected.is_some())),
+    }
+}
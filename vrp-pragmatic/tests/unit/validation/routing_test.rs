@@ -1,5 +1,7 @@
 use super::*;
-use crate::helpers::create_empty_problem;
+use crate::format::Location;
+use crate::format_time;
+use crate::helpers::*;
 
 #[test]
 fn can_detect_duplicates() {
@@ -7,8 +9,18 @@ fn can_detect_duplicates() {
         fleet: Fleet {
             vehicles: vec![],
             profiles: vec![
-                Profile { name: "my_vehicle".to_string(), profile_type: "car".to_string(), speed: None },
-                Profile { name: "my_vehicle".to_string(), profile_type: "truck".to_string(), speed: None },
+                Profile {
+                    name: "my_vehicle".to_string(),
+                    profile_type: "car".to_string(),
+                    speed: None,
+                    road_classes: None,
+                },
+                Profile {
+                    name: "my_vehicle".to_string(),
+                    profile_type: "truck".to_string(),
+                    speed: None,
+                    road_classes: None,
+                },
             ],
         },
         ..create_empty_problem()
@@ -29,3 +41,184 @@ fn can_detect_empty_profiles() {
 
     assert_eq!(result.err().map(|err| err.code), Some("E1501".to_string()));
 }
+
+#[test]
+fn can_detect_invalid_matrix_avoidance() {
+    let problem = create_empty_problem();
+    let matrices = vec![Matrix {
+        profile: "car".to_string(),
+        timestamp: None,
+        travel_times: vec![0, 1, 1, 0],
+        distances: vec![0, 1, 1, 0],
+        error_codes: None,
+        fallback: None,
+        avoidance: Some(MatrixAvoidance { multiplier: 2., flags: vec![0, 1] }),
+    }];
+    let ctx = ValidationContext::new(&problem, Some(&matrices));
+
+    let result = check_e1502_invalid_matrix_avoidance(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), Some("E1502".to_string()));
+}
+
+fn create_matrix_with_timestamp(profile: &str, timestamp: Option<f64>) -> Matrix {
+    Matrix {
+        profile: profile.to_string(),
+        timestamp: timestamp.map(format_time),
+        travel_times: vec![0, 1, 1, 0],
+        distances: vec![0, 1, 1, 0],
+        error_codes: None,
+        fallback: None,
+        avoidance: None,
+    }
+}
+
+parameterized_test! {can_detect_inconsistent_matrix_timestamps, (timestamps, expected), {
+    can_detect_inconsistent_matrix_timestamps_impl(timestamps, expected);
+}}
+
+can_detect_inconsistent_matrix_timestamps! {
+    case01: (vec![("car", None), ("truck", None)], None),
+    case02: (vec![("car", Some(0.)), ("car", Some(10.)), ("truck", Some(0.)), ("truck", Some(10.))], None),
+    case03: (vec![("car", Some(0.)), ("car", Some(10.)), ("truck", Some(0.)), ("truck", Some(20.))],
+             Some("'car' misses timestamps: 1970-01-01T00:00:20Z; 'truck' misses timestamps: 1970-01-01T00:00:10Z")),
+    case04: (vec![("car", Some(0.)), ("car", Some(10.)), ("truck", Some(0.))],
+             Some("'truck' has less than two timestamps")),
+    case05: (vec![("car", Some(0.)), ("car", None)], Some("'car' has matrix without valid timestamp")),
+}
+
+fn can_detect_inconsistent_matrix_timestamps_impl(timestamps: Vec<(&str, Option<f64>)>, expected: Option<&str>) {
+    let problem = create_empty_problem();
+    let matrices = timestamps
+        .into_iter()
+        .map(|(profile, timestamp)| create_matrix_with_timestamp(profile, timestamp))
+        .collect::<Vec<_>>();
+    let ctx = ValidationContext::new(&problem, Some(&matrices));
+
+    let result = check_e1503_inconsistent_matrix_timestamps(&ctx);
+
+    assert_eq!(result.clone().err().map(|err| err.code), expected.map(|_| "E1503".to_string()));
+    if let Some(expected) = expected {
+        assert!(result.err().unwrap().action.ends_with(expected));
+    }
+}
+
+parameterized_test! {can_detect_invalid_matrix_fallback, (fallback, expected), {
+    can_detect_invalid_matrix_fallback_impl(fallback, expected);
+}}
+
+can_detect_invalid_matrix_fallback! {
+    case01: (None, None),
+    case02: (Some(MatrixFallback::Approximation), None),
+    case03: (Some(MatrixFallback::Penalty { distance: 100., duration: 10. }), None),
+    case04: (Some(MatrixFallback::Penalty { distance: -1., duration: 10. }), Some(())),
+    case05: (Some(MatrixFallback::Penalty { distance: 100., duration: -1. }), Some(())),
+}
+
+fn can_detect_invalid_matrix_fallback_impl(fallback: Option<MatrixFallback>, expected: Option<()>) {
+    let problem = create_empty_problem();
+    let matrices =
+        vec![Matrix { error_codes: Some(vec![0, 1, 0, 0]), fallback, ..create_matrix_with_timestamp("car", None) }];
+    let ctx = ValidationContext::new(&problem, Some(&matrices));
+
+    let result = check_e1504_invalid_matrix_fallback(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1504".to_string()));
+}
+
+parameterized_test! {can_detect_invalid_road_classes, (road_classes, expected), {
+    can_detect_invalid_road_classes_impl(road_classes, expected);
+}}
+
+can_detect_invalid_road_classes! {
+    case01: (None, None),
+    case02: (Some(vec![(1000., 15.)]), None),
+    case03: (Some(vec![(0., 15.), (1000., 0.)]), Some(())),
+    case04: (Some(vec![(-1., 15.)]), Some(())),
+}
+
+fn can_detect_invalid_road_classes_impl(road_classes: Option<Vec<(f64, f64)>>, expected: Option<()>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![],
+            profiles: vec![Profile {
+                name: "car".to_string(),
+                profile_type: "car".to_string(),
+                speed: None,
+                road_classes: road_classes.map(|classes| {
+                    classes.into_iter().map(|(min_distance, speed)| RoadClass { min_distance, speed }).collect()
+                }),
+            }],
+        },
+        ..create_empty_problem()
+    };
+    let ctx = ValidationContext::new(&problem, None);
+
+    let result = check_e1505_invalid_road_classes(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1505".to_string()));
+}
+
+parameterized_test! {can_detect_invalid_location_references, (locations, matrix_size, expected), {
+    can_detect_invalid_location_references_impl(locations, matrix_size, expected);
+}}
+
+can_detect_invalid_location_references! {
+    case01: (vec![Location::new(1., 0.), Location::new(2., 0.)], None, None),
+    case02: (vec![Location::new_reference(1), Location::new_reference(2)], Some(3), None),
+    case03: (vec![Location::new_reference(1), Location::new(2., 0.)], Some(3), Some(())),
+    case04: (vec![Location::new_reference(1), Location::new_reference(3)], Some(3), Some(())),
+    case05: (vec![Location::new_reference(1), Location::new_reference(2)], None, Some(())),
+}
+
+fn can_detect_invalid_location_references_impl(
+    locations: Vec<Location>,
+    matrix_size: Option<usize>,
+    expected: Option<()>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: locations
+                .into_iter()
+                .enumerate()
+                .map(|(idx, location)| {
+                    let job = create_delivery_job(format!("job{}", idx).as_str(), vec![0., 0.]);
+                    let place = JobPlace { location, ..create_job_place(vec![0., 0.]) };
+                    Job { deliveries: Some(vec![JobTask { places: vec![place], ..create_task(vec![0., 0.]) }]), ..job }
+                })
+                .collect(),
+            relations: None,
+            hints: None,
+        },
+        ..create_empty_problem()
+    };
+    let matrices = matrix_size.map(|size| vec![create_matrix(vec![0; size * size])]);
+    let ctx = ValidationContext::new(&problem, matrices.as_ref());
+
+    let result = check_e1506_invalid_location_references(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1506".to_string()));
+}
+
+parameterized_test! {can_detect_invalid_traffic_factor, (factor, expected), {
+    can_detect_invalid_traffic_factor_impl(factor, expected);
+}}
+
+can_detect_invalid_traffic_factor! {
+    case01: (None, None),
+    case02: (Some(1.5), None),
+    case03: (Some(0.), Some(())),
+    case04: (Some(-1.), Some(())),
+}
+
+fn can_detect_invalid_traffic_factor_impl(factor: Option<f64>, expected: Option<()>) {
+    let problem = Problem {
+        config: Some(Config { location_precision: None, time_window_grace: None, traffic_factor: factor }),
+        ..create_empty_problem()
+    };
+    let ctx = ValidationContext::new(&problem, None);
+
+    let result = check_e1507_invalid_traffic_factor(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1507".to_string()));
+}
@@ -3,7 +3,7 @@ use crate::format::Location;
 use crate::helpers::*;
 
 fn coord(lat: f64, lng: f64) -> Location {
-    Location { lat, lng }
+    Location::Coordinate { lat, lng }
 }
 
 parameterized_test! {can_detect_invalid_area, (allowed_areas, expected), {
@@ -26,7 +26,17 @@ fn can_detect_invalid_area_impl(allowed_areas: Option<Vec<Vec<Location>>>, expec
     let problem = Problem {
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                limits: Some(VehicleLimits { max_distance: None, shift_time: None, allowed_areas }),
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    allowed_areas,
+                    max_pickups: None,
+                    min_work_before_break: None,
+                    max_breaks: None,
+                    min_break_spacing: None,
+                    min_jobs: None,
+                    max_jobs: None,
+                }),
                 ..create_default_vehicle_type()
             }],
             profiles: vec![],
@@ -38,3 +48,202 @@ fn can_detect_invalid_area_impl(allowed_areas: Option<Vec<Vec<Location>>>, expec
 
     assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1305".to_string()));
 }
+
+parameterized_test! {can_detect_unknown_loaded_profile, (loaded_profile, expected), {
+    can_detect_unknown_loaded_profile_impl(loaded_profile, expected);
+}}
+
+can_detect_unknown_loaded_profile! {
+    case01: (None, None),
+    case02: (Some("car"), None),
+    case03: (Some("truck"), Some(())),
+}
+
+fn can_detect_unknown_loaded_profile_impl(loaded_profile: Option<&str>, expected: Option<()>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                loaded_profile: loaded_profile.map(|name| name.to_string()),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1306_vehicle_loaded_profile_exists(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1306".to_string()));
+}
+
+parameterized_test! {can_detect_invalid_min_duration, (min_duration, expected), {
+    can_detect_invalid_min_duration_impl(min_duration, expected);
+}}
+
+can_detect_invalid_min_duration! {
+    case01: (None, None),
+    case02: (Some(0.), None),
+    case03: (Some(7200.), None),
+    case04: (Some(-1.), Some(())),
+}
+
+fn can_detect_invalid_min_duration_impl(min_duration: Option<f64>, expected: Option<()>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: VehicleCosts { min_duration, ..create_default_vehicle_costs() },
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1307_vehicle_min_duration_is_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1307".to_string()));
+}
+
+parameterized_test! {can_detect_invalid_min_work_before_break, (min_work_before_break, expected), {
+    can_detect_invalid_min_work_before_break_impl(min_work_before_break, expected);
+}}
+
+can_detect_invalid_min_work_before_break! {
+    case01: (None, None),
+    case02: (Some(0.), None),
+    case03: (Some(3600.), None),
+    case04: (Some(-1.), Some(())),
+}
+
+fn can_detect_invalid_min_work_before_break_impl(min_work_before_break: Option<f64>, expected: Option<()>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    allowed_areas: None,
+                    max_pickups: None,
+                    min_work_before_break,
+                    max_breaks: None,
+                    min_break_spacing: None,
+                    min_jobs: None,
+                    max_jobs: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1308_vehicle_min_work_before_break_is_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1308".to_string()));
+}
+
+parameterized_test! {can_detect_invalid_job_limits, (min_jobs, max_jobs, expected), {
+    can_detect_invalid_job_limits_impl(min_jobs, max_jobs, expected);
+}}
+
+can_detect_invalid_job_limits! {
+    case01: (None, None, None),
+    case02: (Some(2), Some(3), None),
+    case03: (Some(3), Some(3), None),
+    case04: (Some(3), None, None),
+    case05: (Some(4), Some(3), Some(())),
+    case06: (None, Some(0), Some(())),
+}
+
+fn can_detect_invalid_job_limits_impl(min_jobs: Option<usize>, max_jobs: Option<usize>, expected: Option<()>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    allowed_areas: None,
+                    max_pickups: None,
+                    min_work_before_break: None,
+                    max_breaks: None,
+                    min_break_spacing: None,
+                    min_jobs,
+                    max_jobs,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1309_vehicle_job_limits_are_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1309".to_string()));
+}
+
+parameterized_test! {can_detect_invalid_per_stop_cost, (per_stop, expected), {
+    can_detect_invalid_per_stop_cost_impl(per_stop, expected);
+}}
+
+can_detect_invalid_per_stop_cost! {
+    case01: (None, None),
+    case02: (Some(0.), None),
+    case03: (Some(100.), None),
+    case04: (Some(-1.), Some(())),
+}
+
+fn can_detect_invalid_per_stop_cost_impl(per_stop: Option<f64>, expected: Option<()>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: VehicleCosts { per_stop, ..create_default_vehicle_costs() },
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1310_vehicle_per_stop_cost_is_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1310".to_string()));
+}
+
+parameterized_test! {can_detect_invalid_min_break_spacing, (min_break_spacing, expected), {
+    can_detect_invalid_min_break_spacing_impl(min_break_spacing, expected);
+}}
+
+can_detect_invalid_min_break_spacing! {
+    case01: (None, None),
+    case02: (Some(0.), None),
+    case03: (Some(120.), None),
+    case04: (Some(-1.), Some(())),
+}
+
+fn can_detect_invalid_min_break_spacing_impl(min_break_spacing: Option<f64>, expected: Option<()>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    allowed_areas: None,
+                    max_pickups: None,
+                    min_work_before_break: None,
+                    max_breaks: None,
+                    min_break_spacing,
+                    min_jobs: None,
+                    max_jobs: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1311_vehicle_min_break_spacing_is_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1311".to_string()));
+}
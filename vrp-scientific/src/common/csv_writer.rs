@@ -0,0 +1,68 @@
+#[cfg(test)]
+#[path = "../../tests/unit/common/csv_writer_test.rs"]
+mod csv_writer_test;
+
+use std::io::{BufWriter, Error, Write};
+use vrp_core::construction::constraints::{Demand, DemandDimension};
+use vrp_core::models::common::IdDimension;
+use vrp_core::models::solution::Activity;
+use vrp_core::models::Solution;
+
+/// Writes solution in csv format with header and one row per tour activity, including vehicle
+/// start and end. Every task of multi job has its own row. Load is the vehicle load after the
+/// activity is performed. Unassigned jobs are not written.
+pub fn write_csv_solution<W: Write>(writer: BufWriter<W>, solution: &Solution) -> Result<(), Error> {
+    let mut writer = writer;
+
+    writer.write_all("route_id,vehicle_id,stop_sequence,job_id,arrival,departure,load\n".as_bytes())?;
+
+    for (route, route_id) in solution.routes.iter().zip(1..) {
+        let vehicle_id = route.actor.vehicle.dimens.get_id().cloned().unwrap_or_default();
+
+        let mut load = route
+            .tour
+            .all_activities()
+            .filter_map(|activity| get_demand(activity))
+            .map(|demand| demand.delivery.0)
+            .sum::<i32>();
+
+        for (sequence, activity) in route.tour.all_activities().enumerate() {
+            if let Some(demand) = get_demand(activity) {
+                load += demand.pickup.0 + demand.pickup.1 - demand.delivery.0 - demand.delivery.1;
+            }
+
+            let job_id = activity
+                .retrieve_job()
+                .and_then(|job| job.dimens().get_id().cloned())
+                .map_or_else(String::new, |job_id| escape(job_id.as_str()));
+
+            writer.write_all(
+                format!(
+                    "{},{},{},{},{},{},{}\n",
+                    route_id,
+                    escape(vehicle_id.as_str()),
+                    sequence,
+                    job_id,
+                    activity.schedule.arrival,
+                    activity.schedule.departure,
+                    load
+                )
+                .as_bytes(),
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+fn get_demand(activity: &Activity) -> Option<&Demand<i32>> {
+    activity.job.as_ref().and_then(|job| job.dimens.get_demand())
+}
+
+fn escape(value: &str) -> String {
+    if value.contains(&[',', '"', '\n'][..]) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
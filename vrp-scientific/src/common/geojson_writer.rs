@@ -0,0 +1,115 @@
+#[cfg(test)]
+#[path = "../../tests/unit/common/geojson_writer_test.rs"]
+mod geojson_writer_test;
+
+use std::io::{BufWriter, Error, ErrorKind, Write};
+use vrp_core::models::common::{IdDimension, Location};
+use vrp_core::models::problem::Job;
+use vrp_core::models::Solution;
+
+const COLORS: &[&str] =
+    &["#e6194b", "#3cb44b", "#4363d8", "#f58231", "#911eb4", "#46f0f0", "#f032e6", "#bcf60c", "#008080", "#9a6324"];
+const UNASSIGNED_COLOR: &str = "#808080";
+
+/// Writes solution as geo json feature collection: every route is written as a line string and
+/// every job activity as a point tagged by route index. Unassigned jobs are written as points with
+/// `unassigned` property. Coordinates are taken from the table by location index and written as is,
+/// so they should be specified in (x, y) or (lng, lat) order.
+pub fn write_geojson_solution<W: Write>(
+    writer: BufWriter<W>,
+    solution: &Solution,
+    coordinates: &[(f64, f64)],
+) -> Result<(), Error> {
+    let mut writer = writer;
+
+    let get_coordinate = |location: Location| {
+        coordinates.get(location).map(|&(x, y)| format!("[{},{}]", x, y)).ok_or_else(|| {
+            Error::new(ErrorKind::InvalidInput, format!("Cannot find coordinate for location {}.", location))
+        })
+    };
+
+    let mut features = vec![];
+
+    for (route_idx, route) in solution.routes.iter().enumerate() {
+        let color = COLORS[route_idx % COLORS.len()];
+        let vehicle_id = route.actor.vehicle.dimens.get_id().cloned().unwrap_or_default();
+
+        let line = route
+            .tour
+            .all_activities()
+            .map(|activity| get_coordinate(activity.place.location))
+            .collect::<Result<Vec<_>, _>>()?
+            .join(",");
+
+        features.push(format!(
+            r#"{{"type":"Feature","properties":{{"route_idx":{},"vehicle_id":"{}","stroke":"{}","stroke-width":4}},"geometry":{{"type":"LineString","coordinates":[{}]}}}}"#,
+            route_idx,
+            escape(vehicle_id.as_str()),
+            color,
+            line
+        ));
+
+        for (activity_idx, activity) in route.tour.all_activities().enumerate() {
+            if let Some(job) = activity.retrieve_job() {
+                features.push(format!(
+                    r#"{{"type":"Feature","properties":{{"route_idx":{},"activity_idx":{},"job_id":"{}","marker-color":"{}"}},"geometry":{{"type":"Point","coordinates":{}}}}}"#,
+                    route_idx,
+                    activity_idx,
+                    escape(get_job_id(&job).as_str()),
+                    color,
+                    get_coordinate(activity.place.location)?
+                ));
+            }
+        }
+    }
+
+    let mut unassigned =
+        solution.unassigned.iter().map(|(job, code)| (get_job_id(job), job, *code)).collect::<Vec<_>>();
+    unassigned.sort_by(|(a, ..), (b, ..)| a.cmp(b));
+
+    for (job_id, job, code) in unassigned {
+        let locations = match job {
+            Job::Single(single) => single.places.iter().filter_map(|place| place.location).collect::<Vec<_>>(),
+            Job::Multi(multi) => {
+                multi.jobs.iter().flat_map(|single| single.places.iter().filter_map(|place| place.location)).collect()
+            }
+        };
+
+        for location in locations {
+            features.push(format!(
+                r#"{{"type":"Feature","properties":{{"job_id":"{}","unassigned":true,"code":{},"marker-color":"{}"}},"geometry":{{"type":"Point","coordinates":{}}}}}"#,
+                escape(job_id.as_str()),
+                code,
+                UNASSIGNED_COLOR,
+                get_coordinate(location)?
+            ));
+        }
+    }
+
+    writer.write_all(format!(r#"{{"type":"FeatureCollection","features":[{}]}}"#, features.join(",")).as_bytes())?;
+
+    Ok(())
+}
+
+fn get_job_id(job: &Job) -> String {
+    job.dimens().get_id().cloned().unwrap_or_default()
+}
+
+/// Escapes string value to be written as json string as specified by RFC 8259.
+fn escape(value: &str) -> String {
+    value.chars().fold(String::with_capacity(value.len()), |mut acc, ch| {
+        match ch {
+            '"' => acc.push_str("\\\""),
+            '\\' => acc.push_str("\\\\"),
+            '\n' => acc.push_str("\\n"),
+            '\r' => acc.push_str("\\r"),
+            '\t' => acc.push_str("\\t"),
+            '\u{08}' => acc.push_str("\\b"),
+            '\u{0C}' => acc.push_str("\\f"),
+            ch if (ch as u32) < 0x20 => acc.push_str(format!("\\u{:04x}", ch as u32).as_str()),
+            ch => acc.push(ch),
+        }
+
+        acc
+    })
+}
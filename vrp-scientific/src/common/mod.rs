@@ -4,4 +4,10 @@ mod text_reader;
 pub use self::text_reader::*;
 
 mod text_writer;
-pub use self::text_writer::write_text_solution;
+pub use self::text_writer::{write_text_solution, write_text_solution_with_stats, write_text_solution_with_unassigned};
+
+mod geojson_writer;
+pub use self::geojson_writer::write_geojson_solution;
+
+mod csv_writer;
+pub use self::csv_writer::write_csv_solution;
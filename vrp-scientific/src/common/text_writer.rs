@@ -1,14 +1,94 @@
+#[cfg(test)]
+#[path = "../../tests/unit/common/text_writer_test.rs"]
+mod text_writer_test;
+
 use std::io::{BufWriter, Error, ErrorKind, Write};
-use vrp_core::models::common::IdDimension;
+use vrp_core::construction::constraints::{Demand, DemandDimension};
+use vrp_core::models::common::{Cost, Distance, Duration, IdDimension};
+use vrp_core::models::problem::TransportCost;
+use vrp_core::models::solution::Route;
 use vrp_core::models::Solution;
 
+/// Writes solution in text format. Returns an error if solution has unassigned jobs.
 pub fn write_text_solution<W: Write>(writer: BufWriter<W>, solution: &Solution) -> Result<(), Error> {
-    let mut writer = writer;
-
     if !solution.unassigned.is_empty() {
         return Err(Error::new(ErrorKind::Other, "Cannot write text solution with unassigned jobs."));
     }
 
+    write_text_solution_with_unassigned(writer, solution)
+}
+
+/// Writes solution in text format. Unassigned jobs, if any, are written after routes in a separate
+/// line which starts with `Unassigned:`.
+pub fn write_text_solution_with_unassigned<W: Write>(writer: BufWriter<W>, solution: &Solution) -> Result<(), Error> {
+    let mut writer = writer;
+
+    write_routes(&mut writer, solution)
+}
+
+/// Writes solution in text format together with unassigned jobs and statistics: total distance,
+/// driving duration and delivered load per route, followed by total cost and amount of used
+/// vehicles. Distances, durations and costs are calculated using given transport cost and
+/// activity schedules. Total cost includes fixed and transport costs only.
+pub fn write_text_solution_with_stats<W: Write>(
+    writer: BufWriter<W>,
+    solution: &Solution,
+    transport: &(dyn TransportCost + Send + Sync),
+) -> Result<(), Error> {
+    let mut writer = writer;
+
+    write_routes(&mut writer, solution)?;
+
+    writer.write_all("Statistics\n".as_bytes())?;
+
+    let total_cost = solution.routes.iter().zip(1..).try_fold(Cost::default(), |total_cost, (route, idx)| {
+        let (distance, duration, cost) = get_route_statistics(route, transport);
+        let load = route
+            .tour
+            .all_activities()
+            .filter_map(|activity| activity.job.as_ref().and_then(|job| job.dimens.get_demand()))
+            .map(|demand: &Demand<i32>| demand.delivery.0 + demand.delivery.1)
+            .sum::<i32>();
+
+        writer.write_all(
+            format!("Route {}: distance: {:.2}, duration: {:.2}, load: {}\n", idx, distance, duration, load).as_bytes(),
+        )?;
+
+        Ok::<_, Error>(total_cost + cost)
+    })?;
+
+    writer.write_all(format!("Total: cost: {:.2}, vehicles: {}\n", total_cost, solution.routes.len()).as_bytes())?;
+
+    Ok(())
+}
+
+fn get_route_statistics(route: &Route, transport: &(dyn TransportCost + Send + Sync)) -> (Distance, Duration, Cost) {
+    let actor = route.actor.as_ref();
+    let profile = actor.vehicle.profile;
+    let fixed = actor.driver.costs.fixed + actor.vehicle.costs.fixed;
+
+    let (distance, cost) = route.tour.legs().fold((0., fixed), |(distance, cost), (items, _)| match items {
+        [from, to] => {
+            let (from, departure, to) = (from.place.location, from.schedule.departure, to.place.location);
+
+            (
+                distance + transport.distance(profile, from, to, departure),
+                cost + transport.cost(actor, profile, from, to, departure),
+            )
+        }
+        _ => (distance, cost),
+    });
+
+    // NOTE duration includes waiting and service times, not only travel time
+    let duration = match (route.tour.start(), route.tour.end()) {
+        (Some(start), Some(end)) => end.schedule.arrival - start.schedule.departure,
+        _ => 0.,
+    };
+
+    (distance, duration, cost)
+}
+
+fn write_routes<W: Write>(writer: &mut BufWriter<W>, solution: &Solution) -> Result<(), Error> {
     writer.write_all("Solution\n".as_bytes())?;
 
     solution.routes.iter().zip(1..).for_each(|(r, i)| {
@@ -23,5 +103,13 @@ pub fn write_text_solution<W: Write>(writer: BufWriter<W>, solution: &Solution)
         writer.write_all(format!("Route {}: {}\n", i, customers).as_bytes()).unwrap();
     });
 
+    if !solution.unassigned.is_empty() {
+        let mut unassigned =
+            solution.unassigned.keys().map(|job| job.dimens().get_id().unwrap().clone()).collect::<Vec<_>>();
+        unassigned.sort();
+
+        writer.write_all(format!("Unassigned: {}\n", unassigned.join(" ")).as_bytes())?;
+    }
+
     Ok(())
 }
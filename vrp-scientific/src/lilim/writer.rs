@@ -1,10 +1,22 @@
-use crate::common::write_text_solution;
+use crate::common::{write_text_solution, write_text_solution_with_stats, write_text_solution_with_unassigned};
 use std::io::{BufWriter, Write};
+use vrp_core::models::problem::TransportCost;
 use vrp_core::models::Solution;
 
 /// A trait to write lilim solution.
 pub trait LilimSolution<W: Write> {
     fn write_lilim(&self, writer: BufWriter<W>) -> Result<(), String>;
+
+    /// Writes lilim solution together with unassigned jobs instead of returning an error.
+    fn write_lilim_with_unassigned(&self, writer: BufWriter<W>) -> Result<(), String>;
+
+    /// Writes lilim solution together with unassigned jobs and route statistics calculated using
+    /// given transport cost.
+    fn write_lilim_with_stats(
+        &self,
+        writer: BufWriter<W>,
+        transport: &(dyn TransportCost + Send + Sync),
+    ) -> Result<(), String>;
 }
 
 impl<W: Write> LilimSolution<W> for Solution {
@@ -12,4 +24,16 @@ impl<W: Write> LilimSolution<W> for Solution {
         write_text_solution(writer, &self).map_err(|err| err.to_string())?;
         Ok(())
     }
+
+    fn write_lilim_with_unassigned(&self, writer: BufWriter<W>) -> Result<(), String> {
+        write_text_solution_with_unassigned(writer, self).map_err(|err| err.to_string())
+    }
+
+    fn write_lilim_with_stats(
+        &self,
+        writer: BufWriter<W>,
+        transport: &(dyn TransportCost + Send + Sync),
+    ) -> Result<(), String> {
+        write_text_solution_with_stats(writer, self, transport).map_err(|err| err.to_string())
+    }
 }
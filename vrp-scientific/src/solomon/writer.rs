@@ -1,10 +1,22 @@
-use crate::common::write_text_solution;
+use crate::common::{write_text_solution, write_text_solution_with_stats, write_text_solution_with_unassigned};
 use std::io::{BufWriter, Write};
+use vrp_core::models::problem::TransportCost;
 use vrp_core::models::Solution;
 
 /// A trait to write solomon solution.
 pub trait SolomonSolution<W: Write> {
     fn write_solomon(&self, writer: BufWriter<W>) -> Result<(), String>;
+
+    /// Writes solomon solution together with unassigned jobs instead of returning an error.
+    fn write_solomon_with_unassigned(&self, writer: BufWriter<W>) -> Result<(), String>;
+
+    /// Writes solomon solution together with unassigned jobs and route statistics calculated using
+    /// given transport cost.
+    fn write_solomon_with_stats(
+        &self,
+        writer: BufWriter<W>,
+        transport: &(dyn TransportCost + Send + Sync),
+    ) -> Result<(), String>;
 }
 
 impl<W: Write> SolomonSolution<W> for Solution {
@@ -12,4 +24,16 @@ impl<W: Write> SolomonSolution<W> for Solution {
         write_text_solution(writer, &self).map_err(|err| err.to_string())?;
         Ok(())
     }
+
+    fn write_solomon_with_unassigned(&self, writer: BufWriter<W>) -> Result<(), String> {
+        write_text_solution_with_unassigned(writer, self).map_err(|err| err.to_string())
+    }
+
+    fn write_solomon_with_stats(
+        &self,
+        writer: BufWriter<W>,
+        transport: &(dyn TransportCost + Send + Sync),
+    ) -> Result<(), String> {
+        write_text_solution_with_stats(writer, self, transport).map_err(|err| err.to_string())
+    }
 }
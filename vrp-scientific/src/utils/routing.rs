@@ -1,6 +1,6 @@
 use std::sync::Arc;
 use vrp_core::models::common::Location;
-use vrp_core::models::problem::{create_matrix_transport_cost, MatrixData, TransportCost};
+use vrp_core::models::problem::{EuclideanTransportCost, TransportCost};
 
 pub struct MatrixFactory {
     locations: Vec<(i32, i32)>,
@@ -24,20 +24,8 @@ impl MatrixFactory {
     }
 
     pub fn create_transport(&self) -> Result<Arc<dyn TransportCost + Send + Sync>, String> {
-        let matrix_values = self
-            .locations
-            .iter()
-            .flat_map(|&(x1, y1)| {
-                self.locations.iter().map(move |&(x2, y2)| {
-                    let x = x1 as f64 - x2 as f64;
-                    let y = y1 as f64 - y2 as f64;
-                    (x * x + y * y).sqrt()
-                })
-            })
-            .collect::<Vec<f64>>();
+        let coordinates = self.locations.iter().map(|&(x, y)| (x as f64, y as f64)).collect();
 
-        let matrix_data = MatrixData::new(0, matrix_values.clone(), matrix_values);
-
-        create_matrix_transport_cost(vec![matrix_data])
+        Ok(Arc::new(EuclideanTransportCost::new(coordinates, vec![1.])?))
     }
 }
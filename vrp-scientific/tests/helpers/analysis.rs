@@ -1,8 +1,12 @@
+use std::sync::Arc;
 use vrp_core::construction::constraints::{CapacityDimension, Demand, DemandDimension};
 use vrp_core::construction::heuristics::InsertionContext;
 use vrp_core::models::common::IdDimension;
 use vrp_core::models::problem::Job;
-use vrp_core::models::Problem;
+use vrp_core::models::{Problem, Solution};
+use vrp_core::solver::mutation::{Recreate, RecreateWithCheapest};
+use vrp_core::solver::{DominancePopulation, RefinementContext};
+use vrp_core::utils::DefaultRandom;
 
 pub fn get_customer_id(job: &Job) -> String {
     get_job_id(job).to_owned()
@@ -85,3 +89,16 @@ pub fn get_job_simple_demand(job: &Job) -> &Demand<i32> {
     .get_demand()
     .unwrap()
 }
+
+pub fn solve_with_cheapest_insertion(problem: Arc<Problem>) -> Solution {
+    let mut refinement_ctx = RefinementContext::new(
+        problem.clone(),
+        Box::new(DominancePopulation::new(problem.clone(), Arc::new(DefaultRandom::default()), 4, 2, 2)),
+        None,
+    );
+
+    RecreateWithCheapest::default()
+        .run(&mut refinement_ctx, InsertionContext::new(problem.clone(), Arc::new(DefaultRandom::default())))
+        .solution
+        .to_solution(problem.extras.clone())
+}
@@ -0,0 +1,46 @@
+use crate::common::csv_writer::write_csv_solution;
+use crate::helpers::{create_c101_25_problem, create_lc101_problem, solve_with_cheapest_insertion};
+use std::io::BufWriter;
+use std::sync::Arc;
+use vrp_core::models::Solution;
+
+fn write_solution(solution: &Solution) -> Vec<Vec<String>> {
+    let mut buffer = String::new();
+    let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+
+    write_csv_solution(writer, solution).unwrap();
+
+    buffer.lines().map(|line| line.split(',').map(|value| value.to_string()).collect()).collect()
+}
+
+#[test]
+fn can_write_stops_with_schedule_and_load() {
+    let solution = solve_with_cheapest_insertion(Arc::new(create_c101_25_problem()));
+
+    let rows = write_solution(&solution);
+
+    assert_eq!(rows[0].join(","), "route_id,vehicle_id,stop_sequence,job_id,arrival,departure,load");
+    assert_eq!(rows.len() - 1, solution.routes.iter().map(|route| route.tour.total()).sum::<usize>());
+    rows.iter().skip(1).for_each(|row| {
+        assert_eq!(row.len(), 7);
+        assert!(row[5].parse::<f64>().unwrap() >= row[4].parse::<f64>().unwrap());
+        assert!(row[6].parse::<i32>().unwrap() >= 0);
+    });
+
+    let first_route = rows.iter().skip(1).filter(|row| row[0] == "1").collect::<Vec<_>>();
+    assert_eq!(first_route.first().unwrap()[3], "");
+    assert!(first_route.first().unwrap()[6].parse::<i32>().unwrap() > 0);
+    assert_eq!(first_route.last().unwrap()[6], "0");
+    assert!(first_route.iter().skip(1).any(|row| row[4].parse::<f64>().unwrap() > 0.));
+}
+
+#[test]
+fn can_write_row_per_multi_job_task() {
+    let solution = solve_with_cheapest_insertion(Arc::new(create_lc101_problem()));
+
+    let rows = write_solution(&solution);
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(rows.iter().filter(|row| row[3] == "mlt0").count(), 2);
+    assert_eq!(rows.iter().filter(|row| row[3].starts_with("mlt")).count(), 53 * 2);
+}
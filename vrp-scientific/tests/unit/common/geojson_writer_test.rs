@@ -0,0 +1,82 @@
+use super::escape;
+use crate::common::geojson_writer::write_geojson_solution;
+use crate::common::text_reader::read_init_solution;
+use crate::helpers::{create_c101_100_problem, get_test_resource};
+use std::io::{BufReader, BufWriter};
+use std::sync::Arc;
+use vrp_core::models::Solution;
+
+fn get_solution() -> Solution {
+    let problem = Arc::new(create_c101_100_problem());
+    let file = get_test_resource("../../examples/data/scientific/solomon/C101.100.best.txt").unwrap();
+
+    read_init_solution(BufReader::new(file), problem).expect("Cannot read initial solution")
+}
+
+fn get_coordinates() -> Vec<(f64, f64)> {
+    (0..101).map(|idx| (idx as f64, 0.)).collect()
+}
+
+fn write_solution(solution: &Solution, coordinates: &[(f64, f64)]) -> Result<String, String> {
+    let mut buffer = String::new();
+    let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+
+    write_geojson_solution(writer, solution, coordinates).map_err(|err| err.to_string())?;
+
+    Ok(buffer)
+}
+
+#[test]
+fn can_write_routes_and_stops() {
+    let solution = get_solution();
+    let last_route_jobs = solution.routes.last().unwrap().tour.job_count();
+
+    let geojson = write_solution(&solution, &get_coordinates()).unwrap();
+
+    assert!(geojson.starts_with(r#"{"type":"FeatureCollection","features":["#));
+    assert_eq!(geojson.matches(r#""type":"LineString""#).count(), 10);
+    assert_eq!(geojson.matches(r#""type":"Point""#).count(), 100);
+    assert_eq!(geojson.matches(r#""route_idx":9,"activity_idx""#).count(), last_route_jobs);
+    assert_eq!(geojson.matches(r#""unassigned":true"#).count(), 0);
+}
+
+#[test]
+fn can_write_unassigned_jobs() {
+    let mut solution = get_solution();
+    let route = solution.routes.pop().unwrap();
+    let unassigned_jobs = route.tour.job_count();
+    route.tour.jobs().for_each(|job| {
+        solution.unassigned.insert(job, 1);
+    });
+
+    let geojson = write_solution(&solution, &get_coordinates()).unwrap();
+
+    assert_eq!(geojson.matches(r#""type":"LineString""#).count(), 9);
+    assert_eq!(geojson.matches(r#""type":"Point""#).count(), 100);
+    assert_eq!(geojson.matches(r#""unassigned":true,"code":1"#).count(), unassigned_jobs);
+}
+
+#[test]
+fn can_return_error_when_coordinate_is_missing() {
+    let solution = get_solution();
+
+    let result = write_solution(&solution, &get_coordinates()[..10]);
+
+    assert!(result.is_err());
+}
+
+parameterized_test! {can_escape_json_string, (value, expected), {
+    can_escape_json_string_impl(value, expected);
+}}
+
+can_escape_json_string! {
+    case01_plain: ("job1", "job1"),
+    case02_quote_and_backslash: ("a\"b\\c", "a\\\"b\\\\c"),
+    case03_short_escapes: ("a\nb\rc\td\u{08}e\u{0C}", "a\\nb\\rc\\td\\be\\f"),
+    case04_other_control: ("a\u{00}b\u{1F}", "a\\u0000b\\u001f"),
+    case05_non_ascii: ("\u{7F}é", "\u{7F}é"),
+}
+
+fn can_escape_json_string_impl(value: &str, expected: &str) {
+    assert_eq!(escape(value), expected);
+}
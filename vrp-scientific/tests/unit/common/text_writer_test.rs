@@ -0,0 +1,90 @@
+use crate::common::text_reader::read_init_solution;
+use crate::common::text_writer::*;
+use crate::helpers::{
+    create_c101_100_problem, create_c101_25_problem, get_test_resource, solve_with_cheapest_insertion,
+};
+use std::io::{BufReader, BufWriter, Error};
+use std::sync::Arc;
+use vrp_core::models::Solution;
+
+fn get_solution_with_unassigned() -> Solution {
+    let problem = Arc::new(create_c101_100_problem());
+    let file = get_test_resource("../../examples/data/scientific/solomon/C101.100.best.txt").unwrap();
+    let mut solution = read_init_solution(BufReader::new(file), problem).expect("Cannot read initial solution");
+
+    let route = solution.routes.remove(0);
+    route.tour.jobs().for_each(|job| {
+        solution.unassigned.insert(job, 0);
+    });
+
+    solution
+}
+
+fn write_solution(solution: &Solution, is_strict: bool) -> Result<String, Error> {
+    let mut buffer = String::new();
+    let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+
+    if is_strict {
+        write_text_solution(writer, solution)?;
+    } else {
+        write_text_solution_with_unassigned(writer, solution)?;
+    }
+
+    Ok(buffer)
+}
+
+#[test]
+fn can_return_error_for_unassigned_jobs_in_strict_mode() {
+    let solution = get_solution_with_unassigned();
+
+    let result = write_solution(&solution, true);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn can_write_unassigned_jobs() {
+    let solution = get_solution_with_unassigned();
+
+    let result = write_solution(&solution, false).unwrap();
+
+    let lines = result.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 11);
+    assert_eq!(lines.first().unwrap(), &"Solution");
+    assert_eq!(lines.iter().filter(|line| line.starts_with("Route ")).count(), 9);
+    assert_eq!(lines.last().unwrap(), &"Unassigned: 70 71 73 76 77 78 79 80 81");
+}
+
+#[test]
+fn can_write_solution_without_unassigned_jobs() {
+    let mut solution = get_solution_with_unassigned();
+    solution.unassigned.clear();
+
+    let strict = write_solution(&solution, true).unwrap();
+    let lenient = write_solution(&solution, false).unwrap();
+
+    assert_eq!(strict, lenient);
+    assert!(!strict.contains("Unassigned"));
+}
+
+#[test]
+fn can_write_route_statistics() {
+    let problem = Arc::new(create_c101_25_problem());
+    let solution = solve_with_cheapest_insertion(problem.clone());
+    let mut buffer = String::new();
+    let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+
+    write_text_solution_with_stats(writer, &solution, problem.transport.as_ref()).unwrap();
+
+    let lines = buffer.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 1 + 3 + 1 + 3 + 1);
+    assert_eq!(lines[4], "Statistics");
+    lines[5..8].iter().zip(1..).for_each(|(line, idx)| {
+        assert!(line.starts_with(format!("Route {}: distance: ", idx).as_str()));
+        assert!(line.contains(", duration: "));
+        assert!(!line.ends_with("load: 0"));
+    });
+    assert_eq!(lines[5], "Route 1: distance: 76.24, duration: 1029.04, load: 160");
+    assert_eq!(lines[7], "Route 3: distance: 83.86, duration: 533.86, load: 120");
+    assert_eq!(lines[8], "Total: cost: 259.15, vehicles: 3");
+}